@@ -521,6 +521,11 @@ pub(crate) fn install_canvas_events(runner_ref: &WebRunner) -> Result<(), JsValu
 
     runner_ref.add_event_listener(&canvas, "dragover", |event: web_sys::DragEvent, runner| {
         if let Some(data_transfer) = event.data_transfer() {
+            runner.input.raw.hovered_files_pos = Some(pos_from_mouse_event(
+                runner.canvas(),
+                &event,
+                runner.egui_ctx(),
+            ));
             runner.input.raw.hovered_files.clear();
             for i in 0..data_transfer.items().length() {
                 if let Some(item) = data_transfer.items().get(i) {
@@ -538,6 +543,7 @@ pub(crate) fn install_canvas_events(runner_ref: &WebRunner) -> Result<(), JsValu
 
     runner_ref.add_event_listener(&canvas, "dragleave", |event: web_sys::DragEvent, runner| {
         runner.input.raw.hovered_files.clear();
+        runner.input.raw.hovered_files_pos = None;
         runner.needs_repaint.repaint_asap();
         event.stop_propagation();
         event.prevent_default();
@@ -550,6 +556,7 @@ pub(crate) fn install_canvas_events(runner_ref: &WebRunner) -> Result<(), JsValu
             if let Some(data_transfer) = event.data_transfer() {
                 // TODO(https://github.com/emilk/egui/issues/3702): support dropping folders
                 runner.input.raw.hovered_files.clear();
+                runner.input.raw.hovered_files_pos = None;
                 runner.needs_repaint.repaint_asap();
 
                 if let Some(files) = data_transfer.files() {
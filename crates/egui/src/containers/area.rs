@@ -332,6 +332,50 @@ impl Area {
 
             if movable && move_response.dragged() {
                 state.pivot_pos += move_response.drag_delta();
+
+                let interaction = ctx.style().interaction.clone();
+                if interaction.window_snapping {
+                    // Snap from where the user is actually dragging, so we don't get stuck:
+                    let unsnapped_id = interact_id.with("unsnapped_pos");
+                    let unsnapped_pos = ctx
+                        .data(|d| d.get_temp::<Pos2>(unsnapped_id))
+                        .filter(|_| !move_response.drag_started())
+                        .map_or(state.pivot_pos, |pos| pos + move_response.drag_delta());
+                    ctx.data_mut(|d| d.insert_temp(unsnapped_id, unsnapped_pos));
+                    state.pivot_pos = unsnapped_pos;
+
+                    let other_areas: Vec<Rect> = ctx.memory(|mem| {
+                        let areas = mem.areas();
+                        areas
+                            .order()
+                            .iter()
+                            .filter(|layer| {
+                                layer.order == order
+                                    && **layer != layer_id
+                                    && areas.visible_last_frame(layer)
+                            })
+                            .filter_map(|layer| areas.get(layer.id).map(|state| state.rect()))
+                            .collect()
+                    });
+                    let containers = [
+                        constrain_rect.unwrap_or_else(|| ctx.screen_rect()),
+                        ctx.available_rect(),
+                    ];
+
+                    let (offset, hints) = snap_rect(
+                        state.rect(),
+                        &other_areas,
+                        &containers,
+                        interaction.window_snap_distance,
+                    );
+                    state.pivot_pos += offset;
+
+                    let painter = ctx.layer_painter(LayerId::new(Order::Tooltip, interact_id));
+                    let stroke = ctx.style().visuals.selection.stroke;
+                    for hint in hints {
+                        painter.line_segment(hint, stroke);
+                    }
+                }
             }
 
             if (move_response.dragged() || move_response.clicked())
@@ -472,6 +516,96 @@ impl Prepared {
     }
 }
 
+/// Snap the edges of `rect` to the edges of nearby `areas` (from the outside or aligned with them)
+/// and to the inside of the `containers`, if they are within `distance` of each other.
+///
+/// Returns how much to move `rect`, and the edges it was snapped to (to show as a hint).
+fn snap_rect(
+    rect: Rect,
+    areas: &[Rect],
+    containers: &[Rect],
+    distance: f32,
+) -> (Vec2, Vec<[Pos2; 2]>) {
+    /// The smallest offset found so far, together with the edge we snap to.
+    #[derive(Clone, Copy)]
+    struct Snap {
+        offset: f32,
+        edge: f32,
+        span: Rangef,
+    }
+
+    fn consider(best: &mut Option<Snap>, distance: f32, from: f32, to: f32, span: Rangef) {
+        let offset = to - from;
+        if offset.abs() <= distance && best.map_or(true, |best| offset.abs() < best.offset.abs()) {
+            *best = Some(Snap {
+                offset,
+                edge: to,
+                span,
+            });
+        }
+    }
+
+    fn union(a: Rangef, b: Rangef) -> Rangef {
+        Rangef::new(a.min.min(b.min), a.max.max(b.max))
+    }
+
+    let mut best_x = None;
+    let mut best_y = None;
+
+    for container in containers {
+        for (from, to) in [
+            (rect.left(), container.left()),
+            (rect.right(), container.right()),
+        ] {
+            consider(&mut best_x, distance, from, to, rect.y_range());
+        }
+        for (from, to) in [
+            (rect.top(), container.top()),
+            (rect.bottom(), container.bottom()),
+        ] {
+            consider(&mut best_y, distance, from, to, rect.x_range());
+        }
+    }
+
+    for area in areas {
+        // Only snap to edges that are actually close, not just in line:
+        if rect.y_range().expand(distance).intersects(area.y_range()) {
+            let span = union(rect.y_range(), area.y_range());
+            for from in [rect.left(), rect.right()] {
+                for to in [area.left(), area.right()] {
+                    consider(&mut best_x, distance, from, to, span);
+                }
+            }
+        }
+        if rect.x_range().expand(distance).intersects(area.x_range()) {
+            let span = union(rect.x_range(), area.x_range());
+            for from in [rect.top(), rect.bottom()] {
+                for to in [area.top(), area.bottom()] {
+                    consider(&mut best_y, distance, from, to, span);
+                }
+            }
+        }
+    }
+
+    let mut offset = Vec2::ZERO;
+    let mut hints = vec![];
+    if let Some(snap) = best_x {
+        offset.x = snap.offset;
+        hints.push([
+            pos2(snap.edge, snap.span.min),
+            pos2(snap.edge, snap.span.max),
+        ]);
+    }
+    if let Some(snap) = best_y {
+        offset.y = snap.offset;
+        hints.push([
+            pos2(snap.span.min, snap.edge),
+            pos2(snap.span.max, snap.edge),
+        ]);
+    }
+    (offset, hints)
+}
+
 fn pointer_pressed_on_area(ctx: &Context, layer_id: LayerId) -> bool {
     if let Some(pointer_pos) = ctx.pointer_interact_pos() {
         let any_pressed = ctx.input(|i| i.pointer.any_pressed());
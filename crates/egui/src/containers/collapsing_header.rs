@@ -363,7 +363,9 @@ pub type IconPainter = Box<dyn FnOnce(&mut Ui, f32, &Response)>;
 /// # });
 /// ```
 ///
-/// If you want to customize the header contents, see [`CollapsingState::show_header`].
+/// To add your own widgets to the header (icons, badges, buttons, …),
+/// see [`CollapsingHeader::show_with_header`].
+/// If you want to customize the header contents completely, see [`CollapsingState::show_header`].
 #[must_use = "You should call .show()"]
 pub struct CollapsingHeader {
     text: WidgetText,
@@ -481,7 +483,8 @@ struct Prepared {
 }
 
 impl CollapsingHeader {
-    fn begin(self, ui: &mut Ui) -> Prepared {
+    /// `add_header` is for any extra widgets in the header, after the text.
+    fn begin(self, ui: &mut Ui, add_header: Option<Box<dyn FnOnce(&mut Ui) + '_>>) -> Prepared {
         assert!(
             ui.layout().main_dir().is_vertical(),
             "Horizontal collapsing is unimplemented"
@@ -511,7 +514,7 @@ impl CollapsingHeader {
         let text_max_x = text_pos.x + galley.size().x;
 
         let mut desired_width = text_max_x + button_padding.x - available.left();
-        if ui.visuals().collapsing_header_frame {
+        if ui.visuals().collapsing_header_frame || add_header.is_some() {
             desired_width = desired_width.max(available.width()); // fill full width
         }
 
@@ -575,7 +578,21 @@ impl CollapsingHeader {
                 }
             }
 
-            ui.painter().galley(text_pos, galley, visuals.text_color());
+            ui.painter()
+                .galley(text_pos, galley.clone(), visuals.text_color());
+        }
+
+        if let Some(add_header) = add_header {
+            // These are added after the header itself, so they get the clicks before it does:
+            let mut contents_rect = rect;
+            contents_rect.min.x = if galley.is_empty() {
+                text_pos.x
+            } else {
+                text_max_x + ui.spacing().item_spacing.x
+            };
+            contents_rect.max.x -= button_padding.x;
+            let mut contents_ui = ui.child_ui(contents_rect, Layout::left_to_right(Align::Center));
+            add_header(&mut contents_ui);
         }
 
         Prepared {
@@ -591,7 +608,7 @@ impl CollapsingHeader {
         ui: &mut Ui,
         add_body: impl FnOnce(&mut Ui) -> R,
     ) -> CollapsingResponse<R> {
-        self.show_dyn(ui, Box::new(add_body), true)
+        self.show_dyn(ui, None, Box::new(add_body), true)
     }
 
     #[inline]
@@ -600,12 +617,56 @@ impl CollapsingHeader {
         ui: &mut Ui,
         add_body: impl FnOnce(&mut Ui) -> R,
     ) -> CollapsingResponse<R> {
-        self.show_dyn(ui, Box::new(add_body), false)
+        self.show_dyn(ui, None, Box::new(add_body), false)
+    }
+
+    /// Like [`Self::show`], but with your own widgets in the header, after the text.
+    ///
+    /// The header fills the available width, and `add_header` gets a left-to-right [`Ui`]
+    /// covering the rest of it. Clicking anywhere in the header that isn't one of your widgets
+    /// still opens and closes the [`CollapsingHeader`].
+    ///
+    /// Returns the response of the header (with the toggle) and of your header widgets.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let (collapsing, header) = egui::CollapsingHeader::new("Layer 1").show_with_header(
+    ///     ui,
+    ///     |ui| {
+    ///         ui.label("🔒");
+    ///         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+    ///             ui.button("🗑").clicked()
+    ///         })
+    ///         .inner
+    ///     },
+    ///     |ui| ui.label("Body"),
+    /// );
+    /// if header.inner {
+    ///     // delete the layer
+    /// }
+    /// # });
+    /// ```
+    pub fn show_with_header<HeaderRet, BodyRet>(
+        self,
+        ui: &mut Ui,
+        add_header: impl FnOnce(&mut Ui) -> HeaderRet,
+        add_body: impl FnOnce(&mut Ui) -> BodyRet,
+    ) -> (CollapsingResponse<BodyRet>, InnerResponse<HeaderRet>) {
+        let mut header = None;
+        let add_header = |ui: &mut Ui| {
+            let inner = add_header(ui);
+            let response = ui.interact(ui.min_rect(), ui.id(), Sense::hover());
+            header = Some(InnerResponse::new(inner, response));
+        };
+        let collapsing = self.show_dyn(ui, Some(Box::new(add_header)), Box::new(add_body), true);
+        let header = header.expect("the header contents are always shown");
+        (collapsing, header)
     }
 
     fn show_dyn<'c, R>(
         self,
         ui: &mut Ui,
+        add_header: Option<Box<dyn FnOnce(&mut Ui) + 'c>>,
         add_body: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
         indented: bool,
     ) -> CollapsingResponse<R> {
@@ -618,7 +679,7 @@ impl CollapsingHeader {
                 header_response,
                 mut state,
                 openness,
-            } = self.begin(ui); // show the header
+            } = self.begin(ui, add_header); // show the header
 
             let ret_response = if indented {
                 state.show_body_indented(&header_response, ui, add_body)
@@ -41,6 +41,7 @@ pub struct ComboBox {
     height: Option<f32>,
     icon: Option<IconPainter>,
     wrap_enabled: bool,
+    searchable: bool,
 }
 
 impl ComboBox {
@@ -54,6 +55,7 @@ impl ComboBox {
             height: None,
             icon: None,
             wrap_enabled: false,
+            searchable: false,
         }
     }
 
@@ -68,6 +70,7 @@ impl ComboBox {
             height: None,
             icon: None,
             wrap_enabled: false,
+            searchable: false,
         }
     }
 
@@ -81,6 +84,7 @@ impl ComboBox {
             height: None,
             icon: None,
             wrap_enabled: false,
+            searchable: false,
         }
     }
 
@@ -155,6 +159,39 @@ impl ComboBox {
         self
     }
 
+    /// Show a text field at the top of the menu, which filters the items of [`Self::show_index`] as you type.
+    ///
+    /// The items are matched ignoring case, and the matching part is highlighted.
+    /// Pressing enter selects the first matching item.
+    /// This makes long lists of items usable.
+    ///
+    /// With [`Self::show_ui`] the text field is shown too,
+    /// and you can read what was typed with [`Self::search_text`] to filter your items yourself.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let countries = ["Denmark", "Finland", "Norway", "Sweden"];
+    /// let mut selected = 0;
+    /// egui::ComboBox::from_label("Country")
+    ///     .searchable(true)
+    ///     .show_index(ui, &mut selected, countries.len(), |i| countries[i]);
+    /// # });
+    /// ```
+    #[inline]
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
+    /// What the user typed in the text field of a [`Self::searchable`] combo box.
+    ///
+    /// `id_source` is the one given to the [`ComboBox`]
+    /// (for [`Self::from_label`] it is the text of the label),
+    /// and `ui` the one the combo box is shown in.
+    pub fn search_text(ui: &Ui, id_source: impl std::hash::Hash) -> String {
+        load_search_text(ui, search_id(ui.make_persistent_id(Id::new(id_source))))
+    }
+
     /// Show the combo box, with the given ui code for the menu contents.
     ///
     /// Returns `InnerResponse { inner: None }` if the combo box is closed.
@@ -179,6 +216,7 @@ impl ComboBox {
             height,
             icon,
             wrap_enabled,
+            searchable,
         } = self;
 
         let button_id = ui.make_persistent_id(id_source);
@@ -191,6 +229,7 @@ impl ComboBox {
                 menu_contents,
                 icon,
                 wrap_enabled,
+                searchable.then(|| search_id(button_id)),
                 (width, height),
             );
             if let Some(label) = label {
@@ -231,16 +270,35 @@ impl ComboBox {
         len: usize,
         get: impl Fn(usize) -> Text,
     ) -> Response {
+        let search_text = self
+            .searchable
+            .then(|| load_search_text(ui, search_id(ui.make_persistent_id(self.id_source))))
+            .unwrap_or_default();
         let slf = self.selected_text(get(*selected));
 
         let mut changed = false;
 
         let mut response = slf
             .show_ui(ui, |ui| {
+                let mut first_match = None;
                 for i in 0..len {
-                    if ui.selectable_label(i == *selected, get(i)).clicked() {
+                    let Some(text) = search_item(ui, get(i).into(), &search_text) else {
+                        continue;
+                    };
+                    if !search_text.is_empty() {
+                        first_match.get_or_insert(i);
+                    }
+                    if ui.selectable_label(i == *selected, text).clicked() {
+                        *selected = i;
+                        changed = true;
+                    }
+                }
+
+                if let Some(i) = first_match {
+                    if ui.input(|input| input.key_pressed(Key::Enter)) {
                         *selected = i;
                         changed = true;
+                        ui.memory_mut(|mem| mem.close_popup());
                     }
                 }
             })
@@ -251,8 +309,110 @@ impl ComboBox {
         }
         response
     }
+
+    /// Show a list of items with a checkbox each, so that any number of them can be selected.
+    ///
+    /// `selected` has one entry per item, which is toggled when the user clicks the item.
+    /// The menu stays open while toggling items.
+    /// Unless you set [`Self::selected_text`], the button shows a summary of the selection:
+    /// the selected item if there is only one, or e.g. "3 selected".
+    ///
+    /// With [`Self::searchable`], pressing enter toggles the first matching item.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let toppings = ["Cheese", "Ham", "Mushrooms", "Pineapple"];
+    /// let mut selected = [true, false, true, false];
+    /// let response = egui::ComboBox::from_label("Toppings").show_checklist(
+    ///     ui,
+    ///     &mut selected,
+    ///     |i| toppings[i],
+    /// );
+    /// if response.changed() {
+    ///     // …
+    /// }
+    /// # });
+    /// ```
+    pub fn show_checklist<Text: Into<WidgetText>>(
+        self,
+        ui: &mut Ui,
+        selected: &mut [bool],
+        get: impl Fn(usize) -> Text,
+    ) -> Response {
+        let button_id = ui.make_persistent_id(self.id_source);
+        let search_text = self
+            .searchable
+            .then(|| load_search_text(ui, search_id(button_id)))
+            .unwrap_or_default();
+        let slf = if self.selected_text.is_empty() {
+            let num_selected = selected.iter().filter(|&&is_selected| is_selected).count();
+            let summary: WidgetText = match num_selected {
+                0 => "None".into(),
+                1 => get(selected
+                    .iter()
+                    .position(|&is_selected| is_selected)
+                    .unwrap_or(0))
+                .into(),
+                n => format!("{n} selected").into(),
+            };
+            self.selected_text(summary)
+        } else {
+            self
+        };
+
+        let mut toggled = false;
+
+        let mut response = slf
+            .show_ui(ui, |ui| {
+                let mut first_match = None;
+                for (i, is_selected) in selected.iter_mut().enumerate() {
+                    let Some(text) = search_item(ui, get(i).into(), &search_text) else {
+                        continue;
+                    };
+                    if !search_text.is_empty() {
+                        first_match.get_or_insert(i);
+                    }
+                    toggled |= ui.checkbox(is_selected, text).changed();
+                }
+
+                if let Some(i) = first_match {
+                    if ui.input(|input| input.key_pressed(Key::Enter)) {
+                        selected[i] = !selected[i];
+                        toggled = true;
+                    }
+                }
+            })
+            .response;
+
+        if toggled {
+            // Clicking anywhere but the button closes the popup, but keep it open while toggling:
+            ui.memory_mut(|mem| mem.open_popup(button_id.with("popup")));
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// Where the text of a [`ComboBox::searchable`] is stored.
+fn search_id(button_id: Id) -> Id {
+    button_id.with("search")
 }
 
+/// Filter and highlight an item of a [`ComboBox::searchable`]. Returns `None` if it doesn't match.
+fn search_item(ui: &Ui, text: WidgetText, search_text: &str) -> Option<WidgetText> {
+    if search_text.is_empty() {
+        return Some(text);
+    }
+    let range = find_ignoring_case(text.text(), search_text)?;
+    Some(highlight_range(ui, text.text(), range).into())
+}
+
+fn load_search_text(ui: &Ui, search_id: Id) -> String {
+    ui.data(|d| d.get_temp::<String>(search_id))
+        .unwrap_or_default()
+}
+
+#[allow(clippy::too_many_arguments)]
 fn combo_box_dyn<'c, R>(
     ui: &mut Ui,
     button_id: Id,
@@ -260,6 +420,7 @@ fn combo_box_dyn<'c, R>(
     menu_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
     icon: Option<IconPainter>,
     wrap_enabled: bool,
+    search_id: Option<Id>,
     (width, height): (Option<f32>, Option<f32>),
 ) -> InnerResponse<Option<R>> {
     let popup_id = button_id.with("popup");
@@ -353,8 +514,16 @@ fn combo_box_dyn<'c, R>(
 
     if button_response.clicked() {
         ui.memory_mut(|mem| mem.toggle_popup(popup_id));
+
+        if let Some(search_id) = search_id {
+            // Start with an empty search, and let the user type right away:
+            ui.data_mut(|d| d.remove::<String>(search_id));
+            ui.memory_mut(|mem| mem.request_focus(search_id.with("text_edit")));
+        }
     }
 
+    let mut search_field_clicked = false;
+
     let height = height.unwrap_or_else(|| ui.spacing().combo_height);
 
     let inner = crate::popup::popup_above_or_below_widget(
@@ -363,6 +532,18 @@ fn combo_box_dyn<'c, R>(
         &button_response,
         above_or_below,
         |ui| {
+            if let Some(search_id) = search_id {
+                let mut search_text = load_search_text(ui, search_id);
+                let response = ui.add(
+                    TextEdit::singleline(&mut search_text)
+                        .id(search_id.with("text_edit"))
+                        .hint_text("Search…"),
+                );
+                search_field_clicked =
+                    response.contains_pointer() && ui.input(|i| i.pointer.any_released());
+                ui.data_mut(|d| d.insert_temp(search_id, search_text));
+            }
+
             ScrollArea::vertical()
                 .max_height(height)
                 .show(ui, |ui| {
@@ -378,12 +559,51 @@ fn combo_box_dyn<'c, R>(
         },
     );
 
+    if search_field_clicked {
+        // Clicking anywhere but the button closes the popup, but not the search field:
+        ui.memory_mut(|mem| mem.open_popup(popup_id));
+    }
+
     InnerResponse {
         inner,
         response: button_response,
     }
 }
 
+/// Find `query` in `text`, ignoring case, and return the byte range of the match in `text`.
+fn find_ignoring_case(text: &str, query: &str) -> Option<std::ops::Range<usize>> {
+    text.char_indices().find_map(|(start, _)| {
+        let mut end = start;
+        let mut rest = text[start..].chars();
+        let is_match = query.chars().all(|q| {
+            rest.next().map_or(false, |c| {
+                end += c.len_utf8();
+                c.to_lowercase().eq(q.to_lowercase())
+            })
+        });
+        is_match.then_some(start..end)
+    })
+}
+
+/// Lay out `text` with a highlighted background behind `range`.
+fn highlight_range(ui: &Ui, text: &str, range: std::ops::Range<usize>) -> text::LayoutJob {
+    let format = TextFormat {
+        font_id: TextStyle::Button.resolve(ui.style()),
+        color: Color32::PLACEHOLDER, // use the color of the label
+        ..Default::default()
+    };
+    let highlighted = TextFormat {
+        background: ui.visuals().selection.bg_fill,
+        ..format.clone()
+    };
+
+    let mut job = text::LayoutJob::default();
+    job.append(&text[..range.start], 0.0, format.clone());
+    job.append(&text[range.clone()], 0.0, highlighted);
+    job.append(&text[range.end..], 0.0, format);
+    job
+}
+
 fn button_frame(
     ui: &mut Ui,
     id: Id,
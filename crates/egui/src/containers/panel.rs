@@ -98,6 +98,7 @@ pub struct SidePanel {
     show_separator_line: bool,
     default_width: f32,
     width_range: Rangef,
+    min_width_from_content: bool,
 }
 
 impl SidePanel {
@@ -121,6 +122,7 @@ impl SidePanel {
             show_separator_line: true,
             default_width: 200.0,
             width_range: Rangef::new(96.0, f32::INFINITY),
+            min_width_from_content: false,
         }
     }
 
@@ -184,6 +186,16 @@ impl SidePanel {
         self
     }
 
+    /// Don't let the user resize the panel to be narrower than its contents. Default: `false`.
+    ///
+    /// This is useful for panels with contents that can't shrink, like a toolbar.
+    /// Contents that always fill the available width (e.g. a [`Separator`]) don't count.
+    #[inline]
+    pub fn min_width_from_content(mut self, min_width_from_content: bool) -> Self {
+        self.min_width_from_content = min_width_from_content;
+        self
+    }
+
     /// Enforce this exact width, including margins.
     #[inline]
     pub fn exact_width(mut self, width: f32) -> Self {
@@ -224,6 +236,7 @@ impl SidePanel {
             show_separator_line,
             default_width,
             width_range,
+            min_width_from_content,
         } = self;
 
         let available_rect = ui.available_rect_before_wrap();
@@ -239,6 +252,7 @@ impl SidePanel {
         }
 
         let resize_id = id.with("__resize");
+        let content_width_id = id.with("__content_width");
         let mut resize_hover = false;
         let mut is_resizing = false;
         if resizable {
@@ -250,6 +264,13 @@ impl SidePanel {
                 if is_resizing {
                     if let Some(pointer) = resize_response.interact_pointer_pos() {
                         width = (pointer.x - side.side_x(panel_rect)).abs();
+                        if min_width_from_content {
+                            if let Some(content_width) =
+                                ui.data(|d| d.get_temp::<f32>(content_width_id))
+                            {
+                                width = width.at_least(content_width);
+                            }
+                        }
                         width = clamp_to_range(width, width_range).at_most(available_rect.width());
                         side.set_rect_width(&mut panel_rect, width);
                     }
@@ -268,6 +289,15 @@ impl SidePanel {
 
         let rect = inner_response.response.rect;
 
+        if min_width_from_content {
+            // If the contents didn't fit, that is how narrow we can get:
+            if panel_rect.width() + 0.5 < rect.width() {
+                ui.data_mut(|d| d.insert_temp(content_width_id, rect.width()));
+            } else if !is_resizing {
+                ui.data_mut(|d| d.remove::<f32>(content_width_id));
+            }
+        }
+
         {
             let mut cursor = ui.cursor();
             match side {
@@ -329,6 +359,25 @@ impl SidePanel {
         inner_response
     }
 
+    /// The response of the resize handle of the panel with the given id,
+    /// e.g. to check if the user is dragging it.
+    ///
+    /// Returns `None` if the panel is not resizable, or hasn't been shown yet.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// let panel = egui::SidePanel::left("tools").show(ctx, |ui| ui.label("Tools"));
+    /// if let Some(resize) = egui::SidePanel::resize_response(ctx, "tools") {
+    ///     if resize.drag_stopped() && panel.response.rect.width() < 100.0 {
+    ///         // e.g. hide the panel
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    pub fn resize_response(ctx: &Context, id: impl Into<Id>) -> Option<Response> {
+        ctx.read_response(id.into().with("__resize"))
+    }
+
     /// Show the panel at the top level.
     pub fn show<R>(
         self,
@@ -432,6 +481,74 @@ impl SidePanel {
         }
     }
 
+    /// Show the panel with a thin handle along its inner edge, which the user can click
+    /// to collapse and expand the panel.
+    ///
+    /// When collapsed, only the handle is shown.
+    /// The panel animates between the two states, and remembers which one it is in.
+    /// It starts out expanded. See also [`Self::is_expanded`] and [`Self::set_expanded`].
+    ///
+    /// Returns `None` if the panel is collapsed (or animating).
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// egui::SidePanel::left("outline").show_collapsible(ctx, |ui| {
+    ///    ui.label("Hello World!");
+    /// });
+    /// # });
+    /// ```
+    pub fn show_collapsible<R>(
+        self,
+        ctx: &Context,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let (side, id) = (self.side, self.id);
+        let mut is_expanded = Self::is_expanded(ctx, id);
+        let inner_response = self.show_animated(ctx, is_expanded, add_contents);
+        Self::collapse_handle(side, id, &ctx.style()).show(ctx, |ui| {
+            side_panel_handle_ui(ui, side, &mut is_expanded);
+        });
+        Self::set_expanded(ctx, id, is_expanded);
+        inner_response
+    }
+
+    /// Like [`Self::show_collapsible`], but inside a [`Ui`].
+    pub fn show_collapsible_inside<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let (side, id) = (self.side, self.id);
+        let mut is_expanded = Self::is_expanded(ui.ctx(), id);
+        let inner_response = self.show_animated_inside(ui, is_expanded, add_contents);
+        Self::collapse_handle(side, id, ui.style()).show_inside(ui, |ui| {
+            side_panel_handle_ui(ui, side, &mut is_expanded);
+        });
+        Self::set_expanded(ui.ctx(), id, is_expanded);
+        inner_response
+    }
+
+    /// Is the panel with the given id expanded? See [`Self::show_collapsible`].
+    pub fn is_expanded(ctx: &Context, id: impl Into<Id>) -> bool {
+        let expanded_id = id.into().with("expanded");
+        ctx.data_mut(|d| d.get_persisted(expanded_id))
+            .unwrap_or(true)
+    }
+
+    /// Expand or collapse the panel with the given id. See [`Self::show_collapsible`].
+    pub fn set_expanded(ctx: &Context, id: impl Into<Id>, is_expanded: bool) {
+        let expanded_id = id.into().with("expanded");
+        ctx.data_mut(|d| d.insert_persisted(expanded_id, is_expanded));
+    }
+
+    /// The thin panel along the inner edge of a collapsible panel.
+    fn collapse_handle(side: Side, id: Id, style: &Style) -> Self {
+        Self::new(side, id.with("collapse_handle"))
+            .resizable(false)
+            .exact_width(style.spacing.icon_width)
+            .frame(Frame::side_top_panel(style).inner_margin(0.0))
+    }
+
     /// Show either a collapsed or a expanded panel, with a nice animation between.
     pub fn show_animated_between<R>(
         ctx: &Context,
@@ -498,6 +615,47 @@ impl SidePanel {
     }
 }
 
+/// The contents of the handle of a collapsible [`SidePanel`]:
+/// an arrow pointing in the direction the panel will move when clicked.
+fn side_panel_handle_ui(ui: &mut Ui, side: Side, is_expanded: &mut bool) -> Response {
+    let label = if *is_expanded {
+        "Collapse panel"
+    } else {
+        "Expand panel"
+    };
+
+    let (rect, mut response) = ui.allocate_exact_size(ui.available_size(), Sense::click());
+    if response.clicked() {
+        *is_expanded = !*is_expanded;
+        response.mark_changed();
+    }
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, label));
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact(&response);
+        if response.hovered() || response.has_focus() {
+            ui.painter().rect_filled(rect, 0.0, visuals.weak_bg_fill);
+        }
+
+        let center = pos2(rect.center().x, ui.clip_rect().intersect(rect).center().y);
+        let r = 0.25 * rect.width();
+        let points_left = (side == Side::Left) == *is_expanded;
+        let dir = if points_left { -1.0 } else { 1.0 };
+        let points = vec![
+            center + vec2(dir * r, 0.0),
+            center + vec2(-dir * r, -1.5 * r),
+            center + vec2(-dir * r, 1.5 * r),
+        ];
+        ui.painter().add(Shape::convex_polygon(
+            points,
+            visuals.fg_stroke.color,
+            Stroke::NONE,
+        ));
+    }
+
+    response.on_hover_text(label)
+}
+
 // ----------------------------------------------------------------------------
 
 /// [`Top`](TopBottomSide::Top) or [`Bottom`](TopBottomSide::Bottom)
@@ -557,6 +715,7 @@ pub struct TopBottomPanel {
     show_separator_line: bool,
     default_height: Option<f32>,
     height_range: Rangef,
+    min_height_from_content: bool,
 }
 
 impl TopBottomPanel {
@@ -580,6 +739,7 @@ impl TopBottomPanel {
             show_separator_line: true,
             default_height: None,
             height_range: Rangef::new(20.0, f32::INFINITY),
+            min_height_from_content: false,
         }
     }
 
@@ -646,6 +806,16 @@ impl TopBottomPanel {
         self
     }
 
+    /// Don't let the user resize the panel to be shorter than its contents. Default: `false`.
+    ///
+    /// This is useful for panels with contents that can't shrink, like a toolbar.
+    /// Contents that always fill the available height don't count.
+    #[inline]
+    pub fn min_height_from_content(mut self, min_height_from_content: bool) -> Self {
+        self.min_height_from_content = min_height_from_content;
+        self
+    }
+
     /// Enforce this exact height, including margins.
     #[inline]
     pub fn exact_height(mut self, height: f32) -> Self {
@@ -686,6 +856,7 @@ impl TopBottomPanel {
             show_separator_line,
             default_height,
             height_range,
+            min_height_from_content,
         } = self;
 
         let available_rect = ui.available_rect_before_wrap();
@@ -704,6 +875,7 @@ impl TopBottomPanel {
         }
 
         let resize_id = id.with("__resize");
+        let content_height_id = id.with("__content_height");
         let mut resize_hover = false;
         let mut is_resizing = false;
         if resizable {
@@ -715,6 +887,13 @@ impl TopBottomPanel {
                 if is_resizing {
                     if let Some(pointer) = resize_response.interact_pointer_pos() {
                         height = (pointer.y - side.side_y(panel_rect)).abs();
+                        if min_height_from_content {
+                            if let Some(content_height) =
+                                ui.data(|d| d.get_temp::<f32>(content_height_id))
+                            {
+                                height = height.at_least(content_height);
+                            }
+                        }
                         height =
                             clamp_to_range(height, height_range).at_most(available_rect.height());
                         side.set_rect_height(&mut panel_rect, height);
@@ -734,6 +913,15 @@ impl TopBottomPanel {
 
         let rect = inner_response.response.rect;
 
+        if min_height_from_content {
+            // If the contents didn't fit, that is how short we can get:
+            if panel_rect.height() + 0.5 < rect.height() {
+                ui.data_mut(|d| d.insert_temp(content_height_id, rect.height()));
+            } else if !is_resizing {
+                ui.data_mut(|d| d.remove::<f32>(content_height_id));
+            }
+        }
+
         {
             let mut cursor = ui.cursor();
             match side {
@@ -796,6 +984,14 @@ impl TopBottomPanel {
         inner_response
     }
 
+    /// The response of the resize handle of the panel with the given id,
+    /// e.g. to check if the user is dragging it.
+    ///
+    /// Returns `None` if the panel is not resizable, or hasn't been shown yet.
+    pub fn resize_response(ctx: &Context, id: impl Into<Id>) -> Option<Response> {
+        ctx.read_response(id.into().with("__resize"))
+    }
+
     /// Show the panel at the top level.
     pub fn show<R>(
         self,
@@ -109,6 +109,31 @@ pub fn show_tooltip_for<R>(
     rect: &Rect,
     add_contents: impl FnOnce(&mut Ui) -> R,
 ) -> Option<R> {
+    let interactable = false;
+    show_tooltip_for_dyn(ctx, id, rect, interactable, Box::new(add_contents))
+        .map(|inner_response| inner_response.inner)
+}
+
+/// Like [`show_tooltip_for`], but the user can interact with the contents of the tooltip.
+///
+/// Used by [`Response::on_hover_ui_rich`].
+pub(crate) fn show_rich_tooltip_for<R>(
+    ctx: &Context,
+    id: Id,
+    rect: &Rect,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<InnerResponse<R>> {
+    let interactable = true;
+    show_tooltip_for_dyn(ctx, id, rect, interactable, Box::new(add_contents))
+}
+
+fn show_tooltip_for_dyn<'c, R>(
+    ctx: &Context,
+    id: Id,
+    rect: &Rect,
+    interactable: bool,
+    add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+) -> Option<InnerResponse<R>> {
     let expanded_rect = rect.expand2(vec2(2.0, 4.0));
     let (above, position) = if ctx.input(|i| i.any_touches()) {
         (true, expanded_rect.left_top())
@@ -121,7 +146,8 @@ pub fn show_tooltip_for<R>(
         Some(position),
         above,
         expanded_rect,
-        Box::new(add_contents),
+        interactable,
+        add_contents,
     )
 }
 
@@ -135,14 +161,17 @@ pub fn show_tooltip_at<R>(
     add_contents: impl FnOnce(&mut Ui) -> R,
 ) -> Option<R> {
     let above = false;
+    let interactable = false;
     show_tooltip_at_avoid_dyn(
         ctx,
         id,
         suggested_position,
         above,
         Rect::NOTHING,
+        interactable,
         Box::new(add_contents),
     )
+    .map(|inner_response| inner_response.inner)
 }
 
 fn show_tooltip_at_avoid_dyn<'c, R>(
@@ -151,8 +180,9 @@ fn show_tooltip_at_avoid_dyn<'c, R>(
     suggested_position: Option<Pos2>,
     above: bool,
     mut avoid_rect: Rect,
+    interactable: bool,
     add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
-) -> Option<R> {
+) -> Option<InnerResponse<R>> {
     let spacing = 4.0;
 
     // if there are multiple tooltips open they should use the same common_id for the `tooltip_size` caching to work.
@@ -210,8 +240,8 @@ fn show_tooltip_at_avoid_dyn<'c, R>(
 
     let area_id = frame_state.common_id.with(frame_state.count);
 
-    let InnerResponse { inner, response } =
-        show_tooltip_area_dyn(ctx, area_id, position, add_contents);
+    let inner_response = show_tooltip_area_dyn(ctx, area_id, position, interactable, add_contents);
+    let response = &inner_response.response;
 
     long_state.set_individual_tooltip(
         frame_state.common_id,
@@ -225,7 +255,7 @@ fn show_tooltip_at_avoid_dyn<'c, R>(
     frame_state.rect = frame_state.rect.union(response.rect);
     ctx.frame_state_mut(|fs| fs.tooltip_state = Some(frame_state));
 
-    Some(inner)
+    Some(inner_response)
 }
 
 /// Show some text at the current pointer position (if any).
@@ -254,6 +284,7 @@ fn show_tooltip_area_dyn<'c, R>(
     ctx: &Context,
     area_id: Id,
     window_pos: Pos2,
+    interactable: bool,
     add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
 ) -> InnerResponse<R> {
     use containers::*;
@@ -261,7 +292,8 @@ fn show_tooltip_area_dyn<'c, R>(
         .order(Order::Tooltip)
         .fixed_pos(window_pos)
         .constrain_to(ctx.screen_rect())
-        .interactable(false)
+        .interactable(interactable)
+        .sense(Sense::hover())
         .show(ctx, |ui| {
             Frame::popup(&ctx.style())
                 .show(ui, |ui| {
@@ -39,6 +39,10 @@ pub struct State {
     /// and remains that way until the user moves the scroll_handle. Once unstuck (false)
     /// it remains false until the scroll touches the end position, which reenables stickiness.
     scroll_stuck_to_end: Vec2b,
+
+    /// When did the offset last change? Used for fading out floating scroll bars.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_scroll_time: Option<f64>,
 }
 
 impl Default for State {
@@ -52,6 +56,7 @@ impl Default for State {
             vel: Vec2::ZERO,
             scroll_start_offset_from_top_left: [None; 2],
             scroll_stuck_to_end: Vec2b::TRUE,
+            last_scroll_time: None,
         }
     }
 }
@@ -69,6 +74,25 @@ impl State {
     pub fn velocity(&self) -> Vec2 {
         self.vel
     }
+
+    /// Start (or retarget) a smooth scroll animation towards the given offset.
+    fn animate_offset_to(&mut self, d: usize, target_offset: f32, now: f64) {
+        if let Some(animation) = &mut self.offset_target[d] {
+            // For instance: the user is continuously calling `ui.scroll_to_cursor`,
+            // so we don't want to reset the animation, but perhaps update the target:
+            animation.target_offset = target_offset;
+        } else {
+            // The further we scroll, the more time we take.
+            // TODO(emilk): let users configure this in `Style`.
+            let points_per_second = 1000.0;
+            let animation_duration =
+                ((self.offset[d] - target_offset).abs() / points_per_second).clamp(0.1, 0.3);
+            self.offset_target[d] = Some(ScrollTarget {
+                animation_time_span: (now, now + animation_duration as f64),
+                target_offset,
+            });
+        }
+    }
 }
 
 pub struct ScrollAreaOutput<R> {
@@ -135,6 +159,7 @@ impl ScrollBarVisibility {
 /// There are two flavors of scroll areas: solid and floating.
 /// Solid scroll bars use up space, reducing the amount of space available
 /// to the contents. Floating scroll bars float on top of the contents, covering it.
+/// With [`crate::style::ScrollStyle::overlay`], the floating scroll bars only show up while scrolling.
 /// You can change the scroll style by changing the [`crate::style::Spacing::scroll`].
 ///
 /// ### Coordinate system
@@ -179,6 +204,12 @@ pub struct ScrollArea {
 
     /// If false, `scroll_to_*` functions will not be animated
     animated: bool,
+
+    /// Scroll so that this range of the content is visible, with the given alignment.
+    scroll_to: [Option<(Rangef, Option<Align>)>; 2],
+
+    /// Used by [`Self::show_rows`] to fill in [`Self::scroll_to`].
+    scroll_to_row: Option<(usize, Option<Align>)>,
 }
 
 impl ScrollArea {
@@ -223,6 +254,8 @@ impl ScrollArea {
             drag_to_scroll: true,
             stick_to_end: Vec2b::FALSE,
             animated: true,
+            scroll_to: [None; 2],
+            scroll_to_row: None,
         }
     }
 
@@ -326,6 +359,70 @@ impl ScrollArea {
         self
     }
 
+    /// Scroll to the given offset, animated unless [`Self::animated`] is `false`.
+    ///
+    /// Unlike [`Self::scroll_offset`], this takes precedence over any ongoing
+    /// kinetic scrolling or dragging by the user, and is only meant to be called
+    /// on the frame you want to start the scroll.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let go_to_top = ui.button("Go to top").clicked();
+    /// let mut scroll_area = egui::ScrollArea::vertical();
+    /// if go_to_top {
+    ///     scroll_area = scroll_area.scroll_to_offset(egui::Vec2::ZERO);
+    /// }
+    /// scroll_area.show(ui, |ui| {
+    ///     ui.label("Lots of text…");
+    /// });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn scroll_to_offset(mut self, offset: Vec2) -> Self {
+        for d in 0..2 {
+            self.scroll_to[d] = Some((Rangef::point(offset[d]), Some(Align::Min)));
+        }
+        self
+    }
+
+    /// Scroll so that the given rectangle of the contents becomes visible,
+    /// animated unless [`Self::animated`] is `false`.
+    ///
+    /// The rectangle is relative to the top left corner of the contents,
+    /// just like the viewport given to [`Self::show_viewport`].
+    ///
+    /// If `align` is `None`, this scrolls as little as possible to make the rectangle visible.
+    /// Otherwise, the rectangle is aligned with the given side or center of the scroll area.
+    ///
+    /// See also [`Self::scroll_to_offset`].
+    #[inline]
+    pub fn scroll_to_rect(mut self, rect: Rect, align: Option<Align>) -> Self {
+        self.scroll_to = [Some((rect.x_range(), align)), Some((rect.y_range(), align))];
+        self
+    }
+
+    /// Scroll so that the given row becomes visible, when using [`Self::show_rows`].
+    ///
+    /// If `align` is `None`, this scrolls as little as possible to make the row visible.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let row_height = ui.text_style_height(&egui::TextStyle::Body);
+    /// egui::ScrollArea::vertical()
+    ///     .scroll_to_row(5_000, Some(egui::Align::Center))
+    ///     .show_rows(ui, row_height, 10_000, |ui, row_range| {
+    ///         for row in row_range {
+    ///             ui.label(format!("Row {row}"));
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn scroll_to_row(mut self, row: usize, align: Option<Align>) -> Self {
+        self.scroll_to_row = Some((row, align));
+        self
+    }
+
     /// Turn on/off scrolling on the horizontal axis.
     #[inline]
     pub fn hscroll(mut self, hscroll: bool) -> Self {
@@ -473,6 +570,12 @@ struct Prepared {
     scrolling_enabled: bool,
     stick_to_end: Vec2b,
     animated: bool,
+
+    /// Was there a [`ScrollArea::scroll_to_offset`] or similar this frame?
+    scroll_requested: Vec2b,
+
+    /// The offset we ended the previous frame with.
+    offset_last_frame: Vec2,
 }
 
 impl ScrollArea {
@@ -490,6 +593,8 @@ impl ScrollArea {
             drag_to_scroll,
             stick_to_end,
             animated,
+            scroll_to,
+            scroll_to_row: _, // handled by `show_rows`
         } = self;
 
         let ctx = ui.ctx().clone();
@@ -503,6 +608,7 @@ impl ScrollArea {
             "ScrollArea",
         );
         let mut state = State::load(&ctx, id).unwrap_or_default();
+        let offset_last_frame = state.offset;
 
         state.offset.x = offset_x.unwrap_or(state.offset.x);
         state.offset.y = offset_y.unwrap_or(state.offset.y);
@@ -541,6 +647,39 @@ impl ScrollArea {
 
         let inner_rect = Rect::from_min_size(available_outer.min, inner_size);
 
+        // Programmatic scrolling wins over whatever the user is doing this frame:
+        let mut scroll_requested = Vec2b::FALSE;
+        for d in 0..2 {
+            if !scroll_enabled[d] {
+                continue;
+            }
+            if let Some((range, align)) = scroll_to[d] {
+                let visible = Rangef::new(state.offset[d], state.offset[d] + inner_size[d]);
+                let target_offset = if let Some(align) = align {
+                    let t = align.to_factor();
+                    range.min + t * (range.span() - inner_size[d])
+                } else if range.min < visible.min {
+                    range.min
+                } else if range.max > visible.max {
+                    (range.max - inner_size[d]).at_most(range.min)
+                } else {
+                    state.offset[d] // Already visible
+                };
+                let target_offset = target_offset.at_least(0.0);
+
+                scroll_requested[d] = true;
+                state.vel[d] = 0.0;
+                state.scroll_stuck_to_end[d] = false;
+                if animated {
+                    state.animate_offset_to(d, target_offset, ui.input(|i| i.time));
+                    ctx.request_repaint();
+                } else {
+                    state.offset[d] = target_offset;
+                    state.offset_target[d] = None;
+                }
+            }
+        }
+
         let mut content_max_size = inner_size;
 
         if true {
@@ -591,7 +730,7 @@ impl ScrollArea {
 
             if content_response.dragged() {
                 for d in 0..2 {
-                    if scroll_enabled[d] {
+                    if scroll_enabled[d] && !scroll_requested[d] {
                         ui.input(|input| {
                             state.offset[d] -= input.pointer.delta()[d];
                             state.vel[d] = input.pointer.velocity()[d];
@@ -603,16 +742,18 @@ impl ScrollArea {
                     }
                 }
             } else {
+                let interaction = &ui.style().interaction;
+                let kinetic_scrolling = interaction.kinetic_scrolling;
+                let friction = interaction.kinetic_scroll_friction;
+
                 for d in 0..2 {
-                    // Kinetic scrolling
+                    // Kinetic scrolling, with exponential decay of the velocity:
                     let stop_speed = 20.0; // Pixels per second.
-                    let friction_coeff = 1000.0; // Pixels per second squared.
 
-                    let friction = friction_coeff * dt;
-                    if friction > state.vel[d].abs() || state.vel[d].abs() < stop_speed {
+                    if !kinetic_scrolling || state.vel[d].abs() < stop_speed {
                         state.vel[d] = 0.0;
                     } else {
-                        state.vel[d] -= friction * state.vel[d].signum();
+                        state.vel[d] *= (-friction * dt).exp();
                         // Offset has an inverted coordinate system compared to
                         // the velocity, so we subtract it instead of adding it
                         state.offset[d] -= state.vel[d] * dt;
@@ -667,6 +808,8 @@ impl ScrollArea {
             scrolling_enabled,
             stick_to_end,
             animated,
+            scroll_requested,
+            offset_last_frame,
         }
     }
 
@@ -704,9 +847,16 @@ impl ScrollArea {
         total_rows: usize,
         add_contents: impl FnOnce(&mut Ui, std::ops::Range<usize>) -> R,
     ) -> ScrollAreaOutput<R> {
+        let mut scroll_area = self;
         let spacing = ui.spacing().item_spacing;
         let row_height_with_spacing = row_height_sans_spacing + spacing.y;
-        self.show_viewport(ui, |ui, viewport| {
+        if let Some((row, align)) = scroll_area.scroll_to_row.take() {
+            let row = row.min(total_rows.saturating_sub(1));
+            let top = row as f32 * row_height_with_spacing;
+            scroll_area.scroll_to[1] =
+                Some((Rangef::new(top, top + row_height_sans_spacing), align));
+        }
+        scroll_area.show_viewport(ui, |ui, viewport| {
             ui.set_height((row_height_with_spacing * total_rows as f32 - spacing.y).at_least(0.0));
 
             let mut min_row = (viewport.min.y / row_height_with_spacing).floor() as usize;
@@ -779,6 +929,8 @@ impl Prepared {
             scrolling_enabled,
             stick_to_end,
             animated,
+            scroll_requested,
+            offset_last_frame,
         } = self;
 
         let content_size = content_ui.min_size();
@@ -824,21 +976,8 @@ impl Prepared {
 
                         if !animated {
                             state.offset[d] = target_offset;
-                        } else if let Some(animation) = &mut state.offset_target[d] {
-                            // For instance: the user is continuously calling `ui.scroll_to_cursor`,
-                            // so we don't want to reset the animation, but perhaps update the target:
-                            animation.target_offset = target_offset;
                         } else {
-                            // The further we scroll, the more time we take.
-                            // TODO(emilk): let users configure this in `Style`.
-                            let now = ui.input(|i| i.time);
-                            let points_per_second = 1000.0;
-                            let animation_duration =
-                                (delta.abs() / points_per_second).clamp(0.1, 0.3);
-                            state.offset_target[d] = Some(ScrollTarget {
-                                animation_time_span: (now, now + animation_duration as f64),
-                                target_offset,
-                            });
+                            state.animate_offset_to(d, target_offset, ui.input(|i| i.time));
                         }
                         ui.ctx().request_repaint();
                     }
@@ -875,7 +1014,7 @@ impl Prepared {
             let always_scroll_enabled_direction = ui.style().always_scroll_the_only_direction
                 && scroll_enabled[0] != scroll_enabled[1];
             for d in 0..2 {
-                if scroll_enabled[d] {
+                if scroll_enabled[d] && !scroll_requested[d] {
                     let scroll_delta = ui.ctx().input_mut(|input| {
                         if always_scroll_enabled_direction {
                             // no bidirectional scrolling; allow horizontal scrolling without pressing shift
@@ -924,6 +1063,26 @@ impl Prepared {
 
         let scroll_style = ui.spacing().scroll;
 
+        // Should floating scroll bars be shown as active (as opposed to dormant)?
+        let is_active = if let Some(fade_out_delay) = scroll_style.fade_out_delay {
+            let now = ui.input(|i| i.time);
+            if state.offset != offset_last_frame {
+                state.last_scroll_time = Some(now);
+            }
+            state.last_scroll_time.map_or(false, |last_scroll_time| {
+                let time_left = last_scroll_time + fade_out_delay as f64 - now;
+                if 0.0 < time_left {
+                    ui.ctx()
+                        .request_repaint_after(std::time::Duration::from_secs_f64(time_left));
+                    true
+                } else {
+                    false
+                }
+            })
+        } else {
+            is_hovering_outer_rect
+        };
+
         // Paint the bars:
         for d in 0..2 {
             // maybe force increase in offset to keep scroll stuck to end position
@@ -1113,14 +1272,13 @@ impl Prepared {
                     if response.hovered() || response.dragged() {
                         scroll_style.interact_handle_opacity
                     } else {
-                        let is_hovering_outer_rect_t = ui.ctx().animate_bool(
-                            id.with((d, "is_hovering_outer_rect")),
-                            is_hovering_outer_rect,
-                        );
+                        let is_active_t = ui
+                            .ctx()
+                            .animate_bool(id.with((d, "is_hovering_outer_rect")), is_active);
                         lerp(
                             scroll_style.dormant_handle_opacity
                                 ..=scroll_style.active_handle_opacity,
-                            is_hovering_outer_rect_t,
+                            is_active_t,
                         )
                     }
                 } else {
@@ -1130,7 +1288,7 @@ impl Prepared {
                 let background_opacity = if scroll_style.floating {
                     if response.hovered() || response.dragged() {
                         scroll_style.interact_background_opacity
-                    } else if is_hovering_outer_rect {
+                    } else if is_active {
                         scroll_style.active_background_opacity
                     } else {
                         scroll_style.dormant_background_opacity
@@ -0,0 +1,103 @@
+use crate::*;
+
+/// A section of a vertical [`ScrollArea`] with a header that sticks to the top
+/// of the viewport while the section is scrolled through.
+///
+/// When the end of the section reaches the header, the header is pushed up and out
+/// of view, making room for the header of the next section, like in the grouped
+/// lists of mobile platforms.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::ScrollArea::vertical().show(ui, |ui| {
+///     for group in ["Fruit", "Vegetables", "Nuts"] {
+///         egui::StickySection::new(group).show(
+///             ui,
+///             |ui| ui.strong(group),
+///             |ui| {
+///                 for i in 0..20 {
+///                     ui.label(format!("{group} #{i}"));
+///                 }
+///             },
+///         );
+///     }
+/// });
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "You should call .show()"]
+pub struct StickySection {
+    id_source: Id,
+    frame: Option<Frame>,
+}
+
+impl StickySection {
+    /// The id source must be unique within the parent [`Ui`].
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            frame: None,
+        }
+    }
+
+    /// Change the frame around the header.
+    ///
+    /// The frame should have a fill, since the contents of the section scrolls behind it.
+    /// By default this is [`Frame::side_top_panel`].
+    #[inline]
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// Show the header and then the body of the section.
+    ///
+    /// Returns the responses of the header and the body.
+    pub fn show<HeaderRet, BodyRet>(
+        self,
+        ui: &mut Ui,
+        add_header: impl FnOnce(&mut Ui) -> HeaderRet,
+        add_body: impl FnOnce(&mut Ui) -> BodyRet,
+    ) -> (InnerResponse<HeaderRet>, InnerResponse<BodyRet>) {
+        let Self { id_source, frame } = self;
+        let id = ui.make_persistent_id(id_source);
+        let frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
+
+        // We need to know the height of the header before showing it,
+        // since it is shown after the body, so that it covers the body.
+        let header_height_id = id.with("header_height");
+        let header_height = ui
+            .data(|d| d.get_temp::<f32>(header_height_id))
+            .unwrap_or(ui.spacing().interact_size.y);
+        let (_, header_slot) = ui.allocate_space(vec2(ui.available_width(), header_height));
+
+        let body = ui.scope(add_body);
+
+        // The top of the viewport of the surrounding `ScrollArea`:
+        let viewport_top = ui.clip_rect().top() + ui.visuals().clip_rect_margin;
+        let section_bottom = body.response.rect.bottom();
+        // The header can't go above its own slot, nor below the end of the section:
+        let lowest_top = (section_bottom - header_height).max(header_slot.top());
+        let header_top = viewport_top.clamp(header_slot.top(), lowest_top);
+        let is_stuck = header_top != header_slot.top();
+
+        let header_rect = header_slot.translate(vec2(0.0, header_top - header_slot.top()));
+        let mut header_ui = ui.child_ui_with_id_source(header_rect, *ui.layout(), "sticky_header");
+        if is_stuck {
+            // Don't let the pointer reach the body behind the header:
+            header_ui.interact(header_rect, id.with("background"), Sense::click());
+        }
+        let header = frame.show(&mut header_ui, |ui| {
+            ui.set_min_width(ui.available_width());
+            add_header(ui)
+        });
+
+        let new_header_height = header.response.rect.height();
+        if (new_header_height - header_height).abs() > 0.5 {
+            ui.data_mut(|d| d.insert_temp(header_height_id, new_header_height));
+            ui.ctx().request_repaint();
+        }
+
+        (header, body)
+    }
+}
@@ -15,7 +15,9 @@ use super::*;
 /// * default, minimum, maximum and/or fixed size, collapsed/expanded
 /// * if the window has a scroll area (off by default)
 /// * if the window can be collapsed (minimized) to just the title bar (yes, by default)
+/// * if there should be a maximize button (no by default)
 /// * if there should be a close button (none by default)
+/// * if the window is modal, blocking interaction with everything behind it (no by default)
 ///
 /// ```
 /// # egui::__run_test_ctx(|ctx| {
@@ -38,6 +40,8 @@ pub struct Window<'open> {
     resize: Resize,
     scroll: ScrollArea,
     collapsible: bool,
+    maximize_button: bool,
+    modal: bool,
     default_open: bool,
     with_title_bar: bool,
 }
@@ -60,6 +64,8 @@ impl<'open> Window<'open> {
                 .default_size([340.0, 420.0]), // Default inner size of a window
             scroll: ScrollArea::neither(),
             collapsible: true,
+            maximize_button: false,
+            modal: false,
             default_open: true,
             with_title_bar: true,
         }
@@ -72,6 +78,13 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// The [`LayerId`] of this window, e.g. for use with [`Context::move_to_top`],
+    /// [`Context::move_above`] and [`Context::layer_ids_in_order`].
+    #[inline]
+    pub fn layer_id(&self) -> LayerId {
+        self.area.layer()
+    }
+
     /// Call this to add a close-button to the window title bar.
     ///
     /// * If `*open == false`, the window will not be visible.
@@ -316,8 +329,51 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Show a button in the title bar that maximizes the window? Default: `false`.
+    ///
+    /// A maximized window fills [`Context::available_rect`] (or the rect given to [`Self::constrain_to`]),
+    /// and can't be moved or resized.
+    /// Clicking the button again restores the window to where it was before.
+    ///
+    /// The maximized state is remembered together with the rest of the window state.
+    #[inline]
+    pub fn maximize_button(mut self, maximize_button: bool) -> Self {
+        self.maximize_button = maximize_button;
+        self
+    }
+
+    /// Make the window modal? Default: `false`.
+    ///
+    /// A modal window is shown on top of everything else, and dims the rest of the UI.
+    /// Nothing behind it can be clicked, hovered or get keyboard focus,
+    /// so pressing Tab only moves focus between the widgets of the modal window
+    /// (and any popups opened from it).
+    ///
+    /// If the window has an [`Self::open`] flag, pressing Escape closes it.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let mut show_dialog = true;
+    /// egui::Window::new("Are you sure?")
+    ///     .modal(true)
+    ///     .open(&mut show_dialog)
+    ///     .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+    ///     .show(ctx, |ui| {
+    ///         ui.label("This cannot be undone.");
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn modal(mut self, modal: bool) -> Self {
+        self.modal = modal;
+        if modal {
+            self.area = self.area.order(Order::Foreground);
+        }
+        self
+    }
+
     /// Show title bar on top of the window?
-    /// If `false`, the window will not be collapsible nor have a close-button.
+    /// If `false`, the window will not be collapsible nor have a close- or maximize-button.
     #[inline]
     pub fn title_bar(mut self, title_bar: bool) -> Self {
         self.with_title_bar = title_bar;
@@ -394,16 +450,30 @@ impl<'open> Window<'open> {
     ) -> Option<InnerResponse<Option<R>>> {
         let Window {
             title,
-            open,
+            mut open,
             area,
             frame,
             resize,
             scroll,
             collapsible,
+            maximize_button,
+            modal,
             default_open,
             with_title_bar,
         } = self;
 
+        if modal {
+            if let Some(open) = &mut open {
+                // Escape closes the modal window, unless it is meant for a popup inside of it:
+                if **open
+                    && !ctx.memory(|mem| mem.any_popup_open())
+                    && ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape))
+                {
+                    **open = false;
+                }
+            }
+        }
+
         let header_color =
             frame.map_or_else(|| ctx.style().visuals.widgets.open.weak_bg_fill, |f| f.fill);
         let mut window_frame = frame.unwrap_or_else(|| Frame::window(&ctx.style()));
@@ -427,15 +497,34 @@ impl<'open> Window<'open> {
         let mut collapsing =
             CollapsingState::load_with_default_open(ctx, area_id.with("collapsing"), default_open);
 
+        let maximize_id = area_id.with("maximize");
+        let show_maximize_button = with_title_bar && maximize_button;
+        let mut maximize_state = if show_maximize_button {
+            MaximizeState::load(ctx, maximize_id).unwrap_or_default()
+        } else {
+            MaximizeState::default()
+        };
+
+        // A maximized window stays where it is put:
+        let (area, resize) = if maximize_state.maximized {
+            (area.movable(false), resize.resizable(false))
+        } else {
+            (area, resize)
+        };
+
         let is_collapsed = with_title_bar && !collapsing.is_open();
         let possible = PossibleInteractions::new(&area, &resize, is_collapsed);
 
         let resize = resize.resizable(false); // We resize it manually
         let mut resize = resize.id(resize_id);
 
-        let on_top = Some(area_layer_id) == ctx.top_layer_id();
+        let on_top = Some(area_layer_id) == ctx.top_layer_id() || modal;
         let mut area = area.begin(ctx);
 
+        if modal {
+            ctx.memory_mut(|mem| mem.areas_mut().set_modal_layer(area_layer_id));
+        }
+
         // Calculate roughly how much larger the window size is compared to the inner rect
         let (title_bar_height, title_content_spacing) = if with_title_bar {
             let style = ctx.style();
@@ -476,14 +565,38 @@ impl<'open> Window<'open> {
             resize_id,
         );
 
+        if show_maximize_button {
+            maximize_response(
+                &mut maximize_state,
+                ctx,
+                maximize_id,
+                margins,
+                &mut area,
+                resize_id,
+            );
+        }
+
         let mut area_content_ui = area.content_ui(ctx);
 
+        if modal {
+            // Dim everything behind the modal window:
+            let screen_rect = ctx.screen_rect();
+            area_content_ui
+                .painter()
+                .with_clip_rect(screen_rect)
+                .rect_filled(screen_rect, 0.0, Color32::from_black_alpha(100));
+        }
+
         let content_inner = {
             // BEGIN FRAME --------------------------------
             let frame_stroke = window_frame.stroke;
             let mut frame = window_frame.begin(&mut area_content_ui);
 
-            let show_close_button = open.is_some();
+            let buttons = TitleBarButtons {
+                collapse: collapsible,
+                close: open.is_some(),
+                maximize: show_maximize_button,
+            };
 
             let where_to_put_header_background = &area_content_ui.painter().add(Shape::Noop);
 
@@ -493,13 +606,8 @@ impl<'open> Window<'open> {
             frame.content_ui.spacing_mut().item_spacing.y = title_content_spacing;
 
             let title_bar = if with_title_bar {
-                let title_bar = show_title_bar(
-                    &mut frame.content_ui,
-                    title,
-                    show_close_button,
-                    &mut collapsing,
-                    collapsible,
-                );
+                let title_bar =
+                    show_title_bar(&mut frame.content_ui, title, buttons, &mut collapsing);
                 resize.min_size.x = resize.min_size.x.at_least(title_bar.rect.width()); // Prevent making window smaller than title bar width
                 Some(title_bar)
             } else {
@@ -568,18 +676,27 @@ impl<'open> Window<'open> {
                     response.rect.min.y = outer_rect.min.y + title_bar_height + border_padding;
                 }
 
+                let maximized = show_maximize_button.then_some(&mut maximize_state.maximized);
                 title_bar.ui(
                     &mut area_content_ui,
                     title_rect,
                     &content_response,
                     open,
+                    maximized,
                     &mut collapsing,
-                    collapsible,
                 );
             }
 
             collapsing.store(ctx);
 
+            if show_maximize_button {
+                if maximize_state.maximized && maximize_state.restore_rect.is_none() {
+                    // Just maximized: remember where to go back to.
+                    maximize_state.restore_rect = Some(outer_rect);
+                }
+                maximize_state.store(ctx, maximize_id);
+            }
+
             paint_frame_interaction(&area_content_ui, outer_rect, resize_interaction);
 
             content_inner
@@ -765,6 +882,62 @@ fn resize_response(
     ctx.memory_mut(|mem| mem.areas_mut().move_to_top(area_layer_id));
 }
 
+/// Whether a [`Window`] is maximized, and where it was before that.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct MaximizeState {
+    maximized: bool,
+
+    /// The outer rect of the window before it was maximized.
+    ///
+    /// Kept until the restore animation is done.
+    restore_rect: Option<Rect>,
+}
+
+impl MaximizeState {
+    fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_persisted(id))
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+}
+
+/// Move and resize a maximized window (or one that is animating to or from being maximized).
+fn maximize_response(
+    maximize_state: &mut MaximizeState,
+    ctx: &Context,
+    maximize_id: Id,
+    margins: Vec2,
+    area: &mut area::Prepared,
+    resize_id: Id,
+) {
+    let how_maximized = ctx.animate_bool(maximize_id, maximize_state.maximized);
+
+    let Some(restore_rect) = maximize_state.restore_rect else {
+        return;
+    };
+
+    let maximized_rect = area
+        .constrain_rect()
+        .unwrap_or_else(|| ctx.available_rect());
+    let new_rect =
+        ctx.round_rect_to_pixels(restore_rect.lerp_towards(&maximized_rect, how_maximized));
+
+    if !maximize_state.maximized && how_maximized == 0.0 {
+        // Done restoring:
+        maximize_state.restore_rect = None;
+    }
+
+    area.state_mut().set_left_top_pos(new_rect.left_top());
+
+    if let Some(mut state) = resize::State::load(ctx, resize_id) {
+        state.requested_size = Some(new_rect.size() - margins);
+        state.store(ctx, resize_id);
+    }
+}
+
 fn move_and_resize_window(ctx: &Context, interaction: &ResizeInteraction) -> Option<Rect> {
     if !interaction.any_dragged() {
         return None;
@@ -1003,14 +1176,28 @@ struct TitleBar {
     /// Size of the title bar in an expanded state. This size become known only
     /// after expanding window and painting its content
     rect: Rect,
+
+    buttons: TitleBarButtons,
+}
+
+/// Which buttons to show in the title bar of a [`Window`].
+#[derive(Clone, Copy)]
+struct TitleBarButtons {
+    /// The collapse button on the left. Also allows collapsing by double-clicking the title bar.
+    collapse: bool,
+
+    /// The close button on the right.
+    close: bool,
+
+    /// The maximize button on the right, left of the close button.
+    maximize: bool,
 }
 
 fn show_title_bar(
     ui: &mut Ui,
     title: WidgetText,
-    show_close_button: bool,
+    buttons: TitleBarButtons,
     collapsing: &mut CollapsingState,
-    collapsible: bool,
 ) -> TitleBar {
     let inner_response = ui.horizontal(|ui| {
         let height = ui
@@ -1023,19 +1210,23 @@ fn show_title_bar(
 
         let pad = (height - button_size.y) / 2.0; // calculated so that the icon is on the diagonal (if window padding is symmetrical)
 
-        if collapsible {
+        if buttons.collapse {
             ui.add_space(pad);
             collapsing.show_default_button_with_size(ui, button_size);
         }
 
         let title_galley = title.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Heading);
 
-        let minimum_width = if collapsible || show_close_button {
+        let mut minimum_width = if buttons.collapse || buttons.close || buttons.maximize {
             // If at least one button is shown we make room for both buttons (since title is centered):
             2.0 * (pad + button_size.x + item_spacing.x) + title_galley.size().x
         } else {
             pad + title_galley.size().x + pad
         };
+        if buttons.close && buttons.maximize {
+            // Two buttons on the right, so make room for two on each side:
+            minimum_width += 2.0 * (button_size.x + item_spacing.x);
+        }
         let min_rect = Rect::from_min_size(ui.min_rect().min, vec2(minimum_width, height));
         let id = ui.advance_cursor_after_rect(min_rect);
 
@@ -1044,6 +1235,7 @@ fn show_title_bar(
             title_galley,
             min_rect,
             rect: Rect::NAN, // Will be filled in later
+            buttons,
         }
     });
 
@@ -1064,24 +1256,25 @@ impl TitleBar {
     ///   a result of rendering the window content
     /// - `open`: if `None`, no "Close" button will be rendered, otherwise renders and processes
     ///   the "Close" button and writes a `false` if window was closed
+    /// - `maximized`: if `None`, no "Maximize" button will be rendered, otherwise renders and
+    ///   processes the "Maximize" button and toggles the value if it was clicked
     /// - `collapsing`: holds the current expanding state. Can be changed by double click on the
-    ///   title if `collapsible` is `true`
-    /// - `collapsible`: if `true`, double click on the title bar will be handled for a change
-    ///   of `collapsing` state
+    ///   title if the window is collapsible
     fn ui(
         mut self,
         ui: &mut Ui,
         outer_rect: Rect,
         content_response: &Option<Response>,
         open: Option<&mut bool>,
+        maximized: Option<&mut bool>,
         collapsing: &mut CollapsingState,
-        collapsible: bool,
     ) {
         if let Some(content_response) = &content_response {
             // Now we know how large we got to be:
             self.rect.max.x = self.rect.max.x.max(content_response.rect.max.x);
         }
 
+        let show_close_button = open.is_some();
         if let Some(open) = open {
             // Add close button now that we know our full width:
             if self.close_button_ui(ui).clicked() {
@@ -1089,6 +1282,16 @@ impl TitleBar {
             }
         }
 
+        let show_maximize_button = maximized.is_some();
+        if let Some(maximized) = maximized {
+            if self
+                .maximize_button_ui(ui, *maximized, show_close_button)
+                .clicked()
+            {
+                *maximized = !*maximized;
+            }
+        }
+
         let full_top_rect = Rect::from_x_y_ranges(self.rect.x_range(), self.min_rect.y_range());
         let text_pos =
             emath::align::center_size_in_rect(self.title_galley.size(), full_top_rect).left_top();
@@ -1111,13 +1314,16 @@ impl TitleBar {
             ui.painter().hline(x_range, y, stroke);
         }
 
-        // Don't cover the close- and collapse buttons:
-        let double_click_rect = self.rect.shrink2(vec2(32.0, 0.0));
+        // Don't cover the close-, maximize- and collapse buttons:
+        let mut double_click_rect = self.rect.shrink2(vec2(32.0, 0.0));
+        if show_close_button && show_maximize_button {
+            double_click_rect.max.x -= ui.spacing().icon_width + ui.spacing().item_spacing.x;
+        }
 
         if ui
             .interact(double_click_rect, self.id, Sense::click())
             .double_clicked()
-            && collapsible
+            && self.buttons.collapse
         {
             collapsing.toggle(ui);
         }
@@ -1141,6 +1347,31 @@ impl TitleBar {
 
         close_button(ui, button_rect)
     }
+
+    /// Paints the "Maximize" (or "Restore") button at the right side of the title bar,
+    /// to the left of the "Close" button if there is one, and processes clicks on it.
+    fn maximize_button_ui(
+        &self,
+        ui: &mut Ui,
+        maximized: bool,
+        show_close_button: bool,
+    ) -> Response {
+        let button_size = Vec2::splat(ui.spacing().icon_width);
+        let pad = (self.rect.height() - button_size.y) / 2.0; // calculated so that the icon is on the diagonal (if window padding is symmetrical)
+        let mut right = self.rect.right() - pad;
+        if show_close_button {
+            right -= button_size.x + ui.spacing().item_spacing.x;
+        }
+        let button_rect = Rect::from_min_size(
+            pos2(
+                right - button_size.x,
+                self.rect.center().y - 0.5 * button_size.y,
+            ),
+            button_size,
+        );
+
+        maximize_button(ui, button_rect, maximized)
+    }
 }
 
 /// Paints the "Close" button of the window and processes clicks on it.
@@ -1167,3 +1398,28 @@ fn close_button(ui: &mut Ui, rect: Rect) -> Response {
         .line_segment([rect.right_top(), rect.left_bottom()], stroke);
     response
 }
+
+/// Paints the "Maximize" button of the window and processes clicks on it.
+///
+/// The button is a square, or two overlapping squares ("Restore") if the window is already maximized.
+///
+/// Returns the result of a click on a button if it was pressed
+fn maximize_button(ui: &mut Ui, rect: Rect, maximized: bool) -> Response {
+    let maximize_id = ui.auto_id_with("window_maximize_button");
+    let response = ui.interact(rect, maximize_id, Sense::click());
+    ui.expand_to_include_rect(response.rect);
+
+    let visuals = ui.style().interact(&response);
+    let rect = rect.shrink(2.0).expand(visuals.expansion);
+    let stroke = visuals.fg_stroke;
+    if maximized {
+        let offset = 0.25 * rect.width();
+        let back = Rect::from_min_max(rect.min + vec2(offset, 0.0), rect.max - vec2(0.0, offset));
+        let front = Rect::from_min_max(rect.min + vec2(0.0, offset), rect.max - vec2(offset, 0.0));
+        ui.painter().rect_stroke(back, 0.0, stroke);
+        ui.painter().rect_stroke(front, 0.0, stroke);
+    } else {
+        ui.painter().rect_stroke(rect, 0.0, stroke);
+    }
+    response
+}
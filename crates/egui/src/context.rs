@@ -463,6 +463,10 @@ impl ContextImpl {
                 }
             });
 
+            // Nothing below a modal window can be interacted with:
+            let areas = self.memory.areas();
+            layers.retain(|layer| !areas.is_below_modal_layer(*layer));
+
             viewport.hits = if let Some(pos) = viewport.input.pointer.interact_pos() {
                 let interact_radius = self.memory.options.style.interaction.interact_radius;
 
@@ -702,6 +706,7 @@ impl Default for Context {
         crate::debug_text::register(&ctx);
         crate::text_selection::LabelSelectionState::register(&ctx);
         crate::DragAndDrop::register(&ctx);
+        crate::drag_and_drop::FileDropTarget::register(&ctx);
 
         ctx
     }
@@ -1018,7 +1023,7 @@ impl Context {
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn create_widget(&self, w: WidgetRect) -> Response {
         // Remember this widget
-        self.write(|ctx| {
+        let below_modal = self.write(|ctx| {
             let viewport = ctx.viewport();
 
             // We add all widgets here, even non-interactive ones,
@@ -1026,12 +1031,17 @@ impl Context {
             // but also to know when we have reached the widget we are checking for cover.
             viewport.widgets_this_frame.insert(w.layer_id, w);
 
-            if w.sense.focusable {
+            // Keyboard focus is trapped inside a modal window:
+            let below_modal = ctx.memory.areas().is_below_modal_layer(w.layer_id);
+
+            if w.sense.focusable && !below_modal {
                 ctx.memory.interested_in_focus(w.id);
             }
+
+            below_modal
         });
 
-        if !w.enabled || !w.sense.focusable || !w.layer_id.allow_interaction() {
+        if !w.enabled || !w.sense.focusable || !w.layer_id.allow_interaction() || below_modal {
             // Not interested or allowed input:
             self.memory_mut(|mem| mem.surrender_focus(w.id));
         }
@@ -1202,6 +1212,17 @@ impl Context {
         res
     }
 
+    /// All widgets added to the given layer so far this frame, in the order they were added.
+    pub(crate) fn widgets_in_layer(&self, layer_id: LayerId) -> Vec<WidgetRect> {
+        self.write(|ctx| {
+            ctx.viewport()
+                .widgets_this_frame
+                .get_layer(layer_id)
+                .copied()
+                .collect()
+        })
+    }
+
     /// This is called by [`Response::widget_info`], but can also be called directly.
     ///
     /// With some debug flags it will store the widget info in [`WidgetRects`] for later display.
@@ -1566,6 +1587,42 @@ impl Context {
         }
     }
 
+    /// Add a font to the existing ones, e.g. to support more characters.
+    ///
+    /// Unlike [`Self::set_fonts`], this does not start over with all fonts:
+    /// the glyphs already in the font atlas are kept,
+    /// and only the text that the new font changes is laid out again.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// # let font_bytes: &'static [u8] = &[];
+    /// use egui::{FontData, FontFamily, FontInsert, FontPriority, InsertFontFamily};
+    ///
+    /// ctx.add_font(FontInsert::new(
+    ///     "my_cjk_font",
+    ///     FontData::from_static(font_bytes),
+    ///     vec![InsertFontFamily {
+    ///         family: FontFamily::Proportional,
+    ///         priority: FontPriority::Lowest,
+    ///     }],
+    /// ));
+    /// ```
+    pub fn add_font(&self, new_font: FontInsert) {
+        crate::profile_function!();
+
+        self.write(|ctx| {
+            if let Some(pending) = &mut ctx.memory.new_font_definitions {
+                pending.add_font(new_font.clone());
+            }
+            for fonts in ctx.fonts.values() {
+                fonts.add_font(new_font.clone());
+            }
+            ctx.font_definitions.add_font(new_font);
+        });
+
+        self.request_repaint();
+    }
+
     /// The [`Style`] used by all subsequent windows, panels etc.
     pub fn style(&self) -> Arc<Style> {
         self.options(|opt| opt.style.clone())
@@ -2237,7 +2294,10 @@ impl Context {
     pub fn is_pointer_over_area(&self) -> bool {
         let pointer_pos = self.input(|i| i.pointer.interact_pos());
         if let Some(pointer_pos) = pointer_pos {
-            if let Some(layer) = self.layer_id_at(pointer_pos) {
+            if self.memory(|mem| mem.areas().modal_layer().is_some()) {
+                // A modal window blocks everything behind it.
+                true
+            } else if let Some(layer) = self.layer_id_at(pointer_pos) {
                 if layer.order == Order::Background {
                     !self.frame_state(|state| state.unused_rect.contains(pointer_pos))
                 } else {
@@ -2384,6 +2444,36 @@ impl Context {
         self.memory_mut(|mem| mem.areas_mut().move_to_top(layer_id));
     }
 
+    /// Moves the given area to the bottom in its [`Order`], below all others.
+    pub fn move_to_bottom(&self, layer_id: LayerId) {
+        self.memory_mut(|mem| mem.areas_mut().move_to_bottom(layer_id));
+    }
+
+    /// Moves the area `layer_id` to be directly above the area `other`.
+    ///
+    /// Does nothing unless both are in the same [`Order`], and `other` has been shown.
+    pub fn move_above(&self, layer_id: LayerId, other: LayerId) {
+        self.memory_mut(|mem| mem.areas_mut().move_above(layer_id, other));
+    }
+
+    /// Moves the area `layer_id` to be directly below the area `other`.
+    ///
+    /// Does nothing unless both are in the same [`Order`], and `other` has been shown.
+    pub fn move_below(&self, layer_id: LayerId, other: LayerId) {
+        self.memory_mut(|mem| mem.areas_mut().move_below(layer_id, other));
+    }
+
+    /// The current stacking of all layers in the given [`Order`], back-to-front (top-most last).
+    ///
+    /// For instance, `ctx.layer_ids_in_order(Order::Middle)` lists all the [`Window`]s.
+    pub fn layer_ids_in_order(&self, order: Order) -> Vec<LayerId> {
+        self.memory(|mem| {
+            mem.layer_ids()
+                .filter(|layer| layer.order == order)
+                .collect()
+        })
+    }
+
     /// Retrieve the [`LayerId`] of the top level windows.
     pub fn top_layer_id(&self) -> Option<LayerId> {
         self.memory(|mem| mem.areas().top_layer_id(Order::Middle))
@@ -63,6 +63,11 @@ pub struct RawInput {
     /// Dragged files hovering over egui.
     pub hovered_files: Vec<HoveredFile>,
 
+    /// Where the [`Self::hovered_files`] are being held, in points, if known.
+    ///
+    /// If `None`, egui will use the latest pointer position instead.
+    pub hovered_files_pos: Option<Pos2>,
+
     /// Dragged files dropped into egui.
     ///
     /// Note: when using `eframe` on Windows you need to enable
@@ -87,6 +92,7 @@ impl Default for RawInput {
             modifiers: Modifiers::default(),
             events: vec![],
             hovered_files: Default::default(),
+            hovered_files_pos: None,
             dropped_files: Default::default(),
             focused: true, // integrations opt into global focus tracking
         }
@@ -102,7 +108,7 @@ impl RawInput {
 
     /// Helper: move volatile (deltas and events), clone the rest.
     ///
-    /// * [`Self::hovered_files`] is cloned.
+    /// * [`Self::hovered_files`] and [`Self::hovered_files_pos`] are cloned.
     /// * [`Self::dropped_files`] is moved.
     pub fn take(&mut self) -> Self {
         Self {
@@ -115,6 +121,7 @@ impl RawInput {
             modifiers: self.modifiers,
             events: std::mem::take(&mut self.events),
             hovered_files: self.hovered_files.clone(),
+            hovered_files_pos: self.hovered_files_pos,
             dropped_files: std::mem::take(&mut self.dropped_files),
             focused: self.focused,
         }
@@ -132,6 +139,7 @@ impl RawInput {
             modifiers,
             mut events,
             mut hovered_files,
+            hovered_files_pos,
             mut dropped_files,
             focused,
         } = newer;
@@ -145,6 +153,7 @@ impl RawInput {
         self.modifiers = modifiers; // use latest
         self.events.append(&mut events);
         self.hovered_files.append(&mut hovered_files);
+        self.hovered_files_pos = hovered_files_pos; // use latest
         self.dropped_files.append(&mut dropped_files);
         self.focused = focused;
     }
@@ -1011,6 +1020,7 @@ impl RawInput {
             modifiers,
             events,
             hovered_files,
+            hovered_files_pos,
             dropped_files,
             focused,
         } = self;
@@ -1035,6 +1045,7 @@ impl RawInput {
         ui.label(format!("predicted_dt: {:.1} ms", 1e3 * predicted_dt));
         ui.label(format!("modifiers: {modifiers:#?}"));
         ui.label(format!("hovered_files: {}", hovered_files.len()));
+        ui.label(format!("hovered_files_pos: {hovered_files_pos:?}"));
         ui.label(format!("dropped_files: {}", dropped_files.len()));
         ui.label(format!("focused: {focused}"));
         ui.scope(|ui| {
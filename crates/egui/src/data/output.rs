@@ -628,7 +628,10 @@ impl WidgetInfo {
             WidgetType::TextEdit => "text edit",
             WidgetType::Button => "button",
             WidgetType::Checkbox => "checkbox",
+            WidgetType::Switch => "switch",
             WidgetType::RadioButton => "radio",
+            WidgetType::MenuItemCheckbox => "menu checkbox",
+            WidgetType::MenuItemRadio => "menu radio",
             WidgetType::SelectableLabel => "selectable",
             WidgetType::ComboBox => "combo",
             WidgetType::Slider => "slider",
@@ -643,9 +646,12 @@ impl WidgetInfo {
         let mut description = widget_type.to_owned();
 
         if let Some(selected) = selected {
-            if *typ == WidgetType::Checkbox {
+            if *typ == WidgetType::Checkbox || *typ == WidgetType::MenuItemCheckbox {
                 let state = if *selected { "checked" } else { "unchecked" };
                 description = format!("{state} {description}");
+            } else if *typ == WidgetType::Switch {
+                let state = if *selected { "on" } else { "off" };
+                description = format!("{state} {description}");
             } else {
                 description += if *selected { "selected" } else { "" };
             };
@@ -105,12 +105,11 @@ impl State {
 
             {
                 // Paint `text` to right of `pos`:
-                let wrap = true;
                 let available_width = ctx.screen_rect().max.x - pos.x;
                 let galley = text.into_galley_impl(
                     ctx,
                     &ctx.style(),
-                    wrap,
+                    TextWrapMode::Wrap,
                     available_width,
                     font_id.clone().into(),
                     Align::TOP,
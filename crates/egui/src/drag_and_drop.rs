@@ -12,6 +12,8 @@ use crate::{Context, CursorIcon, Id};
 /// - [`crate::Response::dnd_set_drag_payload`]
 /// - [`crate::Response::dnd_hover_payload`]
 /// - [`crate::Response::dnd_release_payload`]
+/// - [`crate::Response::dnd_highlight_drop_target`]
+/// - [`crate::Response::dnd_drag_ghost`]
 ///
 /// See [this example](https://github.com/emilk/egui/blob/master/crates/egui_demo_lib/src/demo/drag_and_drop.rs).
 #[doc(alias = "drag and drop")]
@@ -123,3 +125,52 @@ impl DragAndDrop {
         })
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// Tracks which widget is the target for files dragged onto egui from the OS.
+///
+/// See [`crate::Response::accept_file_drop`].
+#[derive(Clone, Default)]
+pub(crate) struct FileDropTarget {
+    /// The widget that accepted the hovered files during this frame.
+    this_frame: Option<Id>,
+
+    /// The widget that last accepted the hovered files.
+    ///
+    /// Dropped files are delivered to it.
+    target: Option<Id>,
+}
+
+impl FileDropTarget {
+    pub(crate) fn register(ctx: &Context) {
+        ctx.on_end_frame("FileDropTarget", std::sync::Arc::new(Self::end_frame));
+    }
+
+    fn end_frame(ctx: &Context) {
+        let is_hovering_files = ctx.input(|i| !i.raw.hovered_files.is_empty());
+
+        ctx.data_mut(|data| {
+            let state = data.get_temp_mut_or_default::<Self>(Id::NULL);
+            let this_frame = state.this_frame.take();
+
+            // We keep the target after the hover ends, because the dropped files
+            // may arrive some frames later (e.g. on web, where they are loaded asynchronously).
+            if is_hovering_files {
+                state.target = this_frame;
+            }
+        });
+    }
+
+    /// Make the given widget the target for the files being hovered this frame.
+    pub(crate) fn accept(ctx: &Context, id: Id) {
+        ctx.data_mut(|data| {
+            data.get_temp_mut_or_default::<Self>(Id::NULL).this_frame = Some(id);
+        });
+    }
+
+    /// The widget which should receive the dropped files, if any.
+    pub(crate) fn target(ctx: &Context) -> Option<Id> {
+        ctx.data(|data| data.get_temp::<Self>(Id::NULL)?.target)
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use crate::*;
 
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -30,6 +32,36 @@ impl State {
         self.row_heights[row] = self.row_heights[row].max(height);
     }
 
+    /// Make sure the given columns are together at least this wide,
+    /// by widening the last of them if needed.
+    fn set_min_span_width(&mut self, cols: Range<usize>, width: f32, x_spacing: f32) {
+        let current = cols
+            .clone()
+            .map(|col| self.col_width(col).unwrap_or(0.0))
+            .sum::<f32>()
+            + (cols.len() - 1) as f32 * x_spacing;
+        if current < width {
+            let last = cols.end - 1;
+            let last_width = self.col_width(last).unwrap_or(0.0);
+            self.set_min_col_width(last, last_width + width - current);
+        }
+    }
+
+    /// Make sure the given rows are together at least this high,
+    /// by heightening the last of them if needed.
+    fn set_min_span_height(&mut self, rows: Range<usize>, height: f32, y_spacing: f32) {
+        let current = rows
+            .clone()
+            .map(|row| self.row_height(row).unwrap_or(0.0))
+            .sum::<f32>()
+            + (rows.len() - 1) as f32 * y_spacing;
+        if current < height {
+            let last = rows.end - 1;
+            let last_height = self.row_height(last).unwrap_or(0.0);
+            self.set_min_row_height(last, last_height + height - current);
+        }
+    }
+
     fn col_width(&self, col: usize) -> Option<f32> {
         self.col_widths.get(col).copied()
     }
@@ -49,6 +81,28 @@ impl State {
 // type alias for boxed function to determine row color during grid generation
 type ColorPickerFn = Box<dyn Send + Sync + Fn(usize, &Style) -> Option<Color32>>;
 
+/// Options for a single column of a [`Grid`].
+#[derive(Clone, Copy, Debug, Default)]
+struct ColumnOptions {
+    align: Option<Align>,
+    min_width: Option<f32>,
+    max_width: Option<f32>,
+}
+
+/// A cell spanning more than one column and/or row.
+struct CellSpan {
+    cols: Range<usize>,
+    rows: Range<usize>,
+    size: Vec2,
+}
+
+impl CellSpan {
+    /// Does this cell cover the given cell of a later row?
+    fn covers_below(&self, col: usize, row: usize) -> bool {
+        self.rows.start < row && self.rows.contains(&row) && self.cols.contains(&col)
+    }
+}
+
 pub(crate) struct GridLayout {
     ctx: Context,
     style: std::sync::Arc<Style>,
@@ -70,11 +124,18 @@ pub(crate) struct GridLayout {
     spacing: Vec2,
     min_cell_size: Vec2,
     max_cell_size: Vec2,
+    columns: Vec<ColumnOptions>,
     color_picker: Option<ColorPickerFn>,
 
     // Cursor:
     col: usize,
     row: usize,
+
+    /// How many columns and rows the next cell spans.
+    cell_span: [usize; 2],
+
+    /// All cells so far this frame that span more than one column or row.
+    spans: Vec<CellSpan>,
 }
 
 impl GridLayout {
@@ -105,19 +166,36 @@ impl GridLayout {
             spacing: ui.spacing().item_spacing,
             min_cell_size: ui.spacing().interact_size,
             max_cell_size: Vec2::INFINITY,
+            columns: Vec::new(),
             color_picker: None,
 
             col: 0,
             row: 0,
+
+            cell_span: [1, 1],
+            spans: Vec::new(),
         }
     }
 }
 
 impl GridLayout {
+    fn column(&self, col: usize) -> ColumnOptions {
+        self.columns.get(col).copied().unwrap_or_default()
+    }
+
+    fn min_col_width(&self, col: usize) -> f32 {
+        self.column(col).min_width.unwrap_or(self.min_cell_size.x)
+    }
+
+    /// Soft maximum (wrapping) width of the column.
+    fn max_col_width(&self, col: usize) -> f32 {
+        self.column(col).max_width.unwrap_or(self.max_cell_size.x)
+    }
+
     fn prev_col_width(&self, col: usize) -> f32 {
         self.prev_state
             .col_width(col)
-            .unwrap_or(self.min_cell_size.x)
+            .unwrap_or_else(|| self.min_col_width(col))
     }
 
     fn prev_row_height(&self, row: usize) -> f32 {
@@ -126,12 +204,52 @@ impl GridLayout {
             .unwrap_or(self.min_cell_size.y)
     }
 
+    /// Width of the given columns last frame, including the spacing between them.
+    fn prev_span_width(&self, cols: Range<usize>) -> f32 {
+        let num_cols = cols.len();
+        cols.map(|col| self.prev_col_width(col)).sum::<f32>()
+            + num_cols.saturating_sub(1) as f32 * self.spacing.x
+    }
+
+    /// Height of the given rows last frame, including the spacing between them.
+    fn prev_span_height(&self, rows: Range<usize>) -> f32 {
+        let num_rows = rows.len();
+        rows.map(|row| self.prev_row_height(row)).sum::<f32>()
+            + num_rows.saturating_sub(1) as f32 * self.spacing.y
+    }
+
+    fn span_cols(&self) -> Range<usize> {
+        self.col..self.col + self.cell_span[0]
+    }
+
+    fn span_rows(&self) -> Range<usize> {
+        self.row..self.row + self.cell_span[1]
+    }
+
+    fn is_covered(&self, col: usize) -> bool {
+        self.spans
+            .iter()
+            .any(|span| span.covers_below(col, self.row))
+    }
+
+    pub(crate) fn set_cell_span(&mut self, columns: usize, rows: usize) {
+        self.cell_span = [columns.max(1), rows.max(1)];
+    }
+
+    /// Skip the cells covered by cells above spanning several rows.
+    fn skip_covered_cells(&mut self, cursor: &mut Rect) {
+        while self.is_covered(self.col) {
+            cursor.min.x += self.prev_col_width(self.col) + self.spacing.x;
+            self.col += 1;
+        }
+    }
+
     pub(crate) fn wrap_text(&self) -> bool {
-        self.max_cell_size.x.is_finite()
+        self.max_col_width(self.col).is_finite()
     }
 
     pub(crate) fn available_rect(&self, region: &Region) -> Rect {
-        let is_last_column = Some(self.col + 1) == self.num_columns;
+        let is_last_column = Some(self.span_cols().end) == self.num_columns;
 
         let width = if is_last_column {
             // The first frame we don't really know the widths of the previous columns,
@@ -139,25 +257,31 @@ impl GridLayout {
             if self.is_first_frame {
                 self.curr_state
                     .col_width(self.col)
-                    .unwrap_or(self.min_cell_size.x)
+                    .unwrap_or_else(|| self.min_col_width(self.col))
             } else {
                 (self.initial_available.right() - region.cursor.left())
-                    .at_most(self.max_cell_size.x)
+                    .at_most(self.max_col_width(self.col))
             }
-        } else if self.max_cell_size.x.is_finite() {
+        } else if 1 < self.cell_span[0] {
+            self.prev_span_width(self.span_cols())
+        } else if self.max_col_width(self.col).is_finite() {
             // TODO(emilk): should probably heed `prev_state` here too
-            self.max_cell_size.x
+            self.max_col_width(self.col)
         } else {
             // If we want to allow width-filling widgets like [`Separator`] in one of the first cells
             // then we need to make sure they don't spill out of the first cell:
             self.prev_state
                 .col_width(self.col)
                 .or_else(|| self.curr_state.col_width(self.col))
-                .unwrap_or(self.min_cell_size.x)
+                .unwrap_or_else(|| self.min_col_width(self.col))
         };
 
         // If something above was wider, we can be wider:
-        let width = width.max(self.curr_state.col_width(self.col).unwrap_or(0.0));
+        let width = if self.cell_span[0] == 1 {
+            width.max(self.curr_state.col_width(self.col).unwrap_or(0.0))
+        } else {
+            width
+        };
 
         let available = region.max_rect.intersect(region.cursor);
 
@@ -170,16 +294,19 @@ impl GridLayout {
     }
 
     pub(crate) fn next_cell(&self, cursor: Rect, child_size: Vec2) -> Rect {
-        let width = self.prev_state.col_width(self.col).unwrap_or(0.0);
-        let height = self.prev_row_height(self.row);
+        let width = if self.cell_span[0] == 1 {
+            self.prev_state.col_width(self.col).unwrap_or(0.0)
+        } else {
+            self.prev_span_width(self.span_cols())
+        };
+        let height = self.prev_span_height(self.span_rows());
         let size = child_size.max(vec2(width, height));
         Rect::from_min_size(cursor.min, size)
     }
 
-    #[allow(clippy::unused_self)]
     pub(crate) fn align_size_within_rect(&self, size: Vec2, frame: Rect) -> Rect {
-        // TODO(emilk): allow this alignment to be customized
-        Align2::LEFT_CENTER.align_size_within_rect(size, frame)
+        let halign = self.column(self.col).align.unwrap_or(Align::LEFT);
+        Align2([halign, Align::Center]).align_size_within_rect(size, frame)
     }
 
     pub(crate) fn justify_and_align(&self, frame: Rect, size: Vec2) -> Rect {
@@ -193,8 +320,8 @@ impl GridLayout {
             let debug_expand_height = self.style.debug.show_expand_height;
             if debug_expand_width || debug_expand_height {
                 let rect = widget_rect;
-                let too_wide = rect.width() > self.prev_col_width(self.col);
-                let too_high = rect.height() > self.prev_row_height(self.row);
+                let too_wide = rect.width() > self.prev_span_width(self.span_cols());
+                let too_high = rect.height() > self.prev_span_height(self.span_rows());
 
                 if (debug_expand_width && too_wide) || (debug_expand_height && too_high) {
                     let painter = self.ctx.debug_painter();
@@ -212,13 +339,34 @@ impl GridLayout {
             }
         }
 
+        let [columns, rows] = self.cell_span;
+        let size = widget_rect
+            .size()
+            .max(vec2(self.min_col_width(self.col), self.min_cell_size.y));
+
+        // Cells spanning several columns or rows are accounted for at the end of the row or grid,
+        // once we know how large the other cells in those columns or rows are.
+        for col in self.span_cols() {
+            let width = if columns == 1 { size.x } else { 0.0 };
+            self.curr_state
+                .set_min_col_width(col, width.max(self.min_col_width(col)));
+        }
+        let height = if rows == 1 { size.y } else { 0.0 };
         self.curr_state
-            .set_min_col_width(self.col, widget_rect.width().max(self.min_cell_size.x));
-        self.curr_state
-            .set_min_row_height(self.row, widget_rect.height().max(self.min_cell_size.y));
+            .set_min_row_height(self.row, height.max(self.min_cell_size.y));
+        if 1 < columns || 1 < rows {
+            self.spans.push(CellSpan {
+                cols: self.span_cols(),
+                rows: self.span_rows(),
+                size,
+            });
+        }
 
-        cursor.min.x += self.prev_col_width(self.col) + self.spacing.x;
-        self.col += 1;
+        cursor.min.x += self.prev_span_width(self.span_cols()) + self.spacing.x;
+        self.col += columns;
+        self.cell_span = [1, 1];
+
+        self.skip_covered_cells(cursor);
     }
 
     fn paint_row(&mut self, cursor: &Rect, painter: &Painter) {
@@ -234,14 +382,45 @@ impl GridLayout {
         };
         // Paint background for coming row:
         let size = Vec2::new(self.prev_state.full_width(self.spacing.x), height);
-        let rect = Rect::from_min_size(cursor.min, size);
+        let rect = Rect::from_min_size(pos2(self.initial_available.min.x, cursor.min.y), size);
         let rect = rect.expand2(0.5 * self.spacing.y * Vec2::Y);
         let rect = rect.expand2(2.0 * Vec2::X); // HACK: just looks better with some spacing on the sides
 
-        painter.rect_filled(rect, 2.0, row_color);
+        let num_cols = self.prev_state.col_widths.len();
+        if (0..num_cols).any(|col| self.is_covered(col)) {
+            // Don't paint over cells from above that span into this row:
+            let mut x = rect.min.x;
+            let mut segment_start = None;
+            for col in 0..num_cols {
+                let col_width = self.prev_col_width(col) + self.spacing.x;
+                if self.is_covered(col) {
+                    if let Some(start) = segment_start.take() {
+                        let segment = Rect::from_x_y_ranges(start..=x, rect.y_range());
+                        painter.rect_filled(segment, 2.0, row_color);
+                    }
+                } else if segment_start.is_none() {
+                    segment_start = Some(x);
+                }
+                x += col_width;
+            }
+            if let Some(start) = segment_start {
+                let segment = Rect::from_x_y_ranges(start..=rect.max.x, rect.y_range());
+                painter.rect_filled(segment, 2.0, row_color);
+            }
+        } else {
+            painter.rect_filled(rect, 2.0, row_color);
+        }
     }
 
     pub(crate) fn end_row(&mut self, cursor: &mut Rect, painter: &Painter) {
+        // Make room for the cells spanning several rows that end in this row:
+        for span in &self.spans {
+            if span.rows.end == self.row + 1 && 1 < span.rows.len() {
+                self.curr_state
+                    .set_min_span_height(span.rows.clone(), span.size.y, self.spacing.y);
+            }
+        }
+
         cursor.min.x = self.initial_available.min.x;
         cursor.min.y += self.spacing.y;
         cursor.min.y += self
@@ -251,11 +430,20 @@ impl GridLayout {
 
         self.col = 0;
         self.row += 1;
+        self.cell_span = [1, 1];
 
         self.paint_row(cursor, painter);
+        self.skip_covered_cells(cursor);
     }
 
-    pub(crate) fn save(&self) {
+    pub(crate) fn save(&mut self) {
+        for span in &self.spans {
+            self.curr_state
+                .set_min_span_width(span.cols.clone(), span.size.x, self.spacing.x);
+            self.curr_state
+                .set_min_span_height(span.rows.clone(), span.size.y, self.spacing.y);
+        }
+
         if self.curr_state != self.prev_state {
             self.curr_state.clone().store(&self.ctx, self.id);
             self.ctx.request_repaint();
@@ -273,6 +461,11 @@ impl GridLayout {
 /// If you want to add multiple widgets to a cell you need to group them with
 /// [`Ui::horizontal`], [`Ui::vertical`] etc.
 ///
+/// A cell can span several columns and/or rows using [`Ui::set_grid_cell_span`].
+///
+/// The alignment and width of each column can be set with [`Self::column_align`]
+/// and [`Self::column_width_range`].
+///
 /// ```
 /// # egui::__run_test_ui(|ui| {
 /// egui::Grid::new("some_unique_id").show(ui, |ui| {
@@ -298,6 +491,7 @@ pub struct Grid {
     min_col_width: Option<f32>,
     min_row_height: Option<f32>,
     max_cell_size: Vec2,
+    columns: Vec<ColumnOptions>,
     spacing: Option<Vec2>,
     start_row: usize,
     color_picker: Option<ColorPickerFn>,
@@ -312,6 +506,7 @@ impl Grid {
             min_col_width: None,
             min_row_height: None,
             max_cell_size: Vec2::INFINITY,
+            columns: Vec::new(),
             spacing: None,
             start_row: 0,
             color_picker: None,
@@ -373,6 +568,53 @@ impl Grid {
         self
     }
 
+    /// Horizontal alignment of the cells of the given column (zero-based).
+    ///
+    /// For instance, use [`Align::RIGHT`] for a column of numbers.
+    /// Default: [`Align::LEFT`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::Grid::new("prices")
+    ///     .column_align(1, egui::Align::RIGHT)
+    ///     .column_width_range(0, 0.0..=120.0)
+    ///     .show(ui, |ui| {
+    ///         ui.label("Apple");
+    ///         ui.label("1.25");
+    ///         ui.end_row();
+    ///
+    ///         ui.label("Watermelon");
+    ///         ui.label("12.50");
+    ///         ui.end_row();
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn column_align(mut self, column: usize, align: Align) -> Self {
+        self.column_mut(column).align = Some(align);
+        self
+    }
+
+    /// Set the minimum and (soft) maximum width of the given column (zero-based).
+    ///
+    /// This overrides [`Self::min_col_width`] and [`Self::max_col_width`] for that column.
+    /// Text wider than the maximum width is wrapped.
+    #[inline]
+    pub fn column_width_range(mut self, column: usize, width_range: impl Into<Rangef>) -> Self {
+        let width_range = width_range.into();
+        let options = self.column_mut(column);
+        options.min_width = Some(width_range.min);
+        options.max_width = Some(width_range.max);
+        self
+    }
+
+    fn column_mut(&mut self, column: usize) -> &mut ColumnOptions {
+        if self.columns.len() <= column {
+            self.columns.resize(column + 1, ColumnOptions::default());
+        }
+        &mut self.columns[column]
+    }
+
     /// Set spacing between columns/rows.
     /// Default: [`crate::style::Spacing::item_spacing`].
     #[inline]
@@ -406,6 +648,7 @@ impl Grid {
             min_col_width,
             min_row_height,
             max_cell_size,
+            columns,
             spacing,
             start_row,
             mut color_picker,
@@ -434,6 +677,7 @@ impl Grid {
                     color_picker,
                     min_cell_size: vec2(min_col_width, min_row_height),
                     max_cell_size,
+                    columns,
                     spacing,
                     row: start_row,
                     ..GridLayout::new(ui, id, prev_state)
@@ -462,3 +706,103 @@ fn striped_row_color(row: usize, style: &Style) -> Option<Color32> {
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_span_sizes() {
+        let mut state = State::default();
+        state.set_min_col_width(0, 30.0);
+        state.set_min_col_width(1, 20.0);
+
+        // Already wide enough:
+        state.set_min_span_width(0..2, 50.0, 4.0);
+        assert_eq!(state.col_widths, vec![30.0, 20.0]);
+
+        // Only the last column grows:
+        state.set_min_span_width(0..2, 60.0, 4.0);
+        assert_eq!(state.col_widths, vec![30.0, 26.0]);
+
+        // Also when the spanned columns have no width yet:
+        state.set_min_span_width(1..4, 50.0, 4.0);
+        assert_eq!(state.col_widths, vec![30.0, 26.0, 0.0, 16.0]);
+
+        state.set_min_row_height(0, 10.0);
+        state.set_min_span_height(0..2, 30.0, 2.0);
+        assert_eq!(state.row_heights, vec![10.0, 18.0]);
+    }
+
+    #[test]
+    fn test_covers_below() {
+        let span = CellSpan {
+            cols: 1..3,
+            rows: 2..4,
+            size: Vec2::ZERO,
+        };
+        assert!(span.covers_below(1, 3));
+        assert!(span.covers_below(2, 3));
+        assert!(!span.covers_below(1, 2), "Not the row of the cell itself");
+        assert!(!span.covers_below(0, 3));
+        assert!(!span.covers_below(3, 3));
+        assert!(!span.covers_below(1, 4));
+    }
+
+    #[test]
+    fn test_grid_span_layout() {
+        let ctx = Context::default();
+        let mut rects = vec![];
+        // The grid uses the sizes from the previous frame:
+        for _ in 0..2 {
+            rects.clear();
+            let _ = ctx.run(Default::default(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    Grid::new("grid")
+                        .spacing([4.0, 4.0])
+                        .min_col_width(0.0)
+                        .min_row_height(0.0)
+                        .show(ui, |ui| {
+                            let mut cell = |ui: &mut Ui, size: Vec2| {
+                                rects.push(ui.available_rect_before_wrap());
+                                ui.allocate_exact_size(size, Sense::hover());
+                            };
+
+                            cell(ui, vec2(30.0, 10.0));
+                            cell(ui, vec2(20.0, 10.0));
+                            ui.set_grid_cell_span(1, 2);
+                            cell(ui, vec2(10.0, 30.0));
+                            ui.end_row();
+
+                            ui.set_grid_cell_span(2, 1);
+                            cell(ui, vec2(10.0, 10.0));
+                            // Skips the column covered by the cell above:
+                            cell(ui, vec2(10.0, 10.0));
+                            ui.end_row();
+
+                            cell(ui, vec2(10.0, 10.0));
+                            ui.end_row();
+                        });
+                });
+            });
+        }
+
+        let [a, b, tall, wide, after_tall, last] = rects[..] else {
+            panic!("Expected six cells, got {rects:?}");
+        };
+        assert_eq!(a.width(), 30.0);
+        assert_eq!(b.left(), a.right() + 4.0);
+        assert_eq!(tall.left(), b.left() + 20.0 + 4.0);
+
+        // The wide cell covers the first two columns:
+        assert_eq!(wide.left(), a.left());
+        assert_eq!(wide.width(), 30.0 + 4.0 + 20.0);
+        assert_eq!(wide.top(), a.top() + 10.0 + 4.0);
+
+        // The tall cell covers both rows, so the next cell goes to the fourth column:
+        assert_eq!(after_tall.left(), tall.left() + 10.0 + 4.0);
+
+        // Only the last row of the tall cell grew to make room for it:
+        assert_eq!(last.top(), a.top() + 30.0 + 4.0);
+    }
+}
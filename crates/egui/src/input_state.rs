@@ -271,6 +271,20 @@ impl InputState {
         }
     }
 
+    /// Where are the files the user is dragging from the OS held, if any?
+    ///
+    /// This is [`RawInput::hovered_files_pos`] if the backend provides it,
+    /// and the latest pointer position otherwise.
+    ///
+    /// Returns `None` if no files are being hovered over egui.
+    pub fn file_hover_pos(&self) -> Option<Pos2> {
+        if self.raw.hovered_files.is_empty() {
+            None
+        } else {
+            self.raw.hovered_files_pos.or(self.pointer.latest_pos())
+        }
+    }
+
     /// Info about the active viewport
     #[inline]
     pub fn viewport(&self) -> &ViewportInfo {
@@ -426,7 +426,10 @@ pub use emath::{
 };
 pub use epaint::{
     mutex,
-    text::{FontData, FontDefinitions, FontFamily, FontId, FontTweak},
+    text::{
+        FontData, FontDefinitions, FontFallback, FontFamily, FontId, FontInsert, FontPriority,
+        FontTweak, FontVariations, InsertFontFamily,
+    },
     textures::{TextureFilter, TextureOptions, TextureWrapMode, TexturesDelta},
     ClippedPrimitive, ColorImage, FontImage, ImageData, Margin, Mesh, PaintCallback,
     PaintCallbackInfo, Rounding, Shadow, Shape, Stroke, TextureHandle, TextureId,
@@ -435,8 +438,8 @@ pub use epaint::{
 pub mod text {
     pub use crate::text_selection::{CCursorRange, CursorRange};
     pub use epaint::text::{
-        cursor::CCursor, FontData, FontDefinitions, FontFamily, Fonts, Galley, LayoutJob,
-        LayoutSection, TextFormat, TextWrapping, TAB_SIZE,
+        cursor::CCursor, FontData, FontDefinitions, FontFallback, FontFamily, Fonts, Galley,
+        LayoutJob, LayoutSection, TextFormat, TextWrapping, TAB_SIZE,
     };
 }
 
@@ -458,6 +461,7 @@ pub use {
     layout::*,
     load::SizeHint,
     memory::{Memory, Options},
+    menu::MenuButton,
     painter::Painter,
     response::{InnerResponse, Response},
     sense::Sense,
@@ -466,7 +470,7 @@ pub use {
     ui::Ui,
     viewport::*,
     widget_rect::{WidgetRect, WidgetRects},
-    widget_text::{RichText, WidgetText},
+    widget_text::{RichText, TextWrapMode, WidgetText},
     widgets::*,
 };
 
@@ -609,8 +613,17 @@ pub enum WidgetType {
 
     Checkbox,
 
+    /// An on/off switch, e.g. [`ToggleSwitch`].
+    Switch,
+
     RadioButton,
 
+    /// A menu item with a check mark, e.g. [`Ui::menu_checkbox`].
+    MenuItemCheckbox,
+
+    /// A menu item with a radio bullet, e.g. [`Ui::menu_radio`].
+    MenuItemRadio,
+
     SelectableLabel,
 
     ComboBox,
@@ -887,6 +887,14 @@ pub struct Areas {
     /// So if you close three windows and then reopen them all in one frame,
     /// they will all be sent to the top, but keep their previous internal order.
     wants_to_be_on_top: ahash::HashSet<LayerId>,
+
+    /// The layer of a modal window shown this frame, if any.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    modal_current_frame: Option<LayerId>,
+
+    /// The layer of a modal window shown last frame, if any.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    modal_last_frame: Option<LayerId>,
 }
 
 impl Areas {
@@ -978,6 +986,86 @@ impl Areas {
         }
     }
 
+    /// Moves the given layer below all other layers in the same [`Order`].
+    pub fn move_to_bottom(&mut self, layer_id: LayerId) {
+        self.wants_to_be_on_top.remove(&layer_id);
+        self.order.retain(|x| *x != layer_id);
+        let index = self
+            .order
+            .iter()
+            .position(|x| x.order >= layer_id.order)
+            .unwrap_or(self.order.len());
+        self.order.insert(index, layer_id);
+    }
+
+    /// Moves `layer_id` to be directly above `other`.
+    ///
+    /// Does nothing unless both layers are in the same [`Order`] and `other` is known.
+    pub fn move_above(&mut self, layer_id: LayerId, other: LayerId) {
+        self.move_next_to(layer_id, other, true);
+    }
+
+    /// Moves `layer_id` to be directly below `other`.
+    ///
+    /// Does nothing unless both layers are in the same [`Order`] and `other` is known.
+    pub fn move_below(&mut self, layer_id: LayerId, other: LayerId) {
+        self.move_next_to(layer_id, other, false);
+    }
+
+    fn move_next_to(&mut self, layer_id: LayerId, other: LayerId, above: bool) {
+        if layer_id == other
+            || layer_id.order != other.order
+            || !self.order.iter().any(|x| *x == other)
+        {
+            return;
+        }
+
+        // Stay on the same side of `other` when the layers that want to be on top are sorted:
+        if above && self.wants_to_be_on_top.contains(&other) {
+            self.wants_to_be_on_top.insert(layer_id);
+        } else {
+            self.wants_to_be_on_top.remove(&layer_id);
+        }
+
+        self.order.retain(|x| *x != layer_id);
+        if let Some(index) = self.order.iter().position(|x| *x == other) {
+            let index = if above { index + 1 } else { index };
+            self.order.insert(index, layer_id);
+        }
+    }
+
+    /// Mark the given layer as modal for this frame.
+    ///
+    /// Nothing below it can be interacted with, nor get keyboard focus.
+    pub(crate) fn set_modal_layer(&mut self, layer_id: LayerId) {
+        self.modal_current_frame = Some(layer_id);
+    }
+
+    /// The layer of the modal window, if any.
+    ///
+    /// Until the modal window has been shown this frame, this is the one from last frame.
+    pub fn modal_layer(&self) -> Option<LayerId> {
+        self.modal_current_frame.or(self.modal_last_frame)
+    }
+
+    /// Is the given layer below the modal window, if there is one?
+    ///
+    /// Widgets in such layers can't be interacted with, nor get keyboard focus.
+    /// Layers above the modal window (e.g. popups opened from within it) are unaffected.
+    pub fn is_below_modal_layer(&self, layer_id: LayerId) -> bool {
+        let Some(modal) = self.modal_layer() else {
+            return false;
+        };
+        if layer_id == modal {
+            false
+        } else if layer_id.order != modal.order {
+            layer_id.order < modal.order
+        } else {
+            let position = |layer_id| self.order.iter().position(|x| *x == layer_id);
+            position(layer_id) < position(modal)
+        }
+    }
+
     pub fn top_layer_id(&self, order: Order) -> Option<LayerId> {
         self.order
             .iter()
@@ -992,10 +1080,13 @@ impl Areas {
             visible_current_frame,
             order,
             wants_to_be_on_top,
+            modal_current_frame,
+            modal_last_frame,
             ..
         } = self;
 
         std::mem::swap(visible_last_frame, visible_current_frame);
+        *modal_last_frame = modal_current_frame.take();
         visible_current_frame.clear();
         order.sort_by_key(|layer| (layer.order, wants_to_be_on_top.contains(layer)));
         wants_to_be_on_top.clear();
@@ -1004,6 +1095,40 @@ impl Areas {
 
 // ----------------------------------------------------------------------------
 
+#[test]
+fn areas_z_order() {
+    let a = LayerId::new(Order::Middle, Id::new("a"));
+    let b = LayerId::new(Order::Middle, Id::new("b"));
+    let c = LayerId::new(Order::Middle, Id::new("c"));
+    let popup = LayerId::new(Order::Foreground, Id::new("popup"));
+
+    let mut areas = Areas::default();
+    for layer_id in [LayerId::background(), a, b, c, popup] {
+        areas.move_to_top(layer_id);
+    }
+    areas.end_frame();
+    assert_eq!(areas.order(), [LayerId::background(), a, b, c, popup]);
+
+    areas.move_to_bottom(c);
+    assert_eq!(areas.order(), [LayerId::background(), c, a, b, popup]);
+
+    areas.move_above(c, a);
+    assert_eq!(areas.order(), [LayerId::background(), a, c, b, popup]);
+
+    areas.move_below(b, a);
+    assert_eq!(areas.order(), [LayerId::background(), b, a, c, popup]);
+
+    // Different orders can't be mixed:
+    areas.move_above(a, popup);
+    assert_eq!(areas.order(), [LayerId::background(), b, a, c, popup]);
+
+    // Moving to top is applied at the end of the frame, and keeps the relative order:
+    areas.move_to_top(b);
+    areas.move_above(a, b);
+    areas.end_frame();
+    assert_eq!(areas.order(), [LayerId::background(), c, b, a, popup]);
+}
+
 #[test]
 fn memory_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
@@ -14,6 +14,10 @@
 //!     });
 //! }
 //! ```
+//!
+//! Menus can be used with the keyboard: the arrow keys move between the items of a menu
+//! and in and out of sub-menus, Enter or Space activates an item, and Escape closes the menu.
+//! Use [`Ui::shortcut_button`] for items with a keyboard shortcut.
 
 use super::{
     style::WidgetVisuals, Align, Context, Id, InnerResponse, PointerState, Pos2, Rect, Response,
@@ -47,7 +51,32 @@ impl BarState {
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> Option<InnerResponse<R>> {
         MenuRoot::stationary_click_interaction(button, &mut self.open_menu);
-        self.open_menu.show(button, add_contents)
+        if button.fake_primary_click {
+            // Opened with the keyboard, so continue with the keyboard in the menu:
+            if let Some(root) = self.open_menu.inner.as_ref() {
+                if root.id == button.id {
+                    root.menu_state.write().focus_first_item = true;
+                }
+            }
+        }
+        let inner = self.open_menu.show(button, add_contents);
+
+        if let Some(root) = self.open_menu.inner.as_ref() {
+            if root.id == button.id {
+                let mut menu_state = root.menu_state.write();
+                let menu_rect = menu_state.rect;
+                if menu_rect.y_range().intersects(button.rect.y_range()) {
+                    // There was not enough room below the button, so the menu was pushed up on
+                    // top of it to fit on screen. Show it above the button instead:
+                    let top = button.rect.top()
+                        - button.ctx.style().spacing.menu_spacing
+                        - menu_rect.height();
+                    menu_state.rect = menu_rect.translate(vec2(0.0, top - menu_rect.top()));
+                }
+            }
+        }
+
+        inner
     }
 
     pub(crate) fn has_root(&self) -> bool {
@@ -118,6 +147,79 @@ pub fn menu_image_button<R>(
     stationary_menu_image_impl(ui, image_button, Box::new(add_contents))
 }
 
+/// A button with a ⏷ that shows a menu below it when clicked.
+///
+/// Works like [`Ui::menu_button`], but looks like a drop-down button,
+/// so it is suitable outside of a menu bar too.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::MenuButton::new("Export").show(ui, |ui| {
+///     if ui.button("PNG").clicked() {
+///         // …
+///         ui.close_menu();
+///     }
+///     if ui.button("SVG").clicked() {
+///         // …
+///         ui.close_menu();
+///     }
+/// });
+/// # });
+/// ```
+///
+/// When the button has keyboard focus, Enter, Space or the down arrow opens the menu,
+/// and Escape closes it again.
+/// If there is not enough room below the button, the menu is shown above it.
+#[must_use = "You should call .show()"]
+pub struct MenuButton {
+    text: WidgetText,
+}
+
+impl MenuButton {
+    pub fn new(text: impl Into<WidgetText>) -> Self {
+        Self { text: text.into() }
+    }
+
+    /// Show the button, and the menu if it is open.
+    ///
+    /// Returns `None` if the menu is not open.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<Option<R>> {
+        let bar_id = ui.id();
+        let mut bar_state = BarState::load(ui.ctx(), bar_id);
+
+        let button_id = ui.next_auto_id(); // The id the button will get
+        let is_open = bar_state.is_menu_open(button_id);
+
+        let mut button = Button::new(self.text).shortcut_text("⏷");
+        if is_open {
+            button = button.fill(ui.visuals().widgets.open.weak_bg_fill);
+            button = button.stroke(ui.visuals().widgets.open.bg_stroke);
+        }
+        let button_response = ui.add(button);
+
+        if is_open {
+            if ui.input(|i| i.key_pressed(Key::Escape)) {
+                // The menu is closed by `bar_menu`. Give the focus back to the button:
+                ui.memory_mut(|mem| mem.request_focus(button_response.id));
+            }
+        } else if button_response.has_focus() && ui.input(|i| i.key_pressed(Key::ArrowDown)) {
+            let pos = MenuRoot::stationary_menu_position(&button_response, &bar_state);
+            let root = MenuRoot::new(pos, button_response.id);
+            root.menu_state.write().focus_first_item = true;
+            bar_state.open_menu.inner = Some(root);
+        }
+
+        let inner = bar_state.bar_menu(&button_response, add_contents);
+
+        bar_state.store(ui.ctx(), bar_id);
+        InnerResponse::new(inner.map(|r| r.inner), button_response)
+    }
+}
+
 /// Construct a nested sub menu in another menu.
 ///
 /// Opens on hover.
@@ -167,9 +269,62 @@ pub(crate) fn menu_ui<'c, R>(
 
     menu_state_arc.write().rect = area_response.response.rect;
 
+    menu_keyboard_navigation(
+        ctx,
+        area_response.response.layer_id,
+        &mut menu_state_arc.write(),
+    );
+
     area_response
 }
 
+/// Move the keyboard focus between the items of a menu with the up and down arrow keys.
+///
+/// Only widgets that can be clicked but not dragged (buttons, checkboxes, …) count as items,
+/// so that e.g. a slider in a menu still gets to use the arrow keys.
+fn menu_keyboard_navigation(ctx: &Context, layer_id: LayerId, menu_state: &mut MenuState) {
+    let items: Vec<Id> = ctx
+        .widgets_in_layer(layer_id)
+        .into_iter()
+        .filter(|w| w.enabled && w.sense.focusable && w.sense.click && !w.sense.drag)
+        .map(|w| w.id)
+        .collect();
+
+    if std::mem::take(&mut menu_state.focus_first_item) {
+        if let Some(&first) = items.first() {
+            ctx.memory_mut(|mem| mem.request_focus(first));
+        }
+        menu_state.has_keyboard_focus = true;
+        return;
+    }
+
+    let focused = ctx.memory(|mem| mem.focused());
+    let Some(index) = focused.and_then(|id| items.iter().position(|&item| item == id)) else {
+        menu_state.has_keyboard_focus = false;
+        return;
+    };
+    menu_state.has_keyboard_focus = true;
+
+    ctx.memory_mut(|mem| {
+        mem.set_focus_lock_filter(
+            items[index],
+            EventFilter {
+                // We move the focus ourselves, and the left/right arrows enter and leave sub-menus:
+                horizontal_arrows: true,
+                vertical_arrows: true,
+                ..Default::default()
+            },
+        );
+    });
+
+    let step = ctx
+        .input(|i| i.num_presses(Key::ArrowDown) as isize - i.num_presses(Key::ArrowUp) as isize);
+    if step != 0 {
+        let new_index = (index as isize + step).rem_euclid(items.len() as isize) as usize;
+        ctx.memory_mut(|mem| mem.request_focus(items[new_index]));
+    }
+}
+
 /// Build a top level menu with a button.
 ///
 /// Responds to primary clicks.
@@ -192,7 +347,12 @@ fn stationary_menu_impl<'c, R>(
     }
 
     let button_response = ui.add(button);
+    let was_open = bar_state.open_menu.is_menu_open(button_response.id);
     let inner = bar_state.bar_menu(&button_response, add_contents);
+    if was_open && ui.input(|i| i.key_pressed(Key::Escape)) {
+        // The menu is closed by `bar_menu`. Give the focus back to the button:
+        ui.memory_mut(|mem| mem.request_focus(button_response.id));
+    }
 
     bar_state.store(ui.ctx(), bar_id);
     InnerResponse::new(inner.map(|r| r.inner), button_response)
@@ -210,7 +370,12 @@ fn stationary_menu_image_impl<'c, R>(
 
     let mut bar_state = BarState::load(ui.ctx(), bar_id);
     let button_response = ui.add(image_button);
+    let was_open = bar_state.open_menu.is_menu_open(button_response.id);
     let inner = bar_state.bar_menu(&button_response, add_contents);
+    if was_open && ui.input(|i| i.key_pressed(Key::Escape)) {
+        // The menu is closed by `bar_menu`. Give the focus back to the button:
+        ui.memory_mut(|mem| mem.request_focus(button_response.id));
+    }
 
     bar_state.store(ui.ctx(), bar_id);
     InnerResponse::new(inner.map(|r| r.inner), button_response)
@@ -329,30 +494,11 @@ impl MenuRoot {
             // menu open and button clicked or esc pressed
             return MenuResponse::Close;
         } else if (button.clicked() && !root.is_menu_open(id))
-            || (button.hovered() && root.is_some())
+            || (button.hovered() && root.is_some() && !root.is_menu_open(id))
         {
             // menu not open and button clicked
             // or button hovered while other menu is open
-            let mut pos = button.rect.left_bottom();
-
-            let menu_frame = Frame::menu(&button.ctx.style());
-            pos.x -= menu_frame.total_margin().left; // Make fist button in menu align with the parent button
-            pos.y += button.ctx.style().spacing.menu_spacing;
-
-            if let Some(root) = root.inner.as_mut() {
-                let menu_rect = root.menu_state.read().rect;
-                let screen_rect = button.ctx.input(|i| i.screen_rect);
-
-                if pos.y + menu_rect.height() > screen_rect.max.y {
-                    pos.y = screen_rect.max.y - menu_rect.height() - button.rect.height();
-                }
-
-                if pos.x + menu_rect.width() > screen_rect.max.x {
-                    pos.x = screen_rect.max.x - menu_rect.width();
-                }
-            }
-
-            return MenuResponse::Create(pos, id);
+            return MenuResponse::Create(Self::stationary_menu_position(button, root), id);
         } else if button
             .ctx
             .input(|i| i.pointer.any_pressed() && i.pointer.primary_down())
@@ -372,6 +518,32 @@ impl MenuRoot {
         MenuResponse::Stay
     }
 
+    /// Where to put a menu opened by `button`: below it, or above it if there is no room below.
+    fn stationary_menu_position(button: &Response, root: &MenuRootManager) -> Pos2 {
+        let mut pos = button.rect.left_bottom();
+
+        let menu_frame = Frame::menu(&button.ctx.style());
+        pos.x -= menu_frame.total_margin().left; // Make fist button in menu align with the parent button
+        pos.y += button.ctx.style().spacing.menu_spacing;
+
+        if let Some(root) = root.inner.as_ref() {
+            let menu_rect = root.menu_state.read().rect;
+            let screen_rect = button.ctx.input(|i| i.screen_rect);
+
+            if pos.y + menu_rect.height() > screen_rect.max.y {
+                pos.y = button.rect.top()
+                    - button.ctx.style().spacing.menu_spacing
+                    - menu_rect.height();
+            }
+
+            if pos.x + menu_rect.width() > screen_rect.max.x {
+                pos.x = screen_rect.max.x - menu_rect.width();
+            }
+        }
+
+        pos
+    }
+
     /// Interaction with a context menu (secondary click).
     fn context_interaction(response: &Response, root: &mut Option<Self>) -> MenuResponse {
         let response = response.interact(Sense::click());
@@ -435,6 +607,113 @@ impl MenuResponse {
     }
 }
 
+/// A menu item with a check mark. See [`Ui::menu_checkbox`].
+pub(crate) fn menu_checkbox(
+    ui: &mut Ui,
+    text: impl Into<WidgetText>,
+    checked: &mut bool,
+) -> Response {
+    let mut response = marked_menu_item(
+        ui,
+        text.into(),
+        WidgetType::MenuItemCheckbox,
+        *checked,
+        |painter, rect, stroke| {
+            painter.add(Shape::line(
+                vec![
+                    pos2(rect.left(), rect.center().y),
+                    pos2(rect.center().x, rect.bottom()),
+                    pos2(rect.right(), rect.top()),
+                ],
+                stroke,
+            ));
+        },
+    );
+    if response.clicked() {
+        *checked = !*checked;
+        response.mark_changed();
+        ui.close_menu();
+    }
+    response
+}
+
+/// A menu item with a radio bullet. See [`Ui::menu_radio`].
+pub(crate) fn menu_radio<Value: PartialEq>(
+    ui: &mut Ui,
+    text: impl Into<WidgetText>,
+    current_value: &mut Value,
+    alternative: Value,
+) -> Response {
+    let mut response = marked_menu_item(
+        ui,
+        text.into(),
+        WidgetType::MenuItemRadio,
+        *current_value == alternative,
+        |painter, rect, stroke| {
+            painter.circle_filled(rect.center(), 0.25 * rect.width(), stroke.color);
+        },
+    );
+    if response.clicked() {
+        if *current_value != alternative {
+            *current_value = alternative;
+            response.mark_changed();
+        }
+        ui.close_menu();
+    }
+    response
+}
+
+/// A full-width menu item with room for a mark (e.g. a check mark) to the left of the text.
+///
+/// The mark is painted with `paint_mark` if `selected` is `true`.
+fn marked_menu_item(
+    ui: &mut Ui,
+    text: WidgetText,
+    widget_type: WidgetType,
+    selected: bool,
+    paint_mark: impl FnOnce(&Painter, Rect, Stroke),
+) -> Response {
+    let button_padding = ui.spacing().button_padding;
+    let icon_width = ui.spacing().icon_width;
+    let text_indent = icon_width + ui.spacing().icon_spacing;
+
+    let wrap_width = ui.available_width() - text_indent - 2.0 * button_padding.x;
+    let galley = text.into_galley(ui, None, wrap_width, TextStyle::Button);
+
+    let mut desired_size = vec2(text_indent, 0.0) + galley.size() + 2.0 * button_padding;
+    desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
+    let (rect, response) = ui.allocate_at_least(desired_size, Sense::click());
+    response.widget_info(|| WidgetInfo::selected(widget_type, selected, galley.text()));
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact(&response);
+        if ui.visuals().button_frame {
+            ui.painter().rect_filled(
+                rect.expand(visuals.expansion),
+                visuals.rounding,
+                visuals.weak_bg_fill,
+            );
+        }
+
+        let inner_rect = rect.shrink2(button_padding);
+        if selected {
+            let mark_rect = Rect::from_center_size(
+                pos2(inner_rect.left() + 0.5 * icon_width, inner_rect.center().y),
+                Vec2::splat(ui.spacing().icon_width_inner),
+            );
+            paint_mark(ui.painter(), mark_rect, visuals.fg_stroke);
+        }
+
+        let text_pos = pos2(
+            inner_rect.left() + text_indent,
+            inner_rect.center().y - 0.5 * galley.size().y,
+        );
+        ui.painter().galley(text_pos, galley, visuals.text_color());
+    }
+
+    response
+}
+
 pub struct SubMenuButton {
     text: WidgetText,
     icon: WidgetText,
@@ -549,6 +828,18 @@ impl SubMenu {
             .parent_state
             .write()
             .show_submenu(ui.ctx(), sub_id, add_contents);
+
+        // Leave the sub-menu with the left arrow:
+        let mut parent_state = self.parent_state.write();
+        if parent_state
+            .submenu(sub_id)
+            .map_or(false, |sub| sub.read().has_keyboard_focus)
+            && ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::ArrowLeft))
+        {
+            parent_state.close_submenu();
+            ui.memory_mut(|mem| mem.request_focus(response.id));
+        }
+
         InnerResponse::new(inner, response)
     }
 }
@@ -566,6 +857,12 @@ pub(crate) struct MenuState {
 
     /// Used to hash different [`Id`]s for sub-menus
     entry_count: usize,
+
+    /// Give keyboard focus to the first item of the menu next time it is shown.
+    focus_first_item: bool,
+
+    /// Does one of the items of this menu have keyboard focus?
+    has_keyboard_focus: bool,
 }
 
 impl MenuState {
@@ -575,6 +872,8 @@ impl MenuState {
             sub_menu: None,
             response: MenuResponse::Stay,
             entry_count: 0,
+            focus_first_item: false,
+            has_keyboard_focus: false,
         }
     }
 
@@ -624,21 +923,29 @@ impl MenuState {
     fn submenu_button_interaction(&mut self, ui: &Ui, sub_id: Id, button: &Response) {
         let pointer = ui.input(|i| i.pointer.clone());
         let open = self.is_open(sub_id);
-        if self.moving_towards_current_submenu(&pointer) {
+
+        // TODO(emilk): open menu to the left if there isn't enough space to the right
+        let mut pos = button.rect.right_top();
+        pos.x = self.rect.right() + ui.spacing().menu_spacing;
+        pos.y -= Frame::menu(ui.style()).total_margin().top; // align the first button in the submenu with the parent button
+
+        if button.has_focus() && ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::ArrowRight)) {
+            // Enter the sub-menu with the keyboard:
+            self.open_submenu(sub_id, pos);
+            if let Some(sub) = self.submenu(sub_id) {
+                sub.write().focus_first_item = true;
+            }
+        } else if self.moving_towards_current_submenu(&pointer) {
             // We don't close the submenu if the pointer is on its way to hover it.
             // ensure to repaint once even when pointer is not moving
             ui.ctx().request_repaint();
         } else if !open && button.hovered() {
-            // TODO(emilk): open menu to the left if there isn't enough space to the right
-            let mut pos = button.rect.right_top();
-            pos.x = self.rect.right() + ui.spacing().menu_spacing;
-            pos.y -= Frame::menu(ui.style()).total_margin().top; // align the first button in the submenu with the parent button
-
             self.open_submenu(sub_id, pos);
         } else if open
             && ui.interact_bg(Sense::hover()).contains_pointer()
             && !button.hovered()
             && !self.hovering_current_submenu(&pointer)
+            && !(pointer.is_still() && self.current_submenu_has_keyboard_focus())
         {
             // We are hovering something else in the menu, so close the submenu.
             self.close_submenu();
@@ -677,6 +984,12 @@ impl MenuState {
         }
     }
 
+    /// Is the user using the keyboard in the open sub-menu?
+    fn current_submenu_has_keyboard_focus(&self) -> bool {
+        self.current_submenu()
+            .map_or(false, |sub| sub.read().has_keyboard_focus)
+    }
+
     fn is_open(&self, id: Id) -> bool {
         self.sub_id() == Some(id)
     }
@@ -33,6 +33,12 @@ impl Placer {
         self.grid.as_ref()
     }
 
+    pub(crate) fn set_grid_cell_span(&mut self, columns: usize, rows: usize) {
+        if let Some(grid) = &mut self.grid {
+            grid.set_cell_span(columns, rows);
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn is_grid(&self) -> bool {
         self.grid.is_some()
@@ -1,7 +1,7 @@
 use std::{any::Any, sync::Arc};
 
 use crate::{
-    emath::{Align, Pos2, Rect, Vec2},
+    emath::{Align, Align2, Pos2, Rect, Vec2},
     menu, Context, CursorIcon, Id, LayerId, PointerButton, Sense, Ui, WidgetRect, WidgetText,
 };
 
@@ -448,6 +448,116 @@ impl Response {
         }
     }
 
+    /// Where the files the user is dragging from the OS are held over this widget, if they are.
+    ///
+    /// See also [`Self::accept_file_drop`] and [`crate::InputState::file_hover_pos`].
+    #[doc(alias = "drag and drop")]
+    pub fn file_hover_pos(&self) -> Option<Pos2> {
+        let pos = self.ctx.input(|i| i.file_hover_pos())?;
+        let is_over_widget =
+            self.interact_rect.contains(pos) && self.ctx.layer_id_at(pos) == Some(self.layer_id);
+        is_over_widget.then_some(pos)
+    }
+
+    /// Make this widget the target for the files the user is dragging from the OS,
+    /// if they are held over it.
+    ///
+    /// Call this every frame. Returns `true` if the files are held over this widget.
+    /// When the user drops the files, they are delivered to the widget by [`Self::dropped_files`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = ui.button("Drop files here");
+    /// if response.accept_file_drop() {
+    ///     ui.label("Release to open the files");
+    /// }
+    /// for file in response.dropped_files() {
+    ///     println!("Dropped {:?}", file.path);
+    /// }
+    /// # });
+    /// ```
+    #[doc(alias = "drag and drop")]
+    pub fn accept_file_drop(&self) -> bool {
+        let is_target = self.file_hover_pos().is_some();
+        if is_target {
+            crate::drag_and_drop::FileDropTarget::accept(&self.ctx, self.id);
+        }
+        is_target
+    }
+
+    /// The files the user dropped onto this widget this frame.
+    ///
+    /// Only returns files if this widget was the target for them,
+    /// i.e. if it called [`Self::accept_file_drop`] while they were hovered over it.
+    ///
+    /// With the `eframe` web backend the files are loaded asynchronously,
+    /// so they may arrive a few frames after the user dropped them.
+    #[doc(alias = "drag and drop")]
+    pub fn dropped_files(&self) -> Vec<crate::DroppedFile> {
+        if crate::drag_and_drop::FileDropTarget::target(&self.ctx) == Some(self.id) {
+            self.ctx.input(|i| i.raw.dropped_files.clone())
+        } else {
+            vec![]
+        }
+    }
+
+    /// Drag-and-Drop: Highlight this widget if the payload being dragged can be dropped onto it.
+    ///
+    /// While the user is dragging something of this type, an outline is painted around the widget.
+    /// The outline is stronger while the payload is held over the widget.
+    ///
+    /// Returns `true` if something of this type is being held over this widget.
+    #[doc(alias = "drag and drop")]
+    pub fn dnd_highlight_drop_target<Payload: Any + Send + Sync>(&self) -> bool {
+        if !crate::DragAndDrop::has_payload_of_type::<Payload>(&self.ctx) {
+            return false;
+        }
+
+        // NOTE: we use `response.contains_pointer` here instead of `hovered`, because
+        // `hovered` is always false when another widget is being dragged.
+        let is_hovering = self.contains_pointer();
+
+        let visuals = &self.ctx.style().visuals;
+        let widget_visuals = if is_hovering {
+            visuals.widgets.active
+        } else {
+            visuals.widgets.hovered
+        };
+        self.ctx.layer_painter(self.layer_id).rect_stroke(
+            self.rect,
+            widget_visuals.rounding,
+            widget_visuals.bg_stroke,
+        );
+
+        is_hovering
+    }
+
+    /// Drag-and-Drop: Show the given ui at the pointer while this widget is being dragged.
+    ///
+    /// Use this together with [`Self::dnd_set_drag_payload`] to show the user what they are dragging.
+    /// Nothing is shown unless there is a drag-and-drop payload.
+    ///
+    /// In contrast, [`crate::Ui::dnd_drag_source`] moves the widget itself to the pointer.
+    #[doc(alias = "drag and drop")]
+    pub fn dnd_drag_ghost(&self, add_contents: impl FnOnce(&mut Ui)) {
+        if !self.dragged() || !crate::DragAndDrop::has_any_payload(&self.ctx) {
+            return;
+        }
+        let Some(pointer_pos) = self.ctx.pointer_interact_pos() else {
+            return;
+        };
+
+        crate::Area::new(self.id.with("__dnd_ghost"))
+            .order(crate::Order::Tooltip)
+            .fixed_pos(pointer_pos)
+            .pivot(Align2::CENTER_CENTER)
+            .interactable(false)
+            .show(&self.ctx, |ui| {
+                ui.set_opacity(0.75);
+                crate::Frame::popup(ui.style()).show(ui, add_contents);
+            });
+    }
+
     /// Where the pointer (mouse/touch) were when when this widget was clicked or dragged.
     ///
     /// `None` if the widget is not being interacted with.
@@ -532,6 +642,91 @@ impl Response {
         self
     }
 
+    /// Like [`Self::on_hover_ui`], but wait `delay` seconds after the pointer stops moving
+    /// before showing the tooltip, instead of [`crate::style::Interaction::tooltip_delay`].
+    #[doc(alias = "tooltip")]
+    pub fn on_hover_ui_with_delay(self, delay: f32, add_contents: impl FnOnce(&mut Ui)) -> Self {
+        if self.enabled && self.should_show_hover_ui_after(delay) {
+            crate::containers::show_tooltip_for(
+                &self.ctx,
+                self.id.with("__tooltip"),
+                &self.rect,
+                add_contents,
+            );
+        }
+        self
+    }
+
+    /// Show a tooltip which the user can move the pointer into, and interact with.
+    ///
+    /// Unlike [`Self::on_hover_ui`], the tooltip stays open while the pointer is over it,
+    /// so it can contain buttons, links and other interactive widgets.
+    /// The tooltip is also kept open for [`crate::style::Interaction::tooltip_grace_time`] seconds
+    /// after the pointer has left both the widget and the tooltip,
+    /// so that the user has time to move between them.
+    ///
+    /// The tooltip will not be visible if the widget is not enabled.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.label("Crate").on_hover_ui_rich(|ui| {
+    ///     ui.label("A crate is a compilation unit.");
+    ///     ui.hyperlink_to("Read more", "https://doc.rust-lang.org/book/ch07-01-packages-and-crates.html");
+    /// });
+    /// # });
+    /// ```
+    #[doc(alias = "tooltip")]
+    pub fn on_hover_ui_rich(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
+        if !self.enabled {
+            return self;
+        }
+
+        let tooltip_id = self.id.with("__tooltip");
+        let state_id = tooltip_id.with("__rich");
+        let (now, pointer_pos) = self.ctx.input(|i| (i.time, i.pointer.hover_pos()));
+        let pointer_over = |rect: Rect| pointer_pos.map_or(false, |pos| rect.contains(pos));
+
+        // The rect of the tooltip last frame, and the last time the pointer was over it or the widget:
+        let state = self.ctx.data(|d| d.get_temp::<(Rect, f64)>(state_id));
+
+        let mut show = self.should_show_hover_ui();
+        if !show && self.is_tooltip_open() {
+            if let Some((tooltip_rect, last_inside_time)) = state {
+                let grace_time = self.ctx.style().interaction.tooltip_grace_time;
+                let time_left = grace_time - (now - last_inside_time) as f32;
+                show = pointer_over(tooltip_rect) || pointer_over(self.rect) || 0.0 < time_left;
+                if 0.0 < time_left {
+                    if let Ok(duration) = std::time::Duration::try_from_secs_f32(time_left) {
+                        self.ctx.request_repaint_after(duration);
+                    }
+                }
+            }
+        }
+
+        if show {
+            if let Some(inner_response) = crate::containers::popup::show_rich_tooltip_for(
+                &self.ctx,
+                tooltip_id,
+                &self.rect,
+                add_contents,
+            ) {
+                let tooltip_rect = inner_response.response.rect;
+                let is_inside =
+                    self.hovered || pointer_over(tooltip_rect) || pointer_over(self.rect);
+                let last_inside_time = match state {
+                    Some((_, last_inside_time)) if !is_inside => last_inside_time,
+                    _ => now,
+                };
+                self.ctx
+                    .data_mut(|d| d.insert_temp(state_id, (tooltip_rect, last_inside_time)));
+            }
+        } else if state.is_some() {
+            self.ctx.data_mut(|d| d.remove::<(Rect, f64)>(state_id));
+        }
+
+        self
+    }
+
     /// Show this UI when hovering if the widget is disabled.
     pub fn on_disabled_hover_ui(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
         if !self.enabled && self.should_show_hover_ui() {
@@ -563,6 +758,12 @@ impl Response {
     }
 
     fn should_show_hover_ui(&self) -> bool {
+        self.should_show_hover_ui_after(self.ctx.style().interaction.tooltip_delay)
+    }
+
+    /// Should we show a tooltip, given that it should be shown `tooltip_delay` seconds
+    /// after the pointer stops moving?
+    fn should_show_hover_ui_after(&self, tooltip_delay: f32) -> bool {
         if self.ctx.memory(|mem| mem.everything_is_visible()) {
             return true;
         }
@@ -591,8 +792,8 @@ impl Response {
         }
 
         if !self.is_tooltip_open() {
-            let time_til_tooltip = self.ctx.style().interaction.tooltip_delay
-                - self.ctx.input(|i| i.pointer.time_since_last_movement());
+            let time_til_tooltip =
+                tooltip_delay - self.ctx.input(|i| i.pointer.time_since_last_movement());
 
             if 0.0 < time_til_tooltip {
                 // Wait until the mouse has been still for a while
@@ -818,7 +1019,10 @@ impl Response {
                 Role::Button
             }
             WidgetType::Checkbox => Role::CheckBox,
+            WidgetType::Switch => Role::Switch,
             WidgetType::RadioButton => Role::RadioButton,
+            WidgetType::MenuItemCheckbox => Role::MenuItemCheckBox,
+            WidgetType::MenuItemRadio => Role::MenuItemRadio,
             WidgetType::SelectableLabel => Role::ToggleButton,
             WidgetType::ComboBox => Role::ComboBox,
             WidgetType::Slider => Role::Slider,
@@ -432,6 +432,14 @@ pub struct ScrollStyle {
     /// This is only for floating scroll bars.
     /// Solid scroll bars are always opaque.
     pub interact_handle_opacity: f32,
+
+    /// If set, floating scroll bars are only active while the contents is scrolling,
+    /// and for this many seconds afterwards, after which they fade out to the dormant opacity.
+    ///
+    /// If `None`, floating scroll bars are active whenever the pointer is over the scroll area.
+    ///
+    /// This is only for floating scroll bars.
+    pub fade_out_delay: Option<f32>,
 }
 
 impl Default for ScrollStyle {
@@ -461,6 +469,8 @@ impl ScrollStyle {
             dormant_handle_opacity: 0.0,
             active_handle_opacity: 0.6,
             interact_handle_opacity: 1.0,
+
+            fade_out_delay: None,
         }
     }
 
@@ -501,6 +511,32 @@ impl ScrollStyle {
         }
     }
 
+    /// Thin scroll bars that float over the content and only show up while scrolling,
+    /// fading out shortly after, like on macOS and mobile.
+    ///
+    /// They expand when you hover them.
+    pub fn overlay() -> Self {
+        Self {
+            floating: true,
+            bar_width: 10.0,
+            floating_width: 4.0,
+            floating_allocated_width: 0.0,
+            foreground_color: true,
+
+            dormant_background_opacity: 0.0,
+            active_background_opacity: 0.0,
+            interact_background_opacity: 0.6,
+
+            dormant_handle_opacity: 0.0,
+            active_handle_opacity: 0.6,
+            interact_handle_opacity: 1.0,
+
+            fade_out_delay: Some(1.0),
+
+            ..Self::solid()
+        }
+    }
+
     /// Width of a solid vertical scrollbar, or height of a horizontal scroll bar, when it is at its widest.
     pub fn allocated_width(&self) -> f32 {
         if self.floating {
@@ -516,6 +552,7 @@ impl ScrollStyle {
             ui.selectable_value(self, Self::solid(), "Solid");
             ui.selectable_value(self, Self::thin(), "Thin");
             ui.selectable_value(self, Self::floating(), "Floating");
+            ui.selectable_value(self, Self::overlay(), "Overlay");
         });
 
         ui.collapsing("Details", |ui| {
@@ -541,6 +578,7 @@ impl ScrollStyle {
             dormant_handle_opacity,
             active_handle_opacity,
             interact_handle_opacity,
+            fade_out_delay,
         } = self;
 
         ui.horizontal(|ui| {
@@ -603,6 +641,23 @@ impl ScrollStyle {
                 opacity_ui(ui, interact_handle_opacity);
                 ui.end_row();
             });
+
+            ui.horizontal(|ui| {
+                let mut fade_out = fade_out_delay.is_some();
+                ui.checkbox(&mut fade_out, "Only active while scrolling");
+                if fade_out {
+                    let delay = fade_out_delay.get_or_insert(1.0);
+                    ui.add(
+                        DragValue::new(delay)
+                            .clamp_range(0.0..=10.0)
+                            .speed(0.05)
+                            .suffix(" s"),
+                    )
+                    .on_hover_text("How long the scroll bars stay active after scrolling");
+                } else {
+                    *fade_out_delay = None;
+                }
+            });
         } else {
             ui.horizontal(|ui| {
                 ui.add(DragValue::new(bar_inner_margin).clamp_range(0.0..=32.0));
@@ -637,6 +692,13 @@ pub struct Interaction {
     /// Delay in seconds before showing tooltips after the mouse stops moving
     pub tooltip_delay: f32,
 
+    /// How long (in seconds) a rich tooltip stays open after the pointer has left both
+    /// the widget and the tooltip.
+    ///
+    /// This gives the user time to move the pointer from the widget into the tooltip.
+    /// See [`crate::Response::on_hover_ui_rich`].
+    pub tooltip_grace_time: f32,
+
     /// Can you select the text on a [`crate::Label`] by default?
     pub selectable_labels: bool,
 
@@ -645,6 +707,22 @@ pub struct Interaction {
     /// The default is `true`, but text seelction can be slightly glitchy,
     /// so you may want to disable it.
     pub multi_widget_text_select: bool,
+
+    /// When dragging a window, snap it to the edges of the screen and of other windows?
+    pub window_snapping: bool,
+
+    /// How close (in points) the edges of a dragged window must be to snap, if [`Self::window_snapping`] is on.
+    pub window_snap_distance: f32,
+
+    /// Keep scrolling a [`crate::ScrollArea`] after the user lets go of a drag,
+    /// gradually slowing down, like on a touch screen.
+    pub kinetic_scrolling: bool,
+
+    /// How quickly kinetic scrolling slows down, if [`Self::kinetic_scrolling`] is on.
+    ///
+    /// Each second, the scroll velocity is multiplied by `exp(-kinetic_scroll_friction)`,
+    /// so higher values stop sooner.
+    pub kinetic_scroll_friction: f32,
 }
 
 /// Look and feel of the text cursor.
@@ -1079,8 +1157,13 @@ impl Default for Interaction {
             interact_radius: 5.0,
             show_tooltips_only_when_still: true,
             tooltip_delay: 0.3,
+            tooltip_grace_time: 0.3,
             selectable_labels: true,
             multi_widget_text_select: true,
+            window_snapping: false,
+            window_snap_distance: 8.0,
+            kinetic_scrolling: true,
+            kinetic_scroll_friction: 4.0,
         }
     }
 }
@@ -1559,8 +1642,13 @@ impl Interaction {
             resize_grab_radius_corner,
             show_tooltips_only_when_still,
             tooltip_delay,
+            tooltip_grace_time,
             selectable_labels,
             multi_widget_text_select,
+            window_snapping,
+            window_snap_distance,
+            kinetic_scrolling,
+            kinetic_scroll_friction,
         } = self;
 
         ui.spacing_mut().item_spacing = vec2(12.0, 8.0);
@@ -1592,6 +1680,17 @@ impl Interaction {
                         .suffix(" s"),
                 );
                 ui.end_row();
+
+                ui.label("Tooltip grace time").on_hover_text(
+                    "How long a rich tooltip stays open after the pointer has left both the widget and the tooltip",
+                );
+                ui.add(
+                    DragValue::new(tooltip_grace_time)
+                        .clamp_range(0.0..=1.0)
+                        .speed(0.05)
+                        .suffix(" s"),
+                );
+                ui.end_row();
             });
 
         ui.checkbox(
@@ -1606,6 +1705,31 @@ impl Interaction {
             }
         });
 
+        ui.horizontal(|ui| {
+            ui.checkbox(window_snapping, "Snap windows to edges")
+                .on_hover_text("Snap dragged windows to the edges of the screen and other windows");
+            if *window_snapping {
+                ui.add(
+                    DragValue::new(window_snap_distance)
+                        .clamp_range(0.0..=32.0)
+                        .suffix(" pt"),
+                );
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.checkbox(kinetic_scrolling, "Kinetic scrolling")
+                .on_hover_text("Keep scrolling after letting go of a drag, slowing down gradually");
+            if *kinetic_scrolling {
+                ui.label("Friction:");
+                ui.add(
+                    DragValue::new(kinetic_scroll_friction)
+                        .clamp_range(0.1..=20.0)
+                        .speed(0.05),
+                );
+            }
+        });
+
         ui.vertical_centered(|ui| reset_button(ui, self, "Reset interaction settings"));
     }
 }
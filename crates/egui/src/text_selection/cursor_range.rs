@@ -41,6 +41,22 @@ impl CursorRange {
         Self::two(galley.begin(), galley.end())
     }
 
+    /// The range covering a byte range of `text`, which was laid out as `galley`.
+    ///
+    /// Returns `None` if the range is out of bounds, or not on `char` boundaries.
+    pub fn from_byte_range(
+        text: &str,
+        galley: &Galley,
+        byte_range: std::ops::Range<usize>,
+    ) -> Option<Self> {
+        let start = text.get(..byte_range.start)?.chars().count();
+        let end = start + text.get(byte_range)?.chars().count();
+        Some(Self::two(
+            galley.from_ccursor(CCursor::new(start)),
+            galley.from_ccursor(CCursor::new(end)),
+        ))
+    }
+
     pub fn as_ccursor_range(&self) -> CCursorRange {
         CCursorRange {
             primary: self.primary.ccursor,
@@ -18,36 +18,71 @@ pub fn paint_text_selection(
 
     // We paint the cursor selection on top of the text, so make it transparent:
     let color = visuals.selection.bg_fill.linear_multiply(0.5);
+
+    for rect in cursor_range_rects(galley_pos, galley, cursor_range) {
+        let shape_idx = painter.rect_filled(rect, 0.0, color);
+        if let Some(out_shaped_idx) = &mut out_shaped_idx {
+            out_shaped_idx.push(shape_idx);
+        }
+    }
+}
+
+/// Paint a background behind the text in `cursor_range`, e.g. to highlight a search result.
+///
+/// Call this before painting the text.
+pub fn paint_text_highlight(
+    painter: &Painter,
+    galley_pos: Pos2,
+    galley: &Galley,
+    cursor_range: &CursorRange,
+    color: Color32,
+) {
+    for rect in cursor_range_rects(galley_pos, galley, cursor_range) {
+        painter.rect_filled(rect, 0.0, color);
+    }
+}
+
+/// The rectangles covering the text in `cursor_range`, in screen space.
+///
+/// This is one rectangle per row, except for rows that mix left-to-right and right-to-left text,
+/// where the selected characters may not be next to each other.
+pub(crate) fn cursor_range_rects<'g>(
+    galley_pos: Pos2,
+    galley: &'g Galley,
+    cursor_range: &CursorRange,
+) -> impl Iterator<Item = Rect> + 'g {
     let [min, max] = cursor_range.sorted_cursors();
     let min = min.rcursor;
     let max = max.rcursor;
 
-    for ri in min.row..=max.row {
+    (min.row..=max.row).flat_map(move |ri| {
         let row = &galley.rows[ri];
-        let left = if ri == min.row {
-            row.x_offset(min.column)
+        let start = if ri == min.row { min.column } else { 0 };
+        let end = if ri == max.row {
+            max.column
         } else {
-            row.rect.left()
+            row.char_count_excluding_newline()
         };
-        let right = if ri == max.row {
-            row.x_offset(max.column)
-        } else {
+
+        let mut x_ranges = row.x_ranges(start..end);
+        if ri != max.row {
             let newline_size = if row.ends_with_newline {
                 row.height() / 2.0 // visualize that we select the newline
             } else {
                 0.0
             };
-            row.rect.right() + newline_size
-        };
-        let rect = Rect::from_min_max(
-            galley_pos + vec2(left, row.min_y()),
-            galley_pos + vec2(right, row.max_y()),
-        );
-        let shape_idx = painter.rect_filled(rect, 0.0, color);
-        if let Some(out_shaped_idx) = &mut out_shaped_idx {
-            out_shaped_idx.push(shape_idx);
+            if let Some(last) = x_ranges.last_mut() {
+                last.max = last.max.max(row.rect.right()) + newline_size;
+            }
         }
-    }
+
+        x_ranges.into_iter().map(move |x_range| {
+            Rect::from_min_max(
+                galley_pos + vec2(x_range.min, row.min_y()),
+                galley_pos + vec2(x_range.max, row.max_y()),
+            )
+        })
+    })
 }
 
 /// Paint one end of the selection, e.g. the primary cursor.
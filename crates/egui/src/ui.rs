@@ -343,6 +343,14 @@ impl Ui {
         }
     }
 
+    /// How should text that doesn't fit in this [`Ui`] be handled?
+    ///
+    /// This is [`TextWrapMode::Wrap`] if [`Self::wrap_text`] is `true`, otherwise [`TextWrapMode::Extend`].
+    #[inline]
+    pub fn wrap_mode(&self) -> TextWrapMode {
+        TextWrapMode::from_wrap(self.wrap_text())
+    }
+
     /// Create a painter for a sub-region of this Ui.
     ///
     /// The clip-rect of the returned [`Painter`] will be the intersection
@@ -1463,14 +1471,60 @@ impl Ui {
         Button::new(text).small().ui(self)
     }
 
+    /// A button with a keyboard shortcut shown to the right of the text,
+    /// which is also clicked when the user presses that shortcut.
+    ///
+    /// This is mostly useful in menus. Note that the contents of a closed menu are not run,
+    /// so the shortcut only clicks the button while the menu is open.
+    /// Check for the shortcut yourself to also handle it while the menu is closed:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::{Key, KeyboardShortcut, Modifiers};
+    /// const SAVE: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+    ///
+    /// ui.menu_button("File", |ui| {
+    ///     if ui.shortcut_button("Save", SAVE).clicked() {
+    ///         // …
+    ///         ui.close_menu();
+    ///     }
+    /// });
+    ///
+    /// if ui.input_mut(|i| i.consume_shortcut(&SAVE)) {
+    ///     // …
+    /// }
+    /// # });
+    /// ```
+    #[must_use = "You should check if the user clicked this with `if ui.shortcut_button(…).clicked() { … } "]
+    pub fn shortcut_button(
+        &mut self,
+        text: impl Into<WidgetText>,
+        shortcut: KeyboardShortcut,
+    ) -> Response {
+        let shortcut_text = self.ctx().format_shortcut(&shortcut);
+        let mut response = Button::new(text).shortcut_text(shortcut_text).ui(self);
+        if response.enabled && self.input_mut(|i| i.consume_shortcut(&shortcut)) {
+            response.fake_primary_click = true;
+        }
+        response
+    }
+
     /// Show a checkbox.
     ///
-    /// See also [`Self::toggle_value`].
+    /// See also [`Self::toggle_value`] and [`Self::toggle_switch`].
     #[inline]
     pub fn checkbox(&mut self, checked: &mut bool, text: impl Into<WidgetText>) -> Response {
         Checkbox::new(checked, text).ui(self)
     }
 
+    /// Show an iOS-style on/off switch.
+    ///
+    /// See also [`Self::checkbox`].
+    #[inline]
+    pub fn toggle_switch(&mut self, on: &mut bool, text: impl Into<WidgetText>) -> Response {
+        ToggleSwitch::new(on, text).ui(self)
+    }
+
     /// Acts like a checkbox, but looks like a [`SelectableLabel`].
     ///
     /// Click to toggle to bool.
@@ -2110,6 +2164,34 @@ impl Ui {
             .end_row(self.spacing().item_spacing, &self.painter().clone());
     }
 
+    /// In a [`Grid`], make the next cell span several columns and/or rows.
+    ///
+    /// In the rows below, the cells covered by the spanning cell are skipped.
+    /// Outside of a grid this does nothing.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::Grid::new("form").num_columns(3).show(ui, |ui| {
+    ///     ui.label("Name:");
+    ///     ui.text_edit_singleline(&mut String::new());
+    ///     ui.set_grid_cell_span(1, 2);
+    ///     ui.label("A tall preview");
+    ///     ui.end_row();
+    ///
+    ///     ui.label("Age:");
+    ///     ui.label("42");
+    ///     ui.end_row();
+    ///
+    ///     ui.set_grid_cell_span(3, 1);
+    ///     ui.separator();
+    ///     ui.end_row();
+    /// });
+    /// # });
+    /// ```
+    pub fn set_grid_cell_span(&mut self, columns: usize, rows: usize) {
+        self.placer.set_grid_cell_span(columns, rows);
+    }
+
     /// Set row height in horizontal wrapping layout.
     pub fn set_row_height(&mut self, height: f32) {
         self.placer.set_row_height(height);
@@ -2325,6 +2407,48 @@ impl Ui {
         }
     }
 
+    /// A menu item with a check mark, showing whether `checked` is `true`.
+    ///
+    /// Clicking it toggles `checked` and closes the menu.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut show_grid = true;
+    /// ui.menu_button("View", |ui| {
+    ///     ui.menu_checkbox("Show grid", &mut show_grid);
+    /// });
+    /// # });
+    /// ```
+    pub fn menu_checkbox(&mut self, text: impl Into<WidgetText>, checked: &mut bool) -> Response {
+        menu::menu_checkbox(self, text, checked)
+    }
+
+    /// A menu item with a radio bullet, for choosing between alternatives.
+    ///
+    /// The bullet is shown if `*current_value == alternative`.
+    /// Clicking it sets `*current_value = alternative` and closes the menu.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// #[derive(PartialEq)]
+    /// enum Zoom { Fit, Actual }
+    /// let mut zoom = Zoom::Fit;
+    ///
+    /// ui.menu_button("Zoom", |ui| {
+    ///     ui.menu_radio("Fit to window", &mut zoom, Zoom::Fit);
+    ///     ui.menu_radio("Actual size", &mut zoom, Zoom::Actual);
+    /// });
+    /// # });
+    /// ```
+    pub fn menu_radio<Value: PartialEq>(
+        &mut self,
+        text: impl Into<WidgetText>,
+        current_value: &mut Value,
+        alternative: Value,
+    ) -> Response {
+        menu::menu_radio(self, text, current_value, alternative)
+    }
+
     /// Create a menu button with an image that when clicked will show the given menu.
     ///
     /// If called from within a menu this will instead create a button for a sub-menu.
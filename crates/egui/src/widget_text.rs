@@ -1,8 +1,9 @@
 use std::{borrow::Cow, sync::Arc};
 
 use crate::{
-    text::LayoutJob, Align, Color32, FontFamily, FontSelection, Galley, Style, TextStyle, Ui,
-    Visuals,
+    text::{LayoutJob, TextWrapping},
+    Align, Color32, FontFamily, FontSelection, FontVariations, Galley, Stroke, Style, TextStyle,
+    Ui, Visuals,
 };
 
 /// Text and optional style choices for it.
@@ -25,8 +26,10 @@ pub struct RichText {
     text: String,
     size: Option<f32>,
     extra_letter_spacing: f32,
+    extra_word_spacing: f32,
     line_height: Option<f32>,
     family: Option<FontFamily>,
+    variations: FontVariations,
     text_style: Option<TextStyle>,
     background_color: Color32,
     text_color: Option<Color32>,
@@ -37,6 +40,9 @@ pub struct RichText {
     underline: bool,
     italics: bool,
     raised: bool,
+    superscript: bool,
+    subscript: bool,
+    stroke: Stroke,
 }
 
 impl From<&str> for RichText {
@@ -113,6 +119,18 @@ impl RichText {
         self
     }
 
+    /// Extra spacing after each space, in points.
+    ///
+    /// Default: 0.0.
+    ///
+    /// For even text it is recommended you round this to an even number of _pixels_,
+    /// e.g. using [`crate::Painter::round_to_pixel`].
+    #[inline]
+    pub fn extra_word_spacing(mut self, extra_word_spacing: f32) -> Self {
+        self.extra_word_spacing = extra_word_spacing;
+        self
+    }
+
     /// Explicit line height of the text in points.
     ///
     /// This is the distance between the bottom row of two subsequent lines of text.
@@ -142,9 +160,26 @@ impl RichText {
     /// This overrides the value from [`Self::text_style`].
     #[inline]
     pub fn font(mut self, font_id: crate::FontId) -> Self {
-        let crate::FontId { size, family } = font_id;
+        let crate::FontId {
+            size,
+            family,
+            variations,
+        } = font_id;
         self.size = Some(size);
         self.family = Some(family);
+        self.variations = variations;
+        self
+    }
+
+    /// Select the axis values to use with variable fonts, e.g. the weight.
+    ///
+    /// ```
+    /// # use egui::{FontVariations, RichText};
+    /// RichText::new("semi-bold").variations(FontVariations::NONE.weight(600.0));
+    /// ```
+    #[inline]
+    pub fn variations(mut self, variations: FontVariations) -> Self {
+        self.variations = variations;
         self
     }
 
@@ -220,6 +255,18 @@ impl RichText {
         self
     }
 
+    /// Draw an outline around the characters, so the text is readable on any background.
+    ///
+    /// ```
+    /// # use egui::{Color32, RichText, Stroke};
+    /// RichText::new("Berlin").color(Color32::WHITE).stroke(Stroke::new(1.5, Color32::BLACK));
+    /// ```
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
     /// Smaller text.
     #[inline]
     pub fn small(self) -> Self {
@@ -239,6 +286,38 @@ impl RichText {
         self
     }
 
+    /// Smaller text above the baseline, e.g. for exponents and footnote markers.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.horizontal(|ui| {
+    ///     ui.spacing_mut().item_spacing.x = 0.0;
+    ///     ui.label("E = mc");
+    ///     ui.label(egui::RichText::new("2").superscript());
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// To keep the text on the same baseline as the text around it,
+    /// put both in the same [`LayoutJob`], e.g. with [`Self::append_to`].
+    #[inline]
+    pub fn superscript(mut self) -> Self {
+        self.superscript = true;
+        self.subscript = false;
+        self
+    }
+
+    /// Smaller text below the baseline, e.g. for chemical formulas like H₂O.
+    ///
+    /// To keep the text on the same baseline as the text around it,
+    /// put both in the same [`LayoutJob`], e.g. with [`Self::append_to`].
+    #[inline]
+    pub fn subscript(mut self) -> Self {
+        self.subscript = true;
+        self.superscript = false;
+        self
+    }
+
     /// Fill-color behind the text.
     #[inline]
     pub fn background_color(mut self, background_color: impl Into<Color32>) -> Self {
@@ -269,6 +348,7 @@ impl RichText {
         if let Some(family) = &self.family {
             font_id.family = family.clone();
         }
+        font_id.variations = self.variations.or(font_id.variations);
         fonts.row_height(&font_id)
     }
 
@@ -335,8 +415,10 @@ impl RichText {
             text,
             size,
             extra_letter_spacing,
+            extra_word_spacing,
             line_height,
             family,
+            variations,
             text_style,
             background_color,
             text_color: _, // already used by `get_text_color`
@@ -347,12 +429,15 @@ impl RichText {
             underline,
             italics,
             raised,
+            superscript,
+            subscript,
+            stroke,
         } = self;
 
         let line_color = text_color.unwrap_or_else(|| style.visuals.text_color());
         let text_color = text_color.unwrap_or(crate::Color32::PLACEHOLDER);
 
-        let font_id = {
+        let mut font_id = {
             let mut font_id = text_style
                 .or_else(|| style.override_text_style.clone())
                 .map_or_else(
@@ -365,9 +450,22 @@ impl RichText {
             if let Some(family) = family {
                 font_id.family = family;
             }
+            font_id.variations = variations.or(font_id.variations);
             font_id
         };
 
+        let baseline_offset = if superscript || subscript {
+            let full_size = font_id.size;
+            font_id.size *= 0.6;
+            if superscript {
+                -0.3 * full_size
+            } else {
+                0.15 * full_size
+            }
+        } else {
+            0.0
+        };
+
         let mut background_color = background_color;
         if code {
             background_color = style.visuals.code_bg_color;
@@ -394,13 +492,18 @@ impl RichText {
             crate::text::TextFormat {
                 font_id,
                 extra_letter_spacing,
+                extra_word_spacing,
                 line_height,
+                line_height_factor: 1.0,
                 color: text_color,
                 background: background_color,
                 italics,
                 underline,
                 strikethrough,
+                stroke,
                 valign,
+                baseline_offset,
+                inline_size: None,
             },
         )
     }
@@ -420,6 +523,53 @@ impl RichText {
 
 // ----------------------------------------------------------------------------
 
+/// What to do with text that doesn't fit in the available width.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TextWrapMode {
+    /// Don't wrap, and let the text expand the parent [`Ui`].
+    Extend,
+
+    /// Wrap the text onto new rows to stay within the available width.
+    Wrap,
+
+    /// Keep the text on one row, and replace what doesn't fit with `…`.
+    ///
+    /// Widgets show the full text on hover when it is truncated.
+    Truncate,
+}
+
+impl TextWrapMode {
+    /// [`Self::Wrap`] if `wrap` is `true`, otherwise [`Self::Extend`].
+    #[inline]
+    pub fn from_wrap(wrap: bool) -> Self {
+        if wrap {
+            Self::Wrap
+        } else {
+            Self::Extend
+        }
+    }
+
+    /// Set up `wrapping` for this mode, given the available width.
+    pub fn apply(self, wrapping: &mut TextWrapping, available_width: f32) {
+        match self {
+            Self::Extend => {
+                wrapping.max_width = f32::INFINITY;
+            }
+            Self::Wrap => {
+                wrapping.max_width = available_width;
+            }
+            Self::Truncate => {
+                wrapping.max_width = available_width;
+                wrapping.max_rows = 1;
+                wrapping.break_anywhere = true;
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// This is how you specify text for a widget.
 ///
 /// A lot of widgets use `impl Into<WidgetText>` as an argument,
@@ -648,14 +798,32 @@ impl WidgetText {
         available_width: f32,
         fallback_font: impl Into<FontSelection>,
     ) -> Arc<Galley> {
-        let wrap = wrap.unwrap_or_else(|| ui.wrap_text());
+        self.into_galley_with_wrap_mode(
+            ui,
+            wrap.map(TextWrapMode::from_wrap),
+            available_width,
+            fallback_font,
+        )
+    }
+
+    /// Layout with wrap mode based on the containing [`Ui`].
+    ///
+    /// `wrap_mode`: override for [`Ui::wrap_mode`].
+    pub fn into_galley_with_wrap_mode(
+        self,
+        ui: &Ui,
+        wrap_mode: Option<TextWrapMode>,
+        available_width: f32,
+        fallback_font: impl Into<FontSelection>,
+    ) -> Arc<Galley> {
+        let wrap_mode = wrap_mode.unwrap_or_else(|| ui.wrap_mode());
         let valign = ui.layout().vertical_align();
         let style = ui.style();
 
         self.into_galley_impl(
             ui.ctx(),
             style,
-            wrap,
+            wrap_mode,
             available_width,
             fallback_font.into(),
             valign,
@@ -666,21 +834,19 @@ impl WidgetText {
         self,
         ctx: &crate::Context,
         style: &Style,
-        wrap: bool,
+        wrap_mode: TextWrapMode,
         available_width: f32,
         fallback_font: FontSelection,
         default_valign: Align,
     ) -> Arc<Galley> {
-        let wrap_width = if wrap { available_width } else { f32::INFINITY };
-
         match self {
             Self::RichText(text) => {
                 let mut layout_job = text.into_layout_job(style, fallback_font, default_valign);
-                layout_job.wrap.max_width = wrap_width;
+                wrap_mode.apply(&mut layout_job.wrap, available_width);
                 ctx.fonts(|f| f.layout_job(layout_job))
             }
             Self::LayoutJob(mut job) => {
-                job.wrap.max_width = wrap_width;
+                wrap_mode.apply(&mut job.wrap, available_width);
                 ctx.fonts(|f| f.layout_job(job))
             }
             Self::Galley(galley) => galley,
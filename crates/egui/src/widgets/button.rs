@@ -23,7 +23,7 @@ pub struct Button<'a> {
     image: Option<Image<'a>>,
     text: Option<WidgetText>,
     shortcut_text: WidgetText,
-    wrap: Option<bool>,
+    wrap_mode: Option<TextWrapMode>,
 
     /// None means default for interact
     fill: Option<Color32>,
@@ -58,7 +58,7 @@ impl<'a> Button<'a> {
             text,
             image,
             shortcut_text: Default::default(),
-            wrap: None,
+            wrap_mode: None,
             fill: None,
             stroke: None,
             sense: Sense::click(),
@@ -79,7 +79,17 @@ impl<'a> Button<'a> {
     /// Note that any `\n` in the text will always produce a new line.
     #[inline]
     pub fn wrap(mut self, wrap: bool) -> Self {
-        self.wrap = Some(wrap);
+        self.wrap_mode = Some(TextWrapMode::from_wrap(wrap));
+        self
+    }
+
+    /// Set what to do with text that doesn't fit, overriding [`Ui::wrap_mode`].
+    ///
+    /// With [`TextWrapMode::Truncate`] the text is cut off with `…`,
+    /// and the full text is shown on hover.
+    #[inline]
+    pub fn wrap_mode(mut self, wrap_mode: TextWrapMode) -> Self {
+        self.wrap_mode = Some(wrap_mode);
         self
     }
 
@@ -165,7 +175,7 @@ impl Widget for Button<'_> {
             text,
             image,
             shortcut_text,
-            wrap,
+            wrap_mode,
             fill,
             stroke,
             sense,
@@ -210,8 +220,9 @@ impl Widget for Button<'_> {
             text_wrap_width -= 60.0; // Some space for the shortcut text (which we never wrap).
         }
 
-        let galley =
-            text.map(|text| text.into_galley(ui, wrap, text_wrap_width, TextStyle::Button));
+        let galley = text.map(|text| {
+            text.into_galley_with_wrap_mode(ui, wrap_mode, text_wrap_width, TextStyle::Button)
+        });
         let shortcut_galley = (!shortcut_text.is_empty())
             .then(|| shortcut_text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button));
 
@@ -238,6 +249,10 @@ impl Widget for Button<'_> {
         desired_size = desired_size.at_least(min_size);
 
         let (rect, mut response) = ui.allocate_at_least(desired_size, sense);
+        if let Some(galley) = galley.as_ref().filter(|galley| galley.elided) {
+            // Show the full (non-elided) text on hover:
+            response = response.on_hover_text(galley.text());
+        }
         response.widget_info(|| {
             if let Some(galley) = &galley {
                 WidgetInfo::labeled(WidgetType::Button, galley.text())
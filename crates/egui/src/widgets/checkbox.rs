@@ -15,15 +15,23 @@ use crate::*;
 /// ```
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Checkbox<'a> {
-    checked: &'a mut bool,
+    checked: CheckboxValue<'a>,
     text: WidgetText,
     indeterminate: bool,
 }
 
+/// What a [`Checkbox`] toggles.
+enum CheckboxValue<'a> {
+    Bool(&'a mut bool),
+
+    /// `None` is the indeterminate state.
+    Tristate(&'a mut Option<bool>),
+}
+
 impl<'a> Checkbox<'a> {
     pub fn new(checked: &'a mut bool, text: impl Into<WidgetText>) -> Self {
         Checkbox {
-            checked,
+            checked: CheckboxValue::Bool(checked),
             text: text.into(),
             indeterminate: false,
         }
@@ -33,6 +41,36 @@ impl<'a> Checkbox<'a> {
         Self::new(checked, WidgetText::default())
     }
 
+    /// A checkbox with three states: checked (`Some(true)`), unchecked (`Some(false)`),
+    /// and indeterminate (`None`), which is shown as a dash.
+    ///
+    /// This is useful for a "select all" checkbox above a list where only some items are selected.
+    /// Clicking an indeterminate checkbox checks it, so the user can only toggle between checked and unchecked.
+    /// It is up to you to set it to `None` again, e.g. when the selection of the list changes.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut items = [true, false, true];
+    /// let mut all = if items.iter().all(|&item| item) {
+    ///     Some(true)
+    /// } else if items.iter().any(|&item| item) {
+    ///     None
+    /// } else {
+    ///     Some(false)
+    /// };
+    /// if ui.add(egui::Checkbox::new_tristate(&mut all, "Select all")).changed() {
+    ///     items = [all == Some(true); 3];
+    /// }
+    /// # });
+    /// ```
+    pub fn new_tristate(checked: &'a mut Option<bool>, text: impl Into<WidgetText>) -> Self {
+        Checkbox {
+            checked: CheckboxValue::Tristate(checked),
+            text: text.into(),
+            indeterminate: false,
+        }
+    }
+
     /// Display an indeterminate state (neither checked nor unchecked)
     ///
     /// This only affects the checkbox's appearance. It will still toggle its boolean value when
@@ -47,7 +85,7 @@ impl<'a> Checkbox<'a> {
 impl<'a> Widget for Checkbox<'a> {
     fn ui(self, ui: &mut Ui) -> Response {
         let Checkbox {
-            checked,
+            mut checked,
             text,
             indeterminate,
         } = self;
@@ -75,9 +113,17 @@ impl<'a> Widget for Checkbox<'a> {
         let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
 
         if response.clicked() {
-            *checked = !*checked;
+            match &mut checked {
+                CheckboxValue::Bool(checked) => **checked = !**checked,
+                CheckboxValue::Tristate(checked) => **checked = Some(**checked != Some(true)),
+            }
             response.mark_changed();
         }
+
+        let (checked, indeterminate) = match checked {
+            CheckboxValue::Bool(checked) => (*checked, indeterminate),
+            CheckboxValue::Tristate(checked) => (*checked == Some(true), checked.is_none()),
+        };
         response.widget_info(|| {
             if indeterminate {
                 WidgetInfo::labeled(
@@ -87,7 +133,7 @@ impl<'a> Widget for Checkbox<'a> {
             } else {
                 WidgetInfo::selected(
                     WidgetType::Checkbox,
-                    *checked,
+                    checked,
                     galley.as_ref().map_or("", |x| x.text()),
                 )
             }
@@ -111,7 +157,7 @@ impl<'a> Widget for Checkbox<'a> {
                     small_icon_rect.center().y,
                     visuals.fg_stroke,
                 ));
-            } else if *checked {
+            } else if checked {
                 // Check mark:
                 ui.painter().add(Shape::line(
                     vec![
@@ -236,7 +236,7 @@ pub enum Alpha {
     BlendOrAdditive,
 }
 
-fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
+fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha, palette: &[Color32]) {
     use crate::style::NumericColorSpace;
 
     let alpha_control = if is_additive_alpha(hsvag.a) {
@@ -296,6 +296,14 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
         }
     }
 
+    ui.horizontal(|ui| {
+        hex_edit_ui(ui, hsvag, alpha_control);
+
+        #[cfg(all(feature = "eyedropper", not(target_arch = "wasm32")))]
+        eyedropper_ui(ui, hsvag);
+    });
+    hsv_edit_ui(ui, hsvag);
+
     let current_color_size = vec2(ui.spacing().slider_width, ui.spacing().interact_size.y);
     show_color(ui, *hsvag, current_color_size).on_hover_text("Selected color");
 
@@ -358,6 +366,10 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
             color_slider_1d(ui, a, |a| HsvaGamma { a, ..opaque }.into()).on_hover_text("Alpha");
         }
     }
+
+    if !palette.is_empty() {
+        palette_ui(ui, hsvag, palette);
+    }
 }
 
 fn input_type_button_ui(ui: &mut Ui) {
@@ -444,13 +456,271 @@ fn rgba_edit_ui(ui: &mut Ui, [r, g, b, a]: &mut [f32; 4], alpha: Alpha) -> bool
     edited
 }
 
+/// Shows a text field where the user can type the color as a hex string, e.g. `#ff8000`.
+///
+/// The alpha channel is only shown (and only editable) when `alpha` is not [`Alpha::Opaque`].
+/// Both the 3/4 and 6/8 digit formats are accepted, with or without the leading `#`.
+///
+/// Returns `true` on change.
+fn hex_edit_ui(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) -> bool {
+    let [r, g, b, a] = Hsva::from(*hsvag).to_srgba_unmultiplied();
+    let current_hex = if alpha == Alpha::Opaque {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    } else {
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    };
+
+    let mut edited = false;
+
+    ui.horizontal(|ui| {
+        ui.label("Hex");
+
+        // While the user is typing we keep their text, even if it is not (yet) a valid color:
+        let text_id = ui.auto_id_with("hex_text");
+        let mut text = ui
+            .data_mut(|d| d.get_temp::<String>(text_id))
+            .unwrap_or(current_hex);
+
+        let response = TextEdit::singleline(&mut text)
+            .font(TextStyle::Monospace)
+            .desired_width(ui.spacing().interact_size.x * 2.0)
+            .char_filter(|c| c == '#' || c.is_ascii_hexdigit())
+            .ui(ui);
+
+        if response.changed() {
+            let hex = text.trim();
+            if let Ok(hex_color) =
+                HexColor::from_str_without_hash(hex.strip_prefix('#').unwrap_or(hex))
+            {
+                let [r, g, b, a] = hex_color.color().to_srgba_unmultiplied();
+                if is_additive_alpha(hsvag.a) {
+                    let alpha = hsvag.a;
+                    *hsvag = HsvaGamma::from(Hsva::from_additive_srgb([r, g, b]));
+                    // Don't edit the alpha:
+                    hsvag.a = alpha;
+                } else {
+                    *hsvag = HsvaGamma::from(Hsva::from_srgba_unmultiplied([r, g, b, a]));
+                }
+                edited = true;
+            }
+        }
+
+        if response.has_focus() {
+            ui.data_mut(|d| d.insert_temp(text_id, text));
+        } else {
+            ui.data_mut(|d| d.remove::<String>(text_id));
+        }
+    });
+
+    edited
+}
+
+/// The state of the eyedropper.
+///
+/// There is only ever one active eyedropper, since it captures all clicks while picking.
+#[cfg(all(feature = "eyedropper", not(target_arch = "wasm32")))]
+#[derive(Clone, Copy, Debug)]
+struct Eyedropper {
+    /// The id of the eyedropper button that started the picking.
+    owner: Id,
+
+    /// Where the user clicked, in points.
+    ///
+    /// Once set, we are waiting for the screenshot we requested.
+    picked_pos: Option<Pos2>,
+}
+
+/// Is some color picker currently picking a color from the screen?
+fn is_eyedropper_active(ctx: &Context) -> bool {
+    #[cfg(all(feature = "eyedropper", not(target_arch = "wasm32")))]
+    {
+        ctx.data(|d| d.get_temp::<Eyedropper>(Id::NULL)).is_some()
+    }
+
+    #[cfg(not(all(feature = "eyedropper", not(target_arch = "wasm32"))))]
+    {
+        let _ = ctx;
+        false
+    }
+}
+
+/// Shows a button that lets the user pick a color by clicking anywhere in the window.
+///
+/// The color is read from a screenshot, requested with [`ViewportCommand::Screenshot`].
+/// If the integration does not support screenshots, the click is simply ignored.
+///
+/// Returns `true` on change.
+#[cfg(all(feature = "eyedropper", not(target_arch = "wasm32")))]
+fn eyedropper_ui(ui: &mut Ui, hsvag: &mut HsvaGamma) -> bool {
+    let ctx = ui.ctx().clone();
+    let owner = ui.next_auto_id(); // the id of the button below
+
+    let mut eyedropper = ctx
+        .data(|d| d.get_temp::<Eyedropper>(Id::NULL))
+        .filter(|eyedropper| eyedropper.owner == owner);
+
+    let response = ui
+        .add(Button::new("💧").selected(eyedropper.is_some()))
+        .on_hover_text("Pick a color from the screen.\nPress Escape to cancel.");
+
+    let mut edited = false;
+
+    if response.clicked() {
+        eyedropper = match eyedropper {
+            Some(_) => None,
+            None => Some(Eyedropper {
+                owner,
+                picked_pos: None,
+            }),
+        };
+    } else if eyedropper.is_some() && ctx.input(|i| i.key_pressed(Key::Escape)) {
+        eyedropper = None;
+    } else if let Some(Eyedropper {
+        picked_pos: Some(pos),
+        ..
+    }) = eyedropper
+    {
+        let viewport_id = ctx.viewport_id();
+        let screenshot = ctx.input(|i| {
+            i.raw.events.iter().find_map(|event| match event {
+                Event::Screenshot {
+                    viewport_id: id,
+                    image,
+                } if *id == viewport_id => Some(image.clone()),
+                _ => None,
+            })
+        });
+
+        if let Some(screenshot) = screenshot {
+            if let Some(color) = screen_color_at(&screenshot, pos, ctx.pixels_per_point()) {
+                // Keep the alpha (and additiveness) of the current color:
+                let alpha = hsvag.a;
+                *hsvag = HsvaGamma::from(color);
+                hsvag.a = alpha;
+                edited = true;
+            }
+            eyedropper = None;
+        } else {
+            ctx.request_repaint(); // keep going until the screenshot arrives
+        }
+    } else if let Some(eyedropper) = &mut eyedropper {
+        // Cover the whole screen so the click doesn't reach whatever is below it:
+        let screen_rect = ctx.screen_rect();
+        let overlay = Area::new(owner.with("overlay"))
+            .order(Order::Debug)
+            .fixed_pos(screen_rect.min)
+            .show(&ctx, |ui| {
+                ui.allocate_response(screen_rect.size(), Sense::click())
+            })
+            .inner;
+
+        ctx.set_cursor_icon(CursorIcon::Crosshair);
+
+        if overlay.clicked() {
+            if let Some(pos) = overlay.interact_pointer_pos() {
+                eyedropper.picked_pos = Some(pos);
+                ctx.send_viewport_cmd(ViewportCommand::Screenshot);
+            }
+        }
+    }
+
+    ctx.data_mut(|d| match eyedropper {
+        Some(eyedropper) => d.insert_temp(Id::NULL, eyedropper),
+        None => {
+            if d.get_temp::<Eyedropper>(Id::NULL).map(|e| e.owner) == Some(owner) {
+                d.remove::<Eyedropper>(Id::NULL);
+            }
+        }
+    });
+
+    edited
+}
+
+/// The color of the pixel at the given position (in points) of a screenshot.
+#[cfg(all(feature = "eyedropper", not(target_arch = "wasm32")))]
+fn screen_color_at(screenshot: &ColorImage, pos: Pos2, pixels_per_point: f32) -> Option<Color32> {
+    let x = (pos.x * pixels_per_point) as usize;
+    let y = (pos.y * pixels_per_point) as usize;
+    let [width, height] = screenshot.size;
+    (x < width && y < height).then(|| screenshot[(x, y)])
+}
+
+/// Shows 3 `DragValue` widgets to be used to edit the hue (0-360°), saturation and value (0-100%).
+///
+/// These edit the same values as the 2D color picker, so the two always agree.
+///
+/// Returns `true` on change.
+fn hsv_edit_ui(ui: &mut Ui, hsvag: &mut HsvaGamma) -> bool {
+    fn drag_value(ui: &mut Ui, prefix: &str, suffix: &str, value: &mut f32, max: f32) -> bool {
+        let mut scaled = *value * max;
+        let changed = DragValue::new(&mut scaled)
+            .speed(0.5)
+            .prefix(prefix)
+            .suffix(suffix)
+            .clamp_range(0.0..=max)
+            .max_decimals(1)
+            .ui(ui)
+            .changed();
+        if changed {
+            *value = scaled / max;
+        }
+        changed
+    }
+
+    let mut edited = false;
+
+    ui.horizontal(|ui| {
+        let HsvaGamma { h, s, v, a: _ } = hsvag;
+        edited |= drag_value(ui, "H ", "°", h, 360.0);
+        edited |= drag_value(ui, "S ", "%", s, 100.0);
+        edited |= drag_value(ui, "V ", "%", v, 100.0);
+    });
+
+    edited
+}
+
+/// Shows a row of color swatches. Clicking one picks that color.
+///
+/// Returns `true` on change.
+fn palette_ui(ui: &mut Ui, hsvag: &mut HsvaGamma, palette: &[Color32]) -> bool {
+    let mut edited = false;
+
+    ui.horizontal_wrapped(|ui| {
+        ui.set_max_width(ui.spacing().slider_width);
+        for &color in palette {
+            let response = color_button(ui, color, false).on_hover_text(color.to_hex());
+            if response.clicked() {
+                *hsvag = HsvaGamma::from(color);
+                edited = true;
+            }
+        }
+    });
+
+    edited
+}
+
 /// Shows a color picker where the user can change the given [`Hsva`] color.
 ///
 /// Returns `true` on change.
 pub fn color_picker_hsva_2d(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> bool {
+    color_picker_hsva_2d_with_palette(ui, hsva, alpha, &[])
+}
+
+/// Like [`color_picker_hsva_2d`], but also shows a row of swatches with the given colors,
+/// e.g. a set of recently used colors.
+///
+/// Clicking a swatch picks its color.
+///
+/// Returns `true` on change.
+pub fn color_picker_hsva_2d_with_palette(
+    ui: &mut Ui,
+    hsva: &mut Hsva,
+    alpha: Alpha,
+    palette: &[Color32],
+) -> bool {
     let mut hsvag = HsvaGamma::from(*hsva);
     ui.vertical(|ui| {
-        color_picker_hsvag_2d(ui, &mut hsvag, alpha);
+        color_picker_hsvag_2d(ui, &mut hsvag, alpha, palette);
     });
     let new_hasva = Hsva::from(hsvag);
     if *hsva == new_hasva {
@@ -465,14 +735,38 @@ pub fn color_picker_hsva_2d(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> bool
 ///
 /// Returns `true` on change.
 pub fn color_picker_color32(ui: &mut Ui, srgba: &mut Color32, alpha: Alpha) -> bool {
+    color_picker_color32_with_palette(ui, srgba, alpha, &[])
+}
+
+/// Like [`color_picker_color32`], but also shows a row of swatches with the given colors,
+/// e.g. a set of recently used colors.
+///
+/// Returns `true` on change.
+pub fn color_picker_color32_with_palette(
+    ui: &mut Ui,
+    srgba: &mut Color32,
+    alpha: Alpha,
+    palette: &[Color32],
+) -> bool {
     let mut hsva = color_cache_get(ui.ctx(), *srgba);
-    let changed = color_picker_hsva_2d(ui, &mut hsva, alpha);
+    let changed = color_picker_hsva_2d_with_palette(ui, &mut hsva, alpha, palette);
     *srgba = Color32::from(hsva);
     color_cache_set(ui.ctx(), *srgba, hsva);
     changed
 }
 
 pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Response {
+    color_edit_button_hsva_with_palette(ui, hsva, alpha, &[])
+}
+
+/// Like [`color_edit_button_hsva`], but the color picker also shows a row of swatches
+/// with the given colors, e.g. a set of recently used colors.
+pub fn color_edit_button_hsva_with_palette(
+    ui: &mut Ui,
+    hsva: &mut Hsva,
+    alpha: Alpha,
+    palette: &[Color32],
+) -> Response {
     let popup_id = ui.auto_id_with("popup");
     let open = ui.memory(|mem| mem.is_popup_open(popup_id));
     let mut button_response = color_button(ui, (*hsva).into(), open);
@@ -486,6 +780,9 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
 
     const COLOR_SLIDER_WIDTH: f32 = 275.0;
 
+    // While the eyedropper is picking, clicks and Escape are meant for it, not for the popup:
+    let eyedropper_active = is_eyedropper_active(ui.ctx());
+
     // TODO(emilk): make it easier to show a temporary popup that closes when you click outside it
     if ui.memory(|mem| mem.is_popup_open(popup_id)) {
         let area_response = Area::new(popup_id)
@@ -495,7 +792,7 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
             .show(ui.ctx(), |ui| {
                 ui.spacing_mut().slider_width = COLOR_SLIDER_WIDTH;
                 Frame::popup(ui.style()).show(ui, |ui| {
-                    if color_picker_hsva_2d(ui, hsva, alpha) {
+                    if color_picker_hsva_2d_with_palette(ui, hsva, alpha, palette) {
                         button_response.mark_changed();
                     }
                 });
@@ -503,6 +800,7 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
             .response;
 
         if !button_response.clicked()
+            && !eyedropper_active
             && (ui.input(|i| i.key_pressed(Key::Escape)) || area_response.clicked_elsewhere())
         {
             ui.memory_mut(|mem| mem.close_popup());
@@ -515,8 +813,19 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
 /// Shows a button with the given color.
 /// If the user clicks the button, a full color picker is shown.
 pub fn color_edit_button_srgba(ui: &mut Ui, srgba: &mut Color32, alpha: Alpha) -> Response {
+    color_edit_button_srgba_with_palette(ui, srgba, alpha, &[])
+}
+
+/// Like [`color_edit_button_srgba`], but the color picker also shows a row of swatches
+/// with the given colors, e.g. a set of recently used colors.
+pub fn color_edit_button_srgba_with_palette(
+    ui: &mut Ui,
+    srgba: &mut Color32,
+    alpha: Alpha,
+    palette: &[Color32],
+) -> Response {
     let mut hsva = color_cache_get(ui.ctx(), *srgba);
-    let response = color_edit_button_hsva(ui, &mut hsva, alpha);
+    let response = color_edit_button_hsva_with_palette(ui, &mut hsva, alpha, palette);
     *srgba = Color32::from(hsva);
     color_cache_set(ui.ctx(), *srgba, hsva);
     response
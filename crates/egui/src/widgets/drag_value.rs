@@ -470,7 +470,7 @@ impl<'a> Widget for DragValue<'a> {
                 // Make sure we applied the last text value:
                 let parsed_value = match &custom_parser {
                     Some(parser) => parser(&value_text),
-                    None => value_text.parse().ok(),
+                    None => default_parser(&value_text),
                 };
                 if let Some(parsed_value) = parsed_value {
                     let parsed_value = clamp_to_range(parsed_value, clamp_range.clone());
@@ -507,7 +507,7 @@ impl<'a> Widget for DragValue<'a> {
             if update {
                 let parsed_value = match &custom_parser {
                     Some(parser) => parser(&value_text),
-                    None => value_text.parse().ok(),
+                    None => default_parser(&value_text),
                 };
                 if let Some(parsed_value) = parsed_value {
                     let parsed_value = clamp_to_range(parsed_value, clamp_range.clone());
@@ -663,6 +663,145 @@ fn clamp_to_range(x: f64, range: RangeInclusive<f64>) -> f64 {
     }
 }
 
+/// Parses the text of a [`DragValue`] when no [`DragValue::custom_parser`] is set.
+///
+/// With the `math_expressions` feature, simple arithmetic like `1920/2` or `(45+15)*2` is also accepted.
+fn default_parser(text: &str) -> Option<f64> {
+    let text = text.trim();
+    if let Ok(value) = text.parse() {
+        return Some(value);
+    }
+
+    #[cfg(feature = "math_expressions")]
+    {
+        evaluate_expression(text)
+    }
+
+    #[cfg(not(feature = "math_expressions"))]
+    {
+        None
+    }
+}
+
+/// Evaluates an expression consisting of numbers, `+ - * /` and parentheses.
+///
+/// Returns `None` if the expression is malformed, nested too deeply,
+/// or if the result is not finite (e.g. `1/0`).
+#[cfg(feature = "math_expressions")]
+fn evaluate_expression(text: &str) -> Option<f64> {
+    /// How deeply parentheses and signs may be nested, so we don't overflow the stack.
+    const MAX_DEPTH: usize = 64;
+
+    struct Parser<'s> {
+        chars: std::iter::Peekable<std::str::Chars<'s>>,
+        depth: usize,
+    }
+
+    impl Parser<'_> {
+        fn peek(&mut self) -> Option<char> {
+            while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+            self.chars.peek().copied()
+        }
+
+        /// `expression = term (('+' | '-') term)*`
+        fn expression(&mut self) -> Option<f64> {
+            let mut value = self.term()?;
+            loop {
+                match self.peek() {
+                    Some('+') => {
+                        self.chars.next();
+                        value += self.term()?;
+                    }
+                    Some('-') => {
+                        self.chars.next();
+                        value -= self.term()?;
+                    }
+                    _ => return Some(value),
+                }
+            }
+        }
+
+        /// `term = factor (('*' | '/') factor)*`
+        fn term(&mut self) -> Option<f64> {
+            let mut value = self.factor()?;
+            loop {
+                match self.peek() {
+                    Some('*') => {
+                        self.chars.next();
+                        value *= self.factor()?;
+                    }
+                    Some('/') => {
+                        self.chars.next();
+                        value /= self.factor()?;
+                    }
+                    _ => return Some(value),
+                }
+            }
+        }
+
+        fn factor(&mut self) -> Option<f64> {
+            if MAX_DEPTH <= self.depth {
+                return None;
+            }
+            self.depth += 1;
+            let value = self.nested_factor();
+            self.depth -= 1;
+            value
+        }
+
+        /// `factor = ('+' | '-') factor | '(' expression ')' | number`
+        fn nested_factor(&mut self) -> Option<f64> {
+            match self.peek()? {
+                '+' => {
+                    self.chars.next();
+                    self.factor()
+                }
+                '-' => {
+                    self.chars.next();
+                    self.factor().map(|value| -value)
+                }
+                '(' => {
+                    self.chars.next();
+                    let value = self.expression()?;
+                    if self.peek()? != ')' {
+                        return None;
+                    }
+                    self.chars.next();
+                    Some(value)
+                }
+                _ => self.number(),
+            }
+        }
+
+        fn number(&mut self) -> Option<f64> {
+            let mut number = String::new();
+            while let Some(c) = self.chars.next_if(|c| c.is_ascii_digit() || *c == '.') {
+                number.push(c);
+            }
+            // Scientific notation, e.g. `1e-3`:
+            if !number.is_empty() {
+                if let Some(e) = self.chars.next_if(|c| *c == 'e' || *c == 'E') {
+                    number.push(e);
+                    if let Some(sign) = self.chars.next_if(|c| *c == '+' || *c == '-') {
+                        number.push(sign);
+                    }
+                    while let Some(c) = self.chars.next_if(|c| c.is_ascii_digit()) {
+                        number.push(c);
+                    }
+                }
+            }
+            number.parse().ok()
+        }
+    }
+
+    let mut parser = Parser {
+        chars: text.chars().peekable(),
+        depth: 0,
+    };
+    let value = parser.expression()?;
+    (parser.peek().is_none() && value.is_finite()).then_some(value)
+}
+
 #[cfg(test)]
 mod tests {
     use super::clamp_to_range;
@@ -691,4 +830,32 @@ mod tests {
         total_assert_eq!(5.0_f64, clamp_to_range(15.0, 5.0..=1.0));
         total_assert_eq!(1.0_f64, clamp_to_range(-5.0, 5.0..=1.0));
     }
+
+    #[cfg(feature = "math_expressions")]
+    #[test]
+    fn test_evaluate_expression() {
+        use super::evaluate_expression;
+
+        assert_eq!(evaluate_expression("1920/2"), Some(960.0));
+        assert_eq!(evaluate_expression("45+15"), Some(60.0));
+        assert_eq!(evaluate_expression(" 2 + 3 * 4 "), Some(14.0));
+        assert_eq!(evaluate_expression("(2 + 3) * 4"), Some(20.0));
+        assert_eq!(evaluate_expression("-(1.5 - 0.5)"), Some(-1.0));
+        assert_eq!(evaluate_expression("1e3 / 4"), Some(250.0));
+        assert_eq!(evaluate_expression("10 - 2 - 3"), Some(5.0));
+        assert_eq!(evaluate_expression("1 +"), None);
+        assert_eq!(evaluate_expression("(1 + 2"), None);
+        assert_eq!(evaluate_expression("2 x 3"), None);
+        assert_eq!(evaluate_expression(""), None);
+
+        // Not finite:
+        assert_eq!(evaluate_expression("1/0"), None);
+        assert_eq!(evaluate_expression("0/0"), None);
+
+        // Nested too deeply:
+        assert_eq!(evaluate_expression(&"(".repeat(100_000)), None);
+        assert_eq!(evaluate_expression(&"-".repeat(100_000)), None);
+        let nested = format!("{}1{}", "(".repeat(50), ")".repeat(50));
+        assert_eq!(evaluate_expression(&nested), Some(1.0));
+    }
 }
@@ -241,10 +241,31 @@ impl<'a> Image<'a> {
         self.image_options.rounding = rounding.into();
         if self.image_options.rounding != Rounding::ZERO {
             self.image_options.rotation = None; // incompatible with rounding
+            self.image_options.nine_slice = None; // incompatible with rounding
         }
         self
     }
 
+    /// Scale the image with nine-slice scaling (also known as nine-patch).
+    ///
+    /// The `margins` cut the texture into a 3x3 grid.
+    /// The four corners keep their size, the edges are only stretched along their length,
+    /// and the center is stretched in both directions.
+    /// This is useful for e.g. skinned button and panel backgrounds.
+    ///
+    /// The `margins` are in the same unit as the size of the texture (usually pixels),
+    /// and the corners are shown with one texture unit per point.
+    /// If the image is too small for the corners, they are scaled down to fit.
+    ///
+    /// Due to limitations in the current implementation,
+    /// this will turn off rounding of the image.
+    #[inline]
+    pub fn nine_slice(mut self, margins: impl Into<Margin>) -> Self {
+        self.image_options.nine_slice = Some(margins.into());
+        self.image_options.rounding = Rounding::ZERO; // incompatible with nine-slice scaling
+        self
+    }
+
     /// Show a spinner when the image is loading.
     ///
     /// By default this uses the value of [`Visuals::image_loading_spinners`].
@@ -688,6 +709,10 @@ impl<T: Into<SizedTexture>> From<T> for ImageSource<'static> {
     }
 }
 
+/// How an image is painted.
+///
+/// New options are added here from time to time,
+/// so construct it with `..Default::default()` to stay compatible.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct ImageOptions {
@@ -718,6 +743,11 @@ pub struct ImageOptions {
     /// Due to limitations in the current implementation,
     /// this will turn off any rotation of the image.
     pub rounding: Rounding,
+
+    /// Scale the image with nine-slice scaling, with these margins in texture units.
+    ///
+    /// See [`Image::nine_slice`].
+    pub nine_slice: Option<Margin>,
 }
 
 impl Default for ImageOptions {
@@ -728,6 +758,7 @@ impl Default for ImageOptions {
             tint: Color32::WHITE,
             rotation: None,
             rounding: Rounding::ZERO,
+            nine_slice: None,
         }
     }
 }
@@ -742,6 +773,23 @@ pub fn paint_texture_at(
         painter.add(RectShape::filled(rect, options.rounding, options.bg_fill));
     }
 
+    if let Some(margins) = options.nine_slice {
+        debug_assert!(
+            options.rounding == Rounding::ZERO,
+            "Image had both rounding and nine-slice scaling. Please pick only one"
+        );
+
+        let mut mesh = Mesh::with_texture(texture.id);
+        let (inner_rect, inner_uv) =
+            nine_slice_inner_rects(rect, options.uv, margins, texture.size);
+        mesh.add_rect_nine_slice(rect, inner_rect, options.uv, inner_uv, options.tint);
+        if let Some((rot, origin)) = options.rotation {
+            mesh.rotate(rot, rect.min + origin * rect.size());
+        }
+        painter.add(Shape::mesh(mesh));
+        return;
+    }
+
     match options.rotation {
         Some((rot, origin)) => {
             // TODO(emilk): implement this using `PathShape` (add texture support to it).
@@ -769,3 +817,30 @@ pub fn paint_texture_at(
         }
     }
 }
+
+/// The stretched center of a nine-slice scaled image, on screen and in the texture.
+fn nine_slice_inner_rects(
+    rect: Rect,
+    uv: Rect,
+    margins: Margin,
+    texture_size: Vec2,
+) -> (Rect, Rect) {
+    // Shrink the corners if they don't fit:
+    let corners = margins.sum();
+    let scale = vec2(
+        (rect.width() / corners.x).min(1.0),
+        (rect.height() / corners.y).min(1.0),
+    );
+    let inner_rect = Rect::from_min_max(
+        rect.min + vec2(margins.left, margins.top) * scale,
+        rect.max - vec2(margins.right, margins.bottom) * scale,
+    );
+
+    let uv_per_texel = uv.size() / texture_size.max(Vec2::splat(1.0));
+    let inner_uv = Rect::from_min_max(
+        uv.min + vec2(margins.left, margins.top) * uv_per_texel,
+        uv.max - vec2(margins.right, margins.bottom) * uv_per_texel,
+    );
+
+    (inner_rect, inner_uv)
+}
@@ -1,8 +1,8 @@
-use std::sync::Arc;
+use std::{ops::Range, sync::Arc};
 
 use crate::*;
 
-use self::text_selection::LabelSelectionState;
+use self::text_selection::{visuals::cursor_range_rects, CursorRange, LabelSelectionState};
 
 /// Static text.
 ///
@@ -22,20 +22,22 @@ use self::text_selection::LabelSelectionState;
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Label {
     text: WidgetText,
-    wrap: Option<bool>,
-    truncate: bool,
+    wrap_mode: Option<TextWrapMode>,
     sense: Option<Sense>,
     selectable: Option<bool>,
+    detect_links: bool,
+    angle: f32,
 }
 
 impl Label {
     pub fn new(text: impl Into<WidgetText>) -> Self {
         Self {
             text: text.into(),
-            wrap: None,
-            truncate: false,
+            wrap_mode: None,
             sense: None,
             selectable: None,
+            detect_links: false,
+            angle: 0.0,
         }
     }
 
@@ -56,8 +58,7 @@ impl Label {
     /// You can also use [`crate::Style::wrap`].
     #[inline]
     pub fn wrap(mut self, wrap: bool) -> Self {
-        self.wrap = Some(wrap);
-        self.truncate = false;
+        self.wrap_mode = Some(TextWrapMode::from_wrap(wrap));
         self
     }
 
@@ -72,8 +73,16 @@ impl Label {
     /// Calling `truncate` will override [`Self::wrap`].
     #[inline]
     pub fn truncate(mut self, truncate: bool) -> Self {
-        self.wrap = None;
-        self.truncate = truncate;
+        self.wrap_mode = truncate.then_some(TextWrapMode::Truncate);
+        self
+    }
+
+    /// Set what to do with text that doesn't fit, overriding [`Ui::wrap_mode`].
+    ///
+    /// This replaces any earlier call to [`Self::wrap`] or [`Self::truncate`].
+    #[inline]
+    pub fn wrap_mode(mut self, wrap_mode: TextWrapMode) -> Self {
+        self.wrap_mode = Some(wrap_mode);
         self
     }
 
@@ -86,6 +95,27 @@ impl Label {
         self
     }
 
+    /// Turn URLs in the text (`http://…` and `https://…`) into clickable hyperlinks.
+    ///
+    /// Combined with [`Self::selectable`] this is great for log viewers and chat messages.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add(
+    ///     egui::Label::new("Docs are at https://docs.rs/egui, or ask on https://discord.gg/JFcEma9bJq")
+    ///         .selectable(true)
+    ///         .detect_links(true),
+    /// );
+    /// # });
+    /// ```
+    ///
+    /// Links are not detected in a [`WidgetText::Galley`], but they can still be clicked.
+    #[inline]
+    pub fn detect_links(mut self, detect_links: bool) -> Self {
+        self.detect_links = detect_links;
+        self
+    }
+
     /// Make the label respond to clicks and/or drags.
     ///
     /// By default, a label is inert and does not respond to click or drags.
@@ -105,6 +135,26 @@ impl Label {
         self.sense = Some(sense);
         self
     }
+
+    /// Rotate the text by this many radians clockwise, e.g. for compact column headers or y-axis labels.
+    ///
+    /// The label takes up the bounding box of the rotated text,
+    /// so for multiples of 90° it responds to the pointer exactly where the text is.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// // Reading from bottom to top:
+    /// ui.add(egui::Label::new("Temperature").angle(-std::f32::consts::TAU / 4.0));
+    /// # });
+    /// ```
+    ///
+    /// Rotated text is never wrapped, and can't be selected.
+    /// [`Self::layout_in_ui`] ignores the angle.
+    #[inline]
+    pub fn angle(mut self, angle: f32) -> Self {
+        self.angle = angle;
+        self
+    }
 }
 
 impl Label {
@@ -140,6 +190,12 @@ impl Label {
             sense = sense.union(select_sense);
         }
 
+        if self.detect_links {
+            let mut click_sense = Sense::click();
+            click_sense.focusable = false;
+            sense = sense.union(click_sense);
+        }
+
         if let WidgetText::Galley(galley) = self.text {
             // If the user said "use this specific galley", then just use it:
             let (rect, response) = ui.allocate_exact_size(galley.size(), sense);
@@ -156,11 +212,15 @@ impl Label {
             .text
             .into_layout_job(ui.style(), FontSelection::Default, valign);
 
-        let truncate = self.truncate;
-        let wrap = !truncate && self.wrap.unwrap_or_else(|| ui.wrap_text());
+        if self.detect_links {
+            let links = find_urls(&layout_job.text);
+            style_links(&mut layout_job, &links, ui.visuals().hyperlink_color);
+        }
+
+        let wrap_mode = self.wrap_mode.unwrap_or_else(|| ui.wrap_mode());
         let available_width = ui.available_width();
 
-        if wrap
+        if wrap_mode == TextWrapMode::Wrap
             && ui.layout().main_dir() == Direction::LeftToRight
             && ui.layout().main_wrap()
             && available_width.is_finite()
@@ -192,15 +252,7 @@ impl Label {
             }
             (pos, galley, response)
         } else {
-            if truncate {
-                layout_job.wrap.max_width = available_width;
-                layout_job.wrap.max_rows = 1;
-                layout_job.wrap.break_anywhere = true;
-            } else if wrap {
-                layout_job.wrap.max_width = available_width;
-            } else {
-                layout_job.wrap.max_width = f32::INFINITY;
-            };
+            wrap_mode.apply(&mut layout_job.wrap, available_width);
 
             if ui.is_grid() {
                 // TODO(emilk): remove special Grid hacks like these
@@ -223,18 +275,91 @@ impl Label {
     }
 }
 
+impl Label {
+    /// Layout, allocate and paint a label with a non-zero [`Self::angle`].
+    fn rotated_ui(self, ui: &mut Ui) -> Response {
+        let interactive = self.sense.map_or(false, |sense| sense != Sense::hover());
+        let sense = self.sense.unwrap_or_else(Sense::hover);
+
+        let galley = self.text.into_galley_with_wrap_mode(
+            ui,
+            Some(TextWrapMode::Extend),
+            f32::INFINITY,
+            FontSelection::Default,
+        );
+
+        // The text is rotated around its top left corner:
+        let rot = emath::Rot2::from_angle(self.angle);
+        let corners = [
+            galley.rect.left_top(),
+            galley.rect.right_top(),
+            galley.rect.left_bottom(),
+            galley.rect.right_bottom(),
+        ]
+        .map(|corner| Pos2::ZERO + rot * corner.to_vec2());
+        let bounds = Rect::from_points(&corners);
+
+        let (rect, response) = ui.allocate_exact_size(bounds.size(), sense);
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, galley.text()));
+
+        if ui.is_rect_visible(response.rect) {
+            let color = if interactive {
+                ui.style().interact(&response).text_color()
+            } else {
+                ui.style().visuals.text_color()
+            };
+            let pos = rect.min - bounds.min.to_vec2();
+            ui.painter()
+                .add(epaint::TextShape::new(pos, galley, color).with_angle(self.angle));
+        }
+
+        response
+    }
+}
+
 impl Widget for Label {
     fn ui(self, ui: &mut Ui) -> Response {
+        if self.angle != 0.0 {
+            return self.rotated_ui(ui);
+        }
+
         // Interactive = the uses asked to sense interaction.
         // We DON'T want to have the color respond just because the text is selectable;
         // the cursor is enough to communicate that.
         let interactive = self.sense.map_or(false, |sense| sense != Sense::hover());
 
         let selectable = self.selectable;
+        let detect_links = self.detect_links;
 
         let (galley_pos, galley, mut response) = self.layout_in_ui(ui);
         response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, galley.text()));
 
+        if detect_links {
+            if let Some(pointer_pos) = response.hover_pos() {
+                let text = galley.text();
+                let hovered_link = find_urls(text).into_iter().find(|link| {
+                    CursorRange::from_byte_range(text, &galley, link.clone()).map_or(
+                        false,
+                        |range| {
+                            cursor_range_rects(galley_pos, &galley, &range)
+                                .any(|rect| rect.contains(pointer_pos))
+                        },
+                    )
+                });
+                if let Some(link) = hovered_link {
+                    ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+                    let url = text[link].to_owned();
+                    if response.clicked() || response.middle_clicked() {
+                        let modifiers = ui.ctx().input(|i| i.modifiers);
+                        ui.ctx().open_url(crate::OpenUrl {
+                            url,
+                            new_tab: response.middle_clicked() || modifiers.any(),
+                        });
+                    }
+                }
+            }
+        }
+
         if ui.is_rect_visible(response.rect) {
             if galley.elided {
                 // Show the full (non-elided) text on hover:
@@ -267,3 +392,114 @@ impl Widget for Label {
         response
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// Find the byte ranges of all `http://` and `https://` URLs in `text`.
+fn find_urls(text: &str) -> Vec<Range<usize>> {
+    let mut urls = vec![];
+    let mut search_start = 0;
+    while let Some(offset) = text[search_start..].find("http") {
+        let start = search_start + offset;
+        let rest = &text[start..];
+        let scheme_len = if rest.starts_with("https://") {
+            "https://".len()
+        } else if rest.starts_with("http://") {
+            "http://".len()
+        } else {
+            search_start = start + "http".len();
+            continue;
+        };
+
+        // Don't match in the middle of a word, like `xhttp://`:
+        let at_word_start = text[..start]
+            .chars()
+            .next_back()
+            .map_or(true, |c| !c.is_alphanumeric());
+
+        let len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"'))
+            .unwrap_or(rest.len());
+        // Punctuation at the end is most likely part of the sentence, not the URL:
+        let url = rest[..len].trim_end_matches(|c| {
+            matches!(c, '.' | ',' | ':' | ';' | '!' | '?' | ')' | ']' | '\'')
+        });
+        let end = start + url.len();
+
+        if at_word_start && url.len() > scheme_len {
+            urls.push(start..end);
+        }
+        search_start = end.max(start + scheme_len);
+    }
+    urls
+}
+
+/// Color and underline the `links` in the text of `job`.
+fn style_links(job: &mut text::LayoutJob, links: &[Range<usize>], color: Color32) {
+    if links.is_empty() {
+        return;
+    }
+
+    let mut sections = Vec::with_capacity(job.sections.len() + 2 * links.len());
+    for section in std::mem::take(&mut job.sections) {
+        let range = section.byte_range.clone();
+
+        // Split the section where links start or end:
+        let mut cuts: Vec<usize> = links
+            .iter()
+            .flat_map(|link| [link.start, link.end])
+            .filter(|&i| range.start < i && i < range.end)
+            .collect();
+        cuts.sort_unstable();
+        cuts.dedup();
+
+        let mut start = range.start;
+        for end in cuts.into_iter().chain(std::iter::once(range.end)) {
+            let mut format = section.format.clone();
+            if links
+                .iter()
+                .any(|link| link.start <= start && end <= link.end)
+            {
+                format.color = color;
+                format.underline = Stroke::new(1.0, color);
+            }
+            sections.push(text::LayoutSection {
+                leading_space: if start == range.start {
+                    section.leading_space
+                } else {
+                    0.0
+                },
+                byte_range: start..end,
+                format,
+            });
+            start = end;
+        }
+    }
+    job.sections = sections;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_urls;
+
+    #[test]
+    fn test_find_urls() {
+        let urls = |text: &str| -> Vec<String> {
+            find_urls(text)
+                .into_iter()
+                .map(|range| text[range].to_owned())
+                .collect()
+        };
+
+        assert_eq!(urls("no links here"), Vec::<String>::new());
+        assert_eq!(urls("https://example.com"), vec!["https://example.com"]);
+        assert_eq!(
+            urls("See https://example.com/a?b=c, or http://x.org."),
+            vec!["https://example.com/a?b=c", "http://x.org"]
+        );
+        assert_eq!(urls("(https://example.com)"), vec!["https://example.com"]);
+        assert_eq!(urls("http:// https://"), Vec::<String>::new());
+        assert_eq!(urls("xhttps://example.com"), Vec::<String>::new());
+        assert_eq!(urls("httpx https://ö.se"), vec!["https://ö.se"]);
+    }
+}
@@ -16,11 +16,14 @@ mod image_button;
 mod label;
 mod progress_bar;
 mod radio_button;
+mod range_slider;
+mod segmented_button;
 mod selected_label;
 mod separator;
 mod slider;
 mod spinner;
 pub mod text_edit;
+mod toggle_switch;
 
 pub use self::{
     button::Button,
@@ -32,11 +35,14 @@ pub use self::{
     label::Label,
     progress_bar::ProgressBar,
     radio_button::RadioButton,
+    range_slider::RangeSlider,
+    segmented_button::SegmentedButton,
     selected_label::SelectableLabel,
     separator::Separator,
     slider::{Slider, SliderOrientation},
     spinner::Spinner,
     text_edit::{TextBuffer, TextEdit},
+    toggle_switch::ToggleSwitch,
 };
 
 // ----------------------------------------------------------------------------
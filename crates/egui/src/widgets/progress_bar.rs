@@ -3,6 +3,7 @@ use crate::*;
 enum ProgressBarText {
     Custom(WidgetText),
     Percentage,
+    Fraction { current: u64, total: u64 },
 }
 
 /// A simple progress bar.
@@ -10,10 +11,12 @@ enum ProgressBarText {
 /// See also: [`crate::Spinner`].
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct ProgressBar {
-    progress: f32,
+    /// `None` means indeterminate.
+    progress: Option<f32>,
     desired_width: Option<f32>,
     desired_height: Option<f32>,
     text: Option<ProgressBarText>,
+    text_align: Align,
     fill: Option<Color32>,
     animate: bool,
     rounding: Option<Rounding>,
@@ -23,16 +26,34 @@ impl ProgressBar {
     /// Progress in the `[0, 1]` range, where `1` means "completed".
     pub fn new(progress: f32) -> Self {
         Self {
-            progress: progress.clamp(0.0, 1.0),
+            progress: Some(progress.clamp(0.0, 1.0)),
             desired_width: None,
             desired_height: None,
             text: None,
+            text_align: Align::Min,
             fill: None,
             animate: false,
             rounding: None,
         }
     }
 
+    /// A progress bar for when the total amount of work is unknown.
+    ///
+    /// Instead of a filled portion, a block slides back and forth along the bar (a "marquee").
+    /// This will cause the UI to be redrawn continuously.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add(egui::ProgressBar::indeterminate().text("Connecting…"));
+    /// # });
+    /// ```
+    pub fn indeterminate() -> Self {
+        Self {
+            progress: None,
+            ..Self::new(0.0)
+        }
+    }
+
     /// The desired width of the bar. Will use all horizontal space if not set.
     #[inline]
     pub fn desired_width(mut self, desired_width: f32) -> Self {
@@ -62,12 +83,37 @@ impl ProgressBar {
     }
 
     /// Show the progress in percent on the progress bar.
+    ///
+    /// Nothing is shown for an [`Self::indeterminate`] progress bar.
     #[inline]
     pub fn show_percentage(mut self) -> Self {
         self.text = Some(ProgressBarText::Percentage);
         self
     }
 
+    /// Show the progress as `current/total` on the progress bar, e.g. `3/10`.
+    ///
+    /// This only affects the text, the progress passed to [`Self::new`] still decides how much of the bar is filled.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let (done, total) = (3, 10);
+    /// ui.add(egui::ProgressBar::new(done as f32 / total as f32).show_fraction(done, total));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn show_fraction(mut self, current: u64, total: u64) -> Self {
+        self.text = Some(ProgressBarText::Fraction { current, total });
+        self
+    }
+
+    /// Where to put the text on the progress bar. Default: [`Align::LEFT`].
+    #[inline]
+    pub fn text_align(mut self, text_align: Align) -> Self {
+        self.text_align = text_align;
+        self
+    }
+
     /// Whether to display a loading animation when progress `< 1`.
     /// Note that this will cause the UI to be redrawn.
     /// Defaults to `false`.
@@ -100,12 +146,13 @@ impl Widget for ProgressBar {
             desired_width,
             desired_height,
             text,
+            text_align,
             fill,
             animate,
             rounding,
         } = self;
 
-        let animate = animate && progress < 1.0;
+        let animate = animate && progress.map_or(false, |progress| progress < 1.0);
 
         let desired_width =
             desired_width.unwrap_or_else(|| ui.available_size_before_wrap().x.at_least(96.0));
@@ -119,13 +166,13 @@ impl Widget for ProgressBar {
             } else {
                 WidgetInfo::new(WidgetType::ProgressIndicator)
             };
-            info.value = Some((progress as f64 * 100.0).floor());
+            info.value = progress.map(|progress| (progress as f64 * 100.0).floor());
 
             info
         });
 
         if ui.is_rect_visible(response.rect) {
-            if animate {
+            if animate || progress.is_none() {
                 ui.ctx().request_repaint();
             }
 
@@ -136,9 +183,19 @@ impl Widget for ProgressBar {
             ui.painter()
                 .rect(outer_rect, rounding, visuals.extreme_bg_color, Stroke::NONE);
             let min_width = 2.0 * rounding.sw.at_least(rounding.nw).at_most(corner_radius);
-            let filled_width = (outer_rect.width() * progress).at_least(min_width);
-            let inner_rect =
-                Rect::from_min_size(outer_rect.min, vec2(filled_width, outer_rect.height()));
+            let inner_rect = if let Some(progress) = progress {
+                let filled_width = (outer_rect.width() * progress).at_least(min_width);
+                Rect::from_min_size(outer_rect.min, vec2(filled_width, outer_rect.height()))
+            } else {
+                // Marquee: a block sliding from left to right, entering and leaving at the edges.
+                let block_width = (0.3 * outer_rect.width()).at_least(min_width);
+                let period = 1.5; // seconds
+                let t = (ui.input(|i| i.time) / period).fract() as f32;
+                let left = lerp((outer_rect.left() - block_width)..=outer_rect.right(), t);
+                let x_range =
+                    Rangef::new(left, left + block_width).intersection(outer_rect.x_range());
+                Rect::from_x_y_ranges(x_range, outer_rect.y_range())
+            };
 
             let (dark, bright) = (0.7, 1.0);
             let color_factor = if animate {
@@ -148,14 +205,16 @@ impl Widget for ProgressBar {
                 bright
             };
 
-            ui.painter().rect(
-                inner_rect,
-                rounding,
-                Color32::from(
-                    Rgba::from(fill.unwrap_or(visuals.selection.bg_fill)) * color_factor as f32,
-                ),
-                Stroke::NONE,
-            );
+            if inner_rect.is_positive() {
+                ui.painter().rect(
+                    inner_rect,
+                    rounding,
+                    Color32::from(
+                        Rgba::from(fill.unwrap_or(visuals.selection.bg_fill)) * color_factor as f32,
+                    ),
+                    Stroke::NONE,
+                );
+            }
 
             if animate && !is_custom_rounding {
                 let n_points = 20;
@@ -176,16 +235,24 @@ impl Widget for ProgressBar {
                     .add(Shape::line(points, Stroke::new(2.0, visuals.text_color())));
             }
 
-            if let Some(text_kind) = text {
-                let text = match text_kind {
-                    ProgressBarText::Custom(text) => text,
-                    ProgressBarText::Percentage => {
-                        format!("{}%", (progress * 100.0) as usize).into()
-                    }
-                };
+            let text = text.and_then(|text_kind| match text_kind {
+                ProgressBarText::Custom(text) => Some(text),
+                ProgressBarText::Percentage => {
+                    progress.map(|progress| format!("{}%", (progress * 100.0) as usize).into())
+                }
+                ProgressBarText::Fraction { current, total } => {
+                    Some(format!("{current}/{total}").into())
+                }
+            });
+            if let Some(text) = text {
                 let galley = text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button);
-                let text_pos = outer_rect.left_center() - Vec2::new(0.0, galley.size().y / 2.0)
-                    + vec2(ui.spacing().item_spacing.x, 0.0);
+                let text_rect = outer_rect.shrink2(vec2(ui.spacing().item_spacing.x, 0.0));
+                let text_pos = pos2(
+                    text_align
+                        .align_size_within_range(galley.size().x, text_rect.x_range())
+                        .min,
+                    outer_rect.center().y - galley.size().y / 2.0,
+                );
                 let text_color = visuals
                     .override_text_color
                     .unwrap_or(visuals.selection.stroke.color);
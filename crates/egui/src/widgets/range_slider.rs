@@ -0,0 +1,408 @@
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use std::ops::RangeInclusive;
+
+use crate::*;
+
+use super::slider::{normalized_from_value, value_from_normalized, NumFormatter, SliderSpec};
+
+// ----------------------------------------------------------------------------
+
+/// Which part of a [`RangeSlider`] is being dragged.
+#[derive(Clone, Copy, Debug)]
+enum DraggedPart {
+    Start,
+    End,
+
+    /// The segment between the handles, which moves both of them.
+    Middle {
+        /// Pointer position minus the position of the start handle, when the drag started.
+        grab_offset: f32,
+
+        /// Position of the end handle minus the position of the start handle.
+        span: f32,
+    },
+}
+
+/// Control a range of numbers with a slider with two handles.
+///
+/// Drag a handle to move that end of the range,
+/// or drag the segment between the handles to move the whole range.
+/// The start of the range can never pass the end.
+///
+/// Like [`Slider`], it supports logarithmic scales, steps and custom formatting of the values,
+/// which are shown in two [`DragValue`]s next to the slider.
+/// Unlike [`Slider`], the values are always clamped to the slider range,
+/// and the slider is always horizontal.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let mut price = 10.0..=50.0;
+/// ui.add(egui::RangeSlider::new(&mut price, 0.0..=100.0).text("Price"));
+/// # });
+/// ```
+///
+/// The default [`RangeSlider`] size is set by [`crate::style::Spacing::slider_width`].
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct RangeSlider<'a> {
+    value: &'a mut RangeInclusive<f64>,
+    range: RangeInclusive<f64>,
+    spec: SliderSpec,
+    show_value: bool,
+    prefix: String,
+    suffix: String,
+    text: WidgetText,
+
+    /// Sets the minimal step of the widget values
+    step: Option<f64>,
+
+    min_decimals: usize,
+    max_decimals: Option<usize>,
+    custom_formatter: Option<NumFormatter<'a>>,
+}
+
+impl<'a> RangeSlider<'a> {
+    /// Creates a new range slider, where `value` can be anywhere within `range`.
+    ///
+    /// `range` should go from low to high.
+    pub fn new(value: &'a mut RangeInclusive<f64>, range: RangeInclusive<f64>) -> Self {
+        Self {
+            value,
+            range,
+            spec: SliderSpec {
+                logarithmic: false,
+                smallest_positive: 1e-6,
+                largest_finite: f64::INFINITY,
+            },
+            show_value: true,
+            prefix: Default::default(),
+            suffix: Default::default(),
+            text: Default::default(),
+            step: None,
+            min_decimals: 0,
+            max_decimals: None,
+            custom_formatter: None,
+        }
+    }
+
+    /// Control whether or not the slider shows the current values.
+    /// Default: `true`.
+    #[inline]
+    pub fn show_value(mut self, show_value: bool) -> Self {
+        self.show_value = show_value;
+        self
+    }
+
+    /// Show a prefix before the numbers, e.g. "x: "
+    #[inline]
+    pub fn prefix(mut self, prefix: impl ToString) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Add a suffix to the numbers, for instance a unit: "°" or " m"
+    #[inline]
+    pub fn suffix(mut self, suffix: impl ToString) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Show a text next to the slider (e.g. explaining what the slider controls).
+    #[inline]
+    pub fn text(mut self, text: impl Into<WidgetText>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    /// Make this a logarithmic slider.
+    /// This is great for when the slider spans a huge range,
+    /// e.g. from one to a million.
+    /// The default is OFF.
+    #[inline]
+    pub fn logarithmic(mut self, logarithmic: bool) -> Self {
+        self.spec.logarithmic = logarithmic;
+        self
+    }
+
+    /// For logarithmic sliders that includes zero:
+    /// what is the smallest positive value you want to be able to select?
+    /// The default is `1` for integer sliders and `1e-6` for real sliders.
+    #[inline]
+    pub fn smallest_positive(mut self, smallest_positive: f64) -> Self {
+        self.spec.smallest_positive = smallest_positive;
+        self
+    }
+
+    /// For logarithmic sliders, the largest positive value we are interested in
+    /// before the slider switches to `INFINITY`, if that is the higher end.
+    /// Default: INFINITY.
+    #[inline]
+    pub fn largest_finite(mut self, largest_finite: f64) -> Self {
+        self.spec.largest_finite = largest_finite;
+        self
+    }
+
+    /// Sets the minimal change of the values.
+    /// Value `0.0` effectively disables the feature.
+    ///
+    /// Default: `0.0` (disabled).
+    #[inline]
+    pub fn step_by(mut self, step: f64) -> Self {
+        self.step = if step != 0.0 { Some(step) } else { None };
+        self
+    }
+
+    /// Set a minimum number of decimals to display.
+    /// Normally you don't need to pick a precision, as the slider will intelligently pick a precision for you.
+    #[inline]
+    pub fn min_decimals(mut self, min_decimals: usize) -> Self {
+        self.min_decimals = min_decimals;
+        self
+    }
+
+    /// Set a maximum number of decimals to display.
+    /// Values will also be rounded to this number of decimals.
+    /// Normally you don't need to pick a precision, as the slider will intelligently pick a precision for you.
+    #[inline]
+    pub fn max_decimals(mut self, max_decimals: usize) -> Self {
+        self.max_decimals = Some(max_decimals);
+        self
+    }
+
+    /// Set an exact number of decimals to display.
+    /// Values will also be rounded to this number of decimals.
+    /// Normally you don't need to pick a precision, as the slider will intelligently pick a precision for you.
+    #[inline]
+    pub fn fixed_decimals(mut self, num_decimals: usize) -> Self {
+        self.min_decimals = num_decimals;
+        self.max_decimals = Some(num_decimals);
+        self
+    }
+
+    /// Set custom formatter defining how numbers are converted into text.
+    ///
+    /// See [`Slider::custom_formatter`].
+    pub fn custom_formatter(
+        mut self,
+        formatter: impl 'a + Fn(f64, RangeInclusive<usize>) -> String,
+    ) -> Self {
+        self.custom_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Helper: equivalent to `self.fixed_decimals(0).smallest_positive(1.0).step_by(1.0)`.
+    pub fn integer(self) -> Self {
+        self.fixed_decimals(0).smallest_positive(1.0).step_by(1.0)
+    }
+
+    /// Clamp to the slider range, and round to the decimals and step.
+    fn round_value(&self, mut value: f64) -> f64 {
+        let start = *self.range.start();
+        let end = *self.range.end();
+        value = value.clamp(start.min(end), start.max(end));
+        if let Some(max_decimals) = self.max_decimals {
+            value = emath::round_to_decimals(value, max_decimals);
+        }
+        if let Some(step) = self.step {
+            value = start + ((value - start) / step).round() * step;
+        }
+        value
+    }
+
+    fn set_value(&mut self, start: f64, end: f64) {
+        let start = self.round_value(start);
+        let end = self.round_value(end).max(start);
+        *self.value = start..=end;
+    }
+
+    fn value_from_position(&self, position: f32, position_range: Rangef) -> f64 {
+        let normalized = remap_clamp(position, position_range, 0.0..=1.0) as f64;
+        value_from_normalized(normalized, self.range.clone(), &self.spec)
+    }
+
+    fn position_from_value(&self, value: f64, position_range: Rangef) -> f32 {
+        let normalized = normalized_from_value(value, self.range.clone(), &self.spec);
+        lerp(position_range, normalized as f32)
+    }
+}
+
+impl<'a> RangeSlider<'a> {
+    /// Just the slider, no text
+    fn slider_ui(&mut self, ui: &Ui, response: &Response) {
+        let rect = response.rect;
+        let handle_radius = handle_radius(rect);
+        let position_range = position_range(rect);
+
+        let start_position = self.position_from_value(*self.value.start(), position_range);
+        let end_position = self.position_from_value(*self.value.end(), position_range);
+
+        if let Some(pointer) = response.interact_pointer_pos() {
+            // Pick what to drag when the pointer is pressed, and stick to it:
+            let dragged_part = ui
+                .data(|d| d.get_temp::<DraggedPart>(response.id))
+                .unwrap_or_else(|| {
+                    pick_part(pointer.x, start_position, end_position, handle_radius)
+                });
+            ui.data_mut(|d| d.insert_temp(response.id, dragged_part));
+
+            match dragged_part {
+                DraggedPart::Start => {
+                    let start = self.value_from_position(pointer.x, position_range);
+                    let end = *self.value.end();
+                    self.set_value(start.min(end), end);
+                }
+                DraggedPart::End => {
+                    let start = *self.value.start();
+                    let end = self.value_from_position(pointer.x, position_range);
+                    self.set_value(start, end.max(start));
+                }
+                DraggedPart::Middle { grab_offset, span } => {
+                    let start_position = (pointer.x - grab_offset)
+                        .at_most(position_range.max - span)
+                        .at_least(position_range.min);
+                    let start = self.value_from_position(start_position, position_range);
+                    let end = self.value_from_position(start_position + span, position_range);
+                    self.set_value(start, end);
+                }
+            }
+        } else {
+            ui.data_mut(|d| d.remove::<DraggedPart>(response.id));
+        }
+
+        // Paint it:
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact(response);
+            let widget_visuals = &ui.visuals().widgets;
+
+            let rail_radius = (ui.spacing().slider_rail_height / 2.0).at_least(0.0);
+            let rail_rect = Rect::from_x_y_ranges(
+                rect.x_range(),
+                Rangef::new(rect.center().y - rail_radius, rect.center().y + rail_radius),
+            );
+            let rounding = widget_visuals.inactive.rounding;
+            ui.painter()
+                .rect_filled(rail_rect, rounding, widget_visuals.inactive.bg_fill);
+
+            let start_position = self.position_from_value(*self.value.start(), position_range);
+            let end_position = self.position_from_value(*self.value.end(), position_range);
+
+            let mut selected_rail_rect = rail_rect;
+            selected_rail_rect.min.x = start_position;
+            selected_rail_rect.max.x = end_position;
+            ui.painter()
+                .rect_filled(selected_rail_rect, rounding, ui.visuals().selection.bg_fill);
+
+            for position in [start_position, end_position] {
+                ui.painter().add(epaint::CircleShape {
+                    center: pos2(position, rail_rect.center().y),
+                    radius: handle_radius + visuals.expansion,
+                    fill: visuals.bg_fill,
+                    stroke: visuals.fg_stroke,
+                });
+            }
+        }
+    }
+
+    fn value_ui(&mut self, ui: &mut Ui, position_range: Rangef) -> Response {
+        let (mut start, mut end) = (*self.value.start(), *self.value.end());
+        let range_start = self.range.start().min(*self.range.end());
+        let range_end = self.range.start().max(*self.range.end());
+
+        let start_speed = self.drag_value_speed(start, position_range);
+        let end_speed = self.drag_value_speed(end, position_range);
+        let start_response = ui.add(self.drag_value(&mut start, start_speed, range_start..=end));
+        let end_response = ui.add(self.drag_value(&mut end, end_speed, start..=range_end));
+
+        if start != *self.value.start() || end != *self.value.end() {
+            self.set_value(start, end);
+        }
+        start_response.union(end_response)
+    }
+
+    /// delta(value) / delta(points) at `value`, or the step if any.
+    fn drag_value_speed(&self, value: f64, position_range: Rangef) -> f64 {
+        if let Some(step) = self.step {
+            return step;
+        }
+        let position = self.position_from_value(value, position_range);
+        self.value_from_position(position + 0.5, position_range)
+            - self.value_from_position(position - 0.5, position_range)
+    }
+
+    fn drag_value<'v>(
+        &'v self,
+        value: &'v mut f64,
+        speed: f64,
+        clamp_range: RangeInclusive<f64>,
+    ) -> DragValue<'v> {
+        let mut dv = DragValue::new(value)
+            .speed(speed)
+            .clamp_range(clamp_range)
+            .min_decimals(self.min_decimals)
+            .max_decimals_opt(self.max_decimals)
+            .suffix(self.suffix.clone())
+            .prefix(self.prefix.clone());
+        if let Some(fmt) = &self.custom_formatter {
+            dv = dv.custom_formatter(fmt);
+        }
+        dv
+    }
+
+    fn add_contents(&mut self, ui: &mut Ui) -> Response {
+        let old_value = self.value.clone();
+
+        let thickness = ui
+            .text_style_height(&TextStyle::Body)
+            .at_least(ui.spacing().interact_size.y);
+        let desired_size = vec2(ui.spacing().slider_width, thickness);
+        let mut response = ui.allocate_response(desired_size, Sense::click_and_drag());
+        self.slider_ui(ui, &response);
+
+        if self.show_value {
+            let position_range = position_range(response.rect);
+            response = response.union(self.value_ui(ui, position_range));
+        }
+
+        if !self.text.is_empty() {
+            ui.add(Label::new(self.text.clone()).wrap(false));
+        }
+
+        response.changed = *self.value != old_value;
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Slider, self.text.text()));
+        response
+    }
+}
+
+impl<'a> Widget for RangeSlider<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let inner_response = ui.horizontal(|ui| self.add_contents(ui));
+        inner_response.inner | inner_response.response
+    }
+}
+
+fn handle_radius(rect: Rect) -> f32 {
+    rect.height() / 2.5
+}
+
+/// Where the handles can be, so that they stay within the slider.
+fn position_range(rect: Rect) -> Rangef {
+    rect.x_range().shrink(handle_radius(rect))
+}
+
+/// Decide which part of the slider to drag when the pointer is pressed at `pointer_x`.
+fn pick_part(pointer_x: f32, start_x: f32, end_x: f32, handle_radius: f32) -> DraggedPart {
+    let (start_distance, end_distance) = ((pointer_x - start_x).abs(), (pointer_x - end_x).abs());
+    if start_x + handle_radius < pointer_x && pointer_x < end_x - handle_radius {
+        DraggedPart::Middle {
+            grab_offset: pointer_x - start_x,
+            span: end_x - start_x,
+        }
+    } else if start_distance < end_distance
+        || (start_distance == end_distance && pointer_x < start_x)
+    {
+        // If the handles are on top of each other, drag the one on the side of the pointer.
+        DraggedPart::Start
+    } else {
+        DraggedPart::End
+    }
+}
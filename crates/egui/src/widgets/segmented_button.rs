@@ -0,0 +1,270 @@
+use std::sync::Arc;
+
+use crate::*;
+
+/// A connected row of mutually exclusive options, like the segmented controls of iOS and macOS.
+///
+/// Clicking a segment sets the value to that of the segment.
+/// When focused, the arrow keys move the selection left and right.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// #[derive(PartialEq)]
+/// enum Alignment { Left, Center, Right }
+///
+/// let mut alignment = Alignment::Left;
+///
+/// ui.add(
+///     egui::SegmentedButton::new(&mut alignment)
+///         .segment(Alignment::Left, "Left")
+///         .segment(Alignment::Center, "Center")
+///         .segment(Alignment::Right, "Right"),
+/// );
+/// # });
+/// ```
+///
+/// All segments have the same width, which is that of the widest segment.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct SegmentedButton<'a, Value> {
+    current_value: &'a mut Value,
+    segments: Vec<Segment<'a, Value>>,
+}
+
+struct Segment<'a, Value> {
+    value: Value,
+    image: Option<Image<'a>>,
+    text: WidgetText,
+}
+
+impl<'a, Value: PartialEq> SegmentedButton<'a, Value> {
+    pub fn new(current_value: &'a mut Value) -> Self {
+        Self {
+            current_value,
+            segments: vec![],
+        }
+    }
+
+    /// Add a segment with a text label, which selects `value` when clicked.
+    #[inline]
+    pub fn segment(mut self, value: Value, text: impl Into<WidgetText>) -> Self {
+        self.segments.push(Segment {
+            value,
+            image: None,
+            text: text.into(),
+        });
+        self
+    }
+
+    /// Add a segment with an icon to the left of the text, which selects `value` when clicked.
+    ///
+    /// The text may be empty, for an icon-only segment.
+    /// The icon is sized to fit the height of the text, like in [`Button::image_and_text`].
+    #[inline]
+    pub fn segment_with_image(
+        mut self,
+        value: Value,
+        image: impl Into<Image<'a>>,
+        text: impl Into<WidgetText>,
+    ) -> Self {
+        self.segments.push(Segment {
+            value,
+            image: Some(image.into()),
+            text: text.into(),
+        });
+        self
+    }
+}
+
+impl<'a, Value: PartialEq> Widget for SegmentedButton<'a, Value> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            current_value,
+            segments,
+        } = self;
+
+        let button_padding = ui.spacing().button_padding;
+        let icon_spacing = ui.spacing().icon_spacing;
+        let font_height = ui.text_style_height(&TextStyle::Button);
+
+        let contents: Vec<(Option<Vec2>, Option<Arc<Galley>>)> = segments
+            .iter()
+            .map(|segment| {
+                let image_size = segment.image.as_ref().map(|image| {
+                    let space_available_for_image = Vec2::splat(font_height);
+                    image
+                        .load_and_calc_size(ui, space_available_for_image)
+                        .unwrap_or(space_available_for_image)
+                });
+                let galley = (!segment.text.is_empty()).then(|| {
+                    segment.text.clone().into_galley(
+                        ui,
+                        Some(false),
+                        f32::INFINITY,
+                        TextStyle::Button,
+                    )
+                });
+                (image_size, galley)
+            })
+            .collect();
+
+        let content_size = |(image_size, galley): &(Option<Vec2>, Option<Arc<Galley>>)| {
+            let mut size = image_size.unwrap_or(Vec2::ZERO);
+            if let Some(galley) = galley {
+                if image_size.is_some() {
+                    size.x += icon_spacing;
+                }
+                size.x += galley.size().x;
+                size.y = size.y.max(galley.size().y);
+            }
+            size
+        };
+
+        let mut segment_size = contents
+            .iter()
+            .map(content_size)
+            .fold(Vec2::ZERO, Vec2::max)
+            + 2.0 * button_padding;
+        segment_size.y = segment_size.y.at_least(ui.spacing().interact_size.y);
+
+        let desired_size = vec2(segments.len() as f32 * segment_size.x, segment_size.y);
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+
+        let segment_at = |pos: Pos2| -> Option<usize> {
+            if segments.is_empty() || !rect.contains(pos) {
+                return None;
+            }
+            let index = ((pos.x - rect.left()) / segment_size.x).floor() as usize;
+            Some(index.min(segments.len() - 1))
+        };
+
+        let old_selected = segments
+            .iter()
+            .position(|segment| segment.value == *current_value);
+        let mut selected = old_selected;
+
+        if response.clicked() {
+            if let Some(index) = response.interact_pointer_pos().and_then(segment_at) {
+                selected = Some(index);
+            }
+        }
+
+        if response.has_focus() && !segments.is_empty() {
+            ui.memory_mut(|mem| {
+                mem.set_focus_lock_filter(
+                    response.id,
+                    EventFilter {
+                        // The left/right arrows move the selection, not the focus:
+                        horizontal_arrows: true,
+                        ..Default::default()
+                    },
+                );
+            });
+
+            let step = ui.input(|i| {
+                i.num_presses(Key::ArrowRight) as i64 - i.num_presses(Key::ArrowLeft) as i64
+            });
+            if step != 0 {
+                let last = segments.len() as i64 - 1;
+                let index = match selected {
+                    Some(index) => (index as i64 + step).clamp(0, last),
+                    None if step > 0 => 0,
+                    None => last,
+                };
+                selected = Some(index as usize);
+            }
+        }
+
+        if selected != old_selected {
+            response.mark_changed();
+        }
+
+        response.widget_info(|| {
+            let label = selected
+                .and_then(|index| contents[index].1.as_ref())
+                .map_or("", |galley| galley.text());
+            WidgetInfo::labeled(WidgetType::Other, label)
+        });
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact(&response);
+            let widgets = &ui.visuals().widgets;
+            let rounding = widgets.inactive.rounding;
+
+            ui.painter()
+                .rect_filled(rect, rounding, widgets.inactive.weak_bg_fill);
+
+            let hovered = response.hover_pos().and_then(segment_at);
+
+            for (index, (segment, content)) in segments.iter().zip(&contents).enumerate() {
+                let segment_rect = Rect::from_min_size(
+                    pos2(rect.left() + index as f32 * segment_size.x, rect.top()),
+                    segment_size,
+                );
+
+                // Only round the outer corners of the row:
+                let mut segment_rounding = Rounding::ZERO;
+                if index == 0 {
+                    segment_rounding.nw = rounding.nw;
+                    segment_rounding.sw = rounding.sw;
+                }
+                if index + 1 == segments.len() {
+                    segment_rounding.ne = rounding.ne;
+                    segment_rounding.se = rounding.se;
+                }
+
+                let text_color = if selected == Some(index) {
+                    ui.painter().rect_filled(
+                        segment_rect,
+                        segment_rounding,
+                        ui.visuals().selection.bg_fill,
+                    );
+                    ui.visuals().selection.stroke.color
+                } else if hovered == Some(index) {
+                    ui.painter().rect_filled(
+                        segment_rect,
+                        segment_rounding,
+                        widgets.hovered.weak_bg_fill,
+                    );
+                    widgets.hovered.text_color()
+                } else {
+                    widgets.inactive.text_color()
+                };
+
+                // Separate two segments, unless one of them is highlighted:
+                let highlighted = |i| selected == Some(i) || hovered == Some(i);
+                if index > 0 && !highlighted(index) && !highlighted(index - 1) {
+                    ui.painter().vline(
+                        segment_rect.left(),
+                        segment_rect.y_range().shrink(button_padding.y),
+                        widgets.noninteractive.bg_stroke,
+                    );
+                }
+
+                let (image_size, galley) = content;
+                let mut cursor_x = segment_rect.center().x - 0.5 * content_size(content).x;
+                if let (Some(image), Some(image_size)) = (&segment.image, image_size) {
+                    let image_rect = Rect::from_min_size(
+                        pos2(cursor_x, segment_rect.center().y - 0.5 * image_size.y),
+                        *image_size,
+                    );
+                    image.paint_at(ui, image_rect);
+                    cursor_x += image_size.x + icon_spacing;
+                }
+                if let Some(galley) = galley {
+                    let text_pos = pos2(cursor_x, segment_rect.center().y - 0.5 * galley.size().y);
+                    ui.painter().galley(text_pos, galley.clone(), text_color);
+                }
+            }
+
+            ui.painter().rect_stroke(rect, rounding, visuals.bg_stroke);
+        }
+
+        if selected != old_selected {
+            if let Some(segment) = selected.and_then(|index| segments.into_iter().nth(index)) {
+                *current_value = segment.value;
+            }
+        }
+
+        response
+    }
+}
@@ -25,6 +25,7 @@ use crate::*;
 pub struct SelectableLabel {
     selected: bool,
     text: WidgetText,
+    wrap_mode: Option<TextWrapMode>,
 }
 
 impl SelectableLabel {
@@ -32,23 +33,42 @@ impl SelectableLabel {
         Self {
             selected,
             text: text.into(),
+            wrap_mode: None,
         }
     }
+
+    /// Set what to do with text that doesn't fit, overriding [`Ui::wrap_mode`].
+    ///
+    /// With [`TextWrapMode::Truncate`] the text is cut off with `…`,
+    /// and the full text is shown on hover.
+    #[inline]
+    pub fn wrap_mode(mut self, wrap_mode: TextWrapMode) -> Self {
+        self.wrap_mode = Some(wrap_mode);
+        self
+    }
 }
 
 impl Widget for SelectableLabel {
     fn ui(self, ui: &mut Ui) -> Response {
-        let Self { selected, text } = self;
+        let Self {
+            selected,
+            text,
+            wrap_mode,
+        } = self;
 
         let button_padding = ui.spacing().button_padding;
         let total_extra = button_padding + button_padding;
 
         let wrap_width = ui.available_width() - total_extra.x;
-        let galley = text.into_galley(ui, None, wrap_width, TextStyle::Button);
+        let galley = text.into_galley_with_wrap_mode(ui, wrap_mode, wrap_width, TextStyle::Button);
 
         let mut desired_size = total_extra + galley.size();
         desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
-        let (rect, response) = ui.allocate_at_least(desired_size, Sense::click());
+        let (rect, mut response) = ui.allocate_at_least(desired_size, Sense::click());
+        if galley.elided {
+            // Show the full (non-elided) text on hover:
+            response = response.on_hover_text(galley.text());
+        }
         response.widget_info(|| {
             WidgetInfo::selected(WidgetType::SelectableLabel, selected, galley.text())
         });
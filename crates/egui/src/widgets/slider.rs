@@ -1,13 +1,14 @@
 #![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
 
-use std::ops::RangeInclusive;
+use std::{ops::RangeInclusive, sync::Arc};
 
 use crate::{style::HandleShape, *};
 
 // ----------------------------------------------------------------------------
 
-type NumFormatter<'a> = Box<dyn 'a + Fn(f64, RangeInclusive<usize>) -> String>;
+pub(super) type NumFormatter<'a> = Box<dyn 'a + Fn(f64, RangeInclusive<usize>) -> String>;
 type NumParser<'a> = Box<dyn 'a + Fn(&str) -> Option<f64>>;
+type TickFormatter<'a> = Box<dyn 'a + Fn(f64) -> String>;
 
 // ----------------------------------------------------------------------------
 
@@ -26,17 +27,17 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
 // ----------------------------------------------------------------------------
 
 #[derive(Clone)]
-struct SliderSpec {
-    logarithmic: bool,
+pub(super) struct SliderSpec {
+    pub(super) logarithmic: bool,
 
     /// For logarithmic sliders, the smallest positive value we are interested in.
     /// 1 for integer sliders, maybe 1e-6 for others.
-    smallest_positive: f64,
+    pub(super) smallest_positive: f64,
 
     /// For logarithmic sliders, the largest positive value we are interested in
     /// before the slider switches to `INFINITY`, if that is the higher end.
     /// Default: INFINITY.
-    largest_finite: f64,
+    pub(super) largest_finite: f64,
 }
 
 /// Specifies the orientation of a [`Slider`].
@@ -88,6 +89,25 @@ pub struct Slider<'a> {
     custom_parser: Option<NumParser<'a>>,
     trailing_fill: Option<bool>,
     handle_shape: Option<HandleShape>,
+    ticks: Option<SliderTicks>,
+    tick_labels: bool,
+    tick_formatter: Option<TickFormatter<'a>>,
+    snap_to_ticks: bool,
+}
+
+/// Where to put the tick marks of a [`Slider`].
+enum SliderTicks {
+    /// Evenly spaced along the rail, including both ends.
+    Count(usize),
+
+    /// At these values.
+    Values(Vec<f64>),
+}
+
+/// A tick mark of a [`Slider`], with an optional label.
+struct Tick {
+    value: f64,
+    label: Option<Arc<Galley>>,
 }
 
 impl<'a> Slider<'a> {
@@ -135,6 +155,10 @@ impl<'a> Slider<'a> {
             custom_parser: None,
             trailing_fill: None,
             handle_shape: None,
+            ticks: None,
+            tick_labels: false,
+            tick_formatter: None,
+            snap_to_ticks: true,
         }
     }
 
@@ -314,6 +338,74 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Show `count` evenly spaced tick marks under the rail, including both ends of the range.
+    ///
+    /// For logarithmic sliders the ticks are evenly spaced on screen, not in value.
+    ///
+    /// See also [`Self::tick_values`] and [`Self::tick_labels`].
+    #[inline]
+    pub fn ticks(mut self, count: usize) -> Self {
+        self.ticks = Some(SliderTicks::Count(count));
+        self
+    }
+
+    /// Show tick marks under the rail at these values.
+    ///
+    /// Values outside of the range of the slider are ignored.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut volume: f32 = 0.0;
+    /// ui.add(
+    ///     egui::Slider::new(&mut volume, 0.0..=11.0)
+    ///         .tick_values([0.0, 5.0, 10.0, 11.0])
+    ///         .tick_labels(true),
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn tick_values(mut self, values: impl IntoIterator<Item = f64>) -> Self {
+        self.ticks = Some(SliderTicks::Values(values.into_iter().collect()));
+        self
+    }
+
+    /// Show the value of each tick mark as a label next to it. Default is OFF.
+    ///
+    /// The labels use [`Self::custom_formatter`] if set. Use [`Self::tick_formatter`] to format them differently.
+    #[inline]
+    pub fn tick_labels(mut self, tick_labels: bool) -> Self {
+        self.tick_labels = tick_labels;
+        self
+    }
+
+    /// Set a custom formatter for the tick labels, and turn them on.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut zoom: f64 = 1.0;
+    /// ui.add(
+    ///     egui::Slider::new(&mut zoom, 0.25..=4.0)
+    ///         .logarithmic(true)
+    ///         .tick_values([0.25, 0.5, 1.0, 2.0, 4.0])
+    ///         .tick_formatter(|zoom| format!("{}%", zoom * 100.0)),
+    /// );
+    /// # });
+    /// ```
+    pub fn tick_formatter(mut self, formatter: impl 'a + Fn(f64) -> String) -> Self {
+        self.tick_formatter = Some(Box::new(formatter));
+        self.tick_labels = true;
+        self
+    }
+
+    /// Snap to a tick mark when dragging close to it. Default is ON.
+    ///
+    /// Has no effect unless tick marks are shown with [`Self::ticks`] or [`Self::tick_values`].
+    #[inline]
+    pub fn snap_to_ticks(mut self, snap_to_ticks: bool) -> Self {
+        self.snap_to_ticks = snap_to_ticks;
+        self
+    }
+
     /// Set custom formatter defining how numbers are converted into text.
     ///
     /// A custom formatter takes a `f64` for the numeric value and a `RangeInclusive<usize>` representing
@@ -570,18 +662,90 @@ impl<'a> Slider<'a> {
 }
 
 impl<'a> Slider<'a> {
-    /// Just the slider, no text
-    fn allocate_slider_space(&self, ui: &mut Ui, thickness: f32) -> Response {
+    /// Just the slider and its tick labels, no text.
+    ///
+    /// `label_extent` is the extra space needed for the tick labels.
+    fn allocate_slider_space(&self, ui: &mut Ui, thickness: f32, label_extent: f32) -> Response {
         let desired_size = match self.orientation {
-            SliderOrientation::Horizontal => vec2(ui.spacing().slider_width, thickness),
-            SliderOrientation::Vertical => vec2(thickness, ui.spacing().slider_width),
+            SliderOrientation::Horizontal => {
+                vec2(ui.spacing().slider_width, thickness + label_extent)
+            }
+            SliderOrientation::Vertical => {
+                vec2(thickness + label_extent, ui.spacing().slider_width)
+            }
         };
         ui.allocate_response(desired_size, Sense::drag())
     }
 
+    /// The part of the allocated space that is used for the rail and handle,
+    /// i.e. without the tick labels.
+    fn slider_rect(&self, rect: Rect, label_extent: f32) -> Rect {
+        match self.orientation {
+            SliderOrientation::Horizontal => {
+                Rect::from_min_max(rect.min, pos2(rect.max.x, rect.max.y - label_extent))
+            }
+            SliderOrientation::Vertical => {
+                Rect::from_min_max(rect.min, pos2(rect.max.x - label_extent, rect.max.y))
+            }
+        }
+    }
+
+    /// The tick marks inside the range of the slider, with their labels laid out.
+    fn ticks_ui(&self, ui: &Ui) -> Vec<Tick> {
+        let range = self.range();
+        let (min, max) = (
+            range.start().min(*range.end()),
+            range.start().max(*range.end()),
+        );
+
+        let values: Vec<f64> = match &self.ticks {
+            None => return vec![],
+            Some(SliderTicks::Count(count)) => (0..*count)
+                .map(|i| {
+                    let normalized = if *count <= 1 {
+                        0.0
+                    } else {
+                        i as f64 / (*count - 1) as f64
+                    };
+                    value_from_normalized(normalized, range.clone(), &self.spec)
+                })
+                .collect(),
+            Some(SliderTicks::Values(values)) => values
+                .iter()
+                .copied()
+                .filter(|value| (min..=max).contains(value))
+                .collect(),
+        };
+
+        values
+            .into_iter()
+            .map(|value| {
+                let label = self.tick_labels.then(|| {
+                    let text = if let Some(formatter) = &self.tick_formatter {
+                        formatter(value)
+                    } else {
+                        let max_decimals = self.max_decimals.unwrap_or(6).max(self.min_decimals);
+                        let decimals = self.min_decimals..=max_decimals;
+                        match &self.custom_formatter {
+                            Some(formatter) => formatter(value, decimals),
+                            None => emath::format_with_decimals_in_range(value, decimals),
+                        }
+                    };
+                    WidgetText::from(text).into_galley(
+                        ui,
+                        Some(false),
+                        f32::INFINITY,
+                        TextStyle::Small,
+                    )
+                });
+                Tick { value, label }
+            })
+            .collect()
+    }
+
     /// Just the slider, no text
-    fn slider_ui(&mut self, ui: &Ui, response: &Response) {
-        let rect = &response.rect;
+    fn slider_ui(&mut self, ui: &Ui, response: &Response, rect: Rect, ticks: &[Tick]) {
+        let rect = &rect;
         let handle_shape = self
             .handle_shape
             .unwrap_or_else(|| ui.style().visuals.handle_shape);
@@ -589,7 +753,24 @@ impl<'a> Slider<'a> {
 
         if let Some(pointer_position_2d) = response.interact_pointer_pos() {
             let position = self.pointer_position(pointer_position_2d);
-            let new_value = if self.smart_aim {
+            let snapped_tick = if self.snap_to_ticks {
+                let snap_radius = ui.style().interaction.interact_radius;
+                ticks
+                    .iter()
+                    .map(|tick| {
+                        let distance =
+                            (self.position_from_value(tick.value, position_range) - position).abs();
+                        (tick.value, distance)
+                    })
+                    .filter(|(_, distance)| *distance <= snap_radius)
+                    .min_by(|a, b| a.1.total_cmp(&b.1))
+                    .map(|(value, _)| value)
+            } else {
+                None
+            };
+            let new_value = if let Some(value) = snapped_tick {
+                value
+            } else if self.smart_aim {
                 let aim_radius = ui.input(|i| i.aim_radius());
                 emath::smart_aim::best_in_range_f64(
                     self.value_from_position(position - aim_radius, position_range),
@@ -720,6 +901,49 @@ impl<'a> Slider<'a> {
                 );
             }
 
+            // Paint tick marks under the rail, and their labels under that:
+            let tick_stroke = Stroke::new(1.0, widget_visuals.noninteractive.fg_stroke.color);
+            for tick in ticks {
+                let position_1d = self.position_from_value(tick.value, position_range);
+                match self.orientation {
+                    SliderOrientation::Horizontal => {
+                        let x = ui.painter().round_to_pixel(position_1d);
+                        ui.painter().vline(
+                            x,
+                            Rangef::new(rail_rect.bottom() + 1.0, rect.bottom()),
+                            tick_stroke,
+                        );
+                        if let Some(galley) = &tick.label {
+                            let pos = pos2(x - galley.size().x / 2.0, rect.bottom());
+                            ui.painter().galley(
+                                pos,
+                                galley.clone(),
+                                ui.visuals().weak_text_color(),
+                            );
+                        }
+                    }
+                    SliderOrientation::Vertical => {
+                        let y = ui.painter().round_to_pixel(position_1d);
+                        ui.painter().hline(
+                            Rangef::new(rail_rect.right() + 1.0, rect.right()),
+                            y,
+                            tick_stroke,
+                        );
+                        if let Some(galley) = &tick.label {
+                            let pos = pos2(
+                                rect.right() + ui.spacing().item_spacing.x / 2.0,
+                                y - galley.size().y / 2.0,
+                            );
+                            ui.painter().galley(
+                                pos,
+                                galley.clone(),
+                                ui.visuals().weak_text_color(),
+                            );
+                        }
+                    }
+                }
+            }
+
             let radius = self.handle_radius(rect);
 
             let handle_shape = self
@@ -854,8 +1078,18 @@ impl<'a> Slider<'a> {
         let thickness = ui
             .text_style_height(&TextStyle::Body)
             .at_least(ui.spacing().interact_size.y);
-        let mut response = self.allocate_slider_space(ui, thickness);
-        self.slider_ui(ui, &response);
+        let ticks = self.ticks_ui(ui);
+        let label_extent = ticks
+            .iter()
+            .filter_map(|tick| tick.label.as_ref())
+            .map(|galley| match self.orientation {
+                SliderOrientation::Horizontal => galley.size().y,
+                SliderOrientation::Vertical => galley.size().x + ui.spacing().item_spacing.x / 2.0,
+            })
+            .fold(0.0, f32::max);
+        let mut response = self.allocate_slider_space(ui, thickness, label_extent);
+        let slider_rect = self.slider_rect(response.rect, label_extent);
+        self.slider_ui(ui, &response, slider_rect, &ticks);
 
         let value = self.get_value();
         response.changed = value != old_value;
@@ -885,7 +1119,7 @@ impl<'a> Slider<'a> {
             let handle_shape = self
                 .handle_shape
                 .unwrap_or_else(|| ui.style().visuals.handle_shape);
-            let position_range = self.position_range(&response.rect, &handle_shape);
+            let position_range = self.position_range(&slider_rect, &handle_shape);
             let value_response = self.value_ui(ui, position_range);
             if value_response.gained_focus()
                 || value_response.has_focus()
@@ -942,7 +1176,11 @@ use std::f64::INFINITY;
 /// give a scale that this many orders of magnitude in size.
 const INF_RANGE_MAGNITUDE: f64 = 10.0;
 
-fn value_from_normalized(normalized: f64, range: RangeInclusive<f64>, spec: &SliderSpec) -> f64 {
+pub(super) fn value_from_normalized(
+    normalized: f64,
+    range: RangeInclusive<f64>,
+    spec: &SliderSpec,
+) -> f64 {
     let (min, max) = (*range.start(), *range.end());
 
     if min.is_nan() || max.is_nan() {
@@ -991,7 +1229,11 @@ fn value_from_normalized(normalized: f64, range: RangeInclusive<f64>, spec: &Sli
     }
 }
 
-fn normalized_from_value(value: f64, range: RangeInclusive<f64>, spec: &SliderSpec) -> f64 {
+pub(super) fn normalized_from_value(
+    value: f64,
+    range: RangeInclusive<f64>,
+    spec: &SliderSpec,
+) -> f64 {
     let (min, max) = (*range.start(), *range.end());
 
     if min.is_nan() || max.is_nan() {
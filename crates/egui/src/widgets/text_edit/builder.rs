@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{borrow::Cow, ops::Range, sync::Arc};
 
 use epaint::text::{cursor::*, Galley, LayoutJob};
 
@@ -6,7 +6,9 @@ use crate::{
     os::OperatingSystem,
     output::OutputEvent,
     text_selection::{
-        text_cursor_state::cursor_rect, visuals::paint_text_selection, CCursorRange, CursorRange,
+        text_cursor_state::cursor_rect,
+        visuals::{cursor_range_rects, paint_text_highlight, paint_text_selection},
+        CCursorRange, CursorRange,
     },
     *,
 };
@@ -66,6 +68,7 @@ pub struct TextEdit<'t> {
     text_color: Option<Color32>,
     layouter: Option<&'t mut dyn FnMut(&Ui, &str, f32) -> Arc<Galley>>,
     password: bool,
+    password_reveal_button: bool,
     frame: bool,
     margin: Margin,
     multiline: bool,
@@ -78,6 +81,11 @@ pub struct TextEdit<'t> {
     align: Align2,
     clip_text: bool,
     char_limit: usize,
+    char_filter: Option<Box<dyn 't + Fn(char) -> bool>>,
+    validator: Option<Box<dyn 't + Fn(&str) -> bool>>,
+    highlights: Vec<Range<usize>>,
+    current_highlight: Option<usize>,
+    scroll_to_range: Option<Range<usize>>,
     return_key: KeyboardShortcut,
 }
 
@@ -117,6 +125,7 @@ impl<'t> TextEdit<'t> {
             text_color: None,
             layouter: None,
             password: false,
+            password_reveal_button: false,
             frame: true,
             margin: Margin::symmetric(4.0, 2.0),
             multiline: true,
@@ -135,6 +144,11 @@ impl<'t> TextEdit<'t> {
             align: Align2::LEFT_TOP,
             clip_text: false,
             char_limit: usize::MAX,
+            char_filter: None,
+            validator: None,
+            highlights: Vec::new(),
+            current_highlight: None,
+            scroll_to_range: None,
             return_key: KeyboardShortcut::new(Modifiers::NONE, Key::Enter),
         }
     }
@@ -190,12 +204,37 @@ impl<'t> TextEdit<'t> {
     }
 
     /// If true, hide the letters from view and prevent copying from the field.
+    ///
+    /// Screen readers are told that this is a password field, and are never given the actual text.
+    ///
+    /// See also [`Self::password_reveal_button`].
     #[inline]
     pub fn password(mut self, password: bool) -> Self {
         self.password = password;
         self
     }
 
+    /// Show an eye button inside a [`Self::password`] field, which toggles showing the password in plain text.
+    ///
+    /// Copying is only possible while the password is shown.
+    /// Whether or not the password is shown is stored in the [`TextEditState`], but never persisted to disk.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_password = String::new();
+    /// ui.add(
+    ///     egui::TextEdit::singleline(&mut my_password)
+    ///         .password(true)
+    ///         .password_reveal_button(true),
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn password_reveal_button(mut self, password_reveal_button: bool) -> Self {
+        self.password_reveal_button = password_reveal_button;
+        self
+    }
+
     /// Pick a [`FontId`] or [`TextStyle`].
     #[inline]
     pub fn font(mut self, font_selection: impl Into<FontSelection>) -> Self {
@@ -328,6 +367,89 @@ impl<'t> TextEdit<'t> {
         self
     }
 
+    /// Only allow typing or pasting characters for which `char_filter` returns `true`.
+    ///
+    /// Other characters are dropped before they are inserted, so the cursor and IME keep working as expected.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut hex = String::new();
+    /// ui.add(egui::TextEdit::singleline(&mut hex).char_filter(|c| c.is_ascii_hexdigit()));
+    /// # });
+    /// ```
+    ///
+    /// See also [`Self::validator`].
+    #[inline]
+    pub fn char_filter(mut self, char_filter: impl 't + Fn(char) -> bool) -> Self {
+        self.char_filter = Some(Box::new(char_filter));
+        self
+    }
+
+    /// Reject any edit that would result in a text for which `validator` returns `false`.
+    ///
+    /// The validator is called with the whole text as it would be after each edit
+    /// (typing, pasting, deleting, …). If it returns `false`, the edit is undone.
+    /// Text that is still being composed with an IME is only validated once it is committed.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut percent = String::new();
+    /// ui.add(
+    ///     egui::TextEdit::singleline(&mut percent)
+    ///         .validator(|text| text.is_empty() || text.parse::<u8>().map_or(false, |p| p <= 100)),
+    /// );
+    /// # });
+    /// ```
+    ///
+    /// See also [`Self::char_filter`].
+    #[inline]
+    pub fn validator(mut self, validator: impl 't + Fn(&str) -> bool) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Highlight these byte ranges of the text, e.g. the results of a search.
+    ///
+    /// Ranges that are out of bounds, or not on `char` boundaries, are ignored.
+    /// The text can still be selected and edited as usual.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut text = String::from("one fish two fish");
+    /// let matches: Vec<_> = text.match_indices("fish").map(|(i, m)| i..i + m.len()).collect();
+    /// ui.add(
+    ///     egui::TextEdit::multiline(&mut text)
+    ///         .highlights(matches.clone())
+    ///         .current_highlight(1)
+    ///         .scroll_to_range(matches[1].clone()),
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn highlights(mut self, ranges: impl IntoIterator<Item = Range<usize>>) -> Self {
+        self.highlights = ranges.into_iter().collect();
+        self
+    }
+
+    /// Which of the [`Self::highlights`] is the current one (e.g. the current search match).
+    ///
+    /// It is highlighted more strongly than the others.
+    #[inline]
+    pub fn current_highlight(mut self, index: usize) -> Self {
+        self.current_highlight = Some(index);
+        self
+    }
+
+    /// Scroll so that this byte range of the text is visible.
+    ///
+    /// This scrolls a single-line [`TextEdit`] with clipped text, as well as any enclosing [`ScrollArea`].
+    /// Only set this for the frame you want to scroll, e.g. when the user jumps to the next search result.
+    #[inline]
+    pub fn scroll_to_range(mut self, range: Range<usize>) -> Self {
+        self.scroll_to_range = Some(range);
+        self
+    }
+
     /// Set the horizontal align of the inner text.
     #[inline]
     pub fn horizontal_align(mut self, align: Align) -> Self {
@@ -442,8 +564,9 @@ impl<'t> TextEdit<'t> {
             text_color,
             layouter,
             password,
+            password_reveal_button,
             frame: _,
-            margin,
+            mut margin,
             multiline,
             interactive,
             desired_width,
@@ -454,6 +577,11 @@ impl<'t> TextEdit<'t> {
             align,
             clip_text,
             char_limit,
+            char_filter,
+            validator,
+            highlights,
+            current_highlight,
+            scroll_to_range,
             return_key,
         } = self;
 
@@ -464,8 +592,27 @@ impl<'t> TextEdit<'t> {
 
         let prev_text = text.as_str().to_owned();
 
+        let id = id.unwrap_or_else(|| {
+            if let Some(id_source) = id_source {
+                ui.make_persistent_id(id_source)
+            } else {
+                ui.next_auto_id() // Since we are only storing the cursor a persistent Id is not super important
+            }
+        });
+        let mut state = TextEditState::load(ui.ctx(), id).unwrap_or_default();
+
         let font_id = font_selection.resolve(ui.style());
         let row_height = ui.fonts(|f| f.row_height(&font_id));
+
+        // Make room for the eye button to the right of the text:
+        let password_reveal_button = password && password_reveal_button;
+        let reveal_button_width = row_height;
+        if password_reveal_button {
+            margin.right += reveal_button_width + ui.spacing().item_spacing.x;
+        }
+
+        // Should we hide the letters, and prevent copying?
+        let concealed = password && !(password_reveal_button && state.password_revealed);
         const MIN_WIDTH: f32 = 24.0; // Never make a [`TextEdit`] more narrow than this.
         let available_width = (ui.available_width() - margin.sum().x).at_least(MIN_WIDTH);
         let desired_width = desired_width.unwrap_or_else(|| ui.spacing().text_edit_width);
@@ -477,7 +624,7 @@ impl<'t> TextEdit<'t> {
 
         let font_id_clone = font_id.clone();
         let mut default_layouter = move |ui: &Ui, text: &str, wrap_width: f32| {
-            let text = mask_if_password(password, text);
+            let text = mask_if_password(concealed, text);
             let layout_job = if multiline {
                 LayoutJob::simple(text, font_id_clone.clone(), text_color, wrap_width)
             } else {
@@ -498,18 +645,9 @@ impl<'t> TextEdit<'t> {
         let desired_height = (desired_height_rows.at_least(1) as f32) * row_height;
         let desired_inner_size = vec2(desired_width, galley.size().y.max(desired_height));
         let desired_outer_size = (desired_inner_size + margin.sum()).at_least(min_size);
-        let (auto_id, outer_rect) = ui.allocate_space(desired_outer_size);
+        let (_, outer_rect) = ui.allocate_space(desired_outer_size);
         let rect = outer_rect - margin; // inner rect (excluding frame/margin).
 
-        let id = id.unwrap_or_else(|| {
-            if let Some(id_source) = id_source {
-                ui.make_persistent_id(id_source)
-            } else {
-                auto_id // Since we are only storing the cursor a persistent Id is not super important
-            }
-        });
-        let mut state = TextEditState::load(ui.ctx(), id).unwrap_or_default();
-
         // On touch screens (e.g. mobile in `eframe` web), should
         // dragging select text, or scroll the enclosing [`ScrollArea`] (if any)?
         // Since currently copying selected text in not supported on `eframe` web,
@@ -528,6 +666,40 @@ impl<'t> TextEdit<'t> {
         };
         let mut response = ui.interact(outer_rect, id, sense);
         let text_clip_rect = rect;
+
+        if password_reveal_button {
+            let button_rect = Rect::from_min_size(
+                pos2(rect.right() + ui.spacing().item_spacing.x, rect.top()),
+                vec2(reveal_button_width, row_height),
+            );
+            let button_response = ui
+                .interact(
+                    button_rect,
+                    id.with("password_reveal_button"),
+                    Sense::click(),
+                )
+                .on_hover_text(if state.password_revealed {
+                    "Hide password"
+                } else {
+                    "Show password"
+                });
+            if button_response.clicked() {
+                state.password_revealed = !state.password_revealed;
+                ui.ctx().request_repaint(); // The text was already laid out this frame
+            }
+            if ui.is_rect_visible(button_rect) {
+                let visuals = ui
+                    .style()
+                    .interact_selectable(&button_response, state.password_revealed);
+                ui.painter().text(
+                    button_rect.center(),
+                    Align2::CENTER_CENTER,
+                    "👁",
+                    font_id.clone(),
+                    visuals.text_color(),
+                );
+            }
+        }
         let painter = ui.painter_at(text_clip_rect.expand(1.0)); // expand to avoid clipping cursor
 
         if interactive {
@@ -591,9 +763,11 @@ impl<'t> TextEdit<'t> {
                 id,
                 wrap_width,
                 multiline,
-                password,
+                concealed,
                 default_cursor_range,
                 char_limit,
+                char_filter.as_deref(),
+                validator.as_deref(),
                 event_filter,
                 return_key,
             );
@@ -610,21 +784,42 @@ impl<'t> TextEdit<'t> {
             .min;
         let align_offset = rect.left() - galley_pos.x;
 
+        // The part of the galley to scroll to, relative to the galley:
+        let scroll_to_rect = scroll_to_range
+            .and_then(|range| CursorRange::from_byte_range(text.as_str(), &galley, range))
+            .map(|range| {
+                cursor_range_rects(Pos2::ZERO, &galley, &range)
+                    .fold(Rect::NOTHING, |a, b| a.union(b))
+            });
+
         // Visual clipping for singleline text editor with text larger than width
         if clip_text && align_offset == 0.0 {
-            let cursor_pos = match (cursor_range, ui.memory(|mem| mem.has_focus(id))) {
-                (Some(cursor_range), true) => galley.pos_from_cursor(&cursor_range.primary).min.x,
-                _ => 0.0,
-            };
-
             let mut offset_x = state.singleline_offset;
-            let visible_range = offset_x..=offset_x + desired_inner_size.x;
 
-            if !visible_range.contains(&cursor_pos) {
-                if cursor_pos < *visible_range.start() {
-                    offset_x = cursor_pos;
-                } else {
-                    offset_x = cursor_pos - desired_inner_size.x;
+            if let Some(scroll_to_rect) = scroll_to_rect {
+                // Show as much of the range as possible, but prioritize its start:
+                if scroll_to_rect.max.x > offset_x + desired_inner_size.x {
+                    offset_x = scroll_to_rect.max.x - desired_inner_size.x;
+                }
+                if scroll_to_rect.min.x < offset_x {
+                    offset_x = scroll_to_rect.min.x;
+                }
+            } else {
+                let cursor_pos = match (cursor_range, ui.memory(|mem| mem.has_focus(id))) {
+                    (Some(cursor_range), true) => {
+                        galley.pos_from_cursor(&cursor_range.primary).min.x
+                    }
+                    _ => 0.0,
+                };
+
+                let visible_range = offset_x..=offset_x + desired_inner_size.x;
+
+                if !visible_range.contains(&cursor_pos) {
+                    if cursor_pos < *visible_range.start() {
+                        offset_x = cursor_pos;
+                    } else {
+                        offset_x = cursor_pos - desired_inner_size.x;
+                    }
                 }
             }
 
@@ -646,7 +841,27 @@ impl<'t> TextEdit<'t> {
             false
         };
 
+        if let Some(scroll_to_rect) = scroll_to_rect {
+            let scroll_to_rect = scroll_to_rect.translate(galley_pos.to_vec2());
+            if !ui.clip_rect().contains_rect(scroll_to_rect) {
+                ui.scroll_to_rect(scroll_to_rect, None);
+            }
+        }
+
         if ui.is_rect_visible(rect) {
+            for (index, range) in highlights.iter().enumerate() {
+                if let Some(range) =
+                    CursorRange::from_byte_range(text.as_str(), &galley, range.clone())
+                {
+                    let color = if current_highlight == Some(index) {
+                        ui.visuals().warn_fg_color.gamma_multiply(0.6)
+                    } else {
+                        ui.visuals().warn_fg_color.gamma_multiply(0.25)
+                    };
+                    paint_text_highlight(&painter, galley_pos, &galley, &range, color);
+                }
+            }
+
             painter.galley(galley_pos, galley.clone(), text_color);
 
             if text.as_str().is_empty() && !hint_text.is_empty() {
@@ -804,6 +1019,8 @@ fn events(
     password: bool,
     default_cursor_range: CursorRange,
     char_limit: usize,
+    char_filter: Option<&dyn Fn(char) -> bool>,
+    validator: Option<&dyn Fn(&str) -> bool>,
     event_filter: EventFilter,
     return_key: KeyboardShortcut,
 ) -> (bool, CursorRange) {
@@ -826,8 +1043,13 @@ fn events(
 
     let mut any_change = false;
 
+    let is_allowed = |c: char| char_filter.map_or(true, |char_filter| char_filter(c));
+
     let events = ui.input(|i| i.filtered_events(&event_filter));
     for event in &events {
+        // So we can undo the edit if the validator rejects it:
+        let prev_text = validator.map(|_| text.as_str().to_owned());
+
         let did_mutate_text = match event {
             // First handle events that only changes the selection cursor, not the text:
             event if cursor_range.on_event(os, event, galley, id) => None,
@@ -850,10 +1072,11 @@ fn events(
                 }
             }
             Event::Paste(text_to_insert) => {
+                let text_to_insert = filter_chars(text_to_insert, char_filter);
                 if !text_to_insert.is_empty() {
                     let mut ccursor = text.delete_selected(&cursor_range);
 
-                    text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
+                    text.insert_text_at(&mut ccursor, &text_to_insert, char_limit);
 
                     Some(CCursorRange::one(ccursor))
                 } else {
@@ -861,11 +1084,12 @@ fn events(
                 }
             }
             Event::Text(text_to_insert) => {
+                let text_to_insert = filter_chars(text_to_insert, char_filter);
                 // Newlines are handled by `Key::Enter`.
                 if !text_to_insert.is_empty() && text_to_insert != "\n" && text_to_insert != "\r" {
                     let mut ccursor = text.delete_selected(&cursor_range);
 
-                    text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
+                    text.insert_text_at(&mut ccursor, &text_to_insert, char_limit);
 
                     Some(CCursorRange::one(ccursor))
                 } else {
@@ -878,14 +1102,18 @@ fn events(
                 modifiers,
                 ..
             } if multiline => {
-                let mut ccursor = text.delete_selected(&cursor_range);
                 if modifiers.shift {
+                    let mut ccursor = text.delete_selected(&cursor_range);
                     // TODO(emilk): support removing indentation over a selection?
                     text.decrease_indentation(&mut ccursor);
-                } else {
+                    Some(CCursorRange::one(ccursor))
+                } else if is_allowed('\t') {
+                    let mut ccursor = text.delete_selected(&cursor_range);
                     text.insert_text_at(&mut ccursor, "\t", char_limit);
+                    Some(CCursorRange::one(ccursor))
+                } else {
+                    None
                 }
-                Some(CCursorRange::one(ccursor))
             }
             Event::Key {
                 key,
@@ -896,6 +1124,9 @@ fn events(
                 && modifiers.matches_logically(return_key.modifiers) =>
             {
                 if multiline {
+                    if !is_allowed('\n') {
+                        continue;
+                    }
                     let mut ccursor = text.delete_selected(&cursor_range);
                     text.insert_text_at(&mut ccursor, "\n", char_limit);
                     // TODO(emilk): if code editor, auto-indent by same leading tabs, + one if the lines end on an opening bracket
@@ -982,7 +1213,8 @@ fn events(
                                 == state.ime_cursor_range.secondary.ccursor.index
                         {
                             let mut ccursor = text.delete_selected(&cursor_range);
-                            text.insert_text_at(&mut ccursor, prediction, char_limit);
+                            let prediction = filter_chars(prediction, char_filter);
+                            text.insert_text_at(&mut ccursor, &prediction, char_limit);
                             Some(CCursorRange::one(ccursor))
                         } else {
                             let ccursor = cursor_range.primary.ccursor;
@@ -999,6 +1231,23 @@ fn events(
             _ => None,
         };
 
+        let is_preedit = matches!(event, Event::Ime(ImeEvent::Preedit(_)));
+        let did_mutate_text = match (did_mutate_text, prev_text, validator) {
+            (Some(_), Some(prev_text), Some(validator))
+                if !is_preedit && !validator(text.as_str()) =>
+            {
+                // Reject the edit:
+                text.replace_with(&prev_text);
+                if matches!(event, Event::Ime(ImeEvent::Commit(_))) {
+                    // Remove the text that was being composed.
+                    Some(CCursorRange::one(text.delete_selected(&cursor_range)))
+                } else {
+                    None
+                }
+            }
+            (did_mutate_text, _, _) => did_mutate_text,
+        };
+
         if let Some(new_ccursor_range) = did_mutate_text {
             any_change = true;
 
@@ -1023,6 +1272,16 @@ fn events(
     (any_change, cursor_range)
 }
 
+/// Remove the characters that `char_filter` does not allow.
+fn filter_chars<'s>(text: &'s str, char_filter: Option<&dyn Fn(char) -> bool>) -> Cow<'s, str> {
+    match char_filter {
+        Some(char_filter) if !text.chars().all(char_filter) => {
+            Cow::Owned(text.chars().filter(|&c| char_filter(c)).collect())
+        }
+        _ => Cow::Borrowed(text),
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// Returns `Some(new_cursor)` if we did mutate `text`.
@@ -56,6 +56,10 @@ pub struct TextEditState {
     /// Used to pause the cursor animation when typing.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) last_edit_time: f64,
+
+    /// Is the password shown in plain text, using the button of [`TextEdit::password_reveal_button`]?
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) password_revealed: bool,
 }
 
 impl TextEditState {
@@ -96,6 +100,72 @@ impl TextEditState {
         self.set_undoer(TextEditUndoer::default());
     }
 
+    /// Is there anything to undo in `text`?
+    ///
+    /// `text` should be the same text that was last shown in the [`TextEdit`].
+    pub fn has_undo(&self, text: &str) -> bool {
+        self.undoer.lock().has_undo(&self.undo_state(text))
+    }
+
+    /// Is there anything to redo in `text`?
+    ///
+    /// `text` should be the same text that was last shown in the [`TextEdit`].
+    pub fn has_redo(&self, text: &str) -> bool {
+        self.undoer.lock().has_redo(&self.undo_state(text))
+    }
+
+    /// Undo the last edit of `text`, e.g. from an "Edit" menu.
+    ///
+    /// This is what happens when the user presses Ctrl/Cmd+Z in the [`TextEdit`].
+    /// Returns `false` if there was nothing to undo.
+    ///
+    /// Remember to [`Self::store`] the state afterwards:
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut text = String::new();
+    /// let output = egui::TextEdit::multiline(&mut text).show(ui);
+    /// let mut state = output.state;
+    /// if ui.add_enabled(state.has_undo(&text), egui::Button::new("Undo")).clicked() {
+    ///     state.undo(&mut text);
+    ///     state.store(ui.ctx(), output.response.id);
+    /// }
+    /// # });
+    /// ```
+    pub fn undo(&mut self, text: &mut dyn TextBuffer) -> bool {
+        let current_state = self.undo_state(text.as_str());
+        let undone = self.undoer.lock().undo(&current_state).cloned();
+        self.apply_undo_state(text, undone)
+    }
+
+    /// Redo the last undone edit of `text`.
+    ///
+    /// Returns `false` if there was nothing to redo.
+    /// Remember to [`Self::store`] the state afterwards.
+    pub fn redo(&mut self, text: &mut dyn TextBuffer) -> bool {
+        let current_state = self.undo_state(text.as_str());
+        let redone = self.undoer.lock().redo(&current_state).cloned();
+        self.apply_undo_state(text, redone)
+    }
+
+    fn undo_state(&self, text: &str) -> (CCursorRange, String) {
+        let cursor_range = self.cursor.char_range().unwrap_or_default();
+        (cursor_range, text.to_owned())
+    }
+
+    fn apply_undo_state(
+        &mut self,
+        text: &mut dyn TextBuffer,
+        state: Option<(CCursorRange, String)>,
+    ) -> bool {
+        if let Some((cursor_range, new_text)) = state {
+            text.replace_with(&new_text);
+            self.cursor.set_char_range(Some(cursor_range));
+            true
+        } else {
+            false
+        }
+    }
+
     #[deprecated = "Use `self.cursor.range` instead"]
     pub fn cursor_range(&mut self, galley: &Galley) -> Option<CursorRange> {
         self.cursor.range(galley)
@@ -75,12 +75,7 @@ impl super::View for CodeEditor {
             });
         });
 
-        let mut layouter = |ui: &egui::Ui, string: &str, wrap_width: f32| {
-            let mut layout_job =
-                egui_extras::syntax_highlighting::highlight(ui.ctx(), &theme, string, language);
-            layout_job.wrap.max_width = wrap_width;
-            ui.fonts(|f| f.layout_job(layout_job))
-        };
+        let mut layouter = egui_extras::syntax_highlighting::layouter(&theme, language);
 
         egui::ScrollArea::vertical().show(ui, |ui| {
             ui.add(
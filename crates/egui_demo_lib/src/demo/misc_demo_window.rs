@@ -70,6 +70,8 @@ impl View for MiscDemoWindow {
             .default_open(false)
             .show(ui, |ui| {
                 text_layout_demo(ui);
+                ui.separator();
+                inline_objects_demo(ui);
                 ui.vertical_centered(|ui| {
                     ui.add(crate::egui_github_link_file_line!());
                 });
@@ -676,3 +678,45 @@ fn text_layout_demo(ui: &mut Ui) {
 
     ui.label(job);
 }
+
+/// Color chips and a button in the middle of wrapped text.
+fn inline_objects_demo(ui: &mut Ui) {
+    use egui::text::LayoutJob;
+
+    let format = TextFormat {
+        color: ui.visuals().text_color(),
+        ..Default::default()
+    };
+    let chip_size = Vec2::splat(ui.text_style_height(&TextStyle::Body));
+    let button_size = vec2(64.0, ui.spacing().interact_size.y);
+
+    let mut job = LayoutJob::default();
+    job.append(
+        "Text can make room for inline objects, like ",
+        0.0,
+        format.clone(),
+    );
+    job.append_inline(chip_size, 0.0, format.clone());
+    job.append_inline(chip_size, 2.0, format.clone());
+    job.append_inline(chip_size, 2.0, format.clone());
+    job.append(" color chips, or even a ", 0.0, format.clone());
+    job.append_inline(button_size, 0.0, format.clone());
+    job.append(", that wrap along with the text.", 0.0, format);
+
+    let (pos, galley, _response) = Label::new(job).layout_in_ui(ui);
+    let rects: Vec<Rect> = galley
+        .inline_rects()
+        .into_iter()
+        .map(|rect| rect.translate(pos.to_vec2()))
+        .collect();
+    ui.painter().galley(pos, galley, ui.visuals().text_color());
+
+    let chip_colors = [Color32::RED, Color32::GREEN, Color32::BLUE];
+    for (rect, color) in rects.iter().zip(chip_colors) {
+        ui.painter().rect_filled(rect.shrink(1.0), 2.0, color);
+    }
+    if let Some(&button_rect) = rects.get(chip_colors.len()) {
+        ui.put(button_rect, Button::new("button"))
+            .on_hover_text("Widgets can be placed in the text too");
+    }
+}
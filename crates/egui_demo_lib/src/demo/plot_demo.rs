@@ -4,9 +4,10 @@ use std::ops::RangeInclusive;
 use egui::*;
 
 use egui_plot::{
-    Arrows, AxisHints, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, CoordinatesFormatter, Corner,
-    GridInput, GridMark, HLine, Legend, Line, LineStyle, MarkerShape, Plot, PlotImage, PlotPoint,
-    PlotPoints, PlotResponse, Points, Polygon, Text, VLine,
+    Arrows, AxisHints, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, Candle, CandleStick, ColorMap,
+    CoordinatesFormatter, Corner, GridInput, GridMark, HLine, Heatmap, Legend, Line, LineStyle,
+    MarkerShape, Plot, PlotImage, PlotPoint, PlotPoints, PlotResponse, Points, Polygon, Text,
+    VLine,
 };
 
 // ----------------------------------------------------------------------------
@@ -863,7 +864,10 @@ impl InteractionDemo {
 enum Chart {
     GaussBars,
     StackedBars,
+    GroupedBars,
     BoxPlot,
+    CandleStick,
+    Heatmap,
 }
 
 impl Default for Chart {
@@ -901,7 +905,10 @@ impl ChartsDemo {
                 ui.horizontal(|ui| {
                     ui.selectable_value(&mut self.chart, Chart::GaussBars, "Histogram");
                     ui.selectable_value(&mut self.chart, Chart::StackedBars, "Stacked Bar Chart");
+                    ui.selectable_value(&mut self.chart, Chart::GroupedBars, "Grouped Bar Chart");
                     ui.selectable_value(&mut self.chart, Chart::BoxPlot, "Box Plot");
+                    ui.selectable_value(&mut self.chart, Chart::CandleStick, "Candlestick");
+                    ui.selectable_value(&mut self.chart, Chart::Heatmap, "Heatmap");
                 });
                 ui.label("Orientation:");
                 ui.horizontal(|ui| {
@@ -911,7 +918,9 @@ impl ChartsDemo {
             });
             ui.vertical(|ui| {
                 ui.group(|ui| {
-                    ui.add_enabled_ui(self.chart != Chart::StackedBars, |ui| {
+                    let fixed_aspect =
+                        matches!(self.chart, Chart::StackedBars | Chart::GroupedBars);
+                    ui.add_enabled_ui(!fixed_aspect, |ui| {
                         ui.horizontal(|ui| {
                             ui.label("Allow zoom:");
                             ui.checkbox(&mut self.allow_zoom.x, "X");
@@ -934,7 +943,10 @@ impl ChartsDemo {
         match self.chart {
             Chart::GaussBars => self.bar_gauss(ui),
             Chart::StackedBars => self.bar_stacked(ui),
+            Chart::GroupedBars => self.bar_grouped(ui),
             Chart::BoxPlot => self.box_plot(ui),
+            Chart::CandleStick => self.candle_stick(ui),
+            Chart::Heatmap => self.heatmap(ui),
         }
     }
 
@@ -970,8 +982,9 @@ impl ChartsDemo {
             .response
     }
 
-    fn bar_stacked(&self, ui: &mut Ui) -> Response {
-        let mut chart1 = BarChart::new(vec![
+    /// The charts shown by both the stacked and the grouped bar chart demos.
+    fn bar_charts(&self) -> [BarChart; 4] {
+        let chart1 = BarChart::new(vec![
             Bar::new(0.5, 1.0).name("Day 1"),
             Bar::new(1.5, 3.0).name("Day 2"),
             Bar::new(2.5, 1.0).name("Day 3"),
@@ -981,7 +994,7 @@ impl ChartsDemo {
         .width(0.7)
         .name("Set 1");
 
-        let mut chart2 = BarChart::new(vec![
+        let chart2 = BarChart::new(vec![
             Bar::new(0.5, 1.0),
             Bar::new(1.5, 1.5),
             Bar::new(2.5, 0.1),
@@ -989,10 +1002,9 @@ impl ChartsDemo {
             Bar::new(4.5, 0.8),
         ])
         .width(0.7)
-        .name("Set 2")
-        .stack_on(&[&chart1]);
+        .name("Set 2");
 
-        let mut chart3 = BarChart::new(vec![
+        let chart3 = BarChart::new(vec![
             Bar::new(0.5, -0.5),
             Bar::new(1.5, 1.0),
             Bar::new(2.5, 0.5),
@@ -1000,10 +1012,9 @@ impl ChartsDemo {
             Bar::new(4.5, 0.3),
         ])
         .width(0.7)
-        .name("Set 3")
-        .stack_on(&[&chart1, &chart2]);
+        .name("Set 3");
 
-        let mut chart4 = BarChart::new(vec![
+        let chart4 = BarChart::new(vec![
             Bar::new(0.5, 0.5),
             Bar::new(1.5, 1.0),
             Bar::new(2.5, 0.5),
@@ -1011,25 +1022,42 @@ impl ChartsDemo {
             Bar::new(4.5, -0.5),
         ])
         .width(0.7)
-        .name("Set 4")
-        .stack_on(&[&chart1, &chart2, &chart3]);
+        .name("Set 4");
 
-        if !self.vertical {
-            chart1 = chart1.horizontal();
-            chart2 = chart2.horizontal();
-            chart3 = chart3.horizontal();
-            chart4 = chart4.horizontal();
+        let charts = [chart1, chart2, chart3, chart4];
+        if self.vertical {
+            charts
+        } else {
+            charts.map(BarChart::horizontal)
         }
+    }
+
+    fn bar_stacked(&self, ui: &mut Ui) -> Response {
+        let charts = BarChart::stacked(self.bar_charts());
 
         Plot::new("Stacked Bar Chart Demo")
             .legend(Legend::default())
             .data_aspect(1.0)
             .allow_drag(self.allow_drag)
             .show(ui, |plot_ui| {
-                plot_ui.bar_chart(chart1);
-                plot_ui.bar_chart(chart2);
-                plot_ui.bar_chart(chart3);
-                plot_ui.bar_chart(chart4);
+                for chart in charts {
+                    plot_ui.bar_chart(chart);
+                }
+            })
+            .response
+    }
+
+    fn bar_grouped(&self, ui: &mut Ui) -> Response {
+        let charts = BarChart::grouped(self.bar_charts());
+
+        Plot::new("Grouped Bar Chart Demo")
+            .legend(Legend::default())
+            .data_aspect(1.0)
+            .allow_drag(self.allow_drag)
+            .show(ui, |plot_ui| {
+                for chart in charts {
+                    plot_ui.bar_chart(chart);
+                }
             })
             .response
     }
@@ -1077,6 +1105,53 @@ impl ChartsDemo {
             })
             .response
     }
+
+    fn candle_stick(&self, ui: &mut Ui) -> Response {
+        let mut close = 100.0;
+        let candles = (0..60)
+            .map(|day| {
+                let t = day as f64;
+                let open = close;
+                close = open + 3.0 * (0.7 * t).sin() + 2.0 * (1.9 * t).cos();
+                let high = open.max(close) + 1.0 + (t * 1.3).sin().abs();
+                let low = open.min(close) - 1.0 - (t * 2.1).cos().abs();
+                Candle::new(t, open, high, low, close).name(format!("Day {}", day + 1))
+            })
+            .collect();
+        let chart = CandleStick::new(candles).width(0.6).name("Price");
+
+        Plot::new("Candlestick Demo")
+            .legend(Legend::default())
+            .allow_zoom(self.allow_zoom)
+            .allow_drag(self.allow_drag)
+            .allow_scroll(self.allow_scroll)
+            .show(ui, |plot_ui| plot_ui.candle_stick(chart))
+            .response
+    }
+
+    fn heatmap(&self, ui: &mut Ui) -> Response {
+        let size = 40;
+        let values = (0..size * size)
+            .map(|i| {
+                let x = (i % size) as f64 / size as f64 - 0.5;
+                let y = (i / size) as f64 / size as f64 - 0.5;
+                (10.0 * x).sin() * (10.0 * y).cos() + x
+            })
+            .collect();
+        let heatmap = Heatmap::new(values, size)
+            .cell_size([0.1, 0.1])
+            .color_map(ColorMap::Viridis)
+            .name("Waves");
+
+        Plot::new("Heatmap Demo")
+            .legend(Legend::default())
+            .data_aspect(1.0)
+            .allow_zoom(self.allow_zoom)
+            .allow_drag(self.allow_drag)
+            .allow_scroll(self.allow_scroll)
+            .show(ui, |plot_ui| plot_ui.heatmap(heatmap))
+            .response
+    }
 }
 
 fn is_approx_zero(val: f64) -> bool {
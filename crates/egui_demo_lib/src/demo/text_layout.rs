@@ -6,6 +6,7 @@ pub struct TextLayoutDemo {
     max_rows: usize,
     overflow_character: Option<char>,
     extra_letter_spacing_pixels: i32,
+    extra_word_spacing_pixels: i32,
     line_height_pixels: u32,
     lorem_ipsum: bool,
 }
@@ -17,6 +18,7 @@ impl Default for TextLayoutDemo {
             break_anywhere: true,
             overflow_character: Some('…'),
             extra_letter_spacing_pixels: 0,
+            extra_word_spacing_pixels: 0,
             line_height_pixels: 0,
             lorem_ipsum: true,
         }
@@ -46,6 +48,7 @@ impl super::View for TextLayoutDemo {
             max_rows,
             overflow_character,
             extra_letter_spacing_pixels,
+            extra_word_spacing_pixels,
             line_height_pixels,
             lorem_ipsum,
         } = self;
@@ -88,6 +91,10 @@ impl super::View for TextLayoutDemo {
                 ui.add(egui::DragValue::new(extra_letter_spacing_pixels).suffix(" pixels"));
                 ui.end_row();
 
+                ui.label("Extra word spacing:");
+                ui.add(egui::DragValue::new(extra_word_spacing_pixels).suffix(" pixels"));
+                ui.end_row();
+
                 ui.label("Line height:");
                 ui.horizontal(|ui| {
                     if ui
@@ -127,6 +134,7 @@ impl super::View for TextLayoutDemo {
             .auto_shrink(false)
             .show(ui, |ui| {
                 let extra_letter_spacing = points_per_pixel * *extra_letter_spacing_pixels as f32;
+                let extra_word_spacing = points_per_pixel * *extra_word_spacing_pixels as f32;
                 let line_height = (*line_height_pixels != 0)
                     .then_some(points_per_pixel * *line_height_pixels as f32);
 
@@ -134,6 +142,7 @@ impl super::View for TextLayoutDemo {
                     text.to_owned(),
                     egui::TextFormat {
                         extra_letter_spacing,
+                        extra_word_spacing,
                         line_height,
                         ..Default::default()
                     },
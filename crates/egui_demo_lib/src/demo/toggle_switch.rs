@@ -1,5 +1,7 @@
 //! Source code example of how to create your own widget.
 //! This is meant to be read as a tutorial, hence the plethora of comments.
+//!
+//! egui also ships a ready-made version of this widget: [`egui::ToggleSwitch`].
 
 /// iOS-style toggle switch:
 ///
@@ -38,7 +40,7 @@ pub fn toggle_ui(ui: &mut egui::Ui, on: &mut bool) -> egui::Response {
     }
 
     // Attach some meta-data to the response which can be used by screen readers:
-    response.widget_info(|| egui::WidgetInfo::selected(egui::WidgetType::Checkbox, *on, ""));
+    response.widget_info(|| egui::WidgetInfo::selected(egui::WidgetType::Switch, *on, ""));
 
     // 4. Paint!
     // Make sure we need to paint:
@@ -77,7 +79,7 @@ fn toggle_ui_compact(ui: &mut egui::Ui, on: &mut bool) -> egui::Response {
         *on = !*on;
         response.mark_changed();
     }
-    response.widget_info(|| egui::WidgetInfo::selected(egui::WidgetType::Checkbox, *on, ""));
+    response.widget_info(|| egui::WidgetInfo::selected(egui::WidgetType::Switch, *on, ""));
 
     if ui.is_rect_visible(rect) {
         let how_on = ui.ctx().animate_bool(response.id, *on);
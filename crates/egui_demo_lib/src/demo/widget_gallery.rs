@@ -151,6 +151,10 @@ impl WidgetGallery {
         ui.checkbox(boolean, "Checkbox");
         ui.end_row();
 
+        ui.add(doc_link_label("ToggleSwitch", "toggle_switch"));
+        ui.toggle_switch(boolean, "Toggle switch");
+        ui.end_row();
+
         ui.add(doc_link_label("RadioButton", "radio"));
         ui.horizontal(|ui| {
             ui.radio_value(radio, Enum::First, "First");
@@ -167,6 +171,15 @@ impl WidgetGallery {
         });
         ui.end_row();
 
+        ui.add(doc_link_label("SegmentedButton", "SegmentedButton"));
+        ui.add(
+            egui::SegmentedButton::new(radio)
+                .segment(Enum::First, "First")
+                .segment(Enum::Second, "Second")
+                .segment(Enum::Third, "Third"),
+        );
+        ui.end_row();
+
         ui.add(doc_link_label("ComboBox", "ComboBox"));
 
         egui::ComboBox::from_label("Take your pick")
@@ -7,6 +7,8 @@ pub struct WindowOptions {
     title_bar: bool,
     closable: bool,
     collapsible: bool,
+    maximize_button: bool,
+    modal: bool,
     resizable: bool,
     constrain: bool,
     scroll2: Vec2b,
@@ -24,6 +26,8 @@ impl Default for WindowOptions {
             title_bar: true,
             closable: true,
             collapsible: true,
+            maximize_button: false,
+            modal: false,
             resizable: true,
             constrain: true,
             scroll2: Vec2b::TRUE,
@@ -46,6 +50,8 @@ impl super::Demo for WindowOptions {
             title_bar,
             closable,
             collapsible,
+            maximize_button,
+            modal,
             resizable,
             constrain,
             scroll2,
@@ -66,6 +72,8 @@ impl super::Demo for WindowOptions {
             .resizable(resizable)
             .constrain(constrain)
             .collapsible(collapsible)
+            .maximize_button(maximize_button)
+            .modal(modal)
             .title_bar(title_bar)
             .scroll(scroll2)
             .enabled(enabled);
@@ -86,6 +94,8 @@ impl super::View for WindowOptions {
             title_bar,
             closable,
             collapsible,
+            maximize_button,
+            modal,
             resizable,
             constrain,
             scroll2,
@@ -105,6 +115,9 @@ impl super::View for WindowOptions {
                     ui.checkbox(title_bar, "title_bar");
                     ui.checkbox(closable, "closable");
                     ui.checkbox(collapsible, "collapsible");
+                    ui.checkbox(maximize_button, "maximize_button");
+                    ui.checkbox(modal, "modal")
+                        .on_hover_text("Block interaction with everything behind the window");
                     ui.checkbox(resizable, "resizable");
                     ui.checkbox(constrain, "constrain")
                         .on_hover_text("Constrain window to the screen");
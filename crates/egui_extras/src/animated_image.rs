@@ -0,0 +1,319 @@
+use std::io::Cursor;
+
+use egui::{ColorImage, TextureHandle, TextureOptions};
+use image::{AnimationDecoder as _, ImageFormat};
+
+/// Browsers show frames with a delay this short or shorter for [`DEFAULT_FRAME_DELAY`] instead.
+const MIN_FRAME_DELAY: f64 = 0.011;
+
+/// Delay for frames that don't specify one (or specify a too short one).
+const DEFAULT_FRAME_DELAY: f64 = 0.1;
+
+struct Frame {
+    /// Cleared once [`Self::texture`] has been loaded.
+    image: Option<ColorImage>,
+
+    /// Lazily loaded the first time the frame is shown.
+    texture: Option<TextureHandle>,
+
+    /// When this frame starts, in seconds since the start of the animation.
+    start: f64,
+}
+
+/// An animated GIF, `APNG` or `WebP` image.
+///
+/// All frames are decoded up front, but only uploaded to the GPU once they are shown.
+/// The animation advances with [`egui::InputState::time`], and a repaint is requested
+/// for when the next frame is due.
+///
+/// Load once, and save somewhere in your app state.
+///
+/// Non-animated images are shown as a single frame.
+///
+/// Requires the `animated_image` feature.
+pub struct AnimatedImage {
+    debug_name: String,
+
+    size: [usize; 2],
+
+    frames: Vec<Frame>,
+
+    /// The length of one loop of the animation, in seconds.
+    duration: f64,
+
+    options: TextureOptions,
+
+    paused: bool,
+
+    looping: bool,
+
+    speed: f32,
+
+    /// How far into the animation we are, in seconds.
+    position: f64,
+
+    /// [`egui::InputState::time`] when we last advanced [`Self::position`].
+    last_time: Option<f64>,
+}
+
+impl AnimatedImage {
+    /// Decode all the frames of an animated image.
+    ///
+    /// `image_bytes` should be the raw contents of a `.gif`, `.png` (APNG) or `.webp` file.
+    /// Other formats supported by [`crate::image::load_image_bytes`] are shown as a single frame.
+    ///
+    /// # Errors
+    /// On invalid image or unsupported image format.
+    pub fn from_image_bytes(
+        debug_name: impl Into<String>,
+        image_bytes: &[u8],
+    ) -> Result<Self, String> {
+        crate::profile_function!();
+        let frames = decode_frames(image_bytes).map_err(|err| err.to_string())?;
+        let frames = match frames {
+            Some(frames) if !frames.is_empty() => frames,
+            _ => vec![(
+                crate::image::load_image_bytes(image_bytes)?,
+                DEFAULT_FRAME_DELAY,
+            )],
+        };
+
+        let size = frames[0].0.size;
+        let mut duration = 0.0;
+        let frames = frames
+            .into_iter()
+            .map(|(image, delay)| {
+                let start = duration;
+                duration += delay;
+                Frame {
+                    image: Some(image),
+                    texture: None,
+                    start,
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            debug_name: debug_name.into(),
+            size,
+            frames,
+            duration,
+            options: Default::default(),
+            paused: false,
+            looping: true,
+            speed: 1.0,
+            position: 0.0,
+            last_time: None,
+        })
+    }
+
+    /// Set the texture filters to use for the frames.
+    ///
+    /// Frames that have already been uploaded to the GPU keep their old filter.
+    #[inline]
+    pub fn with_options(mut self, options: TextureOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Should the animation start over when it reaches the end?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn with_looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Playback speed, where `1.0` is normal speed.
+    ///
+    /// Default: `1.0`.
+    #[inline]
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.set_speed(speed);
+        self
+    }
+
+    /// Pause or resume the animation.
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    /// Is the animation paused?
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Should the animation start over when it reaches the end?
+    pub fn set_looping(&mut self, looping: bool) {
+        self.looping = looping;
+    }
+
+    /// Does the animation start over when it reaches the end?
+    pub fn is_looping(&self) -> bool {
+        self.looping
+    }
+
+    /// Set the playback speed, where `1.0` is normal speed.
+    ///
+    /// Negative speeds are treated as zero.
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed.max(0.0);
+    }
+
+    /// The playback speed, where `1.0` is normal speed.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Jump back to the first frame.
+    pub fn restart(&mut self) {
+        self.position = 0.0;
+    }
+
+    /// Has a non-looping animation reached its end?
+    pub fn is_finished(&self) -> bool {
+        !self.looping && self.position >= self.duration
+    }
+
+    /// The number of frames in the animation.
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// The index of the frame that is currently shown.
+    pub fn current_frame(&self) -> usize {
+        // The frame that started last, at or before `position`:
+        self.frames
+            .partition_point(|frame| frame.start <= self.position)
+            .saturating_sub(1)
+    }
+
+    /// The size of the image data (number of pixels wide/high).
+    pub fn size(&self) -> [usize; 2] {
+        self.size
+    }
+
+    /// The size of the image data (number of pixels wide/high).
+    pub fn size_vec2(&self) -> egui::Vec2 {
+        let [w, h] = self.size();
+        egui::vec2(w as f32, h as f32)
+    }
+
+    /// The debug name of the image, e.g. the file name.
+    pub fn debug_name(&self) -> &str {
+        &self.debug_name
+    }
+
+    /// Advance the animation to the current time, and request a repaint for the next frame.
+    fn advance(&mut self, ctx: &egui::Context) {
+        let now = ctx.input(|i| i.time);
+        let dt = self.last_time.map_or(0.0, |last_time| now - last_time);
+        self.last_time = Some(now);
+
+        if self.paused || self.frames.len() <= 1 || self.duration <= 0.0 {
+            return;
+        }
+
+        self.position += dt * self.speed as f64;
+        if self.looping {
+            self.position %= self.duration;
+        } else if self.position >= self.duration {
+            self.position = self.duration;
+            return;
+        }
+
+        if 0.0 < self.speed {
+            let next_frame_start = self
+                .frames
+                .get(self.current_frame() + 1)
+                .map_or(self.duration, |frame| frame.start);
+            let delay = (next_frame_start - self.position) / self.speed as f64;
+            ctx.request_repaint_after(std::time::Duration::from_secs_f64(delay.max(0.0)));
+        }
+    }
+
+    /// The texture id of the current frame.
+    ///
+    /// This advances the animation, so call it once per frame.
+    pub fn texture_id(&mut self, ctx: &egui::Context) -> egui::TextureId {
+        self.advance(ctx);
+
+        let index = self.current_frame();
+        let frame = &mut self.frames[index];
+        frame
+            .texture
+            .get_or_insert_with(|| {
+                let image = frame.image.take().unwrap_or_default();
+                let name = format!("{} #{index}", self.debug_name);
+                ctx.load_texture(name, image, self.options)
+            })
+            .id()
+    }
+
+    /// Show the image with the given maximum size.
+    pub fn show_max_size(&mut self, ui: &mut egui::Ui, max_size: egui::Vec2) -> egui::Response {
+        let mut desired_size = self.size_vec2();
+        desired_size *= (max_size.x / desired_size.x).min(1.0);
+        desired_size *= (max_size.y / desired_size.y).min(1.0);
+        self.show_size(ui, desired_size)
+    }
+
+    /// Show the image with the original size (one image pixel = one gui point).
+    pub fn show(&mut self, ui: &mut egui::Ui) -> egui::Response {
+        self.show_size(ui, self.size_vec2())
+    }
+
+    /// Show the image with the given scale factor (1.0 = original size).
+    pub fn show_scaled(&mut self, ui: &mut egui::Ui, scale: f32) -> egui::Response {
+        self.show_size(ui, self.size_vec2() * scale)
+    }
+
+    /// Show the image with the given size.
+    pub fn show_size(&mut self, ui: &mut egui::Ui, desired_size: egui::Vec2) -> egui::Response {
+        ui.image((self.texture_id(ui.ctx()), desired_size))
+    }
+}
+
+/// Decode the frames of an animated image, together with their delays in seconds.
+///
+/// Returns `None` if the image is not animated.
+fn decode_frames(image_bytes: &[u8]) -> image::ImageResult<Option<Vec<(ColorImage, f64)>>> {
+    let reader = Cursor::new(image_bytes);
+    let frames = match image::guess_format(image_bytes)? {
+        ImageFormat::Gif => image::codecs::gif::GifDecoder::new(reader)?.into_frames(),
+        ImageFormat::Png => {
+            let decoder = image::codecs::png::PngDecoder::new(reader)?;
+            if !decoder.is_apng()? {
+                return Ok(None);
+            }
+            decoder.apng()?.into_frames()
+        }
+        ImageFormat::WebP => {
+            let decoder = image::codecs::webp::WebPDecoder::new(reader)?;
+            if !decoder.has_animation() {
+                return Ok(None);
+            }
+            decoder.into_frames()
+        }
+        _ => return Ok(None),
+    };
+
+    let frames = frames
+        .map(|frame| {
+            let frame = frame?;
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            let mut delay = numer as f64 / denom.max(1) as f64 / 1000.0;
+            if delay < MIN_FRAME_DELAY {
+                delay = DEFAULT_FRAME_DELAY;
+            }
+            let buffer = frame.into_buffer();
+            let size = [buffer.width() as _, buffer.height() as _];
+            let image =
+                ColorImage::from_rgba_unmultiplied(size, buffer.as_flat_samples().as_slice());
+            Ok((image, delay))
+        })
+        .collect::<image::ImageResult<_>>()?;
+
+    Ok(Some(frames))
+}
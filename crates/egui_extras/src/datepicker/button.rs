@@ -1,5 +1,5 @@
-use super::popup::DatePickerPopup;
-use chrono::NaiveDate;
+use super::popup::{DatePickerPopup, DateSelection};
+use chrono::{NaiveDate, NaiveTime};
 use egui::{Area, Button, Frame, InnerResponse, Key, Order, RichText, Ui, Widget};
 
 #[derive(Default, Clone, serde::Deserialize, serde::Serialize)]
@@ -8,8 +8,11 @@ pub(crate) struct DatePickerButtonState {
 }
 
 /// Shows a date, and will open a date picker popup when clicked.
+///
+/// Can also pick a time of day with [`Self::time`], or a range of dates with [`Self::new_range`].
 pub struct DatePickerButton<'a> {
-    selection: &'a mut NaiveDate,
+    selection: DateSelection<'a>,
+    time: Option<&'a mut NaiveTime>,
     id_source: Option<&'a str>,
     combo_boxes: bool,
     arrows: bool,
@@ -18,12 +21,27 @@ pub struct DatePickerButton<'a> {
     show_icon: bool,
     format: String,
     highlight_weekends: bool,
+    min_date: Option<NaiveDate>,
+    max_date: Option<NaiveDate>,
 }
 
 impl<'a> DatePickerButton<'a> {
     pub fn new(selection: &'a mut NaiveDate) -> Self {
+        Self::from_selection(DateSelection::Single(selection))
+    }
+
+    /// Pick a range of dates, as an inclusive `(start, end)` pair.
+    ///
+    /// The popup shows two months side by side.
+    /// The first click picks the start of the range, and the second click the end.
+    pub fn new_range(selection: &'a mut (NaiveDate, NaiveDate)) -> Self {
+        Self::from_selection(DateSelection::Range(selection))
+    }
+
+    fn from_selection(selection: DateSelection<'a>) -> Self {
         Self {
             selection,
+            time: None,
             id_source: None,
             combo_boxes: true,
             arrows: true,
@@ -32,6 +50,8 @@ impl<'a> DatePickerButton<'a> {
             show_icon: true,
             format: "%Y-%m-%d".to_owned(),
             highlight_weekends: true,
+            min_date: None,
+            max_date: None,
         }
     }
 
@@ -92,6 +112,29 @@ impl<'a> DatePickerButton<'a> {
         self.highlight_weekends = highlight_weekends;
         self
     }
+
+    /// Also pick a time of day (hours and minutes).
+    ///
+    /// The time is shown on the button after the date.
+    #[inline]
+    pub fn time(mut self, time: &'a mut NaiveTime) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// Dates before this can not be picked. (Default: None)
+    #[inline]
+    pub fn min_date(mut self, min_date: NaiveDate) -> Self {
+        self.min_date = Some(min_date);
+        self
+    }
+
+    /// Dates after this can not be picked. (Default: None)
+    #[inline]
+    pub fn max_date(mut self, max_date: NaiveDate) -> Self {
+        self.max_date = Some(max_date);
+        self
+    }
 }
 
 impl<'a> Widget for DatePickerButton<'a> {
@@ -101,11 +144,21 @@ impl<'a> Widget for DatePickerButton<'a> {
             .data_mut(|data| data.get_persisted::<DatePickerButtonState>(id))
             .unwrap_or_default();
 
-        let mut text = if self.show_icon {
-            RichText::new(format!("{} 📆", self.selection.format(&self.format)))
-        } else {
-            RichText::new(format!("{}", self.selection.format(&self.format)))
+        let mut text = match &self.selection {
+            DateSelection::Single(date) => date.format(&self.format).to_string(),
+            DateSelection::Range((start, end)) => format!(
+                "{} – {}",
+                start.format(&self.format),
+                end.format(&self.format)
+            ),
         };
+        if let Some(time) = &self.time {
+            text = format!("{text} {}", time.format("%H:%M"));
+        }
+        if self.show_icon {
+            text = format!("{text} 📆");
+        }
+        let mut text = RichText::new(text);
         let visuals = ui.visuals().widgets.open;
         if button_state.picker_visible {
             text = text.color(visuals.text_color());
@@ -121,7 +174,11 @@ impl<'a> Widget for DatePickerButton<'a> {
         }
 
         if button_state.picker_visible {
-            let width = 333.0;
+            let width = if self.selection.is_range() {
+                666.0
+            } else {
+                333.0
+            };
             let mut pos = button_response.rect.left_bottom();
             let width_with_padding = width
                 + ui.style().spacing.item_spacing.x
@@ -152,12 +209,15 @@ impl<'a> Widget for DatePickerButton<'a> {
 
                             DatePickerPopup {
                                 selection: self.selection,
+                                time: self.time,
                                 button_id: id,
                                 combo_boxes: self.combo_boxes,
                                 arrows: self.arrows,
                                 calendar: self.calendar,
                                 calendar_week: self.calendar_week,
                                 highlight_weekends: self.highlight_weekends,
+                                min_date: self.min_date,
+                                max_date: self.max_date,
                             }
                             .draw(ui)
                         })
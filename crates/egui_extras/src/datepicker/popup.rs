@@ -1,6 +1,8 @@
-use chrono::{Datelike, NaiveDate, Weekday};
+use chrono::{Datelike, NaiveDate, NaiveTime, Timelike, Weekday};
 
-use egui::{Align, Button, Color32, ComboBox, Direction, Id, Layout, RichText, Ui, Vec2};
+use egui::{
+    Align, Button, Color32, ComboBox, Direction, DragValue, Id, Layout, RichText, Ui, Vec2,
+};
 
 use super::{button::DatePickerButtonState, month_data};
 
@@ -8,13 +10,94 @@ use crate::{Column, Size, StripBuilder, TableBuilder};
 
 #[derive(Default, Clone, serde::Deserialize, serde::Serialize)]
 struct DatePickerPopupState {
+    /// The date shown by the combo boxes and the calendar.
+    ///
+    /// When picking a single date, this is the picked date.
     year: i32,
     month: u32,
     day: u32,
     setup: bool,
+
+    /// The start of the range, when picking a range.
+    start_year: i32,
+    start_month: u32,
+    start_day: u32,
+
+    /// The end of the range, when picking a range.
+    end_year: i32,
+    end_month: u32,
+    end_day: u32,
+
+    /// The next click picks the end of the range.
+    picking_end: bool,
+
+    hour: u32,
+    minute: u32,
 }
 
 impl DatePickerPopupState {
+    fn date(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year, self.month, self.day)
+    }
+
+    fn start_date(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.start_year, self.start_month, self.start_day)
+    }
+
+    fn end_date(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.end_year, self.end_month, self.end_day)
+    }
+
+    fn set_date(&mut self, date: NaiveDate) {
+        self.year = date.year();
+        self.month = date.month();
+        self.day = date.day();
+    }
+
+    fn set_start_date(&mut self, date: NaiveDate) {
+        self.start_year = date.year();
+        self.start_month = date.month();
+        self.start_day = date.day();
+    }
+
+    fn set_end_date(&mut self, date: NaiveDate) {
+        self.end_year = date.year();
+        self.end_month = date.month();
+        self.end_day = date.day();
+    }
+
+    /// The picked start and end date, which are the same when picking a single date.
+    fn picked(&self, range: bool) -> Option<(NaiveDate, NaiveDate)> {
+        if range {
+            self.start_date().zip(self.end_date())
+        } else {
+            self.date().map(|date| (date, date))
+        }
+    }
+
+    /// A day was clicked in the calendar.
+    ///
+    /// When picking a range, this doesn't change the shown month,
+    /// so the calendar doesn't jump when clicking a day of the next month.
+    fn select_day(&mut self, day: NaiveDate, range: bool) {
+        if !range {
+            self.set_date(day);
+            return;
+        }
+        match self.start_date() {
+            Some(start) if self.picking_end => {
+                self.set_start_date(start.min(day));
+                self.set_end_date(start.max(day));
+                self.picking_end = false;
+            }
+            _ => {
+                self.set_start_date(day);
+                self.set_end_date(day);
+                self.picking_end = true;
+            }
+        }
+    }
+
     fn last_day_of_month(&self) -> u32 {
         let date: NaiveDate =
             NaiveDate::from_ymd_opt(self.year, self.month, 1).expect("Could not create NaiveDate");
@@ -26,14 +109,43 @@ impl DatePickerPopupState {
     }
 }
 
+/// What a date picker picks.
+pub(crate) enum DateSelection<'a> {
+    Single(&'a mut NaiveDate),
+    Range(&'a mut (NaiveDate, NaiveDate)),
+}
+
+impl<'a> DateSelection<'a> {
+    pub fn is_range(&self) -> bool {
+        matches!(self, Self::Range(_))
+    }
+
+    fn start(&self) -> NaiveDate {
+        match self {
+            Self::Single(date) => **date,
+            Self::Range(range) => range.0,
+        }
+    }
+
+    fn end(&self) -> NaiveDate {
+        match self {
+            Self::Single(date) => **date,
+            Self::Range(range) => range.1,
+        }
+    }
+}
+
 pub(crate) struct DatePickerPopup<'a> {
-    pub selection: &'a mut NaiveDate,
+    pub selection: DateSelection<'a>,
+    pub time: Option<&'a mut NaiveTime>,
     pub button_id: Id,
     pub combo_boxes: bool,
     pub arrows: bool,
     pub calendar: bool,
     pub calendar_week: bool,
     pub highlight_weekends: bool,
+    pub min_date: Option<NaiveDate>,
+    pub max_date: Option<NaiveDate>,
 }
 
 impl<'a> DatePickerPopup<'a> {
@@ -41,18 +153,38 @@ impl<'a> DatePickerPopup<'a> {
     pub fn draw(&mut self, ui: &mut Ui) -> bool {
         let id = ui.make_persistent_id("date_picker");
         let today = chrono::offset::Utc::now().date_naive();
+        let range = self.selection.is_range();
         let mut popup_state = ui
             .data_mut(|data| data.get_persisted::<DatePickerPopupState>(id))
             .unwrap_or_default();
         if !popup_state.setup {
-            popup_state.year = self.selection.year();
-            popup_state.month = self.selection.month();
-            popup_state.day = self.selection.day();
+            popup_state.set_date(self.selection.start());
+            popup_state.set_start_date(self.selection.start());
+            popup_state.set_end_date(self.selection.end());
+            popup_state.picking_end = false;
+            if let Some(time) = &self.time {
+                popup_state.hour = time.hour();
+                popup_state.minute = time.minute();
+            }
             popup_state.setup = true;
             ui.data_mut(|data| data.insert_persisted(id, popup_state.clone()));
         }
 
-        let weeks = month_data(popup_state.year, popup_state.month);
+        // When picking a range, we also show the month after the current one:
+        let (next_year, next_month) = if popup_state.month == 12 {
+            (popup_state.year + 1, 1)
+        } else {
+            (popup_state.year, popup_state.month + 1)
+        };
+        let num_weeks = if range {
+            month_data(popup_state.year, popup_state.month)
+                .len()
+                .max(month_data(next_year, next_month).len())
+        } else {
+            month_data(popup_state.year, popup_state.month).len()
+        };
+        let years = self.min_date.map_or(today.year() - 100, |date| date.year())
+            ..=self.max_date.map_or(today.year() + 9, |date| date.year());
         let (mut close, mut saved) = (false, false);
         let height = 20.0;
         let spacing = 2.0;
@@ -68,9 +200,10 @@ impl<'a> DatePickerPopup<'a> {
                 },
             )
             .sizes(
-                Size::exact((spacing + height) * (weeks.len() + 1) as f32),
+                Size::exact((spacing + height) * (num_weeks + 1) as f32),
                 self.calendar as usize,
             )
+            .sizes(Size::exact(height), self.time.is_some() as usize)
             .size(Size::exact(height))
             .vertical(|mut strip| {
                 if self.combo_boxes {
@@ -80,7 +213,7 @@ impl<'a> DatePickerPopup<'a> {
                                 ComboBox::from_id_source("date_picker_year")
                                     .selected_text(popup_state.year.to_string())
                                     .show_ui(ui, |ui| {
-                                        for year in today.year() - 100..today.year() + 10 {
+                                        for year in years.clone() {
                                             if ui
                                                 .selectable_value(
                                                     &mut popup_state.year,
@@ -257,120 +390,48 @@ impl<'a> DatePickerPopup<'a> {
                 }
 
                 if self.calendar {
-                    strip.cell(|ui| {
-                        ui.spacing_mut().item_spacing = Vec2::new(1.0, 2.0);
-                        TableBuilder::new(ui)
-                            .vscroll(false)
-                            .columns(Column::remainder(), if self.calendar_week { 8 } else { 7 })
-                            .header(height, |mut header| {
-                                if self.calendar_week {
-                                    header.col(|ui| {
-                                        ui.with_layout(
-                                            Layout::centered_and_justified(Direction::TopDown),
-                                            |ui| {
-                                                ui.label("Week");
-                                            },
-                                        );
-                                    });
-                                }
-
-                                //TODO(elwerene): Locale
-                                for name in ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"] {
-                                    header.col(|ui| {
-                                        ui.with_layout(
-                                            Layout::centered_and_justified(Direction::TopDown),
-                                            |ui| {
-                                                ui.label(name);
-                                            },
-                                        );
-                                    });
-                                }
-                            })
-                            .body(|mut body| {
-                                for week in weeks {
-                                    body.row(height, |mut row| {
-                                        if self.calendar_week {
-                                            row.col(|ui| {
-                                                ui.label(week.number.to_string());
-                                            });
-                                        }
-                                        for day in week.days {
-                                            row.col(|ui| {
-                                                ui.with_layout(
-                                                    Layout::top_down_justified(Align::Center),
-                                                    |ui| {
-                                                        let fill_color = if popup_state.year
-                                                            == day.year()
-                                                            && popup_state.month == day.month()
-                                                            && popup_state.day == day.day()
-                                                        {
-                                                            ui.visuals().selection.bg_fill
-                                                        } else if (day.weekday() == Weekday::Sat
-                                                            || day.weekday() == Weekday::Sun)
-                                                            && self.highlight_weekends
-                                                        {
-                                                            if ui.visuals().dark_mode {
-                                                                Color32::DARK_RED
-                                                            } else {
-                                                                Color32::LIGHT_RED
-                                                            }
-                                                        } else {
-                                                            ui.visuals().extreme_bg_color
-                                                        };
-
-                                                        let mut text_color = ui
-                                                            .visuals()
-                                                            .widgets
-                                                            .inactive
-                                                            .text_color();
-
-                                                        if day.month() != popup_state.month {
-                                                            text_color =
-                                                                text_color.linear_multiply(0.5);
-                                                        };
-
-                                                        let button_response = ui.add(
-                                                            Button::new(
-                                                                RichText::new(
-                                                                    day.day().to_string(),
-                                                                )
-                                                                .color(text_color),
-                                                            )
-                                                            .fill(fill_color),
-                                                        );
-
-                                                        if day == today {
-                                                            // Encircle today's date
-                                                            let stroke = ui
-                                                                .visuals()
-                                                                .widgets
-                                                                .inactive
-                                                                .fg_stroke;
-                                                            ui.painter().circle_stroke(
-                                                                button_response.rect.center(),
-                                                                8.0,
-                                                                stroke,
-                                                            );
-                                                        }
-
-                                                        if button_response.clicked() {
-                                                            popup_state.year = day.year();
-                                                            popup_state.month = day.month();
-                                                            popup_state.day = day.day();
-                                                            ui.data_mut(|data| {
-                                                                data.insert_persisted(
-                                                                    id,
-                                                                    popup_state.clone(),
-                                                                );
-                                                            });
-                                                        }
-                                                    },
-                                                );
-                                            });
-                                        }
-                                    });
-                                }
+                    if range {
+                        strip.strip(|builder| {
+                            builder.sizes(Size::remainder(), 2).horizontal(|mut strip| {
+                                strip.cell(|ui| {
+                                    let month = (popup_state.year, popup_state.month);
+                                    self.calendar_ui(ui, &mut popup_state, id, month, height);
+                                });
+                                strip.cell(|ui| {
+                                    let month = (next_year, next_month);
+                                    self.calendar_ui(ui, &mut popup_state, id, month, height);
+                                });
                             });
+                        });
+                    } else {
+                        strip.cell(|ui| {
+                            let month = (popup_state.year, popup_state.month);
+                            self.calendar_ui(ui, &mut popup_state, id, month, height);
+                        });
+                    }
+                }
+
+                if self.time.is_some() {
+                    strip.cell(|ui| {
+                        ui.horizontal_centered(|ui| {
+                            ui.label("Time:");
+                            let hour = ui.add(
+                                DragValue::new(&mut popup_state.hour)
+                                    .clamp_range(0..=23)
+                                    .custom_formatter(|n, _| format!("{n:02}")),
+                            );
+                            ui.label(":");
+                            let minute = ui.add(
+                                DragValue::new(&mut popup_state.minute)
+                                    .clamp_range(0..=59)
+                                    .custom_formatter(|n, _| format!("{n:02}")),
+                            );
+                            if hour.changed() || minute.changed() {
+                                ui.data_mut(|data| {
+                                    data.insert_persisted(id, popup_state.clone());
+                                });
+                            }
+                        });
                     });
                 }
 
@@ -386,13 +447,27 @@ impl<'a> DatePickerPopup<'a> {
                         });
                         strip.cell(|ui| {
                             ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                if ui.button("Save").clicked() {
-                                    *self.selection = NaiveDate::from_ymd_opt(
-                                        popup_state.year,
-                                        popup_state.month,
-                                        popup_state.day,
-                                    )
-                                    .expect("Could not create NaiveDate");
+                                let picked = popup_state.picked(range).filter(|&(start, end)| {
+                                    self.is_allowed(start) && self.is_allowed(end)
+                                });
+                                let save = ui
+                                    .add_enabled(picked.is_some(), Button::new("Save"))
+                                    .on_disabled_hover_text(
+                                        "The date is outside the allowed range",
+                                    );
+                                if let Some((start, end)) = picked.filter(|_| save.clicked()) {
+                                    match &mut self.selection {
+                                        DateSelection::Single(date) => **date = start,
+                                        DateSelection::Range(range) => **range = (start, end),
+                                    }
+                                    if let Some(time) = &mut self.time {
+                                        **time = NaiveTime::from_hms_opt(
+                                            popup_state.hour,
+                                            popup_state.minute,
+                                            0,
+                                        )
+                                        .expect("Could not create NaiveTime");
+                                    }
                                     saved = true;
                                     close = true;
                                 }
@@ -413,6 +488,130 @@ impl<'a> DatePickerPopup<'a> {
 
         saved && close
     }
+
+    /// Is the date within [`Self::min_date`] and [`Self::max_date`]?
+    fn is_allowed(&self, date: NaiveDate) -> bool {
+        self.min_date.map_or(true, |min_date| min_date <= date)
+            && self.max_date.map_or(true, |max_date| date <= max_date)
+    }
+
+    /// Show the calendar for one month.
+    fn calendar_ui(
+        &self,
+        ui: &mut Ui,
+        popup_state: &mut DatePickerPopupState,
+        id: Id,
+        (year, month): (i32, u32),
+        height: f32,
+    ) {
+        let today = chrono::offset::Utc::now().date_naive();
+        let range = self.selection.is_range();
+        ui.spacing_mut().item_spacing = Vec2::new(1.0, 2.0);
+        ui.push_id((year, month), |ui| {
+            TableBuilder::new(ui)
+                .vscroll(false)
+                .columns(Column::remainder(), if self.calendar_week { 8 } else { 7 })
+                .header(height, |mut header| {
+                    if self.calendar_week {
+                        header.col(|ui| {
+                            ui.with_layout(
+                                Layout::centered_and_justified(Direction::TopDown),
+                                |ui| {
+                                    ui.label("Week");
+                                },
+                            );
+                        });
+                    }
+
+                    //TODO(elwerene): Locale
+                    for name in ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"] {
+                        header.col(|ui| {
+                            ui.with_layout(
+                                Layout::centered_and_justified(Direction::TopDown),
+                                |ui| {
+                                    ui.label(name);
+                                },
+                            );
+                        });
+                    }
+                })
+                .body(|mut body| {
+                    for week in month_data(year, month) {
+                        body.row(height, |mut row| {
+                            if self.calendar_week {
+                                row.col(|ui| {
+                                    ui.label(week.number.to_string());
+                                });
+                            }
+                            for day in week.days {
+                                row.col(|ui| {
+                                    ui.with_layout(
+                                        Layout::top_down_justified(Align::Center),
+                                        |ui| {
+                                            let picked = popup_state.picked(range);
+                                            let start = picked.map(|(start, _)| start);
+                                            let end = picked.map(|(_, end)| end);
+                                            let fill_color = if Some(day) == start
+                                                || Some(day) == end
+                                            {
+                                                ui.visuals().selection.bg_fill
+                                            } else if start < Some(day) && Some(day) < end {
+                                                ui.visuals().selection.bg_fill.linear_multiply(0.4)
+                                            } else if (day.weekday() == Weekday::Sat
+                                                || day.weekday() == Weekday::Sun)
+                                                && self.highlight_weekends
+                                            {
+                                                if ui.visuals().dark_mode {
+                                                    Color32::DARK_RED
+                                                } else {
+                                                    Color32::LIGHT_RED
+                                                }
+                                            } else {
+                                                ui.visuals().extreme_bg_color
+                                            };
+
+                                            let mut text_color =
+                                                ui.visuals().widgets.inactive.text_color();
+
+                                            if day.month() != month {
+                                                text_color = text_color.linear_multiply(0.5);
+                                            };
+
+                                            let button_response = ui.add_enabled(
+                                                self.is_allowed(day),
+                                                Button::new(
+                                                    RichText::new(day.day().to_string())
+                                                        .color(text_color),
+                                                )
+                                                .fill(fill_color),
+                                            );
+
+                                            if day == today {
+                                                // Encircle today's date
+                                                let stroke =
+                                                    ui.visuals().widgets.inactive.fg_stroke;
+                                                ui.painter().circle_stroke(
+                                                    button_response.rect.center(),
+                                                    8.0,
+                                                    stroke,
+                                                );
+                                            }
+
+                                            if button_response.clicked() {
+                                                popup_state.select_day(day, range);
+                                                ui.data_mut(|data| {
+                                                    data.insert_persisted(id, popup_state.clone());
+                                                });
+                                            }
+                                        },
+                                    );
+                                });
+                            }
+                        });
+                    }
+                });
+        });
+    }
 }
 
 fn month_name(i: u32) -> &'static str {
@@ -0,0 +1,674 @@
+//! Tabbed panes that can be split, rearranged with drag-and-drop, and torn out into windows.
+//!
+//! The layout is a tree of [`DockNode`]s, kept in a [`DockState`] that you can serialize.
+
+use std::hash::Hash;
+
+use egui::{
+    pos2, vec2, Align, CursorIcon, Id, LayerId, Layout, Order, Pos2, Rect, Sense, Ui, Vec2,
+};
+
+/// How the two children of a [`DockNode::Split`] are laid out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum SplitDirection {
+    /// Side by side.
+    Horizontal,
+
+    /// On top of each other.
+    Vertical,
+}
+
+/// A node in the layout tree of a [`DockArea`].
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub enum DockNode<Tab> {
+    /// A group of tabs, of which the active one is shown.
+    Tabs { tabs: Vec<Tab>, active: usize },
+
+    /// Two nodes next to each other.
+    Split {
+        direction: SplitDirection,
+
+        /// How much of the space goes to the first child, in `0..=1`.
+        fraction: f32,
+
+        children: Box<[DockNode<Tab>; 2]>,
+    },
+}
+
+impl<Tab> Default for DockNode<Tab> {
+    fn default() -> Self {
+        Self::tabs(Vec::new())
+    }
+}
+
+impl<Tab> DockNode<Tab> {
+    /// A group of tabs, with the first one active.
+    pub fn tabs(tabs: Vec<Tab>) -> Self {
+        Self::Tabs { tabs, active: 0 }
+    }
+
+    /// `left` and `right` side by side, where `left` gets `fraction` of the width.
+    pub fn horizontal(fraction: f32, left: Self, right: Self) -> Self {
+        Self::Split {
+            direction: SplitDirection::Horizontal,
+            fraction,
+            children: Box::new([left, right]),
+        }
+    }
+
+    /// `top` above `bottom`, where `top` gets `fraction` of the height.
+    pub fn vertical(fraction: f32, top: Self, bottom: Self) -> Self {
+        Self::Split {
+            direction: SplitDirection::Vertical,
+            fraction,
+            children: Box::new([top, bottom]),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self, Self::Tabs { tabs, .. } if tabs.is_empty())
+    }
+
+    fn node_mut(&mut self, path: &[usize]) -> Option<&mut Self> {
+        match (path.split_first(), self) {
+            (None, node) => Some(node),
+            (Some((&child, rest)), Self::Split { children, .. }) => {
+                children.get_mut(child)?.node_mut(rest)
+            }
+            (Some(_), Self::Tabs { .. }) => None,
+        }
+    }
+
+    fn first_tabs_mut(&mut self) -> (&mut Vec<Tab>, &mut usize) {
+        match self {
+            Self::Tabs { tabs, active } => (tabs, active),
+            Self::Split { children, .. } => children[0].first_tabs_mut(),
+        }
+    }
+
+    fn collect_tabs<'a>(&'a self, all_tabs: &mut Vec<&'a Tab>) {
+        match self {
+            Self::Tabs { tabs, .. } => all_tabs.extend(tabs),
+            Self::Split { children, .. } => {
+                children[0].collect_tabs(all_tabs);
+                children[1].collect_tabs(all_tabs);
+            }
+        }
+    }
+
+    /// Add a tab to this group of tabs, or split it to put the tab next to it.
+    fn insert(&mut self, tab: Tab, zone: DropZone) {
+        let (direction, new_first) = match zone {
+            DropZone::Center => {
+                let (tabs, active) = self.first_tabs_mut();
+                tabs.push(tab);
+                *active = tabs.len() - 1;
+                return;
+            }
+            DropZone::Left => (SplitDirection::Horizontal, true),
+            DropZone::Right => (SplitDirection::Horizontal, false),
+            DropZone::Top => (SplitDirection::Vertical, true),
+            DropZone::Bottom => (SplitDirection::Vertical, false),
+        };
+        let new = Self::tabs(vec![tab]);
+        let old = std::mem::take(self);
+        *self = Self::Split {
+            direction,
+            fraction: 0.5,
+            children: Box::new(if new_first { [new, old] } else { [old, new] }),
+        };
+    }
+
+    /// Replace splits with an empty side by their other side.
+    fn simplify(&mut self) {
+        if let Self::Split { children, .. } = self {
+            children[0].simplify();
+            children[1].simplify();
+            if children[0].is_empty() {
+                *self = std::mem::take(&mut children[1]);
+            } else if children[1].is_empty() {
+                *self = std::mem::take(&mut children[0]);
+            }
+        }
+    }
+}
+
+/// Tabs that have been torn out of a [`DockArea`] into their own window.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct DockWindow<Tab> {
+    pub id: Id,
+    pub rect: Rect,
+    pub root: DockNode<Tab>,
+}
+
+/// The layout of a [`DockArea`].
+///
+/// Store it in your app state, and serialize it to save the layout.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct DockState<Tab> {
+    /// The layout inside the [`DockArea`].
+    pub root: DockNode<Tab>,
+
+    /// Tabs that have been torn out into their own windows.
+    pub windows: Vec<DockWindow<Tab>>,
+}
+
+impl<Tab> DockState<Tab> {
+    pub fn new(root: DockNode<Tab>) -> Self {
+        Self {
+            root,
+            windows: Vec::new(),
+        }
+    }
+
+    /// Add a tab to the first group of tabs, and make it active.
+    pub fn push_tab(&mut self, tab: Tab) {
+        self.root.insert(tab, DropZone::Center);
+    }
+
+    /// All tabs, including the ones in windows.
+    pub fn all_tabs(&self) -> Vec<&Tab> {
+        let mut all_tabs = Vec::new();
+        self.root.collect_tabs(&mut all_tabs);
+        for window in &self.windows {
+            window.root.collect_tabs(&mut all_tabs);
+        }
+        all_tabs
+    }
+
+    fn node_mut(&mut self, path: &NodePath) -> Option<&mut DockNode<Tab>> {
+        let root = match path.surface {
+            0 => &mut self.root,
+            surface => &mut self.windows.get_mut(surface - 1)?.root,
+        };
+        root.node_mut(&path.path)
+    }
+
+    fn move_tab(&mut self, from: &NodePath, index: usize, to: DropTarget) {
+        // Removing a tab leaves the tree (and so all paths) intact until we simplify.
+        if let DropTarget::Node(target, zone) = &to {
+            let is_tabs = matches!(self.node_mut(target), Some(DockNode::Tabs { .. }));
+            if !is_tabs || (target == from && *zone == DropZone::Center) {
+                return;
+            }
+        }
+        let Some(DockNode::Tabs { tabs, .. }) = self.node_mut(from) else {
+            return;
+        };
+        if tabs.len() <= index {
+            return;
+        }
+        let tab = tabs.remove(index);
+
+        match to {
+            DropTarget::Node(target, zone) => {
+                if let Some(node) = self.node_mut(&target) {
+                    node.insert(tab, zone);
+                }
+            }
+            DropTarget::Window { id, rect } => self.windows.push(DockWindow {
+                id,
+                rect,
+                root: DockNode::tabs(vec![tab]),
+            }),
+        }
+    }
+
+    fn simplify(&mut self) {
+        self.root.simplify();
+        for window in &mut self.windows {
+            window.root.simplify();
+        }
+        self.windows.retain(|window| !window.root.is_empty());
+    }
+}
+
+/// Shows the tabs of a [`DockArea`].
+pub trait TabViewer {
+    type Tab;
+
+    /// The title shown on the tab.
+    fn title(&mut self, tab: &mut Self::Tab) -> egui::WidgetText;
+
+    /// Show the contents of the tab.
+    fn ui(&mut self, ui: &mut Ui, tab: &mut Self::Tab);
+
+    /// Identifies the tab, and must be unique within the [`DockArea`].
+    ///
+    /// By default the title.
+    fn id(&mut self, tab: &mut Self::Tab) -> Id {
+        Id::new(self.title(tab).text())
+    }
+
+    /// Should the tab have a close button? (Default: true)
+    fn closeable(&mut self, _tab: &mut Self::Tab) -> bool {
+        true
+    }
+
+    /// Called when the close button is clicked. Return `false` to keep the tab open.
+    fn on_close(&mut self, _tab: &mut Self::Tab) -> bool {
+        true
+    }
+}
+
+/// Tabbed panes that can be split horizontally and vertically.
+///
+/// Drag a tab onto the middle of another pane to add it to those tabs,
+/// or onto an edge to split that pane.
+/// Drag it outside of the dock area to tear it out into a window.
+/// Drag the gap between two panes to resize them.
+///
+/// ```
+/// struct Viewer;
+///
+/// impl egui_extras::TabViewer for Viewer {
+///     type Tab = String;
+///
+///     fn title(&mut self, tab: &mut String) -> egui::WidgetText {
+///         tab.as_str().into()
+///     }
+///
+///     fn ui(&mut self, ui: &mut egui::Ui, tab: &mut String) {
+///         ui.label(format!("This is {tab}"));
+///     }
+/// }
+///
+/// # egui::__run_test_ui(|ui| {
+/// # let mut dock_state = egui_extras::DockState::new(egui_extras::DockNode::horizontal(
+/// #     0.3,
+/// #     egui_extras::DockNode::tabs(vec!["Files".to_owned()]),
+/// #     egui_extras::DockNode::tabs(vec!["main.rs".to_owned(), "lib.rs".to_owned()]),
+/// # ));
+/// egui_extras::DockArea::new(&mut dock_state).show_inside(ui, &mut Viewer);
+/// # });
+/// ```
+pub struct DockArea<'a, Tab> {
+    id_source: Id,
+    state: &'a mut DockState<Tab>,
+}
+
+impl<'a, Tab> DockArea<'a, Tab> {
+    pub fn new(state: &'a mut DockState<Tab>) -> Self {
+        Self {
+            id_source: Id::new("dock_area"),
+            state,
+        }
+    }
+
+    /// Must be set if multiple dock areas are in the same [`Ui`].
+    #[inline]
+    pub fn id_source(mut self, id_source: impl Hash) -> Self {
+        self.id_source = Id::new(id_source);
+        self
+    }
+
+    /// Fill the available space of the [`Ui`].
+    pub fn show_inside(self, ui: &mut Ui, viewer: &mut impl TabViewer<Tab = Tab>) {
+        let dock_id = ui.make_persistent_id(self.id_source);
+        let rect = ui.available_rect_before_wrap();
+        let mut dock_ui = DockUi {
+            dock_id,
+            leaves: Vec::new(),
+            closed: Vec::new(),
+        };
+
+        let mut path = NodePath::default();
+        dock_ui.node_ui(ui, viewer, &mut self.state.root, &mut path, rect);
+        ui.allocate_rect(rect, Sense::hover());
+
+        for (index, window) in self.state.windows.iter_mut().enumerate() {
+            let mut path = NodePath {
+                surface: index + 1,
+                path: Vec::new(),
+            };
+            let response = egui::Window::new("")
+                .id(window.id)
+                .title_bar(false)
+                .resizable(true)
+                .default_rect(window.rect)
+                .show(ui.ctx(), |ui| {
+                    let rect = ui.available_rect_before_wrap();
+                    dock_ui.node_ui(ui, viewer, &mut window.root, &mut path, rect);
+                    ui.allocate_rect(rect, Sense::hover());
+                });
+            if let Some(response) = response {
+                window.rect = response.response.rect;
+            }
+        }
+
+        let dragged_id = dock_id.with("dragged");
+        if let Some(dragged) = ui.data(|d| d.get_temp::<DraggedTab>(dragged_id)) {
+            ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+            let target = ui
+                .ctx()
+                .pointer_interact_pos()
+                .and_then(|pointer| dock_ui.drop_target(ui, pointer, rect, &dragged));
+
+            if let Some((_, preview)) = &target {
+                let layer_id = LayerId::new(Order::Foreground, dock_id.with("drop_preview"));
+                let fill = ui.visuals().selection.bg_fill.gamma_multiply(0.5);
+                ui.ctx()
+                    .layer_painter(layer_id)
+                    .rect_filled(*preview, 0.0, fill);
+            }
+
+            if !ui.input(|i| i.pointer.any_down()) {
+                ui.data_mut(|d| d.remove::<DraggedTab>(dragged_id));
+                if let Some((target, _)) = target {
+                    self.state.move_tab(&dragged.from, dragged.index, target);
+                }
+            }
+        }
+
+        // Close from the back, so the indices stay valid:
+        for (path, index) in dock_ui.closed.into_iter().rev() {
+            if let Some(DockNode::Tabs { tabs, .. }) = self.state.node_mut(&path) {
+                if index < tabs.len() && viewer.on_close(&mut tabs[index]) {
+                    tabs.remove(index);
+                }
+            }
+        }
+
+        self.state.simplify();
+    }
+}
+
+/// Where a node is in a [`DockState`].
+#[derive(Clone, Debug, Default, Hash, PartialEq)]
+struct NodePath {
+    /// `0` for [`DockState::root`], and `i + 1` for `DockState::windows[i]`.
+    surface: usize,
+
+    /// The index of the child to pick in each [`DockNode::Split`] on the way down.
+    path: Vec<usize>,
+}
+
+/// A tab that is being dragged.
+#[derive(Clone)]
+struct DraggedTab {
+    from: NodePath,
+    index: usize,
+}
+
+/// Where on a group of tabs a tab is dropped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum DropZone {
+    Center,
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl DropZone {
+    /// The part of `rect` the dropped tab would take up.
+    fn preview(self, rect: Rect) -> Rect {
+        let half = 0.5 * rect.size();
+        match self {
+            Self::Center => rect,
+            Self::Left => Rect::from_min_size(rect.min, vec2(half.x, rect.height())),
+            Self::Right => Rect::from_min_max(pos2(rect.center().x, rect.top()), rect.max),
+            Self::Top => Rect::from_min_size(rect.min, vec2(rect.width(), half.y)),
+            Self::Bottom => Rect::from_min_max(pos2(rect.left(), rect.center().y), rect.max),
+        }
+    }
+}
+
+enum DropTarget {
+    Node(NodePath, DropZone),
+    Window { id: Id, rect: Rect },
+}
+
+/// A group of tabs shown this frame.
+struct Leaf {
+    path: NodePath,
+    bar_rect: Rect,
+    body_rect: Rect,
+}
+
+struct DockUi {
+    dock_id: Id,
+
+    /// All groups of tabs shown this frame, with windows last.
+    leaves: Vec<Leaf>,
+
+    /// Tabs whose close button was clicked.
+    closed: Vec<(NodePath, usize)>,
+}
+
+impl DockUi {
+    fn node_ui<Tab>(
+        &mut self,
+        ui: &mut Ui,
+        viewer: &mut impl TabViewer<Tab = Tab>,
+        node: &mut DockNode<Tab>,
+        path: &mut NodePath,
+        rect: Rect,
+    ) {
+        let (direction, fraction, children) = match node {
+            DockNode::Tabs { tabs, active } => {
+                self.tabs_ui(ui, viewer, tabs, active, path, rect);
+                return;
+            }
+            DockNode::Split {
+                direction,
+                fraction,
+                children,
+            } => (*direction, fraction, children),
+        };
+
+        let gap = ui.spacing().item_spacing.x;
+        let separator = split_rects(rect, direction, *fraction, gap)[2];
+        let response = ui.interact(
+            separator.expand(ui.style().interaction.resize_grab_radius_side),
+            self.dock_id.with(("separator", &*path)),
+            Sense::drag(),
+        );
+        if let Some(pointer) = response
+            .interact_pointer_pos()
+            .filter(|_| response.dragged())
+        {
+            let new_fraction = match direction {
+                SplitDirection::Horizontal => (pointer.x - rect.left()) / rect.width(),
+                SplitDirection::Vertical => (pointer.y - rect.top()) / rect.height(),
+            };
+            *fraction = new_fraction.clamp(0.1, 0.9);
+        }
+        let stroke = if response.hovered() || response.dragged() {
+            ui.ctx().set_cursor_icon(match direction {
+                SplitDirection::Horizontal => CursorIcon::ResizeHorizontal,
+                SplitDirection::Vertical => CursorIcon::ResizeVertical,
+            });
+            ui.visuals().widgets.hovered.bg_stroke
+        } else {
+            ui.visuals().widgets.noninteractive.bg_stroke
+        };
+
+        let [first, second, separator] = split_rects(rect, direction, *fraction, gap);
+        let line = match direction {
+            SplitDirection::Horizontal => [separator.center_top(), separator.center_bottom()],
+            SplitDirection::Vertical => [separator.left_center(), separator.right_center()],
+        };
+        ui.painter().line_segment(line, stroke);
+
+        for (index, (child, child_rect)) in children.iter_mut().zip([first, second]).enumerate() {
+            path.path.push(index);
+            self.node_ui(ui, viewer, child, path, child_rect);
+            path.path.pop();
+        }
+    }
+
+    fn tabs_ui<Tab>(
+        &mut self,
+        ui: &mut Ui,
+        viewer: &mut impl TabViewer<Tab = Tab>,
+        tabs: &mut [Tab],
+        active: &mut usize,
+        path: &NodePath,
+        rect: Rect,
+    ) {
+        let bar_height = ui.spacing().interact_size.y;
+        let bar_rect = Rect::from_min_size(rect.min, vec2(rect.width(), bar_height));
+        let body_rect = Rect::from_min_max(pos2(rect.left(), bar_rect.bottom()), rect.max);
+        self.leaves.push(Leaf {
+            path: path.clone(),
+            bar_rect,
+            body_rect,
+        });
+
+        *active = (*active).min(tabs.len().saturating_sub(1));
+        ui.painter()
+            .rect_filled(bar_rect, 0.0, ui.visuals().faint_bg_color);
+        ui.painter()
+            .rect_stroke(rect, 0.0, ui.visuals().widgets.noninteractive.bg_stroke);
+
+        let painter = ui.painter_at(bar_rect);
+        let padding = ui.spacing().button_padding.x;
+        let mut x = bar_rect.left();
+        for (index, tab) in tabs.iter_mut().enumerate() {
+            let tab_id = self.dock_id.with(viewer.id(tab));
+            let galley = viewer.title(tab).into_galley(
+                ui,
+                Some(false),
+                f32::INFINITY,
+                egui::TextStyle::Button,
+            );
+            let close_width = if viewer.closeable(tab) {
+                bar_height
+            } else {
+                0.0
+            };
+            let tab_rect = Rect::from_min_size(
+                pos2(x, bar_rect.top()),
+                vec2(galley.size().x + 2.0 * padding + close_width, bar_height),
+            );
+            x = tab_rect.right();
+
+            let response = ui.interact(
+                tab_rect.intersect(bar_rect),
+                tab_id,
+                Sense::click_and_drag(),
+            );
+            if response.clicked() {
+                *active = index;
+            }
+            if response.drag_started() {
+                let dragged = DraggedTab {
+                    from: path.clone(),
+                    index,
+                };
+                ui.data_mut(|d| d.insert_temp(self.dock_id.with("dragged"), dragged));
+            }
+
+            let is_active = index == *active;
+            let visuals = ui.style().interact_selectable(&response, is_active);
+            if is_active || response.hovered() {
+                painter.rect_filled(tab_rect, 0.0, visuals.weak_bg_fill);
+            }
+            let text_pos = pos2(
+                tab_rect.left() + padding,
+                tab_rect.center().y - 0.5 * galley.size().y,
+            );
+            painter.galley(text_pos, galley, visuals.text_color());
+
+            if 0.0 < close_width {
+                let close_rect = Rect::from_center_size(
+                    pos2(tab_rect.right() - 0.5 * close_width, tab_rect.center().y),
+                    Vec2::splat(0.5 * bar_height),
+                );
+                let close_response = ui
+                    .interact(close_rect, tab_id.with("close"), Sense::click())
+                    .on_hover_text("Close");
+                let stroke = ui.style().interact(&close_response).fg_stroke;
+                let cross = close_rect.shrink(2.0);
+                painter.line_segment([cross.left_top(), cross.right_bottom()], stroke);
+                painter.line_segment([cross.right_top(), cross.left_bottom()], stroke);
+                if close_response.clicked() {
+                    self.closed.push((path.clone(), index));
+                }
+            }
+        }
+
+        if let Some(tab) = tabs.get_mut(*active) {
+            let tab_id = self.dock_id.with(viewer.id(tab));
+            let content_rect = body_rect.shrink(ui.spacing().item_spacing.x);
+            let mut content_ui =
+                ui.child_ui_with_id_source(content_rect, Layout::top_down(Align::Min), tab_id);
+            content_ui.set_clip_rect(body_rect.intersect(ui.clip_rect()));
+            viewer.ui(&mut content_ui, tab);
+        }
+    }
+
+    /// Where would the dragged tab go if dropped at `pointer`, and what area should we highlight?
+    fn drop_target(
+        &self,
+        ui: &Ui,
+        pointer: Pos2,
+        dock_rect: Rect,
+        dragged: &DraggedTab,
+    ) -> Option<(DropTarget, Rect)> {
+        // Windows are on top:
+        let leaf = self
+            .leaves
+            .iter()
+            .rev()
+            .find(|leaf| leaf.bar_rect.union(leaf.body_rect).contains(pointer));
+
+        if let Some(leaf) = leaf {
+            let zone = if leaf.bar_rect.contains(pointer) {
+                DropZone::Center
+            } else {
+                let t = (pointer - leaf.body_rect.min) / leaf.body_rect.size();
+                if t.x < 0.25 {
+                    DropZone::Left
+                } else if t.x > 0.75 {
+                    DropZone::Right
+                } else if t.y < 0.25 {
+                    DropZone::Top
+                } else if t.y > 0.75 {
+                    DropZone::Bottom
+                } else {
+                    DropZone::Center
+                }
+            };
+            let preview = zone.preview(leaf.body_rect);
+            Some((DropTarget::Node(leaf.path.clone(), zone), preview))
+        } else if !dock_rect.contains(pointer) {
+            // Tear out into a window the size of the pane it came from:
+            let size = self
+                .leaves
+                .iter()
+                .find(|leaf| leaf.path == dragged.from)
+                .map_or(Vec2::splat(200.0), |leaf| {
+                    leaf.bar_rect.union(leaf.body_rect).size()
+                });
+            let rect = Rect::from_min_size(pointer, size);
+            let id = self.dock_id.with(("window", ui.ctx().frame_nr()));
+            Some((DropTarget::Window { id, rect }, rect))
+        } else {
+            None
+        }
+    }
+}
+
+/// The rectangles of the first child, the second child, and the gap between them.
+fn split_rects(rect: Rect, direction: SplitDirection, fraction: f32, gap: f32) -> [Rect; 3] {
+    match direction {
+        SplitDirection::Horizontal => {
+            let x = rect.left() + fraction * rect.width();
+            [
+                Rect::from_min_max(rect.min, pos2(x - 0.5 * gap, rect.bottom())),
+                Rect::from_min_max(pos2(x + 0.5 * gap, rect.top()), rect.max),
+                Rect::from_x_y_ranges(x - 0.5 * gap..=x + 0.5 * gap, rect.y_range()),
+            ]
+        }
+        SplitDirection::Vertical => {
+            let y = rect.top() + fraction * rect.height();
+            [
+                Rect::from_min_max(rect.min, pos2(rect.right(), y - 0.5 * gap)),
+                Rect::from_min_max(pos2(rect.left(), y + 0.5 * gap), rect.max),
+                Rect::from_x_y_ranges(rect.x_range(), y - 0.5 * gap..=y + 0.5 * gap),
+            ]
+        }
+    }
+}
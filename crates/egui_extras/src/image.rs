@@ -190,6 +190,160 @@ impl RetainedImage {
 
 // ----------------------------------------------------------------------------
 
+/// An SVG image that stays crisp at any size and zoom level.
+///
+/// Unlike [`RetainedImage::from_svg_bytes`], which rasterizes the SVG once,
+/// this re-rasterizes the SVG whenever it is shown at a new size (in physical pixels).
+/// The rasterized sizes are rounded up to buckets, so that small changes in size
+/// (e.g. during a resize) don't cause a new rasterization every frame.
+///
+/// Load once, and save somewhere in your app state.
+///
+/// Requires the "svg" feature.
+#[cfg(feature = "svg")]
+pub struct SvgImage {
+    debug_name: String,
+
+    svg_bytes: Vec<u8>,
+
+    /// The intrinsic size of the SVG, in points.
+    size: egui::Vec2,
+
+    /// Rasterized textures per size bucket (in pixels), with the frame they were last used.
+    textures: Mutex<egui::ahash::HashMap<[u32; 2], (egui::TextureHandle, u64)>>,
+
+    options: TextureOptions,
+}
+
+#[cfg(feature = "svg")]
+impl SvgImage {
+    /// Pass in the bytes of an SVG that you've loaded.
+    ///
+    /// # Errors
+    /// On invalid image
+    pub fn from_svg_bytes(
+        debug_name: impl Into<String>,
+        svg_bytes: impl Into<Vec<u8>>,
+    ) -> Result<Self, String> {
+        use resvg::usvg::{Options, Tree, TreeParsing};
+
+        let svg_bytes = svg_bytes.into();
+        let tree =
+            Tree::from_data(&svg_bytes, &Options::default()).map_err(|err| err.to_string())?;
+        Ok(Self {
+            debug_name: debug_name.into(),
+            svg_bytes,
+            size: egui::vec2(tree.size.width(), tree.size.height()),
+            textures: Default::default(),
+            options: Default::default(),
+        })
+    }
+
+    /// Pass in the str of an SVG that you've loaded.
+    ///
+    /// # Errors
+    /// On invalid image
+    pub fn from_svg_str(debug_name: impl Into<String>, svg_str: &str) -> Result<Self, String> {
+        Self::from_svg_bytes(debug_name, svg_str.as_bytes())
+    }
+
+    /// Set the texture filters to use for the image.
+    #[inline]
+    pub fn with_options(mut self, options: TextureOptions) -> Self {
+        self.options = options;
+        self.textures.lock().clear();
+        self
+    }
+
+    /// The intrinsic size of the SVG, in points.
+    pub fn size_vec2(&self) -> egui::Vec2 {
+        self.size
+    }
+
+    /// The debug name of the image, e.g. the file name.
+    pub fn debug_name(&self) -> &str {
+        &self.debug_name
+    }
+
+    /// The texture id for this image rasterized at (at least) the given size in pixels.
+    ///
+    /// # Errors
+    /// If the SVG could not be rasterized at this size.
+    pub fn texture_id(
+        &self,
+        ctx: &egui::Context,
+        size_in_pixels: egui::Vec2,
+    ) -> Result<egui::TextureId, String> {
+        let bucket = [size_bucket(size_in_pixels.x), size_bucket(size_in_pixels.y)];
+        let frame_nr = ctx.frame_nr();
+
+        let mut textures = self.textures.lock();
+        if let Some((texture, last_used)) = textures.get_mut(&bucket) {
+            *last_used = frame_nr;
+            return Ok(texture.id());
+        }
+
+        let [w, h] = bucket;
+        let image = load_svg_bytes_with_size(&self.svg_bytes, Some(SizeHint::Size(w, h)))?;
+        let texture = ctx.load_texture(format!("{} {w}x{h}", self.debug_name), image, self.options);
+        let texture_id = texture.id();
+
+        // Forget the sizes that are no longer shown:
+        textures.retain(|_, (_, last_used)| *last_used + 1 >= frame_nr);
+        textures.insert(bucket, (texture, frame_nr));
+
+        Ok(texture_id)
+    }
+
+    /// Show the image with the given maximum size.
+    pub fn show_max_size(&self, ui: &mut egui::Ui, max_size: egui::Vec2) -> egui::Response {
+        let mut desired_size = self.size_vec2();
+        desired_size *= (max_size.x / desired_size.x).min(1.0);
+        desired_size *= (max_size.y / desired_size.y).min(1.0);
+        self.show_size(ui, desired_size)
+    }
+
+    /// Show the image with its intrinsic size.
+    pub fn show(&self, ui: &mut egui::Ui) -> egui::Response {
+        self.show_size(ui, self.size_vec2())
+    }
+
+    /// Show the image with the given scale factor (1.0 = intrinsic size).
+    pub fn show_scaled(&self, ui: &mut egui::Ui, scale: f32) -> egui::Response {
+        self.show_size(ui, self.size_vec2() * scale)
+    }
+
+    /// Show the image with the given size, rasterized for the current `pixels_per_point`.
+    pub fn show_size(&self, ui: &mut egui::Ui, desired_size: egui::Vec2) -> egui::Response {
+        let size_in_pixels = desired_size * ui.ctx().pixels_per_point();
+        match self.texture_id(ui.ctx(), size_in_pixels) {
+            Ok(texture_id) => ui.image((texture_id, desired_size)),
+            Err(err) => {
+                let (rect, response) = ui.allocate_exact_size(desired_size, egui::Sense::hover());
+                ui.painter().rect_filled(
+                    rect,
+                    0.0,
+                    ui.visuals().error_fg_color.gamma_multiply(0.5),
+                );
+                response.on_hover_text(err)
+            }
+        }
+    }
+}
+
+/// Round a size in pixels up so that similar sizes share the same rasterization.
+///
+/// The granularity is an eighth of the next power of two,
+/// so we never rasterize more than 25% bigger than needed.
+#[cfg(feature = "svg")]
+fn size_bucket(pixels: f32) -> u32 {
+    let pixels = (pixels.ceil() as u32).max(1);
+    let granularity = (pixels.next_power_of_two() / 8).max(1);
+    pixels.div_ceil(granularity) * granularity
+}
+
+// ----------------------------------------------------------------------------
+
 use egui::ColorImage;
 
 /// Load a (non-svg) image.
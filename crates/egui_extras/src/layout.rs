@@ -1,4 +1,4 @@
-use egui::{Id, Pos2, Rect, Response, Sense, Ui};
+use egui::{Color32, Id, InnerResponse, Pos2, Rect, Response, Sense, Ui};
 
 #[derive(Clone, Copy)]
 pub(crate) enum CellSize {
@@ -18,6 +18,7 @@ pub(crate) enum CellSize {
 ///
 /// In a table there's a [`StripLayout`] for each table row with a horizontal [`CellDirection`].
 /// Its cells go from left to right. And the lines go from top to bottom.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(crate) enum CellDirection {
     /// Cells go from left to right.
     Horizontal,
@@ -33,6 +34,18 @@ pub(crate) struct StripLayoutFlags {
     pub(crate) striped: bool,
     pub(crate) hovered: bool,
     pub(crate) selected: bool,
+
+    /// Painted on top of the stripe background, if any.
+    pub(crate) bg_fill: Option<Color32>,
+
+    /// Overrides the text color of the cell, unless it is selected.
+    pub(crate) text_color: Option<Color32>,
+
+    /// Overrides the [`Sense`] of the [`StripLayout`] for this cell.
+    pub(crate) sense: Option<Sense>,
+
+    /// Overrides the cell layout of the [`StripLayout`] for this cell.
+    pub(crate) layout: Option<egui::Layout>,
 }
 
 /// Positions cells in [`CellDirection`] and starts a new line on [`StripLayout::end_line`]
@@ -47,7 +60,20 @@ pub struct StripLayout<'l> {
     max: Pos2,
 
     cell_layout: egui::Layout,
-    sense: Sense,
+    pub(crate) sense: Sense,
+
+    /// Cells starting at or right of this x coordinate are scrolled horizontally
+    /// by [`Self::scroll_offset_x`], and clipped so they don't cover the cells left of it.
+    ///
+    /// Used for the frozen columns of a [`crate::Table`].
+    scroll_start_x: f32,
+    scroll_offset_x: f32,
+
+    /// Cells go from right to left (or bottom to top) instead.
+    reversed: bool,
+
+    /// If set, cells that don't fit on the current line go on a new line this high.
+    wrap_line_height: Option<f32>,
 }
 
 impl<'l> StripLayout<'l> {
@@ -68,101 +94,260 @@ impl<'l> StripLayout<'l> {
             max: pos,
             cell_layout,
             sense,
+            scroll_start_x: f32::INFINITY,
+            scroll_offset_x: 0.0,
+            reversed: false,
+            wrap_line_height: None,
+        }
+    }
+
+    /// Place the cells right to left in a horizontal layout, and bottom to top in a vertical one.
+    pub(crate) fn set_reversed(&mut self) {
+        self.reversed = true;
+        match self.direction {
+            CellDirection::Horizontal => self.cursor.x = self.rect.right(),
+            CellDirection::Vertical => self.cursor.y = self.rect.bottom(),
+        }
+    }
+
+    /// Start a new line whenever a cell doesn't fit on the current one.
+    ///
+    /// Only for horizontal, non-reversed layouts.
+    pub(crate) fn set_wrap(&mut self, line_height: f32) {
+        debug_assert!(self.direction == CellDirection::Horizontal && !self.reversed);
+        self.wrap_line_height = Some(line_height);
+    }
+
+    fn wrap_if_needed(&mut self, width: &CellSize) {
+        let (Some(line_height), CellSize::Absolute(width)) = (self.wrap_line_height, width) else {
+            return;
+        };
+        let is_first_on_line = self.cursor.x <= self.rect.left();
+        if !is_first_on_line && self.rect.right() < self.cursor.x + width {
+            self.cursor.x = self.rect.left();
+            self.cursor.y += line_height + self.ui.spacing().item_spacing.y;
+        }
+    }
+
+    /// Keep the cells in the first `frozen_width` points in place,
+    /// and scroll all cells after them horizontally by `scroll_offset_x`.
+    pub(crate) fn set_horizontal_scroll(&mut self, frozen_width: f32, scroll_offset_x: f32) {
+        self.scroll_start_x = self.rect.left() + frozen_width;
+        self.scroll_offset_x = scroll_offset_x;
+    }
+
+    fn is_scrolled(&self, rect: Rect) -> bool {
+        let eps = 0.1; // just to avoid some rounding errors.
+        rect.left() >= self.scroll_start_x - eps
+    }
+
+    /// Where the given cell is actually shown, taking horizontal scrolling into account.
+    fn scrolled_rect(&self, rect: Rect) -> Rect {
+        if self.is_scrolled(rect) {
+            rect.translate(egui::vec2(-self.scroll_offset_x, 0.0))
+        } else {
+            rect
+        }
+    }
+
+    /// The clip rect of the given cell, so that scrolled cells don't cover frozen ones.
+    fn cell_clip_rect(&self, rect: Rect) -> Rect {
+        let mut clip_rect = self.ui.clip_rect();
+        if self.is_scrolled(rect) {
+            let frozen_right = self.scroll_start_x - 0.5 * self.ui.spacing().item_spacing.x;
+            clip_rect.min.x = clip_rect.min.x.max(frozen_right);
         }
+        clip_rect
     }
 
     fn cell_rect(&self, width: &CellSize, height: &CellSize) -> Rect {
-        Rect {
+        let rect = Rect {
             min: self.cursor,
             max: Pos2 {
                 x: match width {
                     CellSize::Absolute(width) => self.cursor.x + width,
                     CellSize::Remainder => self.rect.right(),
                 },
-                y: match height {
-                    CellSize::Absolute(height) => self.cursor.y + height,
-                    CellSize::Remainder => self.rect.bottom(),
+                y: match (height, self.wrap_line_height) {
+                    (CellSize::Absolute(height), _) => self.cursor.y + height,
+                    (CellSize::Remainder, Some(line_height)) => self.cursor.y + line_height,
+                    (CellSize::Remainder, None) => self.rect.bottom(),
                 },
             },
+        };
+
+        if !self.reversed {
+            return rect;
+        }
+
+        // The cursor is at the end of the cell instead:
+        match self.direction {
+            CellDirection::Horizontal => Rect::from_x_y_ranges(
+                match width {
+                    CellSize::Absolute(width) => self.cursor.x - width..=self.cursor.x,
+                    CellSize::Remainder => self.rect.left()..=self.cursor.x,
+                },
+                rect.y_range(),
+            ),
+            CellDirection::Vertical => Rect::from_x_y_ranges(
+                rect.x_range(),
+                match height {
+                    CellSize::Absolute(height) => self.cursor.y - height..=self.cursor.y,
+                    CellSize::Remainder => self.rect.top()..=self.cursor.y,
+                },
+            ),
         }
     }
 
     fn set_pos(&mut self, rect: Rect) {
-        self.max.x = self.max.x.max(rect.right());
-        self.max.y = self.max.y.max(rect.bottom());
+        // Scrolled cells don't make us any wider than what is shown:
+        let shown_rect = self.scrolled_rect(rect);
+        self.max.x = self.max.x.max(shown_rect.right());
+        self.max.y = self.max.y.max(shown_rect.bottom());
 
-        match self.direction {
-            CellDirection::Horizontal => {
+        match (self.direction, self.reversed) {
+            (CellDirection::Horizontal, false) => {
                 self.cursor.x = rect.right() + self.ui.spacing().item_spacing.x;
             }
-            CellDirection::Vertical => {
+            (CellDirection::Horizontal, true) => {
+                self.cursor.x = rect.left() - self.ui.spacing().item_spacing.x;
+            }
+            (CellDirection::Vertical, false) => {
                 self.cursor.y = rect.bottom() + self.ui.spacing().item_spacing.y;
             }
+            (CellDirection::Vertical, true) => {
+                self.cursor.y = rect.top() - self.ui.spacing().item_spacing.y;
+            }
         }
     }
 
-    pub(crate) fn empty(&mut self, width: CellSize, height: CellSize) {
-        self.set_pos(self.cell_rect(&width, &height));
+    /// Returns the rectangle of the empty cell.
+    pub(crate) fn empty(&mut self, width: CellSize, height: CellSize) -> Rect {
+        self.wrap_if_needed(&width);
+        let rect = self.cell_rect(&width, &height);
+        self.set_pos(rect);
+        rect
     }
 
     /// This is the innermost part of [`crate::Table`] and [`crate::Strip`].
     ///
-    /// Return the used space (`min_rect`) plus the [`Response`] of the whole cell.
-    pub(crate) fn add(
+    /// Return the used space (`min_rect`) plus the [`Response`] of the whole cell,
+    /// together with what `add_cell_contents` returned.
+    pub(crate) fn add<R>(
         &mut self,
         flags: StripLayoutFlags,
         width: CellSize,
         height: CellSize,
         child_ui_id_source: Id,
-        add_cell_contents: impl FnOnce(&mut Ui),
-    ) -> (Rect, Response) {
-        let max_rect = self.cell_rect(&width, &height);
+        add_cell_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> (Rect, InnerResponse<R>) {
+        self.wrap_if_needed(&width);
+        let layout_rect = self.cell_rect(&width, &height);
+        let max_rect = self.scrolled_rect(layout_rect);
+        let clip_rect = self.cell_clip_rect(layout_rect);
+        let painter = self.ui.painter().with_clip_rect(clip_rect);
 
         // Make sure we don't have a gap in the stripe/frame/selection background:
         let item_spacing = self.ui.spacing().item_spacing;
         let gapless_rect = max_rect.expand2(0.5 * item_spacing);
 
         if flags.striped {
-            self.ui.painter().rect_filled(
+            painter.rect_filled(
                 gapless_rect,
                 egui::Rounding::ZERO,
                 self.ui.visuals().faint_bg_color,
             );
         }
 
+        if let Some(bg_fill) = flags.bg_fill {
+            painter.rect_filled(gapless_rect, egui::Rounding::ZERO, bg_fill);
+        }
+
         if flags.selected {
-            self.ui.painter().rect_filled(
+            painter.rect_filled(
                 gapless_rect,
                 egui::Rounding::ZERO,
                 self.ui.visuals().selection.bg_fill,
             );
         }
 
-        if flags.hovered && !flags.selected && self.sense.interactive() {
-            self.ui.painter().rect_filled(
+        let sense = flags.sense.unwrap_or(self.sense);
+
+        if flags.hovered && !flags.selected && sense.interactive() {
+            painter.rect_filled(
                 gapless_rect,
                 egui::Rounding::ZERO,
                 self.ui.visuals().widgets.hovered.bg_fill,
             );
         }
 
-        let child_ui = self.cell(flags, max_rect, child_ui_id_source, add_cell_contents);
+        let (child_ui, inner) = self.cell(
+            flags,
+            max_rect,
+            clip_rect,
+            child_ui_id_source,
+            add_cell_contents,
+        );
 
         let used_rect = child_ui.min_rect();
 
-        self.set_pos(max_rect);
+        self.set_pos(layout_rect);
 
         let allocation_rect = if flags.clip {
             max_rect
         } else {
             max_rect.union(used_rect)
         };
+        let allocation_rect = if self.is_scrolled(layout_rect) {
+            allocation_rect.intersect(clip_rect)
+        } else {
+            allocation_rect
+        };
 
         self.ui.advance_cursor_after_rect(allocation_rect);
 
-        let response = child_ui.interact(max_rect, child_ui.id(), self.sense);
+        let response = child_ui.interact(max_rect, child_ui.id(), sense);
+
+        (used_rect, InnerResponse::new(inner, response))
+    }
+
+    /// Can any part of the next cell be seen, horizontally?
+    ///
+    /// Used to skip laying out cells that are scrolled out of view.
+    pub(crate) fn is_horizontally_visible(&self, width: &CellSize, height: &CellSize) -> bool {
+        let layout_rect = self.cell_rect(width, height);
+        let x_range = self
+            .scrolled_rect(layout_rect)
+            .expand2(0.5 * self.ui.spacing().item_spacing)
+            .x_range();
+        let clip_x_range = self.cell_clip_rect(layout_rect).x_range();
+        clip_x_range.min <= x_range.max && x_range.min <= clip_x_range.max
+    }
+
+    /// Skip a cell that can't be seen, without creating a [`Ui`] for it.
+    ///
+    /// Still allocates the space of the cell, so that e.g. scroll areas know about it.
+    pub(crate) fn add_hidden(
+        &mut self,
+        width: CellSize,
+        height: CellSize,
+        child_ui_id_source: Id,
+    ) -> Response {
+        let layout_rect = self.cell_rect(&width, &height);
+        let max_rect = self.scrolled_rect(layout_rect);
+
+        self.set_pos(layout_rect);
+
+        if !self.is_scrolled(layout_rect) {
+            self.ui.advance_cursor_after_rect(max_rect);
+        }
 
-        (used_rect, response)
+        // Same id as the child ui would have had:
+        self.ui.interact(
+            max_rect,
+            self.ui.id().with(child_ui_id_source),
+            Sense::hover(),
+        )
     }
 
     /// only needed for layouts with multiple lines, like [`Table`](crate::Table).
@@ -187,17 +372,21 @@ impl<'l> StripLayout<'l> {
         self.ui.allocate_rect(rect, Sense::hover());
     }
 
-    /// Return the Ui to which the contents where added
-    fn cell(
+    /// Return the Ui to which the contents where added, and what `add_cell_contents` returned
+    fn cell<R>(
         &mut self,
         flags: StripLayoutFlags,
         rect: Rect,
+        clip_rect: Rect,
         child_ui_id_source: egui::Id,
-        add_cell_contents: impl FnOnce(&mut Ui),
-    ) -> Ui {
-        let mut child_ui =
-            self.ui
-                .child_ui_with_id_source(rect, self.cell_layout, child_ui_id_source);
+        add_cell_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> (Ui, R) {
+        let mut child_ui = self.ui.child_ui_with_id_source(
+            rect,
+            flags.layout.unwrap_or(self.cell_layout),
+            child_ui_id_source,
+        );
+        child_ui.set_clip_rect(clip_rect);
 
         if flags.clip {
             let margin = egui::Vec2::splat(self.ui.visuals().clip_rect_margin);
@@ -206,14 +395,18 @@ impl<'l> StripLayout<'l> {
             child_ui.set_clip_rect(clip_rect.intersect(child_ui.clip_rect()));
         }
 
+        if let Some(text_color) = flags.text_color {
+            child_ui.style_mut().visuals.override_text_color = Some(text_color);
+        }
+
         if flags.selected {
             let stroke_color = child_ui.style().visuals.selection.stroke.color;
             child_ui.style_mut().visuals.override_text_color = Some(stroke_color);
         }
 
-        add_cell_contents(&mut child_ui);
+        let inner = add_cell_contents(&mut child_ui);
 
-        child_ui
+        (child_ui, inner)
     }
 
     /// Allocate the rect in [`Self::ui`] so that the scrollview knows about our size
@@ -9,8 +9,11 @@
 #![allow(clippy::float_cmp)]
 #![allow(clippy::manual_range_contains)]
 
+#[cfg(feature = "animated_image")]
+mod animated_image;
 #[cfg(feature = "chrono")]
 mod datepicker;
+mod dock_area;
 
 pub mod syntax_highlighting;
 
@@ -18,20 +21,31 @@ pub mod syntax_highlighting;
 pub mod image;
 mod layout;
 mod loaders;
+#[cfg(feature = "markdown")]
+mod markdown;
 mod sizing;
 mod strip;
 mod table;
+mod tree_view;
 
+#[cfg(feature = "animated_image")]
+pub use crate::animated_image::AnimatedImage;
 #[cfg(feature = "chrono")]
 pub use crate::datepicker::DatePickerButton;
+pub use crate::dock_area::{DockArea, DockNode, DockState, DockWindow, SplitDirection, TabViewer};
 
 #[doc(hidden)]
 #[allow(deprecated)]
 pub use crate::image::RetainedImage;
+#[cfg(feature = "svg")]
+pub use crate::image::SvgImage;
 pub(crate) use crate::layout::StripLayout;
+#[cfg(feature = "markdown")]
+pub use crate::markdown::{Markdown, MarkdownStyle};
 pub use crate::sizing::Size;
 pub use crate::strip::*;
 pub use crate::table::*;
+pub use crate::tree_view::{TreeView, TreeViewBuilder, TreeViewResponse};
 
 pub use loaders::install_image_loaders;
 
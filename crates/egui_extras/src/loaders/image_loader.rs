@@ -1,20 +1,112 @@
 use egui::{
     ahash::HashMap,
-    load::{BytesPoll, ImageLoadResult, ImageLoader, ImagePoll, LoadError, SizeHint},
+    load::{Bytes, BytesPoll, ImageLoadResult, ImageLoader, ImagePoll, LoadError, SizeHint},
     mutex::Mutex,
     ColorImage,
 };
-use std::{mem::size_of, path::Path, sync::Arc};
+use std::{mem::size_of, path::Path, sync::Arc, task::Poll};
 
-type Entry = Result<Arc<ColorImage>, String>;
+type Entry = Poll<Result<Arc<ColorImage>, String>>;
 
+struct CachedImage {
+    entry: Entry,
+
+    /// Used to forget the least recently used images first.
+    last_used_frame: u64,
+}
+
+impl CachedImage {
+    fn byte_size(&self) -> usize {
+        match &self.entry {
+            Poll::Ready(Ok(image)) => image.pixels.len() * size_of::<egui::Color32>(),
+            Poll::Ready(Err(err)) => err.len(),
+            Poll::Pending => 0,
+        }
+    }
+
+    fn poll(&self) -> ImageLoadResult {
+        match self.entry.clone() {
+            Poll::Ready(Ok(image)) => Ok(ImagePoll::Ready { image }),
+            Poll::Ready(Err(err)) => Err(LoadError::Loading(err)),
+            Poll::Pending => Ok(ImagePoll::Pending { size: None }),
+        }
+    }
+}
+
+/// Decodes images with the `image` crate.
+///
+/// On native, the decoding happens on a background thread, so that big images don't block the ui.
 #[derive(Default)]
 pub struct ImageCrateLoader {
-    cache: Mutex<HashMap<String, Entry>>,
+    cache: Arc<Mutex<HashMap<String, CachedImage>>>,
 }
 
 impl ImageCrateLoader {
     pub const ID: &'static str = egui::generate_loader_id!(ImageCrateLoader);
+
+    /// When the decoded images take up more than this many bytes,
+    /// the least recently used ones are forgotten.
+    ///
+    /// Images used in the current frame are never forgotten.
+    ///
+    /// This only limits the [`ColorImage`]s kept by this loader.
+    /// The textures uploaded from them are cached separately by the texture loader,
+    /// which only asks this loader for an image once, and keeps the texture until
+    /// [`egui::Context::forget_image`] is called. Forgetting an image here therefore
+    /// doesn't free its texture, nor does it affect images that are still shown.
+    pub const MAX_CACHE_BYTES: usize = 256 * 1024 * 1024;
+
+    fn decode(&self, ctx: &egui::Context, uri: &str, bytes: Bytes) {
+        let decode = {
+            let ctx = ctx.clone();
+            let cache = self.cache.clone();
+            let uri = uri.to_owned();
+            move || {
+                log::trace!("started loading {uri:?}");
+                let result = crate::image::load_image_bytes(&bytes).map(Arc::new);
+                log::trace!("finished loading {uri:?}");
+                // The image may have been forgotten in the meantime:
+                if let Some(cached) = cache.lock().get_mut(&uri) {
+                    cached.entry = Poll::Ready(result);
+                }
+                ctx.request_repaint();
+            }
+        };
+
+        #[cfg(not(target_arch = "wasm32"))]
+        std::thread::Builder::new()
+            .name(format!("egui_extras::ImageCrateLoader::load({uri:?})"))
+            .spawn(decode)
+            .expect("failed to spawn thread");
+
+        #[cfg(target_arch = "wasm32")]
+        decode();
+    }
+
+    /// Forget the least recently used images until we are below [`Self::MAX_CACHE_BYTES`].
+    fn evict(cache: &mut HashMap<String, CachedImage>, frame_nr: u64) {
+        let mut byte_size: usize = cache.values().map(CachedImage::byte_size).sum();
+        if byte_size <= Self::MAX_CACHE_BYTES {
+            return;
+        }
+
+        let mut least_recently_used: Vec<(u64, String)> = cache
+            .iter()
+            .filter(|(_, cached)| cached.entry.is_ready() && cached.last_used_frame < frame_nr)
+            .map(|(uri, cached)| (cached.last_used_frame, uri.clone()))
+            .collect();
+        least_recently_used.sort();
+
+        for (_, uri) in least_recently_used {
+            if byte_size <= Self::MAX_CACHE_BYTES {
+                break;
+            }
+            if let Some(cached) = cache.remove(&uri) {
+                log::trace!("forgetting {uri:?} to free up memory");
+                byte_size -= cached.byte_size();
+            }
+        }
+    }
 }
 
 fn is_supported_uri(uri: &str) -> bool {
@@ -48,15 +140,14 @@ impl ImageLoader for ImageCrateLoader {
             return Err(LoadError::NotSupported);
         }
 
+        let frame_nr = ctx.frame_nr();
         let mut cache = self.cache.lock();
-        if let Some(entry) = cache.get(uri).cloned() {
-            match entry {
-                Ok(image) => Ok(ImagePoll::Ready { image }),
-                Err(err) => Err(LoadError::Loading(err)),
-            }
+        if let Some(cached) = cache.get_mut(uri) {
+            cached.last_used_frame = frame_nr;
+            cached.poll()
         } else {
             match ctx.try_load_bytes(uri) {
-                Ok(BytesPoll::Ready { bytes, mime, .. }) => {
+                Ok(BytesPoll::Ready { bytes, mime, size }) => {
                     // (2 and 3)
                     if mime.as_deref().is_some_and(is_unsupported_mime)
                         || image::guess_format(&bytes).is_err()
@@ -64,13 +155,24 @@ impl ImageLoader for ImageCrateLoader {
                         return Err(LoadError::NotSupported);
                     }
 
-                    log::trace!("started loading {uri:?}");
-                    let result = crate::image::load_image_bytes(&bytes).map(Arc::new);
-                    log::trace!("finished loading {uri:?}");
-                    cache.insert(uri.into(), result.clone());
-                    match result {
-                        Ok(image) => Ok(ImagePoll::Ready { image }),
-                        Err(err) => Err(LoadError::Loading(err)),
+                    Self::evict(&mut cache, frame_nr);
+                    cache.insert(
+                        uri.into(),
+                        CachedImage {
+                            entry: Poll::Pending,
+                            last_used_frame: frame_nr,
+                        },
+                    );
+                    drop(cache);
+
+                    self.decode(ctx, uri, bytes);
+
+                    // Decoding may have finished already (e.g. on the web):
+                    match self.cache.lock().get(uri).map(CachedImage::poll) {
+                        Some(Ok(ImagePoll::Pending { .. })) | None => {
+                            Ok(ImagePoll::Pending { size })
+                        }
+                        Some(result) => result,
                     }
                 }
                 Ok(BytesPoll::Pending { size }) => Ok(ImagePoll::Pending { size }),
@@ -88,14 +190,7 @@ impl ImageLoader for ImageCrateLoader {
     }
 
     fn byte_size(&self) -> usize {
-        self.cache
-            .lock()
-            .values()
-            .map(|result| match result {
-                Ok(image) => image.pixels.len() * size_of::<egui::Color32>(),
-                Err(err) => err.len(),
-            })
-            .sum()
+        self.cache.lock().values().map(CachedImage::byte_size).sum()
     }
 }
 
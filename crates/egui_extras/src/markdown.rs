@@ -0,0 +1,700 @@
+//! Show [CommonMark](https://commonmark.org) Markdown, parsed with [`pulldown_cmark`].
+
+use std::sync::Arc;
+
+use egui::{vec2, Color32, Response, RichText, Sense, TextStyle, Ui, Widget};
+use pulldown_cmark::{CodeBlockKind, Event, Tag, TagEnd};
+
+use crate::syntax_highlighting::CodeTheme;
+
+/// Shows Markdown text: headings, emphasis, lists, block quotes, tables,
+/// inline code, code blocks with syntax highlighting, links and images.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// ui.add(egui_extras::Markdown::new(
+///     "# Hello\nThis is **Markdown**, with [a link](https://github.com/emilk/egui).",
+/// ));
+/// # });
+/// ```
+///
+/// The text is parsed once and cached, so this is cheap to show every frame.
+///
+/// Images are loaded from their URL with the image loaders of egui,
+/// see [`crate::install_image_loaders`].
+/// Links open with [`egui::Context::open_url`].
+/// Raw HTML and footnote definitions are not shown.
+///
+/// Requires the "markdown" feature.
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Markdown<'a> {
+    text: &'a str,
+    style: MarkdownStyle,
+}
+
+impl<'a> Markdown<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            style: MarkdownStyle::default(),
+        }
+    }
+
+    /// Change how the Markdown is shown.
+    #[inline]
+    pub fn style(mut self, style: MarkdownStyle) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl Widget for Markdown<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        crate::profile_function!();
+
+        let Self { text, style } = self;
+        let blocks = parse_cached(ui.ctx(), text);
+
+        ui.vertical(|ui| blocks_ui(ui, &style, &blocks)).response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// How [`Markdown`] is shown.
+#[derive(Clone, PartialEq)]
+pub struct MarkdownStyle {
+    /// Font size of the headings `#` to `######`, relative to [`TextStyle::Body`].
+    pub heading_scales: [f32; 6],
+
+    /// Space between paragraphs and other blocks, in points.
+    pub block_spacing: f32,
+
+    /// Indentation of lists and block quotes, in points.
+    pub indent: f32,
+
+    /// Colors for the code blocks.
+    ///
+    /// If `None`, [`CodeTheme::from_memory`] is used.
+    pub code_theme: Option<CodeTheme>,
+
+    /// Color of links.
+    ///
+    /// If `None`, [`egui::Visuals::hyperlink_color`] is used.
+    pub link_color: Option<Color32>,
+}
+
+impl Default for MarkdownStyle {
+    fn default() -> Self {
+        Self {
+            heading_scales: [2.0, 1.6, 1.35, 1.15, 1.0, 0.9],
+            block_spacing: 8.0,
+            indent: 16.0,
+            code_theme: None,
+            link_color: None,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq)]
+enum Block {
+    Paragraph(Vec<Inline>),
+
+    /// Level 1 to 6.
+    Heading(usize, Vec<Inline>),
+
+    /// A fenced or indented code block.
+    Code {
+        language: String,
+        code: String,
+    },
+
+    Quote(Vec<Block>),
+
+    List {
+        /// The number of the first item of a numbered list.
+        start: Option<u64>,
+        items: Vec<Vec<Block>>,
+    },
+
+    /// Each cell is a list of inlines.
+    Table {
+        head: Vec<Vec<Inline>>,
+        rows: Vec<Vec<Vec<Inline>>>,
+    },
+
+    Rule,
+}
+
+#[derive(Debug, PartialEq)]
+enum Inline {
+    Span(Span),
+    Image { url: String, alt: String },
+    LineBreak,
+}
+
+#[derive(Debug, PartialEq)]
+struct Span {
+    text: String,
+    strong: bool,
+    emphasis: bool,
+    strikethrough: bool,
+    code: bool,
+    link: Option<String>,
+}
+
+fn parse_cached(ctx: &egui::Context, text: &str) -> Arc<[Block]> {
+    #[derive(Default)]
+    struct Parser;
+
+    impl egui::util::cache::ComputerMut<&str, Arc<[Block]>> for Parser {
+        fn compute(&mut self, text: &str) -> Arc<[Block]> {
+            parse(text).into()
+        }
+    }
+
+    type ParseCache = egui::util::cache::FrameCache<Arc<[Block]>, Parser>;
+
+    ctx.memory_mut(|mem| mem.caches.cache::<ParseCache>().get(text))
+}
+
+fn parse(text: &str) -> Vec<Block> {
+    use pulldown_cmark::Options;
+
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    parse_blocks(&mut pulldown_cmark::Parser::new_ext(text, options))
+}
+
+/// Parse blocks until the end of the enclosing block, or of the document.
+fn parse_blocks<'a>(events: &mut impl Iterator<Item = Event<'a>>) -> Vec<Block> {
+    let mut blocks = vec![];
+
+    // The items of tight lists have text without a paragraph around it:
+    let mut loose_inlines = Inlines::default();
+
+    while let Some(event) = events.next() {
+        if loose_inlines.event(&event) {
+            continue;
+        }
+        blocks.extend(loose_inlines.take_paragraph());
+
+        match event {
+            Event::Start(Tag::Paragraph) => {
+                blocks.push(Block::Paragraph(parse_inlines(events)));
+            }
+            Event::Start(Tag::Heading { level, .. }) => {
+                blocks.push(Block::Heading(level as usize, parse_inlines(events)));
+            }
+            Event::Start(Tag::BlockQuote) => {
+                blocks.push(Block::Quote(parse_blocks(events)));
+            }
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split_whitespace()
+                        .next()
+                        .unwrap_or_default()
+                        .to_owned(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                let mut code = String::new();
+                for event in events.by_ref() {
+                    match event {
+                        Event::Text(text) => code += &text,
+                        Event::End(_) => break,
+                        _ => {}
+                    }
+                }
+                if code.ends_with('\n') {
+                    code.pop();
+                }
+                blocks.push(Block::Code { language, code });
+            }
+            Event::Start(Tag::List(start)) => {
+                let mut items = vec![];
+                while events.next() == Some(Event::Start(Tag::Item)) {
+                    items.push(parse_blocks(events));
+                }
+                blocks.push(Block::List { start, items });
+            }
+            Event::Start(Tag::Table(_)) => {
+                let mut head = vec![];
+                let mut rows = vec![];
+                while let Some(event) = events.next() {
+                    match event {
+                        Event::Start(Tag::TableHead) => head = parse_table_row(events),
+                        Event::Start(Tag::TableRow) => rows.push(parse_table_row(events)),
+                        _ => break, // The end of the table
+                    }
+                }
+                blocks.push(Block::Table { head, rows });
+            }
+            Event::Start(_) => {
+                // HTML blocks, footnote definitions, metadata, …
+                skip_to_end(events);
+            }
+            Event::Rule => blocks.push(Block::Rule),
+            Event::End(_) => break,
+            _ => {}
+        }
+    }
+
+    blocks.extend(loose_inlines.take_paragraph());
+    blocks
+}
+
+/// Parse the inline content of a paragraph, heading or table cell.
+fn parse_inlines<'a>(events: &mut impl Iterator<Item = Event<'a>>) -> Vec<Inline> {
+    let mut inlines = Inlines::default();
+    for event in events.by_ref() {
+        if !inlines.event(&event) {
+            break; // The end of the paragraph, heading or table cell
+        }
+    }
+    inlines.inlines
+}
+
+fn parse_table_row<'a>(events: &mut impl Iterator<Item = Event<'a>>) -> Vec<Vec<Inline>> {
+    let mut cells = vec![];
+    while events.next() == Some(Event::Start(Tag::TableCell)) {
+        cells.push(parse_inlines(events));
+    }
+    cells
+}
+
+fn skip_to_end<'a>(events: &mut impl Iterator<Item = Event<'a>>) {
+    let mut depth = 1;
+    for event in events {
+        match event {
+            Event::Start(_) => depth += 1,
+            Event::End(_) => {
+                depth -= 1;
+                if depth == 0 {
+                    return;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Collects inline content, keeping track of the style.
+#[derive(Default)]
+struct Inlines {
+    inlines: Vec<Inline>,
+    strong: u32,
+    emphasis: u32,
+    strikethrough: u32,
+    link: Option<String>,
+
+    /// The url and alt text of the image we are in.
+    image: Option<(String, String)>,
+}
+
+impl Inlines {
+    /// Returns `false` for events that aren't inline content.
+    fn event(&mut self, event: &Event<'_>) -> bool {
+        match event {
+            Event::Start(Tag::Strong) => self.strong += 1,
+            Event::End(TagEnd::Strong) => self.strong = self.strong.saturating_sub(1),
+            Event::Start(Tag::Emphasis) => self.emphasis += 1,
+            Event::End(TagEnd::Emphasis) => self.emphasis = self.emphasis.saturating_sub(1),
+            Event::Start(Tag::Strikethrough) => self.strikethrough += 1,
+            Event::End(TagEnd::Strikethrough) => {
+                self.strikethrough = self.strikethrough.saturating_sub(1);
+            }
+            Event::Start(Tag::Link { dest_url, .. }) => self.link = Some(dest_url.to_string()),
+            Event::End(TagEnd::Link) => self.link = None,
+            Event::Start(Tag::Image { dest_url, .. }) => {
+                self.image = Some((dest_url.to_string(), String::new()));
+            }
+            Event::End(TagEnd::Image) => {
+                if let Some((url, alt)) = self.image.take() {
+                    self.inlines.push(Inline::Image { url, alt });
+                }
+            }
+            Event::Text(text) => self.text(text, false),
+            Event::Code(code) => self.text(code, true),
+            Event::SoftBreak => self.text(" ", false),
+            Event::HardBreak => self.inlines.push(Inline::LineBreak),
+            Event::TaskListMarker(checked) => self.text(if *checked { "☑ " } else { "☐ " }, false),
+            Event::FootnoteReference(name) => self.text(&format!("[{name}]"), false),
+            Event::InlineHtml(_) => {} // Not supported
+            _ => return false,
+        }
+        true
+    }
+
+    fn text(&mut self, text: &str, code: bool) {
+        if let Some((_, alt)) = &mut self.image {
+            alt.push_str(text);
+            return;
+        }
+        self.inlines.push(Inline::Span(Span {
+            text: text.to_owned(),
+            strong: 0 < self.strong,
+            emphasis: 0 < self.emphasis,
+            strikethrough: 0 < self.strikethrough,
+            code,
+            link: self.link.clone(),
+        }));
+    }
+
+    fn take_paragraph(&mut self) -> Option<Block> {
+        (!self.inlines.is_empty()).then(|| Block::Paragraph(std::mem::take(&mut self.inlines)))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+fn blocks_ui(ui: &mut Ui, style: &MarkdownStyle, blocks: &[Block]) {
+    for (i, block) in blocks.iter().enumerate() {
+        if 0 < i {
+            ui.add_space(style.block_spacing);
+        }
+        block_ui(ui, style, block);
+    }
+}
+
+fn block_ui(ui: &mut Ui, style: &MarkdownStyle, block: &Block) {
+    match block {
+        Block::Paragraph(inlines) => {
+            inlines_ui(ui, style, inlines, None);
+        }
+        Block::Heading(level, inlines) => {
+            let body_size = TextStyle::Body.resolve(ui.style()).size;
+            let scale = style.heading_scales[(*level).clamp(1, 6) - 1];
+            inlines_ui(ui, style, inlines, Some(scale * body_size));
+        }
+        Block::Code { language, code } => {
+            let theme = style
+                .code_theme
+                .clone()
+                .unwrap_or_else(|| CodeTheme::from_memory(ui.ctx()));
+            let layout_job =
+                crate::syntax_highlighting::highlight(ui.ctx(), &theme, code, language);
+            egui::Frame::none()
+                .fill(ui.visuals().code_bg_color)
+                .rounding(ui.visuals().widgets.noninteractive.rounding)
+                .inner_margin(ui.spacing().button_padding)
+                .show(ui, |ui| {
+                    ui.set_min_width(ui.available_width());
+                    ui.add(egui::Label::new(layout_job).selectable(true).wrap(false));
+                });
+        }
+        Block::Quote(blocks) => {
+            ui.horizontal_top(|ui| {
+                let (bar_rect, _) = ui.allocate_exact_size(vec2(style.indent, 0.0), Sense::hover());
+                let rect = ui.vertical(|ui| blocks_ui(ui, style, blocks)).response.rect;
+                ui.painter().vline(
+                    bar_rect.center().x,
+                    rect.y_range(),
+                    ui.visuals().widgets.noninteractive.bg_stroke,
+                );
+            });
+        }
+        Block::List { start, items } => {
+            let row_height = ui.text_style_height(&TextStyle::Body);
+            for (i, item) in items.iter().enumerate() {
+                ui.horizontal_top(|ui| {
+                    let (rect, _) =
+                        ui.allocate_exact_size(vec2(style.indent, row_height), Sense::hover());
+                    let color = ui.visuals().strong_text_color();
+                    if let Some(start) = start {
+                        ui.painter().text(
+                            rect.right_center() - vec2(0.25 * style.indent, 0.0),
+                            egui::Align2::RIGHT_CENTER,
+                            format!("{}.", start + i as u64),
+                            TextStyle::Body.resolve(ui.style()),
+                            color,
+                        );
+                    } else {
+                        ui.painter()
+                            .circle_filled(rect.center(), rect.height() / 8.0, color);
+                    }
+                    ui.vertical(|ui| blocks_ui(ui, style, item));
+                });
+            }
+        }
+        Block::Table { head, rows } => {
+            egui::Grid::new(ui.next_auto_id())
+                .striped(true)
+                .show(ui, |ui| {
+                    for cell in head {
+                        ui.scope(|ui| {
+                            ui.visuals_mut().override_text_color =
+                                Some(ui.visuals().strong_text_color());
+                            inlines_ui(ui, style, cell, None);
+                        });
+                    }
+                    ui.end_row();
+                    for row in rows {
+                        for cell in row {
+                            inlines_ui(ui, style, cell, None);
+                        }
+                        ui.end_row();
+                    }
+                });
+        }
+        Block::Rule => {
+            ui.separator();
+        }
+    }
+}
+
+/// Show some inline content, wrapping it at the available width.
+fn inlines_ui(ui: &mut Ui, style: &MarkdownStyle, inlines: &[Inline], heading_size: Option<f32>) {
+    ui.horizontal_wrapped(|ui| {
+        ui.spacing_mut().item_spacing.x = 0.0;
+        for inline in inlines {
+            match inline {
+                Inline::Span(span) => {
+                    let text = span_rich_text(style, span, heading_size);
+                    if let Some(url) = &span.link {
+                        ui.hyperlink_to(text, url);
+                    } else {
+                        ui.label(text);
+                    }
+                }
+                Inline::Image { url, alt } => {
+                    let image = egui::Image::new(url.clone()).max_width(ui.available_width());
+                    let response = ui.add(image);
+                    if !alt.is_empty() {
+                        response.on_hover_text(alt);
+                    }
+                }
+                Inline::LineBreak => {
+                    ui.end_row();
+                }
+            }
+        }
+    });
+}
+
+fn span_rich_text(style: &MarkdownStyle, span: &Span, heading_size: Option<f32>) -> RichText {
+    let Span {
+        text,
+        strong,
+        emphasis,
+        strikethrough,
+        code,
+        link,
+    } = span;
+
+    let mut rich_text = RichText::new(text);
+    if let Some(size) = heading_size {
+        rich_text = rich_text.size(size).strong();
+    }
+    if *code {
+        rich_text = rich_text.code();
+    }
+    if *strong {
+        rich_text = rich_text.strong();
+    }
+    if *emphasis {
+        rich_text = rich_text.italics();
+    }
+    if *strikethrough {
+        rich_text = rich_text.strikethrough();
+    }
+    if link.is_some() {
+        if let Some(link_color) = style.link_color {
+            rich_text = rich_text.color(link_color);
+        }
+    }
+    rich_text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(text: &str) -> Span {
+        Span {
+            text: text.to_owned(),
+            strong: false,
+            emphasis: false,
+            strikethrough: false,
+            code: false,
+            link: None,
+        }
+    }
+
+    /// The text of some inlines, without any styling.
+    fn plain_text(inlines: &[Inline]) -> String {
+        inlines
+            .iter()
+            .map(|inline| match inline {
+                Inline::Span(span) => span.text.as_str(),
+                Inline::Image { .. } => "",
+                Inline::LineBreak => "\n",
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_headings_and_paragraphs() {
+        let blocks = parse("# Title\n\nSome\ntext\n\n---\n\n### Sub");
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Heading(1, vec![Inline::Span(span("Title"))]),
+                Block::Paragraph(vec![
+                    Inline::Span(span("Some")),
+                    Inline::Span(span(" ")),
+                    Inline::Span(span("text")),
+                ]),
+                Block::Rule,
+                Block::Heading(3, vec![Inline::Span(span("Sub"))]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nested_emphasis() {
+        let blocks = parse("a **b *c* ~~d~~** [`e`](https://e.com)");
+        let [Block::Paragraph(inlines)] = blocks.as_slice() else {
+            panic!("Expected one paragraph, got {blocks:?}");
+        };
+        assert_eq!(
+            inlines,
+            &vec![
+                Inline::Span(span("a ")),
+                Inline::Span(Span {
+                    strong: true,
+                    ..span("b ")
+                }),
+                Inline::Span(Span {
+                    strong: true,
+                    emphasis: true,
+                    ..span("c")
+                }),
+                Inline::Span(Span {
+                    strong: true,
+                    ..span(" ")
+                }),
+                Inline::Span(Span {
+                    strong: true,
+                    strikethrough: true,
+                    ..span("d")
+                }),
+                Inline::Span(span(" ")),
+                Inline::Span(Span {
+                    code: true,
+                    link: Some("https://e.com".to_owned()),
+                    ..span("e")
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lists() {
+        let blocks = parse("3. one\n4. two\n   - nested\n\n- [x] done\n- [ ] todo");
+        let [Block::List {
+            start: Some(3),
+            items,
+        }, Block::List {
+            start: None,
+            items: tasks,
+        }] = blocks.as_slice()
+        else {
+            panic!("Expected a numbered and a bulleted list, got {blocks:?}");
+        };
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(
+            items[0],
+            vec![Block::Paragraph(vec![Inline::Span(span("one"))])]
+        );
+        let [Block::Paragraph(two), Block::List {
+            start: None,
+            items: nested,
+        }] = items[1].as_slice()
+        else {
+            panic!("Expected a paragraph and a nested list, got {:?}", items[1]);
+        };
+        assert_eq!(plain_text(two), "two");
+        assert_eq!(
+            nested,
+            &vec![vec![Block::Paragraph(vec![Inline::Span(span("nested"))])]]
+        );
+
+        let task_texts: Vec<String> = tasks
+            .iter()
+            .map(|item| match item.as_slice() {
+                [Block::Paragraph(inlines)] => plain_text(inlines),
+                _ => panic!("Expected a paragraph, got {item:?}"),
+            })
+            .collect();
+        assert_eq!(task_texts, vec!["☑ done", "☐ todo"]);
+    }
+
+    #[test]
+    fn test_table() {
+        let blocks = parse("| a | *b* |\n|---|:-:|\n| 1 | 2 |\n| 3 | 4 |");
+        let [Block::Table { head, rows }] = blocks.as_slice() else {
+            panic!("Expected a table, got {blocks:?}");
+        };
+        assert_eq!(
+            head,
+            &vec![
+                vec![Inline::Span(span("a"))],
+                vec![Inline::Span(Span {
+                    emphasis: true,
+                    ..span("b")
+                })],
+            ]
+        );
+        let cells: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| row.iter().map(|cell| plain_text(cell)).collect())
+            .collect();
+        assert_eq!(cells, vec![vec!["1", "2"], vec!["3", "4"]]);
+    }
+
+    #[test]
+    fn test_code_quote_and_image() {
+        let blocks =
+            parse("```rust title\nfn main() {}\n```\n\n> quoted\n\n![alt *text*](image.png)");
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Code {
+                    language: "rust".to_owned(),
+                    code: "fn main() {}".to_owned(),
+                },
+                Block::Quote(vec![Block::Paragraph(vec![Inline::Span(span("quoted"))])]),
+                Block::Paragraph(vec![Inline::Image {
+                    url: "image.png".to_owned(),
+                    alt: "alt text".to_owned(),
+                }]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_html_is_skipped() {
+        let blocks = parse("<div>\n\n*hidden*\n\n</div>\n\nshown");
+        assert_eq!(
+            blocks.last(),
+            Some(&Block::Paragraph(vec![Inline::Span(span("shown"))]))
+        );
+    }
+
+    #[test]
+    fn test_show_markdown() {
+        // Make sure all kinds of blocks can be shown:
+        let text =
+            "# Title\n\n- a\n- b\n\n1. c\n\n> d\n\n```\ne\n```\n\n| f |\n|---|\n| g |\n\n---";
+        let ctx = egui::Context::default();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                ui.add(Markdown::new(text));
+            });
+        });
+    }
+}
@@ -11,6 +11,11 @@ pub enum Size {
 
     /// Multiple remainders each get the same space.
     Remainder { range: Rangef },
+
+    /// Sized to fit the contents of the cell, as measured on the previous frame.
+    ///
+    /// `initial` is used until the contents have been measured.
+    ShrinkToFit { initial: f32, range: Rangef },
 }
 
 impl Size {
@@ -46,13 +51,27 @@ impl Size {
         }
     }
 
+    /// As big as the contents of the cell.
+    ///
+    /// The contents are measured each frame, and the result is used on the next frame.
+    /// Until the contents have been measured, the cell is 100 points big.
+    ///
+    /// Use [`Self::at_least`] and [`Self::at_most`] to constrain the size.
+    pub fn shrink_to_fit() -> Self {
+        Self::ShrinkToFit {
+            initial: 100.0,
+            range: Rangef::new(0.0, f32::INFINITY),
+        }
+    }
+
     /// Won't shrink below this size (in points).
     #[inline]
     pub fn at_least(mut self, minimum: f32) -> Self {
         match &mut self {
             Self::Absolute { range, .. }
             | Self::Relative { range, .. }
-            | Self::Remainder { range, .. } => {
+            | Self::Remainder { range, .. }
+            | Self::ShrinkToFit { range, .. } => {
                 range.min = minimum;
             }
         }
@@ -65,19 +84,30 @@ impl Size {
         match &mut self {
             Self::Absolute { range, .. }
             | Self::Relative { range, .. }
-            | Self::Remainder { range, .. } => {
+            | Self::Remainder { range, .. }
+            | Self::ShrinkToFit { range, .. } => {
                 range.max = maximum;
             }
         }
         self
     }
 
+    /// Won't shrink below or grow above this range (in points).
+    ///
+    /// Same as calling both [`Self::at_least`] and [`Self::at_most`].
+    #[inline]
+    pub fn with_range(self, range: impl Into<Rangef>) -> Self {
+        let range = range.into();
+        self.at_least(range.min).at_most(range.max)
+    }
+
     /// Allowed range of movement (in points), if in a resizable [`Table`](crate::table::Table).
     pub fn range(self) -> Rangef {
         match self {
             Self::Absolute { range, .. }
             | Self::Relative { range, .. }
-            | Self::Remainder { range, .. } => range,
+            | Self::Remainder { range, .. }
+            | Self::ShrinkToFit { range, .. } => range,
         }
     }
 }
@@ -92,6 +122,24 @@ impl Sizing {
         self.sizes.push(size);
     }
 
+    /// Use the measured sizes of the contents for all [`Size::ShrinkToFit`].
+    pub(crate) fn shrink_to_fit(&mut self, measured_lengths: &[f32]) {
+        if measured_lengths.len() != self.sizes.len() {
+            return; // outdated
+        }
+        for (size, &measured) in self.sizes.iter_mut().zip(measured_lengths) {
+            if let Size::ShrinkToFit { initial, .. } = size {
+                *initial = measured;
+            }
+        }
+    }
+
+    pub(crate) fn has_shrink_to_fit(&self) -> bool {
+        self.sizes
+            .iter()
+            .any(|size| matches!(size, Size::ShrinkToFit { .. }))
+    }
+
     pub fn to_lengths(&self, length: f32, spacing: f32) -> Vec<f32> {
         if self.sizes.is_empty() {
             return vec![];
@@ -103,6 +151,7 @@ impl Sizing {
             .iter()
             .map(|&size| match size {
                 Size::Absolute { initial, .. } => initial,
+                Size::ShrinkToFit { initial, range } => range.clamp(initial),
                 Size::Relative { fraction, range } => {
                     assert!(0.0 <= fraction && fraction <= 1.0);
                     range.clamp(length * fraction)
@@ -115,35 +164,73 @@ impl Sizing {
             .sum::<f32>()
             + spacing * (self.sizes.len() - 1) as f32;
 
-        let avg_remainder_length = if remainders == 0 {
-            0.0
+        let remainder_lengths = if remainders == 0 {
+            vec![None; self.sizes.len()]
         } else {
-            let mut remainder_length = length - sum_non_remainder;
-            let avg_remainder_length = 0.0f32.max(remainder_length / remainders as f32).floor();
-            self.sizes.iter().for_each(|&size| {
-                if let Size::Remainder { range } = size {
-                    if avg_remainder_length < range.min {
-                        remainder_length -= range.min;
-                        remainders -= 1;
-                    }
-                }
-            });
-            if remainders > 0 {
-                0.0f32.max(remainder_length / remainders as f32)
-            } else {
-                0.0
-            }
+            self.remainder_lengths(length - sum_non_remainder)
         };
 
         self.sizes
             .iter()
-            .map(|&size| match size {
+            .zip(remainder_lengths)
+            .map(|(&size, remainder_length)| match size {
                 Size::Absolute { initial, .. } => initial,
+                Size::ShrinkToFit { initial, range } => range.clamp(initial),
                 Size::Relative { fraction, range } => range.clamp(length * fraction),
-                Size::Remainder { range } => range.clamp(avg_remainder_length),
+                Size::Remainder { .. } => remainder_length.unwrap_or_default(),
             })
             .collect()
     }
+
+    /// Distribute `remainder_length` among the [`Size::Remainder`]s, respecting their ranges.
+    ///
+    /// Like CSS flexbox, this is done iteratively: the space is split evenly between the remainders,
+    /// and then the ones that end up outside of their range are clamped and frozen,
+    /// after which the rest is split again between the others.
+    fn remainder_lengths(&self, mut remainder_length: f32) -> Vec<Option<f32>> {
+        let mut lengths: Vec<Option<f32>> = vec![None; self.sizes.len()];
+
+        loop {
+            let unfrozen: Vec<(usize, Rangef)> = self
+                .sizes
+                .iter()
+                .enumerate()
+                .filter_map(|(i, size)| match size {
+                    Size::Remainder { range } if lengths[i].is_none() => Some((i, *range)),
+                    _ => None,
+                })
+                .collect();
+            if unfrozen.is_empty() {
+                break;
+            }
+
+            let avg_length = (remainder_length / unfrozen.len() as f32).max(0.0).floor();
+
+            // Positive if the minimums are violated the most, negative if the maximums are:
+            let total_violation: f32 = unfrozen
+                .iter()
+                .map(|(_, range)| range.clamp(avg_length) - avg_length)
+                .sum();
+
+            for &(i, range) in &unfrozen {
+                let clamped = range.clamp(avg_length);
+                let violation = clamped - avg_length;
+                let freeze = if total_violation > 0.0 {
+                    violation > 0.0
+                } else if total_violation < 0.0 {
+                    violation < 0.0
+                } else {
+                    true
+                };
+                if freeze {
+                    lengths[i] = Some(clamped);
+                    remainder_length -= clamped;
+                }
+            }
+        }
+
+        lengths
+    }
 }
 
 impl From<Vec<Size>> for Sizing {
@@ -152,24 +239,115 @@ impl From<Vec<Size>> for Sizing {
     }
 }
 
-#[test]
-fn test_sizing() {
-    let sizing: Sizing = vec![].into();
-    assert_eq!(sizing.to_lengths(50.0, 0.0), Vec::<f32>::new());
-
-    let sizing: Sizing = vec![Size::remainder().at_least(20.0), Size::remainder()].into();
-    assert_eq!(sizing.to_lengths(50.0, 0.0), vec![25.0, 25.0]);
-    assert_eq!(sizing.to_lengths(30.0, 0.0), vec![20.0, 10.0]);
-    assert_eq!(sizing.to_lengths(20.0, 0.0), vec![20.0, 0.0]);
-    assert_eq!(sizing.to_lengths(10.0, 0.0), vec![20.0, 0.0]);
-    assert_eq!(sizing.to_lengths(20.0, 10.0), vec![20.0, 0.0]);
-    assert_eq!(sizing.to_lengths(30.0, 10.0), vec![20.0, 0.0]);
-    assert_eq!(sizing.to_lengths(40.0, 10.0), vec![20.0, 10.0]);
-    assert_eq!(sizing.to_lengths(110.0, 10.0), vec![50.0, 50.0]);
-
-    let sizing: Sizing = vec![Size::relative(0.5).at_least(10.0), Size::exact(10.0)].into();
-    assert_eq!(sizing.to_lengths(50.0, 0.0), vec![25.0, 10.0]);
-    assert_eq!(sizing.to_lengths(30.0, 0.0), vec![15.0, 10.0]);
-    assert_eq!(sizing.to_lengths(20.0, 0.0), vec![10.0, 10.0]);
-    assert_eq!(sizing.to_lengths(10.0, 0.0), vec![10.0, 10.0]);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sizing() {
+        let sizing: Sizing = vec![].into();
+        assert_eq!(sizing.to_lengths(50.0, 0.0), Vec::<f32>::new());
+
+        let sizing: Sizing = vec![Size::remainder().at_least(20.0), Size::remainder()].into();
+        assert_eq!(sizing.to_lengths(50.0, 0.0), vec![25.0, 25.0]);
+        assert_eq!(sizing.to_lengths(30.0, 0.0), vec![20.0, 10.0]);
+        assert_eq!(sizing.to_lengths(20.0, 0.0), vec![20.0, 0.0]);
+        assert_eq!(sizing.to_lengths(10.0, 0.0), vec![20.0, 0.0]);
+        assert_eq!(sizing.to_lengths(20.0, 10.0), vec![20.0, 0.0]);
+        assert_eq!(sizing.to_lengths(30.0, 10.0), vec![20.0, 0.0]);
+        assert_eq!(sizing.to_lengths(40.0, 10.0), vec![20.0, 10.0]);
+        assert_eq!(sizing.to_lengths(110.0, 10.0), vec![50.0, 50.0]);
+
+        let sizing: Sizing = vec![Size::relative(0.5).at_least(10.0), Size::exact(10.0)].into();
+        assert_eq!(sizing.to_lengths(50.0, 0.0), vec![25.0, 10.0]);
+        assert_eq!(sizing.to_lengths(30.0, 0.0), vec![15.0, 10.0]);
+        assert_eq!(sizing.to_lengths(20.0, 0.0), vec![10.0, 10.0]);
+        assert_eq!(sizing.to_lengths(10.0, 0.0), vec![10.0, 10.0]);
+
+        let mut sizing: Sizing =
+            vec![Size::shrink_to_fit().at_most(40.0), Size::remainder()].into();
+        assert_eq!(sizing.to_lengths(150.0, 0.0), vec![40.0, 110.0]);
+        sizing.shrink_to_fit(&[30.0, 0.0]);
+        assert_eq!(sizing.to_lengths(150.0, 0.0), vec![30.0, 120.0]);
+        assert_eq!(sizing.to_lengths(150.0, 10.0), vec![30.0, 110.0]);
+
+        // Space that a clamped remainder can't use goes to the other remainders:
+        let sizing: Sizing = vec![Size::remainder().at_most(10.0), Size::remainder()].into();
+        assert_eq!(sizing.to_lengths(100.0, 0.0), vec![10.0, 90.0]);
+
+        let sizing: Sizing = vec![
+            Size::remainder().with_range(40.0..=100.0),
+            Size::remainder().at_least(30.0),
+            Size::remainder(),
+        ]
+        .into();
+        assert_eq!(sizing.to_lengths(90.0, 0.0), vec![40.0, 30.0, 20.0]);
+        assert_eq!(sizing.to_lengths(300.0, 0.0), vec![100.0, 100.0, 100.0]);
+        assert_eq!(sizing.to_lengths(600.0, 0.0), vec![100.0, 250.0, 250.0]);
+    }
+
+    #[test]
+    fn test_size_range() {
+        let range = |size: Size| {
+            let range = size.range();
+            (range.min, range.max)
+        };
+        assert_eq!(range(Size::exact(10.0)), (10.0, 10.0));
+        assert_eq!(range(Size::initial(10.0)), (0.0, f32::INFINITY));
+        assert_eq!(range(Size::remainder().at_least(5.0)), (5.0, f32::INFINITY));
+        assert_eq!(range(Size::relative(0.5).at_most(5.0)), (0.0, 5.0));
+        assert_eq!(
+            range(Size::shrink_to_fit().with_range(5.0..=50.0)),
+            (5.0, 50.0)
+        );
+    }
+
+    #[test]
+    fn test_relative_is_clamped() {
+        let sizing: Sizing = vec![
+            Size::relative(0.25).with_range(20.0..=40.0),
+            Size::relative(0.75),
+        ]
+        .into();
+        assert_eq!(sizing.to_lengths(40.0, 0.0), vec![20.0, 30.0]);
+        assert_eq!(sizing.to_lengths(100.0, 0.0), vec![25.0, 75.0]);
+        assert_eq!(sizing.to_lengths(200.0, 0.0), vec![40.0, 150.0]);
+    }
+
+    #[test]
+    fn test_remainder_distribution() {
+        // The remainders share what is left after the other sizes and the spacing:
+        let sizing: Sizing = vec![
+            Size::exact(20.0),
+            Size::remainder(),
+            Size::relative(0.1),
+            Size::remainder(),
+        ]
+        .into();
+        assert_eq!(sizing.to_lengths(100.0, 0.0), vec![20.0, 35.0, 10.0, 35.0]);
+        // Rounded down to whole points:
+        assert_eq!(sizing.to_lengths(100.0, 5.0), vec![20.0, 27.0, 10.0, 27.0]);
+
+        // No space left:
+        assert_eq!(sizing.to_lengths(20.0, 5.0), vec![20.0, 0.0, 2.0, 0.0]);
+
+        // Remainders that can't take their share leave it to the others,
+        // which may in turn hit their own limits:
+        let sizing: Sizing = vec![
+            Size::remainder().at_most(10.0),
+            Size::remainder().at_most(30.0),
+            Size::remainder(),
+        ]
+        .into();
+        assert_eq!(sizing.to_lengths(60.0, 0.0), vec![10.0, 25.0, 25.0]);
+        assert_eq!(sizing.to_lengths(150.0, 0.0), vec![10.0, 30.0, 110.0]);
+
+        // The lengths add up to the available length, unless a range forbids it:
+        let sizing: Sizing = vec![Size::remainder().at_least(50.0), Size::remainder()].into();
+        for length in [0.0, 30.0, 99.0, 100.0, 500.0] {
+            let lengths = sizing.to_lengths(length, 0.0);
+            assert_eq!(lengths[0], (length / 2.0).max(50.0));
+            assert_eq!(lengths.iter().sum::<f32>(), length.max(50.0));
+        }
+    }
 }
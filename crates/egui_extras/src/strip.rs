@@ -3,7 +3,7 @@ use crate::{
     sizing::Sizing,
     Size,
 };
-use egui::{Response, Ui};
+use egui::{pos2, InnerResponse, NumExt as _, Ui};
 
 /// Builder for creating a new [`Strip`].
 ///
@@ -47,6 +47,13 @@ pub struct StripBuilder<'a> {
     clip: bool,
     cell_layout: egui::Layout,
     sense: egui::Sense,
+    resizable: bool,
+    reverse: bool,
+    animate: bool,
+    easing: fn(f32) -> f32,
+    separator: Option<egui::Stroke>,
+    outer_separators: bool,
+    id_source: Option<egui::Id>,
 }
 
 impl<'a> StripBuilder<'a> {
@@ -59,6 +66,13 @@ impl<'a> StripBuilder<'a> {
             clip: false,
             cell_layout,
             sense: egui::Sense::hover(),
+            resizable: false,
+            reverse: false,
+            animate: false,
+            easing: ease_in_out,
+            separator: None,
+            outer_separators: false,
+            id_source: None,
         }
     }
 
@@ -83,6 +97,88 @@ impl<'a> StripBuilder<'a> {
         self
     }
 
+    /// Add draggable separators between the cells, so that the user can resize them.
+    ///
+    /// Dragging a separator moves space between the two neighboring cells,
+    /// respecting the range of each [`Size`] (see [`Size::at_least`] and [`Size::at_most`]).
+    /// How far the user has moved each separator is stored in [`egui::Memory`], keyed by [`Self::id_source`],
+    /// so [`Size::relative`] and [`Size::remainder`] cells still follow the size of the strip.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Place the cells right to left in a horizontal strip, and bottom to top in a vertical one.
+    ///
+    /// The first [`Self::size`] is still used for the first cell you add,
+    /// so this makes it easy to mirror a layout, e.g. for right-to-left languages.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Animate changes to the sizes of the cells, instead of snapping to the new sizes.
+    ///
+    /// For instance, changing a [`Size::exact`] from `0.0` to `200.0` will smoothly expand the cell.
+    /// The animation takes [`egui::Style::animation_time`] seconds.
+    ///
+    /// The sizes a user has set by dragging the separators of a [`Self::resizable`] strip are not animated.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn animate(mut self, animate: bool) -> Self {
+        self.animate = animate;
+        self
+    }
+
+    /// How the sizes move during an animation, see [`Self::animate`].
+    ///
+    /// The function maps the elapsed fraction of the animation time (`0.0..=1.0`)
+    /// to how far the sizes have moved (`0.0` at the start and `1.0` at the end).
+    ///
+    /// Default: ease in and out.
+    #[inline]
+    pub fn easing(mut self, easing: fn(f32) -> f32) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Paint a line in the middle of the spacing between each two cells.
+    ///
+    /// The lines follow the cell boundaries exactly, also when the strip is [`Self::resizable`]
+    /// or [`Self::reverse`]d. In a [`Self::horizontal_wrapped`] strip, only cells on the same line are separated.
+    ///
+    /// Default: no separators.
+    #[inline]
+    pub fn separator(mut self, stroke: impl Into<egui::Stroke>) -> Self {
+        self.separator = Some(stroke.into());
+        self
+    }
+
+    /// Also paint a [`Self::separator`] at the outer edges of the first and last cell.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn outer_separators(mut self, outer_separators: bool) -> Self {
+        self.outer_separators = outer_separators;
+        self
+    }
+
+    /// Used to identify the stored sizes of a [`Self::resizable`] strip.
+    ///
+    /// Only needed if you have several resizable strips in the same [`Ui`].
+    #[inline]
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(egui::Id::new(id_source));
+        self
+    }
+
     /// Allocate space for one column/row.
     #[inline]
     pub fn size(mut self, size: Size) -> Self {
@@ -102,58 +198,454 @@ impl<'a> StripBuilder<'a> {
     /// Build horizontal strip: Cells are positions from left to right.
     /// Takes the available horizontal width, so there can't be anything right of the strip or the container will grow slowly!
     ///
-    /// Returns a [`egui::Response`] for hover events.
-    pub fn horizontal<F>(self, strip: F) -> Response
+    /// Returns what the closure returned, together with a [`egui::Response`] for hover events.
+    pub fn horizontal<F, R>(self, strip: F) -> InnerResponse<R>
     where
-        F: for<'b> FnOnce(Strip<'a, 'b>),
+        F: for<'b> FnOnce(Strip<'a, 'b>) -> R,
     {
-        let widths = self.sizing.to_lengths(
-            self.ui.available_rect_before_wrap().width(),
-            self.ui.spacing().item_spacing.x,
-        );
-        let mut layout = StripLayout::new(
-            self.ui,
-            CellDirection::Horizontal,
-            self.cell_layout,
-            self.sense,
-        );
-        strip(Strip {
-            layout: &mut layout,
-            direction: CellDirection::Horizontal,
-            clip: self.clip,
-            sizes: widths,
-            size_index: 0,
-        });
-        layout.allocate_rect()
+        self.build(CellDirection::Horizontal, None, strip)
+    }
+
+    /// Build horizontal strip where the cells that don't fit on the current line wrap to the next line,
+    /// like [`Ui::horizontal_wrapped`].
+    ///
+    /// All lines are `line_height` high.
+    /// This is mostly useful with [`Size::exact`] cells, e.g. for a tag cloud or an overflowing toolbar.
+    /// Wrapped strips can't be [`Self::resizable`] or [`Self::reverse`]d.
+    ///
+    /// Returns what the closure returned, together with a [`egui::Response`] for hover events.
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{StripBuilder, Size};
+    /// let tags = ["egui", "rust", "gui", "immediate mode"];
+    /// StripBuilder::new(ui)
+    ///     .sizes(Size::exact(80.0), tags.len())
+    ///     .horizontal_wrapped(20.0, |mut strip| {
+    ///         for tag in tags {
+    ///             strip.cell(|ui| {
+    ///                 ui.label(tag);
+    ///             });
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    pub fn horizontal_wrapped<F, R>(self, line_height: f32, strip: F) -> InnerResponse<R>
+    where
+        F: for<'b> FnOnce(Strip<'a, 'b>) -> R,
+    {
+        self.build(CellDirection::Horizontal, Some(line_height), strip)
     }
 
     /// Build vertical strip: Cells are positions from top to bottom.
     /// Takes the full available vertical height, so there can't be anything below of the strip or the container will grow slowly!
     ///
-    /// Returns a [`egui::Response`] for hover events.
-    pub fn vertical<F>(self, strip: F) -> Response
+    /// Returns what the closure returned, together with a [`egui::Response`] for hover events.
+    pub fn vertical<F, R>(self, strip: F) -> InnerResponse<R>
     where
-        F: for<'b> FnOnce(Strip<'a, 'b>),
+        F: for<'b> FnOnce(Strip<'a, 'b>) -> R,
     {
-        let heights = self.sizing.to_lengths(
-            self.ui.available_rect_before_wrap().height(),
-            self.ui.spacing().item_spacing.y,
-        );
-        let mut layout = StripLayout::new(
-            self.ui,
-            CellDirection::Vertical,
-            self.cell_layout,
-            self.sense,
-        );
-        strip(Strip {
+        self.build(CellDirection::Vertical, None, strip)
+    }
+
+    fn build<F, R>(
+        self,
+        direction: CellDirection,
+        wrap_line_height: Option<f32>,
+        strip: F,
+    ) -> InnerResponse<R>
+    where
+        F: for<'b> FnOnce(Strip<'a, 'b>) -> R,
+    {
+        let Self {
+            ui,
+            mut sizing,
+            clip,
+            cell_layout,
+            sense,
+            resizable,
+            reverse,
+            animate,
+            easing,
+            separator,
+            outer_separators,
+            id_source,
+        } = self;
+
+        let (resizable, reverse) = if wrap_line_height.is_some() {
+            (false, false)
+        } else {
+            (resizable, reverse)
+        };
+
+        let horizontal = direction == CellDirection::Horizontal;
+        let available_rect = ui.available_rect_before_wrap();
+        let (available_length, spacing) = if horizontal {
+            (available_rect.width(), ui.spacing().item_spacing.x)
+        } else {
+            (available_rect.height(), ui.spacing().item_spacing.y)
+        };
+
+        let state_id = ui
+            .id()
+            .with(id_source.unwrap_or(egui::Id::new("__strip_state")));
+
+        let shrink_to_fit = sizing.has_shrink_to_fit();
+        let measured_lengths_id = state_id.with("__measured_lengths");
+        let prev_measured_lengths = if shrink_to_fit {
+            ui.data(|d| d.get_temp::<Vec<f32>>(measured_lengths_id))
+        } else {
+            None
+        };
+        if let Some(prev_measured_lengths) = &prev_measured_lengths {
+            sizing.shrink_to_fit(prev_measured_lengths);
+        }
+
+        let mut lengths = sizing.to_lengths(available_length, spacing);
+
+        if animate {
+            lengths =
+                StripAnimation::animate(ui.ctx(), state_id.with("__animation"), lengths, easing);
+        }
+
+        let mut resize_deltas = vec![];
+        if resizable {
+            resize_deltas = StripState::load(ui.ctx(), state_id)
+                .map(|state| state.deltas)
+                .filter(|deltas| deltas.len() == lengths.len())
+                .unwrap_or_else(|| vec![0.0; lengths.len()]);
+            apply_resize_deltas(&sizing, &mut lengths, &resize_deltas);
+        }
+
+        let mut measured_lengths = vec![0.0; lengths.len()];
+        let mut cell_rects = Vec::with_capacity(lengths.len());
+        let mut layout = StripLayout::new(ui, direction, cell_layout, sense);
+        if reverse {
+            layout.set_reversed();
+        }
+        if let Some(line_height) = wrap_line_height {
+            layout.set_wrap(line_height);
+        }
+        let inner = strip(Strip {
             layout: &mut layout,
-            direction: CellDirection::Vertical,
-            clip: self.clip,
-            sizes: heights,
+            direction,
+            clip,
+            sizes: lengths.clone(),
             size_index: 0,
+            measured_lengths: &mut measured_lengths,
+            cell_rects: &mut cell_rects,
         });
-        layout.allocate_rect()
+        let response = layout.allocate_rect();
+
+        if let Some(stroke) = separator {
+            paint_separators(layout.ui, horizontal, &cell_rects, outer_separators, stroke);
+        }
+
+        if shrink_to_fit {
+            if prev_measured_lengths.as_ref() != Some(&measured_lengths) {
+                // The sizes will change next frame, so make sure there is one:
+                layout.ui.ctx().request_repaint();
+            }
+            layout
+                .ui
+                .data_mut(|d| d.insert_temp(measured_lengths_id, measured_lengths));
+        }
+
+        if resizable {
+            let old_lengths = lengths.clone();
+            let dragged = resize_separators(
+                layout.ui,
+                state_id,
+                horizontal,
+                reverse,
+                response.rect,
+                &sizing,
+                &mut lengths,
+            );
+            if dragged {
+                for ((delta, new), old) in resize_deltas.iter_mut().zip(&lengths).zip(&old_lengths)
+                {
+                    *delta += new - old;
+                }
+                StripState {
+                    deltas: resize_deltas,
+                }
+                .store(layout.ui.ctx(), state_id);
+            }
+        }
+
+        InnerResponse::new(inner, response)
+    }
+}
+
+/// Paint lines between the cells, see [`StripBuilder::separator`].
+fn paint_separators(
+    ui: &Ui,
+    horizontal: bool,
+    cell_rects: &[egui::Rect],
+    outer: bool,
+    stroke: egui::Stroke,
+) {
+    // Swap x and y for vertical strips, so we only have to think about horizontal ones:
+    let along = |pos: egui::Pos2| if horizontal { pos } else { pos2(pos.y, pos.x) };
+    let painter = ui.painter();
+    let paint = |x: f32, rect: egui::Rect| {
+        let (min, max) = (along(rect.min), along(rect.max));
+        painter.line_segment([along(pos2(x, min.y)), along(pos2(x, max.y))], stroke);
+    };
+
+    for pair in cell_rects.windows(2) {
+        let (a_min, a_max) = (along(pair[0].min), along(pair[0].max));
+        let (b_min, b_max) = (along(pair[1].min), along(pair[1].max));
+        if (a_min.y - b_min.y).abs() > 0.5 {
+            continue; // on different lines of a wrapped strip
+        }
+        // The cells may be in reverse order:
+        let x = if a_max.x <= b_min.x {
+            0.5 * (a_max.x + b_min.x)
+        } else {
+            0.5 * (b_max.x + a_min.x)
+        };
+        paint(x, pair[0].union(pair[1]));
+    }
+
+    if outer {
+        if let (Some(first), Some(last)) = (cell_rects.first(), cell_rects.last()) {
+            let all = cell_rects.iter().fold(*first, |all, rect| all.union(*rect));
+            let (min, max) = (along(all.min), along(all.max));
+            let (first_x, last_x) = (along(first.center()).x, along(last.center()).x);
+            let (start, end) = if first_x <= last_x {
+                (min.x, max.x)
+            } else {
+                (max.x, min.x)
+            };
+            paint(start, *first);
+            paint(end, *last);
+        }
+    }
+}
+
+/// How much the user has resized each cell of a resizable strip, stored in [`egui::Memory`].
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+struct StripState {
+    /// Added to the lengths computed from the [`Size`]s.
+    deltas: Vec<f32>,
+}
+
+impl StripState {
+    fn load(ctx: &egui::Context, id: egui::Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_persisted(id))
+    }
+
+    fn store(self, ctx: &egui::Context, id: egui::Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+}
+
+/// An ongoing animation of the sizes of a strip, see [`StripBuilder::animate`].
+///
+/// Also used for the column widths of a [`crate::Table`].
+#[derive(Clone, Debug)]
+pub(crate) struct StripAnimation {
+    from: Vec<f32>,
+    to: Vec<f32>,
+    start_time: f64,
+}
+
+impl StripAnimation {
+    /// Returns the lengths to use this frame.
+    pub(crate) fn animate(
+        ctx: &egui::Context,
+        id: egui::Id,
+        target: Vec<f32>,
+        easing: fn(f32) -> f32,
+    ) -> Vec<f32> {
+        let animation_time = ctx.style().animation_time;
+        let now = ctx.input(|i| i.time);
+
+        let Some(mut animation) = ctx
+            .data(|d| d.get_temp::<Self>(id))
+            .filter(|animation| animation.to.len() == target.len())
+        else {
+            // Nothing to animate from:
+            let animation = Self {
+                from: target.clone(),
+                to: target.clone(),
+                start_time: now,
+            };
+            ctx.data_mut(|d| d.insert_temp(id, animation));
+            return target;
+        };
+
+        if animation.to != target {
+            // Start from wherever we are now, in case the previous animation hasn't finished:
+            animation.from = animation.lengths(now, animation_time, easing);
+            animation.to = target;
+            animation.start_time = now;
+        }
+
+        let lengths = animation.lengths(now, animation_time, easing);
+        if now < animation.start_time + animation_time as f64 {
+            ctx.request_repaint();
+        }
+        ctx.data_mut(|d| d.insert_temp(id, animation));
+        lengths
+    }
+
+    /// Stop animating, and jump straight to these lengths.
+    pub(crate) fn snap(ctx: &egui::Context, id: egui::Id, lengths: Vec<f32>) {
+        let animation = Self {
+            from: lengths.clone(),
+            to: lengths,
+            start_time: ctx.input(|i| i.time),
+        };
+        ctx.data_mut(|d| d.insert_temp(id, animation));
+    }
+
+    fn lengths(&self, now: f64, animation_time: f32, easing: fn(f32) -> f32) -> Vec<f32> {
+        let t = if animation_time > 0.0 {
+            ((now - self.start_time) / animation_time as f64).clamp(0.0, 1.0) as f32
+        } else {
+            1.0
+        };
+        let t = easing(t);
+        self.from
+            .iter()
+            .zip(&self.to)
+            .map(|(&from, &to)| egui::lerp(from..=to, t))
+            .collect()
+    }
+}
+
+/// Smoothstep: starts and ends slowly.
+pub(crate) fn ease_in_out(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Add how much the user has resized each cell to the lengths computed from the [`Size`]s,
+/// staying within the range of each size.
+fn apply_resize_deltas(sizing: &Sizing, lengths: &mut [f32], deltas: &[f32]) {
+    for ((length, delta), size) in lengths.iter_mut().zip(deltas).zip(&sizing.sizes) {
+        *length = size.range().clamp(*length + delta).at_least(0.0);
+    }
+}
+
+/// Move the separator after cell `i` so that the cell gets `new_length`,
+/// taking the space from (or giving it to) cell `i + 1`, respecting the ranges of both.
+fn move_separator(sizing: &Sizing, lengths: &mut [f32], i: usize, new_length: f32) {
+    let (range, next_range) = (sizing.sizes[i].range(), sizing.sizes[i + 1].range());
+    let total = lengths[i] + lengths[i + 1];
+
+    let new_length = new_length
+        .at_most(total - next_range.min)
+        .at_least(total - next_range.max);
+    let new_length = range.clamp(new_length).at_least(0.0);
+
+    lengths[i] = new_length;
+    lengths[i + 1] = (total - new_length).at_least(0.0);
+}
+
+/// Interact with and paint the separators between the cells of a resizable strip.
+///
+/// Returns `true` if the user dragged a separator.
+fn resize_separators(
+    ui: &Ui,
+    state_id: egui::Id,
+    horizontal: bool,
+    reverse: bool,
+    strip_rect: egui::Rect,
+    sizing: &Sizing,
+    lengths: &mut [f32],
+) -> bool {
+    let mut dragged = false;
+    let spacing = if horizontal {
+        ui.spacing().item_spacing.x
+    } else {
+        ui.spacing().item_spacing.y
+    };
+
+    // In which direction the cells go:
+    let sign = if reverse { -1.0 } else { 1.0 };
+    let mut offset = match (horizontal, reverse) {
+        (true, false) => strip_rect.left(),
+        (true, true) => strip_rect.right(),
+        (false, false) => strip_rect.top(),
+        (false, true) => strip_rect.bottom(),
+    };
+
+    for i in 0..lengths.len().saturating_sub(1) {
+        offset += sign * lengths[i];
+        let center = offset + sign * 0.5 * spacing;
+        offset += sign * spacing;
+
+        let line_rect = if horizontal {
+            egui::Rect::from_x_y_ranges(center..=center, strip_rect.y_range())
+        } else {
+            egui::Rect::from_x_y_ranges(strip_rect.x_range(), center..=center)
+        };
+        let interact_rect = line_rect.expand(ui.style().interaction.resize_grab_radius_side);
+        let response = ui.interact(
+            interact_rect,
+            state_id.with("separator").with(i),
+            egui::Sense::drag(),
+        );
+
+        let mut line_rect = line_rect;
+        if response.dragged() {
+            if let Some(pointer) = ui.ctx().pointer_latest_pos() {
+                let pointer = if horizontal { pointer.x } else { pointer.y };
+                let old_length = lengths[i];
+                move_separator(sizing, lengths, i, old_length + sign * (pointer - center));
+                let delta = sign * (lengths[i] - old_length);
+                dragged |= delta != 0.0;
+                offset += delta;
+                line_rect = line_rect.translate(if horizontal {
+                    egui::vec2(delta, 0.0)
+                } else {
+                    egui::vec2(0.0, delta)
+                });
+            }
+        }
+
+        let dragging_something_else = ui.input(|i| i.pointer.any_down() || i.pointer.any_pressed());
+        let hovered = response.hovered() && !dragging_something_else;
+
+        if hovered || response.dragged() {
+            ui.ctx().set_cursor_icon(if horizontal {
+                egui::CursorIcon::ResizeColumn
+            } else {
+                egui::CursorIcon::ResizeRow
+            });
+        }
+
+        let stroke = if response.dragged() {
+            ui.visuals().widgets.active.bg_stroke
+        } else if hovered {
+            ui.visuals().widgets.hovered.bg_stroke
+        } else {
+            ui.visuals().widgets.noninteractive.bg_stroke
+        };
+        ui.painter()
+            .line_segment([line_rect.min, line_rect.max], stroke);
     }
+
+    dragged
+}
+
+/// Overrides of the [`StripBuilder`] settings for a single cell, see [`Strip::cell_with`].
+///
+/// `None` means using the setting of the [`StripBuilder`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CellConfig {
+    /// Overrides [`StripBuilder::clip`].
+    pub clip: Option<bool>,
+
+    /// Overrides [`StripBuilder::cell_layout`].
+    pub layout: Option<egui::Layout>,
+
+    /// Overrides [`StripBuilder::sense`].
+    pub sense: Option<egui::Sense>,
 }
 
 /// A Strip of cells which go in one direction. Each cell has a fixed size.
@@ -164,18 +656,37 @@ pub struct Strip<'a, 'b> {
     clip: bool,
     sizes: Vec<f32>,
     size_index: usize,
+
+    /// How much space the contents of each cell used.
+    measured_lengths: &'b mut [f32],
+
+    /// See [`Self::cell_rects`].
+    cell_rects: &'b mut Vec<egui::Rect>,
 }
 
 impl<'a, 'b> Strip<'a, 'b> {
     #[cfg_attr(debug_assertions, track_caller)]
     fn next_cell_size(&mut self) -> (CellSize, CellSize) {
-        let size = if let Some(size) = self.sizes.get(self.size_index) {
-            self.size_index += 1;
-            *size
+        self.next_cell_sizes(1)
+    }
+
+    /// The combined size of the next `count` pre-allocated sizes, including the spacing between them.
+    #[cfg_attr(debug_assertions, track_caller)]
+    fn next_cell_sizes(&mut self, count: usize) -> (CellSize, CellSize) {
+        let size = if count > 0 && self.size_index + count <= self.sizes.len() {
+            let spacing = match self.direction {
+                CellDirection::Horizontal => self.layout.ui.spacing().item_spacing.x,
+                CellDirection::Vertical => self.layout.ui.spacing().item_spacing.y,
+            };
+            let sizes = &self.sizes[self.size_index..self.size_index + count];
+            self.size_index += count;
+            sizes.iter().sum::<f32>() + spacing * (count - 1) as f32
         } else {
             crate::log_or_panic!(
-                "Added more `Strip` cells than were pre-allocated ({} pre-allocated)",
-                self.sizes.len()
+                "Added more `Strip` cells than were pre-allocated ({} pre-allocated, {} used, {} requested)",
+                self.sizes.len(),
+                self.size_index,
+                count
             );
             8.0 // anything will look wrong, so pick something that is obviously wrong
         };
@@ -187,35 +698,194 @@ impl<'a, 'b> Strip<'a, 'b> {
     }
 
     /// Add cell contents.
+    ///
+    /// Returns what `add_contents` returned, together with the [`egui::Response`] of the cell.
     #[cfg_attr(debug_assertions, track_caller)]
-    pub fn cell(&mut self, add_contents: impl FnOnce(&mut Ui)) {
-        let (width, height) = self.next_cell_size();
+    pub fn cell<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        self.span(1, add_contents)
+    }
+
+    /// Add cell contents spanning the next `count` pre-allocated sizes.
+    ///
+    /// The cell covers the combined space of all of them, including the spacing in between.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{StripBuilder, Size};
+    /// StripBuilder::new(ui)
+    ///     .sizes(Size::remainder(), 3)
+    ///     .horizontal(|mut strip| {
+    ///         strip.span(2, |ui| {
+    ///             ui.label("Covers the first two columns");
+    ///         });
+    ///         strip.cell(|ui| {
+    ///             ui.label("Third column");
+    ///         });
+    ///     });
+    /// # });
+    /// ```
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn span<R>(
+        &mut self,
+        count: usize,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.add_cell(count, CellConfig::default(), add_contents)
+    }
+
+    /// Add cell contents, and sense the whole cell for `sense`,
+    /// overriding [`StripBuilder::sense`] for this cell only.
+    ///
+    /// Use the returned [`egui::Response`] to check if the cell was e.g. hovered or clicked.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{StripBuilder, Size};
+    /// StripBuilder::new(ui)
+    ///     .sizes(Size::remainder(), 2)
+    ///     .horizontal(|mut strip| {
+    ///         for i in 0..2 {
+    ///             let response = strip.cell_sense(egui::Sense::click(), |ui| {
+    ///                 ui.label(format!("Tile {i}"));
+    ///             }).response;
+    ///             if response.clicked() {
+    ///                 // …
+    ///             }
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn cell_sense<R>(
+        &mut self,
+        sense: egui::Sense,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.add_cell(
+            1,
+            CellConfig {
+                sense: Some(sense),
+                ..Default::default()
+            },
+            add_contents,
+        )
+    }
+
+    /// Add cell contents, overriding some of the [`StripBuilder`] settings for this cell only.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{CellConfig, StripBuilder, Size};
+    /// StripBuilder::new(ui)
+    ///     .clip(true)
+    ///     .sizes(Size::remainder(), 2)
+    ///     .horizontal(|mut strip| {
+    ///         strip.cell(|ui| {
+    ///             ui.label("Clipped");
+    ///         });
+    ///         let centered = CellConfig {
+    ///             clip: Some(false),
+    ///             layout: Some(egui::Layout::centered_and_justified(egui::Direction::TopDown)),
+    ///             ..Default::default()
+    ///         };
+    ///         strip.cell_with(centered, |ui| {
+    ///             ui.label("Centered and unclipped");
+    ///         });
+    ///     });
+    /// # });
+    /// ```
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn cell_with<R>(
+        &mut self,
+        config: CellConfig,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.add_cell(1, config, add_contents)
+    }
+
+    #[cfg_attr(debug_assertions, track_caller)]
+    fn add_cell<R>(
+        &mut self,
+        count: usize,
+        config: CellConfig,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let index = self.size_index;
+        let (width, height) = self.next_cell_sizes(count);
         let flags = StripLayoutFlags {
-            clip: self.clip,
+            clip: config.clip.unwrap_or(self.clip),
+            sense: config.sense,
+            layout: config.layout,
             ..Default::default()
         };
-        self.layout.add(
+        let (used_rect, response) = self.layout.add(
             flags,
             width,
             height,
             egui::Id::new(self.size_index),
             add_contents,
         );
+        self.cell_rects.push(response.response.rect);
+
+        if count == 1 && index < self.size_index {
+            if let Some(measured) = self.measured_lengths.get_mut(index) {
+                *measured = match self.direction {
+                    CellDirection::Horizontal => used_rect.width(),
+                    CellDirection::Vertical => used_rect.height(),
+                };
+            }
+        }
+
+        response
     }
 
     /// Add an empty cell.
     #[cfg_attr(debug_assertions, track_caller)]
     pub fn empty(&mut self) {
         let (width, height) = self.next_cell_size();
-        self.layout.empty(width, height);
+        let rect = self.layout.empty(width, height);
+        self.cell_rects.push(rect);
+    }
+
+    /// Where the cells added so far ended up, in the order they were added,
+    /// including [`Self::empty`] cells.
+    ///
+    /// A [`Self::span`] has one rectangle covering all its sizes.
+    /// Return these from the strip closure to paint overlays over several cells,
+    /// like a selection rectangle.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{StripBuilder, Size};
+    /// let cell_rects = StripBuilder::new(ui)
+    ///     .sizes(Size::exact(50.0), 3)
+    ///     .horizontal(|mut strip| {
+    ///         for i in 0..3 {
+    ///             strip.cell(|ui| {
+    ///                 ui.label(i.to_string());
+    ///             });
+    ///         }
+    ///         strip.cell_rects().to_vec()
+    ///     })
+    ///     .inner;
+    /// let selection = cell_rects[0].union(cell_rects[1]);
+    /// ui.painter()
+    ///     .rect_stroke(selection, 2.0, ui.visuals().selection.stroke);
+    /// # });
+    /// ```
+    pub fn cell_rects(&self) -> &[egui::Rect] {
+        self.cell_rects
     }
 
     /// Add a strip as cell.
-    pub fn strip(&mut self, strip_builder: impl FnOnce(StripBuilder<'_>)) {
+    ///
+    /// Returns what `strip_builder` returned, together with the [`egui::Response`] of the cell.
+    pub fn strip<R>(
+        &mut self,
+        strip_builder: impl FnOnce(StripBuilder<'_>) -> R,
+    ) -> InnerResponse<R> {
         let clip = self.clip;
-        self.cell(|ui| {
-            strip_builder(StripBuilder::new(ui).clip(clip));
-        });
+        self.cell(|ui| strip_builder(StripBuilder::new(ui).clip(clip)))
     }
 }
 
@@ -226,3 +896,87 @@ impl<'a, 'b> Drop for Strip<'a, 'b> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_move_separator() {
+        let sizing: Sizing = vec![
+            Size::remainder().at_least(20.0),
+            Size::remainder().at_most(70.0),
+            Size::exact(10.0),
+        ]
+        .into();
+        let mut lengths = vec![50.0, 50.0, 10.0];
+
+        move_separator(&sizing, &mut lengths, 0, 60.0);
+        assert_eq!(lengths, vec![60.0, 40.0, 10.0]);
+
+        // The first cell can't get smaller than 20, and the second not bigger than 70:
+        move_separator(&sizing, &mut lengths, 0, 0.0);
+        assert_eq!(lengths, vec![30.0, 70.0, 10.0]);
+
+        // The second cell can't take from an exact one:
+        move_separator(&sizing, &mut lengths, 1, 80.0);
+        assert_eq!(lengths, vec![30.0, 70.0, 10.0]);
+    }
+
+    #[test]
+    fn test_resize_deltas_follow_the_strip_size() {
+        let sizing: Sizing = vec![Size::relative(0.5), Size::remainder()].into();
+        let deltas = [10.0, -10.0];
+
+        let mut lengths = sizing.to_lengths(100.0, 0.0);
+        apply_resize_deltas(&sizing, &mut lengths, &deltas);
+        assert_eq!(lengths, vec![60.0, 40.0]);
+
+        // The strip got bigger, and the cells still grow with it:
+        let mut lengths = sizing.to_lengths(200.0, 0.0);
+        apply_resize_deltas(&sizing, &mut lengths, &deltas);
+        assert_eq!(lengths, vec![110.0, 90.0]);
+
+        // The deltas never take a cell outside of its range:
+        let sizing: Sizing = vec![Size::exact(30.0), Size::remainder()].into();
+        let mut lengths = sizing.to_lengths(100.0, 0.0);
+        apply_resize_deltas(&sizing, &mut lengths, &deltas);
+        assert_eq!(lengths, vec![30.0, 60.0]);
+    }
+
+    #[test]
+    fn test_span() {
+        egui::__run_test_ui(|ui| {
+            let spacing = ui.spacing().item_spacing;
+
+            let mut cell_rects = vec![];
+            StripBuilder::new(ui)
+                .sizes(Size::exact(50.0), 4)
+                .horizontal(|mut strip| {
+                    strip.span(2, |ui| cell_rects.push(ui.max_rect()));
+                    strip.cell(|ui| cell_rects.push(ui.max_rect()));
+                    strip.span(1, |ui| cell_rects.push(ui.max_rect()));
+                });
+            assert_eq!(cell_rects.len(), 3);
+            // A span covers the spacing between its sizes too:
+            assert_eq!(cell_rects[0].width(), 100.0 + spacing.x);
+            assert_eq!(cell_rects[1].left(), cell_rects[0].right() + spacing.x);
+            assert_eq!(cell_rects[1].width(), 50.0);
+            assert_eq!(cell_rects[2].left(), cell_rects[1].right() + spacing.x);
+            assert_eq!(cell_rects[2].width(), 50.0);
+
+            let mut cell_rects = vec![];
+            StripBuilder::new(ui)
+                .size(Size::exact(10.0))
+                .sizes(Size::exact(20.0), 3)
+                .vertical(|mut strip| {
+                    strip.cell(|ui| cell_rects.push(ui.max_rect()));
+                    strip.span(3, |ui| cell_rects.push(ui.max_rect()));
+                });
+            assert_eq!(cell_rects.len(), 2);
+            assert_eq!(cell_rects[0].height(), 10.0);
+            assert_eq!(cell_rects[1].top(), cell_rects[0].bottom() + spacing.y);
+            assert_eq!(cell_rects[1].height(), 60.0 + 2.0 * spacing.y);
+        });
+    }
+}
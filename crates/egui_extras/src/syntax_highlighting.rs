@@ -37,6 +37,33 @@ pub fn highlight(ctx: &egui::Context, theme: &CodeTheme, code: &str, language: &
     })
 }
 
+/// A layouter for [`egui::TextEdit::layouter`], for editing code with syntax highlighting.
+///
+/// Both the highlighting and the layout are cached, so this is cheap to call every frame.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut code = String::new();
+/// let theme = egui_extras::syntax_highlighting::CodeTheme::from_memory(ui.ctx());
+/// let mut layouter = egui_extras::syntax_highlighting::layouter(&theme, "rs");
+/// ui.add(
+///     egui::TextEdit::multiline(&mut code)
+///         .code_editor()
+///         .layouter(&mut layouter),
+/// );
+/// # });
+/// ```
+pub fn layouter<'a>(
+    theme: &'a CodeTheme,
+    language: &'a str,
+) -> impl FnMut(&egui::Ui, &str, f32) -> std::sync::Arc<egui::Galley> + 'a {
+    move |ui, code, wrap_width| {
+        let mut layout_job = highlight(ui.ctx(), theme, code, language);
+        layout_job.wrap.max_width = wrap_width;
+        ui.fonts(|f| f.layout_job(layout_job))
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 #[cfg(not(feature = "syntect"))]
@@ -3,13 +3,16 @@
 //! | fixed size | all available space/minimum | 30% of available width | fixed size |
 //! Takes all available height, so if you want something below the table, put it in a strip.
 
+use std::collections::BTreeSet;
+
 use egui::{
-    scroll_area::ScrollBarVisibility, Align, NumExt as _, Rangef, Rect, Response, ScrollArea, Ui,
-    Vec2, Vec2b,
+    scroll_area::ScrollBarVisibility, Align, Color32, NumExt as _, Rangef, Rect, Response,
+    ScrollArea, Ui, Vec2, Vec2b,
 };
 
 use crate::{
     layout::{CellDirection, CellSize, StripLayoutFlags},
+    strip::{ease_in_out, StripAnimation},
     StripLayout,
 };
 
@@ -38,6 +41,8 @@ pub struct Column {
     clip: bool,
 
     resizable: Option<bool>,
+
+    sortable: bool,
 }
 
 impl Column {
@@ -86,11 +91,14 @@ impl Column {
             width_range: Rangef::new(0.0, f32::INFINITY),
             resizable: None,
             clip: false,
+            sortable: false,
         }
     }
 
     /// Can this column be resized by dragging the column separator?
     ///
+    /// Double-clicking the column separator fits the column to the widest visible cell.
+    ///
     /// If you don't call this, the fallback value of
     /// [`TableBuilder::resizable`] is used (which by default is `false`).
     #[inline]
@@ -99,6 +107,21 @@ impl Column {
         self
     }
 
+    /// Can the table be sorted by this column?
+    ///
+    /// If `true`, clicking the header cell of this column selects it for sorting,
+    /// and clicking it again toggles between ascending and descending order.
+    /// The header cell of the active column shows an indicator of the [`SortOrder`].
+    ///
+    /// The table doesn't sort anything itself: use [`Table::sort_order`] to sort your data.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
     /// If `true`: Allow the column to shrink enough to clip the contents.
     /// If `false`: The column will always be wide enough to contain all its content.
     ///
@@ -165,6 +188,110 @@ fn to_sizing(columns: &[Column]) -> crate::sizing::Sizing {
 
 // -----------------------------------------------------------------=----------
 
+/// In which order a [`Table`] is sorted by a [`Column::sortable`] column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum SortOrder {
+    /// Smallest first.
+    Ascending,
+
+    /// Largest first.
+    Descending,
+}
+
+impl SortOrder {
+    /// The opposite order.
+    #[inline]
+    pub fn reversed(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+
+    /// Apply this order to the result of a comparison, e.g. in [`slice::sort_by`].
+    #[inline]
+    pub fn apply(self, ordering: std::cmp::Ordering) -> std::cmp::Ordering {
+        match self {
+            Self::Ascending => ordering,
+            Self::Descending => ordering.reverse(),
+        }
+    }
+}
+
+/// The sort order after clicking the header of the column with the given index:
+/// the same column is sorted in the opposite order, and other columns ascending.
+fn sort_order_after_click(
+    sort_order: Option<(usize, SortOrder)>,
+    column_index: usize,
+) -> (usize, SortOrder) {
+    match sort_order {
+        Some((column, order)) if column == column_index => (column, order.reversed()),
+        _ => (column_index, SortOrder::Ascending),
+    }
+}
+
+// -----------------------------------------------------------------=----------
+
+/// How many rows the user can select in a [`TableBuilder::selectable`] table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelectionMode {
+    /// Clicking a row selects it, and only it.
+    Single,
+
+    /// Clicking a row selects it, ctrl/cmd-click toggles single rows,
+    /// and shift-click selects a range of rows.
+    Multi,
+}
+
+/// How to style a body row of a [`Table`], see [`TableBuilder::row_style`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RowStyle {
+    /// Overrides the height of the row that was given to [`TableBody::row`], [`TableBody::rows`]
+    /// or [`TableBody::heterogeneous_rows`].
+    pub height: Option<f32>,
+
+    /// Background color of the row, painted on top of the stripes (if any).
+    pub bg_fill: Option<Color32>,
+
+    /// Overrides the text color of the row, unless it is selected.
+    pub text_color: Option<Color32>,
+
+    /// Highlight the row when hovered, if the cells sense clicks or drags (default: `true`).
+    pub hover_highlight: bool,
+}
+
+impl Default for RowStyle {
+    fn default() -> Self {
+        Self {
+            height: None,
+            bg_fill: None,
+            text_color: None,
+            hover_highlight: true,
+        }
+    }
+}
+
+/// Where the keyboard navigation of a selectable table is.
+#[derive(Clone, Copy, Debug)]
+struct SelectionCursor {
+    /// Where shift-click and shift-arrow ranges start.
+    anchor: usize,
+
+    /// The row last clicked or moved to.
+    row: usize,
+}
+
+impl SelectionCursor {
+    fn range(&self) -> std::ops::RangeInclusive<usize> {
+        self.anchor.min(self.row)..=self.anchor.max(self.row)
+    }
+}
+
+/// Stores which selectable table the arrow keys go to.
+const KEYBOARD_SELECTION_ID: &str = "__table_keyboard_selection";
+
+// -----------------------------------------------------------------=----------
+
 struct TableScrollOptions {
     vscroll: bool,
     drag_to_scroll: bool,
@@ -237,8 +364,38 @@ pub struct TableBuilder<'a> {
     cell_layout: egui::Layout,
     scroll_options: TableScrollOptions,
     sense: egui::Sense,
+    default_sort_order: Option<(usize, SortOrder)>,
+    frozen_columns: usize,
+    selection: Option<(SelectionMode, &'a mut BTreeSet<usize>)>,
+    reorderable_columns: bool,
+    column_names: Option<Vec<String>>,
+    group_header: Option<(f32, AddRow<'a>)>,
+    footer: Option<(f32, AddRow<'a>)>,
+    cell_text: Option<CellText<'a>>,
+    row_style: Option<RowStyleFn<'a>>,
+    cell_cursor: Option<&'a mut Option<(usize, usize)>>,
+    row_move: Option<&'a mut Option<(usize, usize)>>,
+    pagination: Option<Pagination<'a>>,
+    animate: bool,
+    easing: fn(f32) -> f32,
 }
 
+/// See [`TableBuilder::paginate`].
+struct Pagination<'a> {
+    page_size: usize,
+    num_rows: usize,
+    page: &'a mut usize,
+}
+
+/// See [`TableBuilder::group_header`] and [`TableBuilder::footer`].
+type AddRow<'a> = Box<dyn FnOnce(TableRow<'_, '_>) + 'a>;
+
+/// See [`TableBuilder::copy_selection`].
+type CellText<'a> = Box<dyn Fn(usize, usize) -> String + 'a>;
+
+/// See [`TableBuilder::row_style`].
+type RowStyleFn<'a> = Box<dyn Fn(usize) -> Option<RowStyle> + 'a>;
+
 impl<'a> TableBuilder<'a> {
     pub fn new(ui: &'a mut Ui) -> Self {
         let cell_layout = *ui.layout();
@@ -250,6 +407,20 @@ impl<'a> TableBuilder<'a> {
             cell_layout,
             scroll_options: Default::default(),
             sense: egui::Sense::hover(),
+            default_sort_order: None,
+            frozen_columns: 0,
+            selection: None,
+            reorderable_columns: false,
+            column_names: None,
+            group_header: None,
+            footer: None,
+            cell_text: None,
+            row_style: None,
+            cell_cursor: None,
+            row_move: None,
+            pagination: None,
+            animate: false,
+            easing: ease_in_out,
         }
     }
 
@@ -271,6 +442,8 @@ impl<'a> TableBuilder<'a> {
 
     /// Make the columns resizable by dragging.
     ///
+    /// Double-clicking a column separator fits the column to the widest visible cell.
+    ///
     /// You can set this for individual columns with [`Column::resizable`].
     /// [`Self::resizable`] is used as a fallback for any column for which you don't call
     /// [`Column::resizable`].
@@ -285,6 +458,377 @@ impl<'a> TableBuilder<'a> {
         self
     }
 
+    /// Sort by this column in this order, until the user clicks a [`Column::sortable`] header.
+    ///
+    /// Default: not sorted.
+    #[inline]
+    pub fn default_sort_order(mut self, column_index: usize, order: SortOrder) -> Self {
+        self.default_sort_order = Some((column_index, order));
+        self
+    }
+
+    /// Keep the first `count` columns in place, and scroll the other columns horizontally.
+    ///
+    /// If the columns are wider than the table, a horizontal scroll bar is shown below the table,
+    /// and you can scroll horizontally with the mouse wheel (e.g. while holding down shift).
+    /// The header row stays in place vertically as usual, so the top left header cells
+    /// of the frozen columns never move.
+    ///
+    /// Default: `0`.
+    #[inline]
+    pub fn freeze_columns(mut self, count: usize) -> Self {
+        self.frozen_columns = count;
+        self
+    }
+
+    /// Let the user reorder the columns by dragging the header cells sideways.
+    ///
+    /// The columns are then shown in the order of [`Table::column_order`],
+    /// and you MUST add the cells of each row in that order too.
+    /// The column order is stored in [`egui::Memory`], like the column widths.
+    ///
+    /// This only has an effect if the table has a [`Self::header`].
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{TableBuilder, Column};
+    /// let names = ["Name", "Age"];
+    /// let table = TableBuilder::new(ui)
+    ///     .columns(Column::auto(), 2)
+    ///     .reorderable_columns(true)
+    ///     .header(20.0, |mut header| {
+    ///         for &column in header.column_order() {
+    ///             header.col(|ui| {
+    ///                 ui.strong(names[column]);
+    ///             });
+    ///         }
+    ///     });
+    /// let column_order = table.column_order().to_vec();
+    /// table.body(|mut body| {
+    ///     body.row(18.0, |mut row| {
+    ///         for &column in &column_order {
+    ///             row.col(|ui| {
+    ///                 ui.label(if column == 0 { "Alice" } else { "42" });
+    ///             });
+    ///         }
+    ///     });
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn reorderable_columns(mut self, reorderable_columns: bool) -> Self {
+        self.reorderable_columns = reorderable_columns;
+        self
+    }
+
+    /// Let the user hide and show columns with a context menu on the header.
+    ///
+    /// Right-clicking a header cell lists all columns by the given `names`
+    /// (one for each [`Column`], in the order they were added), with a checkbox for each.
+    /// Hidden columns are left out of [`Table::column_order`],
+    /// so you MUST add the cells of each row in that order, like with [`Self::reorderable_columns`].
+    /// The remaining columns re-flow to fill the table whenever a column is hidden or shown.
+    ///
+    /// Which columns are hidden is stored in [`egui::Memory`], like the column widths.
+    ///
+    /// This only has an effect if the table has a [`Self::header`].
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{TableBuilder, Column};
+    /// let names = ["Name", "Age"];
+    /// let table = TableBuilder::new(ui)
+    ///     .columns(Column::auto(), 2)
+    ///     .hideable_columns(names)
+    ///     .header(20.0, |mut header| {
+    ///         for &column in header.column_order() {
+    ///             header.col(|ui| {
+    ///                 ui.strong(names[column]);
+    ///             });
+    ///         }
+    ///     });
+    /// let column_order = table.column_order().to_vec();
+    /// table.body(|mut body| {
+    ///     body.row(18.0, |mut row| {
+    ///         for &column in &column_order {
+    ///             row.col(|ui| {
+    ///                 ui.label(if column == 0 { "Alice" } else { "42" });
+    ///             });
+    ///         }
+    ///     });
+    /// });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn hideable_columns(mut self, names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.column_names = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Let the user select rows by clicking them.
+    ///
+    /// The indices of the selected rows are stored in `selection`, which you own.
+    /// Selected rows are highlighted, and after clicking a row the selection can be moved
+    /// with the up/down arrow keys (hold shift to extend it in [`SelectionMode::Multi`]).
+    ///
+    /// This makes the body cells sense clicks, see [`Self::sense`].
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{TableBuilder, Column, SelectionMode};
+    /// let mut selection = std::collections::BTreeSet::new();
+    /// TableBuilder::new(ui)
+    ///     .column(Column::remainder())
+    ///     .selectable(SelectionMode::Multi, &mut selection)
+    ///     .body(|body| {
+    ///         body.rows(18.0, 100, |mut row| {
+    ///             let row_index = row.index();
+    ///             row.col(|ui| {
+    ///                 ui.label(format!("Row {row_index}"));
+    ///             });
+    ///         });
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn selectable(mut self, mode: SelectionMode, selection: &'a mut BTreeSet<usize>) -> Self {
+        self.selection = Some((mode, selection));
+        self
+    }
+
+    /// Let the user copy the selected rows of a [`Self::selectable`] table,
+    /// with ctrl/cmd+C or the context menu of a row.
+    ///
+    /// `cell_text` is called with the row index and the column index (as added to the builder),
+    /// and should return the text of that cell.
+    /// The rows are copied as tab-separated text, with the columns in the order they are shown.
+    /// Tabs and newlines in the cells are replaced by spaces.
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{TableBuilder, Column, SelectionMode};
+    /// let names = ["Alice", "Bob"];
+    /// let mut selection = std::collections::BTreeSet::new();
+    /// TableBuilder::new(ui)
+    ///     .columns(Column::auto(), 2)
+    ///     .selectable(SelectionMode::Multi, &mut selection)
+    ///     .copy_selection(|row, column| match column {
+    ///         0 => row.to_string(),
+    ///         _ => names[row].to_owned(),
+    ///     })
+    ///     .body(|body| {
+    ///         body.rows(18.0, names.len(), |mut row| {
+    ///             let row_index = row.index();
+    ///             row.col(|ui| {
+    ///                 ui.label(row_index.to_string());
+    ///             });
+    ///             row.col(|ui| {
+    ///                 ui.label(names[row_index]);
+    ///             });
+    ///         });
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn copy_selection(mut self, cell_text: impl Fn(usize, usize) -> String + 'a) -> Self {
+        self.cell_text = Some(Box::new(cell_text));
+        self
+    }
+
+    /// Style individual body rows, e.g. to show rows with errors in red.
+    ///
+    /// `row_style` is called with the index of each shown row.
+    /// Return `None` to use the default style.
+    ///
+    /// If a style sets [`RowStyle::height`], [`TableBody::rows`] and [`TableBody::heterogeneous_rows`]
+    /// need to know the heights of all rows, so then `row_style` is called for every row,
+    /// not just the visible ones.
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{TableBuilder, Column, RowStyle};
+    /// let messages = [("ok", false), ("failed", true)];
+    /// TableBuilder::new(ui)
+    ///     .column(Column::remainder())
+    ///     .row_style(|row_index| {
+    ///         messages[row_index].1.then(|| RowStyle {
+    ///             text_color: Some(egui::Color32::RED),
+    ///             ..Default::default()
+    ///         })
+    ///     })
+    ///     .body(|body| {
+    ///         body.rows(18.0, messages.len(), |mut row| {
+    ///             let (message, _) = messages[row.index()];
+    ///             row.col(|ui| {
+    ///                 ui.label(message);
+    ///             });
+    ///         });
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn row_style(mut self, row_style: impl Fn(usize) -> Option<RowStyle> + 'a) -> Self {
+        self.row_style = Some(Box::new(row_style));
+        self
+    }
+
+    /// Let the user move a cell cursor around the body with the keyboard, like in a spreadsheet.
+    ///
+    /// `cell_cursor` is the `(row, column)` of the cell with the cursor, which you own.
+    /// The column index is in the order the columns are shown (see [`Table::column_order`]).
+    ///
+    /// Clicking a cell moves the cursor there. After that the arrow keys move it,
+    /// home/end go to the first/last column (or with ctrl/cmd to the first/last row),
+    /// and page up/down move it by the number of visible rows.
+    /// The body is scrolled to keep the cursor visible.
+    ///
+    /// This makes the body cells sense clicks, see [`Self::sense`].
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{TableBuilder, Column};
+    /// let mut cell_cursor = None;
+    /// TableBuilder::new(ui)
+    ///     .columns(Column::auto(), 3)
+    ///     .cell_cursor(&mut cell_cursor)
+    ///     .body(|body| {
+    ///         body.rows(18.0, 100, |mut row| {
+    ///             let row_index = row.index();
+    ///             for col in 0..3 {
+    ///                 row.col(|ui| {
+    ///                     ui.label(format!("{row_index}, {col}"));
+    ///                 });
+    ///             }
+    ///         });
+    ///     });
+    /// if let Some((row, col)) = cell_cursor {
+    ///     ui.label(format!("Cursor at {row}, {col}"));
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn cell_cursor(mut self, cell_cursor: &'a mut Option<(usize, usize)>) -> Self {
+        self.cell_cursor = Some(cell_cursor);
+        self
+    }
+
+    /// Let the user reorder the rows by dragging them by their [`TableRow::drag_handle`].
+    ///
+    /// While a row is dragged, a line shows where it would be dropped,
+    /// and the body scrolls when the row is dragged near its top or bottom edge.
+    /// When the row is dropped, `row_move` is set to `Some((from, to))`,
+    /// meaning that the row at index `from` should be moved so that it ends up at index `to`.
+    /// It is up to you to update your data accordingly, and to reset `row_move`.
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{TableBuilder, Column};
+    /// let mut names = vec!["Alice", "Bob", "Carol"];
+    /// let mut row_move = None;
+    /// TableBuilder::new(ui)
+    ///     .column(Column::auto())
+    ///     .column(Column::remainder())
+    ///     .reorderable_rows(&mut row_move)
+    ///     .body(|mut body| {
+    ///         for name in &names {
+    ///             body.row(18.0, |mut row| {
+    ///                 row.drag_handle();
+    ///                 row.col(|ui| {
+    ///                     ui.label(*name);
+    ///                 });
+    ///             });
+    ///         }
+    ///     });
+    /// if let Some((from, to)) = row_move.take() {
+    ///     let name = names.remove(from);
+    ///     names.insert(to, name);
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn reorderable_rows(mut self, row_move: &'a mut Option<(usize, usize)>) -> Self {
+        self.row_move = Some(row_move);
+        self
+    }
+
+    /// Show the rows one page at a time, with a pager below the table for changing the page.
+    ///
+    /// This is useful if you fetch the rows page by page, e.g. from a server.
+    /// Otherwise it is better to use [`TableBody::rows`], which only shows the visible rows anyway.
+    ///
+    /// `page` is the index of the current page, which you own.
+    /// The pager changes it when the user goes to another page,
+    /// and it is clamped to the number of pages given by `num_rows` and `page_size`.
+    /// Add only the rows of the current page to the body.
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{TableBuilder, Column};
+    /// let (num_rows, page_size) = (1000, 50);
+    /// let mut page = 0;
+    /// let first_row = page * page_size;
+    /// TableBuilder::new(ui)
+    ///     .column(Column::remainder())
+    ///     .paginate(page_size, num_rows, &mut page)
+    ///     .body(|mut body| {
+    ///         for row_index in first_row..(first_row + page_size).min(num_rows) {
+    ///             body.row(18.0, |mut row| {
+    ///                 row.col(|ui| {
+    ///                     ui.label(format!("Row {row_index}"));
+    ///                 });
+    ///             });
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn paginate(mut self, page_size: usize, num_rows: usize, page: &'a mut usize) -> Self {
+        *page = (*page).min(num_pages(num_rows, page_size) - 1);
+        self.pagination = Some(Pagination {
+            page_size,
+            num_rows,
+            page,
+        });
+        self
+    }
+
+    /// Animate changes to the column widths, instead of snapping to the new widths.
+    ///
+    /// For instance, double-clicking a column separator will smoothly fit the column to its contents,
+    /// and changing a [`Column::exact`] width will smoothly resize the column.
+    /// The animation takes [`egui::Style::animation_time`] seconds.
+    ///
+    /// Dragging a column separator is not animated,
+    /// and neither are columns being hidden or shown again.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn animate(mut self, animate: bool) -> Self {
+        self.animate = animate;
+        self
+    }
+
+    /// How the column widths move during an animation, see [`Self::animate`].
+    ///
+    /// Works like [`crate::StripBuilder::easing`].
+    ///
+    /// Default: ease in and out.
+    #[inline]
+    pub fn easing(mut self, easing: fn(f32) -> f32) -> Self {
+        self.easing = easing;
+        self
+    }
+
     /// Enable vertical scrolling in body (default: `true`)
     #[inline]
     pub fn vscroll(mut self, vscroll: bool) -> Self {
@@ -399,18 +943,96 @@ impl<'a> TableBuilder<'a> {
         self
     }
 
-    fn available_width(&self) -> f32 {
-        self.ui.available_rect_before_wrap().width()
-            - if self.scroll_options.vscroll {
-                self.ui.spacing().scroll.bar_inner_margin
-                    + self.ui.spacing().scroll.bar_width
-                    + self.ui.spacing().scroll.bar_outer_margin
-            } else {
-                0.0
-            }
+    /// Add a row above the header, with cells which can span several columns.
+    ///
+    /// Use [`TableRow::col_span`] to add a cell spanning several columns,
+    /// e.g. "Position" above the columns "X", "Y" and "Z".
+    /// The columns are counted in the order they are shown (see [`Table::column_order`]).
+    /// A cell should not span both frozen and non-frozen columns (see [`Self::freeze_columns`]).
+    ///
+    /// This is only shown if you also call [`Self::header`].
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{TableBuilder, Column};
+    /// TableBuilder::new(ui)
+    ///     .columns(Column::auto(), 4)
+    ///     .group_header(20.0, |mut row| {
+    ///         row.col(|_ui| {});
+    ///         row.col_span(3, |ui| {
+    ///             ui.strong("Position");
+    ///         });
+    ///     })
+    ///     .header(20.0, |mut header| {
+    ///         for name in ["Name", "X", "Y", "Z"] {
+    ///             header.col(|ui| {
+    ///                 ui.strong(name);
+    ///             });
+    ///         }
+    ///     })
+    ///     .body(|_body| {});
+    /// # });
+    /// ```
+    #[inline]
+    pub fn group_header(
+        mut self,
+        height: f32,
+        add_group_row: impl FnOnce(TableRow<'_, '_>) + 'a,
+    ) -> Self {
+        self.group_header = Some((height, Box::new(add_group_row)));
+        self
     }
 
-    /// Create a header row which always stays visible and at the top
+    /// Add a footer row which always stays visible below the body, e.g. for showing totals.
+    ///
+    /// The body is made short enough to leave room for the footer.
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{TableBuilder, Column};
+    /// let prices = [1.5, 2.0, 0.25];
+    /// TableBuilder::new(ui)
+    ///     .column(Column::remainder())
+    ///     .footer(20.0, |mut footer| {
+    ///         footer.col(|ui| {
+    ///             ui.strong(format!("Total: {}", prices.iter().sum::<f64>()));
+    ///         });
+    ///     })
+    ///     .body(|mut body| {
+    ///         for price in prices {
+    ///             body.row(18.0, |mut row| {
+    ///                 row.col(|ui| {
+    ///                     ui.label(price.to_string());
+    ///                 });
+    ///             });
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn footer(
+        mut self,
+        height: f32,
+        add_footer_row: impl FnOnce(TableRow<'_, '_>) + 'a,
+    ) -> Self {
+        self.footer = Some((height, Box::new(add_footer_row)));
+        self
+    }
+
+    fn available_width(&self) -> f32 {
+        self.ui.available_rect_before_wrap().width()
+            - if self.scroll_options.vscroll {
+                self.ui.spacing().scroll.bar_inner_margin
+                    + self.ui.spacing().scroll.bar_width
+                    + self.ui.spacing().scroll.bar_outer_margin
+            } else {
+                0.0
+            }
+    }
+
+    /// Create a header row which always stays visible and at the top
     pub fn header(self, height: f32, add_header_row: impl FnOnce(TableRow<'_, '_>)) -> Table<'a> {
         let available_width = self.available_width();
 
@@ -422,40 +1044,142 @@ impl<'a> TableBuilder<'a> {
             cell_layout,
             scroll_options,
             sense,
+            default_sort_order,
+            frozen_columns,
+            selection,
+            reorderable_columns,
+            column_names,
+            group_header,
+            footer,
+            cell_text,
+            row_style,
+            cell_cursor,
+            row_move,
+            pagination,
+            animate,
+            easing,
         } = self;
 
         let striped = striped.unwrap_or(ui.visuals().striped);
+        let easing = animate.then_some(easing);
 
         let state_id = ui.id().with("__table_state");
 
         let initial_widths =
             to_sizing(&columns).to_lengths(available_width, ui.spacing().item_spacing.x);
         let mut max_used_widths = vec![0.0; initial_widths.len()];
-        let (had_state, state) = TableState::load(ui, initial_widths, state_id);
+        let (mut had_state, mut state) = TableState::load(
+            ui,
+            initial_widths,
+            default_sort_order,
+            reorderable_columns,
+            column_names.is_some(),
+            state_id,
+        );
+
+        // From now on, everything is in the order the columns are shown in:
+        let all_columns = columns;
+        let columns: Vec<Column> = state.column_order.iter().map(|&i| all_columns[i]).collect();
+        had_state &= !state.reflow(&columns, available_width, ui.spacing().item_spacing.x);
+        let widths = animated_widths(ui.ctx(), state_id, &state.column_widths, easing);
         let is_first_frame = !had_state;
         let first_frame_auto_size_columns = is_first_frame && columns.iter().any(|c| c.is_auto());
 
         let table_top = ui.cursor().top();
 
-        // Hide first-frame-jitters when auto-sizing.
-        ui.add_visible_ui(!first_frame_auto_size_columns, |ui| {
-            let mut layout = StripLayout::new(ui, CellDirection::Horizontal, cell_layout, sense);
-            let mut response: Option<Response> = None;
-            add_header_row(TableRow {
-                layout: &mut layout,
-                columns: &columns,
-                widths: &state.column_widths,
-                max_used_widths: &mut max_used_widths,
-                row_index: 0,
-                col_index: 0,
-                height,
-                striped: false,
-                hovered: false,
-                selected: false,
-                response: &mut response,
+        if let Some((group_height, add_group_row)) = group_header {
+            // Hide first-frame-jitters when auto-sizing.
+            ui.add_visible_ui(!first_frame_auto_size_columns, |ui| {
+                let mut layout =
+                    StripLayout::new(ui, CellDirection::Horizontal, cell_layout, sense);
+                if frozen_columns > 0 {
+                    let frozen_width = state.frozen_width(frozen_columns, layout.ui);
+                    layout.set_horizontal_scroll(frozen_width, state.scroll_offset_x);
+                }
+                let mut response: Option<Response> = None;
+                add_group_row(TableRow {
+                    layout: &mut layout,
+                    columns: &columns,
+                    widths: &widths,
+                    column_order: &state.column_order,
+                    max_used_widths: &mut max_used_widths,
+                    row_index: 0,
+                    col_index: 0,
+                    height: group_height,
+                    striped: false,
+                    hovered: false,
+                    selected: false,
+                    style: RowStyle::default(),
+                    response: &mut response,
+                    sort_order: None,
+                    header_cell_responses: None,
+                    tree_node: None,
+                    edit_state: None,
+                    cell_cursor: None,
+                    row_drag_id: None,
+                });
+                layout.allocate_rect();
             });
-            layout.allocate_rect();
-        });
+        }
+
+        // Hide first-frame-jitters when auto-sizing.
+        let (column_move, toggled_column) = ui
+            .add_visible_ui(!first_frame_auto_size_columns, |ui| {
+                let mut layout =
+                    StripLayout::new(ui, CellDirection::Horizontal, cell_layout, sense);
+                if frozen_columns > 0 {
+                    let frozen_width = state.frozen_width(frozen_columns, layout.ui);
+                    layout.set_horizontal_scroll(frozen_width, state.scroll_offset_x);
+                }
+                let mut response: Option<Response> = None;
+                let mut header_cell_responses = Vec::new();
+                add_header_row(TableRow {
+                    layout: &mut layout,
+                    columns: &columns,
+                    widths: &widths,
+                    column_order: &state.column_order,
+                    max_used_widths: &mut max_used_widths,
+                    row_index: 0,
+                    col_index: 0,
+                    height,
+                    striped: false,
+                    hovered: false,
+                    selected: false,
+                    style: RowStyle::default(),
+                    response: &mut response,
+                    sort_order: Some(&mut state.sort_order),
+                    header_cell_responses: (reorderable_columns || column_names.is_some())
+                        .then_some(&mut header_cell_responses),
+                    tree_node: None,
+                    edit_state: None,
+                    cell_cursor: None,
+                    row_drag_id: None,
+                });
+                layout.allocate_rect();
+
+                let column_move = if reorderable_columns {
+                    column_reorder_ui(ui, state_id, &header_cell_responses)
+                } else {
+                    None
+                };
+
+                let mut toggled_column = None;
+                if let Some(column_names) = &column_names {
+                    for response in &header_cell_responses {
+                        response.context_menu(|ui| {
+                            toggled_column = column_visibility_menu_ui(
+                                ui,
+                                column_names,
+                                all_columns.len(),
+                                &state.column_order,
+                            );
+                        });
+                    }
+                }
+
+                (column_move, toggled_column)
+            })
+            .inner;
 
         Table {
             ui,
@@ -464,6 +1188,8 @@ impl<'a> TableBuilder<'a> {
             columns,
             available_width,
             state,
+            widths,
+            easing,
             max_used_widths,
             first_frame_auto_size_columns,
             resizable,
@@ -471,6 +1197,16 @@ impl<'a> TableBuilder<'a> {
             cell_layout,
             scroll_options,
             sense,
+            frozen_columns,
+            selection,
+            column_move,
+            toggled_column,
+            footer,
+            cell_text,
+            row_style,
+            cell_cursor,
+            row_move,
+            pagination,
         }
     }
 
@@ -489,16 +1225,40 @@ impl<'a> TableBuilder<'a> {
             cell_layout,
             scroll_options,
             sense,
+            default_sort_order,
+            frozen_columns,
+            selection,
+            reorderable_columns: _, // can't reorder without a header
+            column_names: _,        // can't hide columns without a header
+            group_header: _,        // only shown above a header
+            footer,
+            cell_text,
+            row_style,
+            cell_cursor,
+            row_move,
+            pagination,
+            animate,
+            easing,
         } = self;
 
         let striped = striped.unwrap_or(ui.visuals().striped);
+        let easing = animate.then_some(easing);
 
         let state_id = ui.id().with("__table_state");
 
         let initial_widths =
             to_sizing(&columns).to_lengths(available_width, ui.spacing().item_spacing.x);
         let max_used_widths = vec![0.0; initial_widths.len()];
-        let (had_state, state) = TableState::load(ui, initial_widths, state_id);
+        let (mut had_state, mut state) = TableState::load(
+            ui,
+            initial_widths,
+            default_sort_order,
+            false,
+            false,
+            state_id,
+        );
+        had_state &= !state.reflow(&columns, available_width, ui.spacing().item_spacing.x);
+        let widths = animated_widths(ui.ctx(), state_id, &state.column_widths, easing);
         let is_first_frame = !had_state;
         let first_frame_auto_size_columns = is_first_frame && columns.iter().any(|c| c.is_auto());
 
@@ -511,6 +1271,8 @@ impl<'a> TableBuilder<'a> {
             columns,
             available_width,
             state,
+            widths,
+            easing,
             max_used_widths,
             first_frame_auto_size_columns,
             resizable,
@@ -518,6 +1280,16 @@ impl<'a> TableBuilder<'a> {
             cell_layout,
             scroll_options,
             sense,
+            frozen_columns,
+            selection,
+            column_move: None,
+            toggled_column: None,
+            footer,
+            cell_text,
+            row_style,
+            cell_cursor,
+            row_move,
+            pagination,
         }
         .body(add_body_contents);
     }
@@ -525,32 +1297,211 @@ impl<'a> TableBuilder<'a> {
 
 // ----------------------------------------------------------------------------
 
+fn animation_id(state_id: egui::Id) -> egui::Id {
+    state_id.with("__animation")
+}
+
+/// The column widths to show this frame, see [`TableBuilder::animate`].
+fn animated_widths(
+    ctx: &egui::Context,
+    state_id: egui::Id,
+    target_widths: &[f32],
+    easing: Option<fn(f32) -> f32>,
+) -> Vec<f32> {
+    if let Some(easing) = easing {
+        StripAnimation::animate(ctx, animation_id(state_id), target_widths.to_vec(), easing)
+    } else {
+        target_widths.to_vec()
+    }
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 struct TableState {
     column_widths: Vec<f32>,
+
+    /// The column to sort by, if any.
+    #[serde(default)]
+    sort_order: Option<(usize, SortOrder)>,
+
+    /// How far the non-frozen columns are scrolled horizontally.
+    #[serde(default)]
+    scroll_offset_x: f32,
+
+    /// For each shown column, the index of the [`Column`] as added to the [`TableBuilder`].
+    ///
+    /// [`Self::column_widths`] and the column index of [`Self::sort_order`] are in this order.
+    #[serde(default)]
+    column_order: Vec<usize>,
+
+    /// The indices of the [`Column`]s the user has hidden, see [`TableBuilder::hideable_columns`].
+    #[serde(default)]
+    hidden_columns: Vec<usize>,
 }
 
 impl TableState {
     /// Returns `true` if it did load.
-    fn load(ui: &egui::Ui, default_widths: Vec<f32>, state_id: egui::Id) -> (bool, Self) {
+    fn load(
+        ui: &egui::Ui,
+        default_widths: Vec<f32>,
+        default_sort_order: Option<(usize, SortOrder)>,
+        reorderable_columns: bool,
+        hideable_columns: bool,
+        state_id: egui::Id,
+    ) -> (bool, Self) {
         let rect = Rect::from_min_size(ui.available_rect_before_wrap().min, Vec2::ZERO);
         ui.ctx().check_for_id_clash(state_id, rect, "Table");
 
-        if let Some(state) = ui.data_mut(|d| d.get_persisted::<Self>(state_id)) {
+        if let Some(mut state) = ui.data_mut(|d| d.get_persisted::<Self>(state_id)) {
             // make sure that the stored widths aren't out-dated
-            if state.column_widths.len() == default_widths.len() {
+            if state.fix_column_order(default_widths.len(), reorderable_columns, hideable_columns) {
                 return (true, state);
             }
         }
 
+        let column_order = (0..default_widths.len()).collect();
         (
             false,
             Self {
                 column_widths: default_widths,
+                sort_order: default_sort_order,
+                scroll_offset_x: 0.0,
+                column_order,
+                hidden_columns: Vec::new(),
             },
         )
     }
 
+    /// Make sure [`Self::column_order`] is valid,
+    /// go back to the original order if the columns can no longer be reordered,
+    /// and show all columns again if they can no longer be hidden.
+    ///
+    /// Returns `false` if the stored state is out-dated.
+    fn fix_column_order(
+        &mut self,
+        num_columns: usize,
+        reorderable_columns: bool,
+        hideable_columns: bool,
+    ) -> bool {
+        if !hideable_columns && !self.hidden_columns.is_empty() {
+            let sort_column = self.sort_column();
+            self.column_order.append(&mut self.hidden_columns);
+            self.column_widths.clear(); // re-flow
+            self.set_sort_column(sort_column);
+        }
+
+        let mut sorted = self.column_order.clone();
+        sorted.extend_from_slice(&self.hidden_columns);
+        sorted.sort_unstable();
+        if !sorted.into_iter().eq(0..num_columns) {
+            if self.column_widths.len() != num_columns {
+                return false;
+            }
+            self.column_order = (0..num_columns).collect();
+            self.hidden_columns.clear();
+        } else if !reorderable_columns {
+            let sort_column = self.sort_column();
+            if self.column_widths.len() == self.column_order.len() {
+                let mut shown: Vec<(usize, f32)> = self
+                    .column_order
+                    .iter()
+                    .copied()
+                    .zip(self.column_widths.iter().copied())
+                    .collect();
+                shown.sort_by_key(|&(column_index, _)| column_index);
+                (self.column_order, self.column_widths) = shown.into_iter().unzip();
+            } else {
+                self.column_order.sort_unstable();
+            }
+            self.set_sort_column(sort_column);
+        }
+        true
+    }
+
+    /// The index of the [`Column`] to sort by, as added to the [`TableBuilder`].
+    fn sort_column(&self) -> Option<usize> {
+        let (column, _) = self.sort_order?;
+        self.column_order.get(column).copied()
+    }
+
+    /// Point [`Self::sort_order`] at where the given [`Column`] is now shown,
+    /// or stop sorting if it is hidden.
+    fn set_sort_column(&mut self, sort_column: Option<usize>) {
+        self.sort_order = self.sort_order.and_then(|(_, order)| {
+            let column_index = sort_column?;
+            let shown_index = self.column_order.iter().position(|&c| c == column_index)?;
+            Some((shown_index, order))
+        });
+    }
+
+    /// Hide the [`Column`] with the given index (as added to the [`TableBuilder`]),
+    /// or show it again if it is hidden.
+    fn toggle_column(&mut self, column_index: usize) {
+        let sort_column = self.sort_column();
+        if let Some(shown_index) = self.column_order.iter().position(|&c| c == column_index) {
+            if self.column_order.len() <= 1 {
+                return; // Always show at least one column, so the header can be right-clicked.
+            }
+            self.column_order.remove(shown_index);
+            self.hidden_columns.push(column_index);
+        } else if let Some(i) = self.hidden_columns.iter().position(|&c| c == column_index) {
+            self.hidden_columns.remove(i);
+            // Show it after the columns that were added before it:
+            let shown_index = self
+                .column_order
+                .iter()
+                .take_while(|&&c| c < column_index)
+                .count();
+            self.column_order.insert(shown_index, column_index);
+        } else {
+            return;
+        }
+        self.column_widths.clear(); // re-flow
+        self.set_sort_column(sort_column);
+    }
+
+    /// If the shown columns changed, let them re-flow to fill the available width.
+    ///
+    /// `columns` are the shown columns, in the order they are shown.
+    /// Returns `true` if the column widths were reset.
+    fn reflow(&mut self, columns: &[Column], available_width: f32, spacing_x: f32) -> bool {
+        if self.column_widths.len() == columns.len() {
+            return false;
+        }
+        self.column_widths = to_sizing(columns).to_lengths(available_width, spacing_x);
+        true
+    }
+
+    /// Move the shown column at index `from` so that it ends up at index `to`.
+    fn move_column(&mut self, from: usize, to: usize) {
+        if from >= self.column_order.len() || to >= self.column_order.len() {
+            return;
+        }
+
+        let column_index = self.column_order.remove(from);
+        self.column_order.insert(to, column_index);
+        let width = self.column_widths.remove(from);
+        self.column_widths.insert(to, width);
+
+        if let Some((column, _)) = &mut self.sort_order {
+            if *column == from {
+                *column = to;
+            } else {
+                let without_moved = if *column > from { *column - 1 } else { *column };
+                *column = if without_moved >= to {
+                    without_moved + 1
+                } else {
+                    without_moved
+                };
+            }
+        }
+    }
+
+    /// Width of the first `frozen_columns` columns, including the spacing after them.
+    fn frozen_width(&self, frozen_columns: usize, ui: &egui::Ui) -> f32 {
+        let frozen_widths = &self.column_widths[..frozen_columns.min(self.column_widths.len())];
+        frozen_widths.iter().sum::<f32>() + frozen_widths.len() as f32 * ui.spacing().item_spacing.x
+    }
+
     fn store(self, ui: &egui::Ui, state_id: egui::Id) {
         ui.data_mut(|d| d.insert_persisted(state_id, self));
     }
@@ -569,6 +1520,13 @@ pub struct Table<'a> {
     available_width: f32,
     state: TableState,
 
+    /// The column widths shown this frame, which lag behind [`TableState::column_widths`]
+    /// during an animation.
+    widths: Vec<f32>,
+
+    /// See [`TableBuilder::animate`].
+    easing: Option<fn(f32) -> f32>,
+
     /// Accumulated maximum used widths for each column.
     max_used_widths: Vec<f32>,
 
@@ -580,6 +1538,28 @@ pub struct Table<'a> {
     scroll_options: TableScrollOptions,
 
     sense: egui::Sense,
+
+    frozen_columns: usize,
+
+    selection: Option<(SelectionMode, &'a mut BTreeSet<usize>)>,
+
+    /// A column the user dragged to a new position in the header: `(from, to)`.
+    column_move: Option<(usize, usize)>,
+
+    /// A column the user hid or showed in the header context menu, as added to the [`TableBuilder`].
+    toggled_column: Option<usize>,
+
+    footer: Option<(f32, AddRow<'a>)>,
+
+    cell_text: Option<CellText<'a>>,
+
+    row_style: Option<RowStyleFn<'a>>,
+
+    cell_cursor: Option<&'a mut Option<(usize, usize)>>,
+
+    row_move: Option<&'a mut Option<(usize, usize)>>,
+
+    pagination: Option<Pagination<'a>>,
 }
 
 impl<'a> Table<'a> {
@@ -590,6 +1570,59 @@ impl<'a> Table<'a> {
         self.ui
     }
 
+    /// The column to sort by, and in which order.
+    ///
+    /// This is set by clicking the header of a [`Column::sortable`] column,
+    /// or by [`TableBuilder::default_sort_order`].
+    /// Use it to sort your data before adding the rows in [`Self::body`].
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{TableBuilder, Column};
+    /// let mut names = vec!["Bob", "Alice", "Carol"];
+    /// let table = TableBuilder::new(ui)
+    ///     .column(Column::remainder().sortable(true))
+    ///     .header(20.0, |mut header| {
+    ///         header.col(|ui| {
+    ///             ui.strong("Name");
+    ///         });
+    ///     });
+    /// if let Some((_column_index, order)) = table.sort_order() {
+    ///     names.sort_by(|a, b| order.apply(a.cmp(b)));
+    /// }
+    /// table.body(|mut body| {
+    ///     for name in names {
+    ///         body.row(18.0, |mut row| {
+    ///             row.col(|ui| {
+    ///                 ui.label(name);
+    ///             });
+    ///         });
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn sort_order(&self) -> Option<(usize, SortOrder)> {
+        self.state.sort_order.and_then(|(column, order)| {
+            let column = *self.state.column_order.get(column)?;
+            Some((column, order))
+        })
+    }
+
+    /// In which order the columns are shown.
+    ///
+    /// For each shown column, this is the index of the [`Column`] as it was added to the [`TableBuilder`].
+    /// Unless you use [`TableBuilder::reorderable_columns`] or [`TableBuilder::hideable_columns`],
+    /// this is always `0, 1, 2, …`.
+    /// Columns hidden by the user are left out.
+    ///
+    /// Add the cells of the body rows in this order.
+    #[inline]
+    pub fn column_order(&self) -> &[usize] {
+        &self.state.column_order
+    }
+
     /// Create table body after adding a header row
     pub fn body<F>(self, add_body_contents: F)
     where
@@ -603,14 +1636,32 @@ impl<'a> Table<'a> {
             resizable,
             mut available_width,
             mut state,
+            widths,
+            easing,
             mut max_used_widths,
             first_frame_auto_size_columns,
             striped,
             cell_layout,
             scroll_options,
             sense,
+            frozen_columns,
+            selection,
+            column_move,
+            toggled_column,
+            footer,
+            cell_text,
+            row_style,
+            cell_cursor,
+            row_move,
+            pagination,
         } = self;
 
+        let body_sense = if selection.is_some() || cell_cursor.is_some() {
+            sense | egui::Sense::click()
+        } else {
+            sense
+        };
+
         let TableScrollOptions {
             vscroll,
             drag_to_scroll,
@@ -623,8 +1674,29 @@ impl<'a> Table<'a> {
             scroll_bar_visibility,
         } = scroll_options;
 
+        // Keep the cell cursor visible after it was moved with the keyboard:
+        let scroll_to_cursor_id = state_id.with("__table_scroll_to_cursor");
+        let scroll_to_row = scroll_to_row.or_else(|| {
+            ui.data_mut(|d| d.remove_temp::<usize>(scroll_to_cursor_id))
+                .map(|row| (row, None))
+        });
+
+        let dragged_row = row_move
+            .as_ref()
+            .and_then(|_| ui.data(|d| d.get_temp::<usize>(state_id.with("__table_row_drag"))));
+
         let cursor_position = ui.cursor().min;
 
+        // Leave room for the footer below the body:
+        let mut footer_height = footer
+            .as_ref()
+            .map_or(0.0, |(height, _)| height + ui.spacing().item_spacing.y);
+        if pagination.is_some() {
+            footer_height += ui.spacing().interact_size.y + ui.spacing().item_spacing.y;
+        }
+        let max_scroll_height =
+            max_scroll_height.min((ui.available_height() - footer_height).at_least(0.0));
+
         let mut scroll_area = ScrollArea::new([false, vscroll])
             .auto_shrink(true)
             .drag_to_scroll(drag_to_scroll)
@@ -638,8 +1710,18 @@ impl<'a> Table<'a> {
             scroll_area = scroll_area.vertical_scroll_offset(scroll_offset_y);
         }
 
+        let frozen_width = state.frozen_width(frozen_columns, ui);
+        let scroll_offset_x = if frozen_columns > 0 {
+            state.scroll_offset_x
+        } else {
+            0.0
+        };
+
         let columns_ref = &columns;
-        let widths_ref = &state.column_widths;
+        let widths_ref = &widths;
+        let column_order_ref = &state.column_order;
+        let cell_text_ref = cell_text.as_deref();
+        let row_style_ref = row_style.as_deref();
         let max_used_widths_ref = &mut max_used_widths;
 
         scroll_area.show(ui, move |ui| {
@@ -653,7 +1735,13 @@ impl<'a> Table<'a> {
                 let hovered_row_index =
                     ui.data_mut(|data| data.remove_temp::<usize>(hovered_row_index_id));
 
-                let layout = StripLayout::new(ui, CellDirection::Horizontal, cell_layout, sense);
+                let edit_state = CellEditState::load(ui.ctx(), self.state_id.with("__table_edit"));
+
+                let mut layout =
+                    StripLayout::new(ui, CellDirection::Horizontal, cell_layout, body_sense);
+                if frozen_columns > 0 {
+                    layout.set_horizontal_scroll(frozen_width, scroll_offset_x);
+                }
 
                 add_body_contents(TableBody {
                     layout,
@@ -668,6 +1756,21 @@ impl<'a> Table<'a> {
                     scroll_to_y_range: &mut scroll_to_y_range,
                     hovered_row_index,
                     hovered_row_index_id,
+                    selection,
+                    selection_id: self.state_id.with("__table_selection"),
+                    num_rows: 0,
+                    tree_id: self.state_id.with("__table_tree"),
+                    next_tree_node: None,
+                    edit_state,
+                    column_order: column_order_ref,
+                    cell_text: cell_text_ref,
+                    row_style: row_style_ref,
+                    cell_cursor,
+                    scroll_to_cursor_id,
+                    row_move,
+                    row_drag_id: self.state_id.with("__table_row_drag"),
+                    dragged_row,
+                    row_drop: None,
                 });
 
                 if scroll_to_row.is_some() && scroll_to_y_range.is_none() {
@@ -684,11 +1787,50 @@ impl<'a> Table<'a> {
             }
         });
 
+        if let Some((height, add_footer_row)) = footer {
+            // Hide first-frame-jitters when auto-sizing.
+            ui.add_visible_ui(!first_frame_auto_size_columns, |ui| {
+                let mut layout =
+                    StripLayout::new(ui, CellDirection::Horizontal, cell_layout, sense);
+                if frozen_columns > 0 {
+                    layout.set_horizontal_scroll(frozen_width, scroll_offset_x);
+                }
+                let mut response: Option<Response> = None;
+                add_footer_row(TableRow {
+                    layout: &mut layout,
+                    columns: &columns,
+                    widths: &widths,
+                    column_order: &state.column_order,
+                    max_used_widths: &mut max_used_widths,
+                    row_index: 0,
+                    col_index: 0,
+                    height,
+                    striped: false,
+                    hovered: false,
+                    selected: false,
+                    style: RowStyle::default(),
+                    response: &mut response,
+                    sort_order: None,
+                    header_cell_responses: None,
+                    tree_node: None,
+                    edit_state: None,
+                    cell_cursor: None,
+                    row_drag_id: None,
+                });
+                layout.allocate_rect();
+            });
+        }
+
         let bottom = ui.min_rect().bottom();
 
         let spacing_x = ui.spacing().item_spacing.x;
+        let visible_width = available_width;
+        let frozen_right = cursor_position.x + frozen_width - spacing_x * 0.5;
         let mut x = cursor_position.x - spacing_x * 0.5;
+        let mut dragged_separator = false;
         for (i, column_width) in state.column_widths.iter_mut().enumerate() {
+            // Where the column is shown this frame, which may be in the middle of an animation:
+            let shown_width = widths.get(i).copied().unwrap_or(*column_width);
             let column = &columns[i];
             let column_is_resizable = column.resizable.unwrap_or(resizable);
             let width_range = column.width_range;
@@ -713,16 +1855,24 @@ impl<'a> Table<'a> {
                 break;
             }
 
-            x += *column_width + spacing_x;
+            x += shown_width + spacing_x;
+
+            // Scrolled columns are shown left of where they are laid out:
+            let scroll_x = if i < frozen_columns {
+                0.0
+            } else {
+                scroll_offset_x
+            };
+            let is_behind_frozen_columns = i >= frozen_columns && x - scroll_x < frozen_right;
 
             if column.is_auto() && (first_frame_auto_size_columns || !column_is_resizable) {
                 *column_width = max_used_widths[i];
                 *column_width = width_range.clamp(*column_width);
-            } else if column_is_resizable {
+            } else if column_is_resizable && !is_behind_frozen_columns {
                 let column_resize_id = ui.id().with("resize_column").with(i);
 
-                let mut p0 = egui::pos2(x, table_top);
-                let mut p1 = egui::pos2(x, bottom);
+                let mut p0 = egui::pos2(x - scroll_x, table_top);
+                let mut p1 = egui::pos2(x - scroll_x, bottom);
                 let line_rect = egui::Rect::from_min_max(p0, p1)
                     .expand(ui.style().interaction.resize_grab_radius_side);
 
@@ -735,7 +1885,7 @@ impl<'a> Table<'a> {
                     *column_width = width_range.clamp(max_used_widths[i]);
                 } else if resize_response.dragged() {
                     if let Some(pointer) = ui.ctx().pointer_latest_pos() {
-                        let mut new_width = *column_width + pointer.x - x;
+                        let mut new_width = shown_width + pointer.x - (x - scroll_x);
                         if !column.clip {
                             // Unless we clip we don't want to shrink below the
                             // size that was actually used.
@@ -749,10 +1899,11 @@ impl<'a> Table<'a> {
                         }
                         new_width = width_range.clamp(new_width);
 
-                        let x = x - *column_width + new_width;
+                        let x = x - shown_width + new_width - scroll_x;
                         (p0.x, p1.x) = (x, x);
 
                         *column_width = new_width;
+                        dragged_separator = true;
                     }
                 }
 
@@ -779,10 +1930,171 @@ impl<'a> Table<'a> {
             available_width -= *column_width + spacing_x;
         }
 
+        if frozen_columns > 0 {
+            let scroll_rect = Rect::from_x_y_ranges(
+                cursor_position.x + frozen_width..=cursor_position.x + visible_width,
+                table_top..=bottom,
+            );
+            let content_width = state.column_widths.iter().sum::<f32>()
+                + spacing_x * state.column_widths.len().saturating_sub(1) as f32;
+            state.scroll_offset_x = horizontal_scroll_bar(
+                ui,
+                state_id,
+                scroll_rect,
+                content_width - visible_width,
+                state.scroll_offset_x,
+            );
+        } else {
+            state.scroll_offset_x = 0.0;
+        }
+
+        if let Some(pagination) = pagination {
+            pagination.ui(ui);
+        }
+
+        if dragged_separator && easing.is_some() {
+            // Follow the pointer without lagging behind:
+            StripAnimation::snap(
+                ui.ctx(),
+                animation_id(state_id),
+                state.column_widths.clone(),
+            );
+        }
+
+        if let Some((from, to)) = column_move {
+            state.move_column(from, to);
+        }
+        if let Some(column_index) = toggled_column {
+            state.toggle_column(column_index);
+            ui.ctx().request_repaint();
+        }
+
         state.store(ui, state_id);
     }
 }
 
+impl<'a> Pagination<'a> {
+    /// Show the pager.
+    fn ui(self, ui: &mut Ui) {
+        let Self {
+            page_size,
+            num_rows,
+            page,
+        } = self;
+        let num_pages = num_pages(num_rows, page_size);
+        let last_page = num_pages - 1;
+
+        ui.horizontal(|ui| {
+            let (is_first, is_last) = (*page == 0, *page == last_page);
+            if ui.add_enabled(!is_first, egui::Button::new("⏮")).clicked() {
+                *page = 0;
+            }
+            if ui.add_enabled(!is_first, egui::Button::new("⏴")).clicked() {
+                *page -= 1;
+            }
+            ui.label(format!("Page {} of {num_pages}", *page + 1));
+            if ui.add_enabled(!is_last, egui::Button::new("⏵")).clicked() {
+                *page += 1;
+            }
+            if ui.add_enabled(!is_last, egui::Button::new("⏭")).clicked() {
+                *page = last_page;
+            }
+        });
+    }
+}
+
+/// The given rows as tab-separated text, see [`TableBuilder::copy_selection`].
+///
+/// Tabs and newlines in the cells are replaced by spaces, so they don't end up in another cell.
+fn tab_separated(
+    rows: &BTreeSet<usize>,
+    column_order: &[usize],
+    cell_text: &dyn Fn(usize, usize) -> String,
+) -> String {
+    rows.iter()
+        .map(|&row| {
+            column_order
+                .iter()
+                .map(|&column| cell_text(row, column).replace(['\t', '\n', '\r'], " "))
+                .collect::<Vec<_>>()
+                .join("\t")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The number of pages of a [`TableBuilder::paginate`]d table. There is always at least one.
+fn num_pages(num_rows: usize, page_size: usize) -> usize {
+    num_rows.div_ceil(page_size.max(1)).max(1)
+}
+
+/// Scroll the non-frozen columns horizontally with the mouse wheel,
+/// and show a scroll bar for them below the table.
+///
+/// Returns the new scroll offset.
+fn horizontal_scroll_bar(
+    ui: &mut Ui,
+    state_id: egui::Id,
+    scroll_rect: Rect,
+    max_scroll_offset: f32,
+    mut scroll_offset: f32,
+) -> f32 {
+    let max_scroll_offset = max_scroll_offset.at_least(0.0);
+    if max_scroll_offset <= 0.0 {
+        return 0.0;
+    }
+
+    if ui.rect_contains_pointer(scroll_rect) {
+        let scroll_delta = ui.input(|i| i.smooth_scroll_delta.x);
+        if scroll_delta != 0.0 {
+            scroll_offset -= scroll_delta;
+            // Don't scroll any outer scroll area too:
+            ui.input_mut(|i| i.smooth_scroll_delta.x = 0.0);
+        }
+    }
+
+    let bar_width = ui.spacing().scroll.bar_width;
+    let (rect, _) = ui.allocate_exact_size(
+        egui::vec2(scroll_rect.right() - ui.cursor().left(), bar_width),
+        egui::Sense::hover(),
+    );
+    let bar_rect = Rect::from_x_y_ranges(scroll_rect.x_range(), rect.y_range());
+    let response = ui.interact(
+        bar_rect,
+        state_id.with("__table_scroll_bar"),
+        egui::Sense::click_and_drag(),
+    );
+
+    let visible_width = scroll_rect.width();
+    let handle_width = (bar_rect.width() * visible_width / (visible_width + max_scroll_offset))
+        .at_least(bar_width)
+        .at_most(bar_rect.width());
+    let handle_travel = bar_rect.width() - handle_width;
+
+    if let Some(pointer) = response.interact_pointer_pos() {
+        if handle_travel > 0.0 {
+            let t = (pointer.x - bar_rect.left() - 0.5 * handle_width) / handle_travel;
+            scroll_offset = t * max_scroll_offset;
+        }
+    }
+
+    let scroll_offset = scroll_offset.clamp(0.0, max_scroll_offset);
+
+    let handle_left = bar_rect.left() + handle_travel * scroll_offset / max_scroll_offset;
+    let handle_rect = Rect::from_min_size(
+        egui::pos2(handle_left, bar_rect.top()),
+        egui::vec2(handle_width, bar_rect.height()),
+    );
+    let visuals = ui.style().interact(&response);
+    let rounding = egui::Rounding::same(0.5 * bar_width);
+    ui.painter()
+        .rect_filled(bar_rect, rounding, ui.visuals().extreme_bg_color);
+    ui.painter()
+        .rect_filled(handle_rect, rounding, visuals.bg_fill);
+
+    scroll_offset
+}
+
 /// The body of a table.
 ///
 /// Is created by calling `body` on a [`Table`] (after adding a header row) or [`TableBuilder`] (without a header row).
@@ -813,6 +2125,51 @@ pub struct TableBody<'a> {
 
     /// Used to store the hovered row index between frames.
     hovered_row_index_id: egui::Id,
+
+    selection: Option<(SelectionMode, &'a mut BTreeSet<usize>)>,
+
+    /// Used to store the [`SelectionCursor`] between frames.
+    selection_id: egui::Id,
+
+    /// How many rows were added, used for keyboard navigation.
+    num_rows: usize,
+
+    /// Parent of the ids of the [`Self::tree_row`]s.
+    tree_id: egui::Id,
+
+    /// Set by [`Self::tree_row`] for the next row.
+    next_tree_node: Option<TreeNode>,
+
+    /// Which cell is being edited, see [`TableRow::col_editable`].
+    edit_state: CellEditState,
+
+    /// In which order the columns are shown, see [`Table::column_order`].
+    column_order: &'a [usize],
+
+    /// See [`TableBuilder::copy_selection`].
+    cell_text: Option<&'a dyn Fn(usize, usize) -> String>,
+
+    /// See [`TableBuilder::row_style`].
+    row_style: Option<&'a dyn Fn(usize) -> Option<RowStyle>>,
+
+    /// See [`TableBuilder::cell_cursor`].
+    cell_cursor: Option<&'a mut Option<(usize, usize)>>,
+
+    /// Used to scroll to the cell cursor on the next frame, after moving it with the keyboard.
+    scroll_to_cursor_id: egui::Id,
+
+    /// See [`TableBuilder::reorderable_rows`].
+    row_move: Option<&'a mut Option<(usize, usize)>>,
+
+    /// Used to store the index of the row that is being dragged.
+    row_drag_id: egui::Id,
+
+    /// The row that is being dragged by its [`TableRow::drag_handle`], if any.
+    dragged_row: Option<usize>,
+
+    /// Where the dragged row would be dropped: the row index to insert it at,
+    /// and the y coordinate of the line to show there.
+    row_drop: Option<(usize, f32)>,
 }
 
 impl<'a> TableBody<'a> {
@@ -850,20 +2207,34 @@ impl<'a> TableBody<'a> {
     pub fn row(&mut self, height: f32, add_row_content: impl FnOnce(TableRow<'a, '_>)) {
         let mut response: Option<Response> = None;
         let top_y = self.layout.cursor.y;
+        let selected = self.is_row_selected(self.row_index);
+        let style = self.style_of_row(self.row_index);
+        let height = style.height.unwrap_or(height);
+        let row_drag_id = self.row_drag_id();
         add_row_content(TableRow {
             layout: &mut self.layout,
             columns: self.columns,
             widths: self.widths,
+            column_order: self.column_order,
             max_used_widths: self.max_used_widths,
             row_index: self.row_index,
             col_index: 0,
             height,
             striped: self.striped && self.row_index % 2 == 0,
             hovered: self.hovered_row_index == Some(self.row_index),
-            selected: false,
+            selected,
+            style,
             response: &mut response,
+            sort_order: None,
+            header_cell_responses: None,
+            tree_node: self.next_tree_node.take(),
+            edit_state: Some(&mut self.edit_state),
+            cell_cursor: self.cell_cursor.as_deref_mut(),
+            row_drag_id,
         });
         self.capture_hover_state(&response, self.row_index);
+        self.capture_selection(&response, self.row_index);
+        self.capture_row_drop(&response, self.row_index);
         let bottom_y = self.layout.cursor.y;
 
         if Some(self.row_index) == self.scroll_to_row {
@@ -871,29 +2242,101 @@ impl<'a> TableBody<'a> {
         }
 
         self.row_index += 1;
+        self.num_rows = self.num_rows.max(self.row_index);
     }
 
-    /// Add many rows with same height.
+    /// Add a row of a tree, i.e. a row that can have child rows.
     ///
-    /// Is a lot more performant than adding each individual row as non visible rows must not be rendered.
+    /// The first cell of the row is indented according to `depth`.
+    /// If the row `has_children`, the first cell also gets a triangle
+    /// which the user can click to expand or collapse the row.
+    /// The expanded state is stored in [`egui::Memory`], using `id_source`,
+    /// which must be unique within the table.
     ///
-    /// If you need many rows with different heights, use [`Self::heterogeneous_rows`] instead.
+    /// Returns `true` if the row is expanded, in which case you should add its child rows
+    /// (with `depth + 1`) right after it.
     ///
     /// ### Example
     /// ```
     /// # egui::__run_test_ui(|ui| {
-    /// use egui_extras::{TableBuilder, Column};
+    /// use egui_extras::{TableBody, TableBuilder, Column};
+    ///
+    /// struct Node {
+    ///     name: String,
+    ///     children: Vec<Node>,
+    /// }
+    ///
+    /// fn node_rows(body: &mut TableBody<'_>, node: &Node, depth: usize) {
+    ///     let expanded = body.tree_row(18.0, &node.name, depth, !node.children.is_empty(), |mut row| {
+    ///         row.col(|ui| {
+    ///             ui.label(&node.name);
+    ///         });
+    ///         row.col(|ui| {
+    ///             ui.label(format!("{} children", node.children.len()));
+    ///         });
+    ///     });
+    ///     if expanded {
+    ///         for child in &node.children {
+    ///             node_rows(body, child, depth + 1);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let root = Node {
+    ///     name: "root".to_owned(),
+    ///     children: vec![Node { name: "leaf".to_owned(), children: vec![] }],
+    /// };
+    ///
     /// TableBuilder::new(ui)
-    ///     .column(Column::remainder().at_least(100.0))
+    ///     .columns(Column::auto(), 2)
     ///     .body(|mut body| {
-    ///         let row_height = 18.0;
-    ///         let num_rows = 10_000;
-    ///         body.rows(row_height, num_rows, |mut row| {
-    ///             let row_index = row.index();
-    ///             row.col(|ui| {
-    ///                 ui.label(format!("First column of row {row_index}"));
-    ///             });
-    ///         });
+    ///         node_rows(&mut body, &root, 0);
+    ///     });
+    /// # });
+    /// ```
+    pub fn tree_row(
+        &mut self,
+        height: f32,
+        id_source: impl std::hash::Hash,
+        depth: usize,
+        has_children: bool,
+        add_row_content: impl FnOnce(TableRow<'a, '_>),
+    ) -> bool {
+        let id = self.tree_id.with(id_source);
+        self.next_tree_node = Some(TreeNode {
+            id,
+            depth,
+            has_children,
+        });
+        self.row(height, add_row_content);
+        self.next_tree_node = None;
+
+        has_children
+            && egui::collapsing_header::CollapsingState::load(self.layout.ui.ctx(), id)
+                .map_or(false, |state| state.is_open())
+    }
+
+    /// Add many rows with same height.
+    ///
+    /// Is a lot more performant than adding each individual row as non visible rows must not be rendered.
+    ///
+    /// If you need many rows with different heights, use [`Self::heterogeneous_rows`] instead.
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{TableBuilder, Column};
+    /// TableBuilder::new(ui)
+    ///     .column(Column::remainder().at_least(100.0))
+    ///     .body(|mut body| {
+    ///         let row_height = 18.0;
+    ///         let num_rows = 10_000;
+    ///         body.rows(row_height, num_rows, |mut row| {
+    ///             let row_index = row.index();
+    ///             row.col(|ui| {
+    ///                 ui.label(format!("First column of row {row_index}"));
+    ///             });
+    ///         });
     ///     });
     /// # });
     /// ```
@@ -903,6 +2346,12 @@ impl<'a> TableBody<'a> {
         total_rows: usize,
         mut add_row_content: impl FnMut(TableRow<'_, '_>),
     ) {
+        if self.row_style.is_some() {
+            // The style may change the height of some rows:
+            let heights = std::iter::repeat(row_height_sans_spacing).take(total_rows);
+            return self.heterogeneous_rows(heights, add_row_content);
+        }
+
         let spacing = self.layout.ui.spacing().item_spacing;
         let row_height_with_spacing = row_height_sans_spacing + spacing.y;
 
@@ -929,24 +2378,39 @@ impl<'a> TableBody<'a> {
             ((scroll_offset_y + max_height) / row_height_with_spacing).ceil() as usize + 1;
         let max_row = max_row.min(total_rows);
 
+        let row_drag_id = self.row_drag_id();
         for row_index in min_row..max_row {
             let mut response: Option<Response> = None;
+            let selected = self.is_row_selected(row_index);
+            let style = self.style_of_row(row_index);
             add_row_content(TableRow {
                 layout: &mut self.layout,
                 columns: self.columns,
                 widths: self.widths,
+                column_order: self.column_order,
                 max_used_widths: self.max_used_widths,
                 row_index,
                 col_index: 0,
                 height: row_height_sans_spacing,
                 striped: self.striped && (row_index + self.row_index) % 2 == 0,
                 hovered: self.hovered_row_index == Some(row_index),
-                selected: false,
+                selected,
+                style,
                 response: &mut response,
+                sort_order: None,
+                header_cell_responses: None,
+                tree_node: None,
+                edit_state: Some(&mut self.edit_state),
+                cell_cursor: self.cell_cursor.as_deref_mut(),
+                row_drag_id,
             });
             self.capture_hover_state(&response, row_index);
+            self.capture_selection(&response, row_index);
+            self.capture_row_drop(&response, row_index);
         }
 
+        self.num_rows = self.num_rows.max(total_rows);
+
         if total_rows - max_row > 0 {
             let skip_height = (total_rows - max_row) as f32 * row_height_with_spacing;
             self.add_buffer(skip_height - spacing.y);
@@ -956,8 +2420,8 @@ impl<'a> TableBody<'a> {
     /// Add rows with varying heights.
     ///
     /// This takes a very slight performance hit compared to [`TableBody::rows`] due to the need to
-    /// iterate over all row heights in to calculate the virtual table height above and below the
-    /// visible region, but it is many orders of magnitude more performant than adding individual
+    /// iterate over all row heights to build their prefix sum, which is then binary-searched for the
+    /// visible rows. Only the visible rows are laid out, so it is many orders of magnitude more performant than adding individual
     /// heterogeneously-sized rows using [`TableBody::row`] at the cost of the additional complexity
     /// that comes with pre-calculating row heights and representing them as an iterator.
     ///
@@ -987,113 +2451,403 @@ impl<'a> TableBody<'a> {
         mut add_row_content: impl FnMut(TableRow<'_, '_>),
     ) {
         let spacing = self.layout.ui.spacing().item_spacing;
-        let mut enumerated_heights = heights.enumerate();
 
-        let max_height = self.end_y - self.start_y;
-        let scroll_offset_y = self.scroll_offset_y() as f64;
+        // Prefix sum of the row heights (including spacing), relative to the top of the first row:
+        // `row_tops[i]` is the top of row `i`, and the last element is the total height.
+        let mut row_heights = Vec::with_capacity(heights.size_hint().0);
+        let mut row_tops: Vec<f64> = Vec::with_capacity(heights.size_hint().0 + 1);
+        row_tops.push(0.0);
+        let mut cursor_y: f64 = 0.0;
+        for (row_index, row_height) in heights.enumerate() {
+            let row_height = if self.row_style.is_some() {
+                self.style_of_row(row_index).height.unwrap_or(row_height)
+            } else {
+                row_height
+            };
+            cursor_y += (row_height + spacing.y) as f64;
+            row_heights.push(row_height);
+            row_tops.push(cursor_y);
+        }
+        let total_rows = row_heights.len();
+        let row_bottoms = &row_tops[1..];
+        self.num_rows = self.num_rows.max(total_rows);
 
+        let scroll_offset_y = self.scroll_offset_y() as f64;
+        let visible_bottom_y = scroll_offset_y + (self.end_y - self.start_y) as f64;
         let scroll_to_y_range_offset = self.layout.cursor.y as f64;
 
-        let mut cursor_y: f64 = 0.0;
-
-        // Skip the invisible rows, and populate the first non-virtual row.
-        for (row_index, row_height) in &mut enumerated_heights {
-            let old_cursor_y = cursor_y;
-            cursor_y += (row_height + spacing.y) as f64;
+        if let Some(scroll_to_row) = self.scroll_to_row {
+            *self.scroll_to_y_range = Some(if scroll_to_row < total_rows {
+                Rangef::new(
+                    (scroll_to_y_range_offset + row_tops[scroll_to_row]) as f32,
+                    (scroll_to_y_range_offset + row_bottoms[scroll_to_row]) as f32,
+                )
+            } else {
+                // Catch desire to scroll past the end:
+                Rangef::point((scroll_to_y_range_offset + cursor_y) as f32)
+            });
+        }
 
-            if Some(row_index) == self.scroll_to_row {
-                *self.scroll_to_y_range = Some(Rangef::new(
-                    (scroll_to_y_range_offset + old_cursor_y) as f32,
-                    (scroll_to_y_range_offset + cursor_y) as f32,
-                ));
-            }
+        // Binary search for the visible rows:
+        let max_row =
+            (row_bottoms.partition_point(|&bottom| bottom <= visible_bottom_y) + 1).min(total_rows);
+        let min_row = row_bottoms
+            .partition_point(|&bottom| bottom < scroll_offset_y)
+            .min(max_row);
 
-            if cursor_y >= scroll_offset_y {
-                // This row is visible:
-                self.add_buffer(old_cursor_y as f32); // skip all the invisible rows
-                let mut response: Option<Response> = None;
-                add_row_content(TableRow {
-                    layout: &mut self.layout,
-                    columns: self.columns,
-                    widths: self.widths,
-                    max_used_widths: self.max_used_widths,
-                    row_index,
-                    col_index: 0,
-                    height: row_height,
-                    striped: self.striped && (row_index + self.row_index) % 2 == 0,
-                    hovered: self.hovered_row_index == Some(row_index),
-                    selected: false,
-                    response: &mut response,
-                });
-                self.capture_hover_state(&response, row_index);
-                break;
-            }
+        if min_row > 0 {
+            self.add_buffer(row_tops[min_row] as f32); // skip all the invisible rows above
         }
 
-        // populate visible rows:
-        for (row_index, row_height) in &mut enumerated_heights {
-            let top_y = cursor_y;
+        let row_drag_id = self.row_drag_id();
+        for (row_index, &height) in (min_row..max_row).zip(&row_heights[min_row..max_row]) {
             let mut response: Option<Response> = None;
+            let selected = self.is_row_selected(row_index);
+            let style = self.style_of_row(row_index);
             add_row_content(TableRow {
                 layout: &mut self.layout,
                 columns: self.columns,
                 widths: self.widths,
+                column_order: self.column_order,
                 max_used_widths: self.max_used_widths,
                 row_index,
                 col_index: 0,
-                height: row_height,
+                height,
                 striped: self.striped && (row_index + self.row_index) % 2 == 0,
                 hovered: self.hovered_row_index == Some(row_index),
-                selected: false,
+                selected,
+                style,
                 response: &mut response,
+                sort_order: None,
+                header_cell_responses: None,
+                tree_node: None,
+                edit_state: Some(&mut self.edit_state),
+                cell_cursor: self.cell_cursor.as_deref_mut(),
+                row_drag_id,
             });
             self.capture_hover_state(&response, row_index);
-            cursor_y += (row_height + spacing.y) as f64;
+            self.capture_selection(&response, row_index);
+            self.capture_row_drop(&response, row_index);
+        }
+
+        let height_below_visible = cursor_y - row_tops[max_row];
+        if height_below_visible > 0.0 {
+            // we need to add a buffer to allow the table to
+            // accurately calculate the scrollbar position
+            self.add_buffer(height_below_visible as f32);
+        }
+    }
 
-            if Some(row_index) == self.scroll_to_row {
-                *self.scroll_to_y_range = Some(Rangef::new(
-                    (scroll_to_y_range_offset + top_y) as f32,
-                    (scroll_to_y_range_offset + cursor_y) as f32,
-                ));
+    // Create a table row buffer of the given height to represent the non-visible portion of the
+    // table.
+    fn add_buffer(&mut self, height: f32) {
+        self.layout.skip_space(egui::vec2(0.0, height));
+    }
+
+    fn row_drag_id(&self) -> Option<egui::Id> {
+        self.row_move.is_some().then_some(self.row_drag_id)
+    }
+
+    // Find out where the dragged row would be dropped, if the pointer is over the just created row.
+    fn capture_row_drop(&mut self, response: &Option<Response>, row_index: usize) {
+        let (Some(_), Some(response)) = (self.dragged_row, response) else {
+            return;
+        };
+        let ui = &self.layout.ui;
+        let Some(pointer) = ui.ctx().pointer_latest_pos() else {
+            return;
+        };
+        let half_spacing = 0.5 * ui.spacing().item_spacing.y;
+        let rect = response.rect;
+        if (rect.top() - half_spacing..=rect.bottom() + half_spacing).contains(&pointer.y) {
+            self.row_drop = Some(if pointer.y < rect.center().y {
+                (row_index, rect.top() - half_spacing)
+            } else {
+                (row_index + 1, rect.bottom() + half_spacing)
+            });
+        }
+    }
+
+    // Show where the dragged row would be dropped, and move it there when it is released.
+    fn handle_row_drag(&mut self) {
+        let (Some(row_move), Some(from)) = (&mut self.row_move, self.dragged_row) else {
+            return;
+        };
+        let ui = &self.layout.ui;
+
+        if !ui.input(|i| i.pointer.any_down()) {
+            ui.data_mut(|d| d.remove::<usize>(self.row_drag_id));
+            if let Some((to, _)) = self.row_drop {
+                // `to` is counted with the dragged row still in place:
+                let to = if from < to { to - 1 } else { to };
+                if from != to {
+                    **row_move = Some((from, to));
+                }
             }
+            return;
+        }
 
-            if cursor_y > scroll_offset_y + max_height as f64 {
-                break;
+        ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+
+        let clip_rect = ui.clip_rect();
+        if let Some((_, y)) = self.row_drop {
+            ui.painter()
+                .hline(clip_rect.x_range(), y, ui.visuals().selection.stroke);
+        }
+
+        // Scroll when the row is dragged near the top or bottom edge:
+        if let Some(pointer) = ui.ctx().pointer_latest_pos() {
+            let edge = 2.0 * ui.spacing().interact_size.y;
+            let delta = if pointer.y < clip_rect.top() + edge {
+                clip_rect.top() + edge - pointer.y
+            } else if clip_rect.bottom() - edge < pointer.y {
+                clip_rect.bottom() - edge - pointer.y
+            } else {
+                0.0
+            };
+            if delta != 0.0 {
+                let max_speed = 0.5 * edge; // points per frame
+                ui.scroll_with_delta(Vec2::new(0.0, delta.clamp(-max_speed, max_speed)));
             }
+            ui.ctx().request_repaint();
         }
+    }
 
-        // calculate height below the visible table range:
-        let mut height_below_visible: f64 = 0.0;
-        for (row_index, row_height) in enumerated_heights {
-            height_below_visible += (row_height + spacing.y) as f64;
+    fn style_of_row(&self, row_index: usize) -> RowStyle {
+        self.row_style
+            .and_then(|row_style| row_style(row_index))
+            .unwrap_or_default()
+    }
 
-            let top_y = cursor_y;
-            cursor_y += (row_height + spacing.y) as f64;
-            if Some(row_index) == self.scroll_to_row {
-                *self.scroll_to_y_range = Some(Rangef::new(
-                    (scroll_to_y_range_offset + top_y) as f32,
-                    (scroll_to_y_range_offset + cursor_y) as f32,
-                ));
+    fn is_row_selected(&self, row_index: usize) -> bool {
+        self.selection
+            .as_ref()
+            .map_or(false, |(_, selection)| selection.contains(&row_index))
+    }
+
+    // Update the selection if the just created row was clicked.
+    fn capture_selection(&mut self, response: &Option<Response>, row_index: usize) {
+        self.copy_context_menu(response, row_index);
+
+        if self.cell_cursor.is_some() && response.as_ref().map_or(false, |r| r.clicked()) {
+            // The cell cursor was moved by `TableRow`, now make the keyboard go to this table:
+            self.layout.ui.data_mut(|d| {
+                d.insert_temp(egui::Id::new(KEYBOARD_SELECTION_ID), self.selection_id);
+            });
+        }
+
+        let Some((mode, selection)) = &mut self.selection else {
+            return;
+        };
+        if !response.as_ref().map_or(false, |r| r.clicked()) {
+            return;
+        }
+
+        let ui = &self.layout.ui;
+        let modifiers = ui.input(|i| i.modifiers);
+        let prev_cursor = ui.data(|d| d.get_temp::<SelectionCursor>(self.selection_id));
+
+        let mut cursor = SelectionCursor {
+            anchor: row_index,
+            row: row_index,
+        };
+        match mode {
+            SelectionMode::Single => {
+                selection.clear();
+                selection.insert(row_index);
+            }
+            SelectionMode::Multi => {
+                if let (true, Some(prev_cursor)) = (modifiers.shift, prev_cursor) {
+                    // Select the range from the anchor:
+                    if !modifiers.command {
+                        selection.clear();
+                    }
+                    cursor.anchor = prev_cursor.anchor;
+                    selection.extend(cursor.range());
+                } else if modifiers.command {
+                    if !selection.remove(&row_index) {
+                        selection.insert(row_index);
+                    }
+                } else {
+                    selection.clear();
+                    selection.insert(row_index);
+                }
             }
         }
 
-        if self.scroll_to_row.is_some() && self.scroll_to_y_range.is_none() {
-            // Catch desire to scroll past the end:
-            *self.scroll_to_y_range =
-                Some(Rangef::point((scroll_to_y_range_offset + cursor_y) as f32));
+        ui.data_mut(|d| {
+            d.insert_temp(self.selection_id, cursor);
+            d.insert_temp(egui::Id::new(KEYBOARD_SELECTION_ID), self.selection_id);
+        });
+    }
+
+    // Show a context menu for copying the selection, if enabled.
+    fn copy_context_menu(&mut self, response: &Option<Response>, row_index: usize) {
+        let (Some(response), Some((_, selection)), Some(_)) =
+            (response, &mut self.selection, self.cell_text)
+        else {
+            return;
+        };
+
+        if response.secondary_clicked() && !selection.contains(&row_index) {
+            // Copy the row that was right-clicked:
+            selection.clear();
+            selection.insert(row_index);
         }
 
-        if height_below_visible > 0.0 {
-            // we need to add a buffer to allow the table to
-            // accurately calculate the scrollbar position
-            self.add_buffer(height_below_visible as f32);
+        let mut copy = false;
+        response.context_menu(|ui| {
+            if ui.button("Copy").clicked() {
+                copy = true;
+                ui.close_menu();
+            }
+        });
+        if copy {
+            self.copy_selected_rows();
         }
     }
 
-    // Create a table row buffer of the given height to represent the non-visible portion of the
-    // table.
-    fn add_buffer(&mut self, height: f32) {
-        self.layout.skip_space(egui::vec2(0.0, height));
+    fn copy_selected_rows(&self) {
+        let (Some((_, selection)), Some(cell_text)) = (&self.selection, self.cell_text) else {
+            return;
+        };
+
+        let text = tab_separated(selection, self.column_order, cell_text);
+        self.layout.ui.ctx().copy_text(text);
+    }
+
+    /// Is this the selectable table the keyboard goes to?
+    fn has_keyboard_focus(&self) -> bool {
+        let ui = &self.layout.ui;
+        let is_active = ui.data(|d| d.get_temp::<egui::Id>(egui::Id::new(KEYBOARD_SELECTION_ID)))
+            == Some(self.selection_id);
+        is_active && ui.memory(|mem| mem.focused().is_none())
+    }
+
+    // Copy the selection with ctrl/cmd+C, if this table was the last one clicked.
+    fn handle_copy_key(&self) {
+        if self.cell_text.is_none() || !self.has_keyboard_focus() {
+            return;
+        }
+        let copy = self
+            .layout
+            .ui
+            .input(|i| i.events.iter().any(|e| matches!(e, egui::Event::Copy)));
+        if copy {
+            self.copy_selected_rows();
+        }
+    }
+
+    /// Roughly how many rows are visible at once.
+    fn rows_per_page(&self) -> usize {
+        let avg_row_height = (self.layout.cursor.y - self.layout.rect.top()) / self.num_rows as f32;
+        if avg_row_height > 0.0 {
+            ((self.end_y - self.start_y) / avg_row_height)
+                .floor()
+                .at_least(1.0) as usize
+        } else {
+            1
+        }
+    }
+
+    // Move the cell cursor with the keyboard, if this table was the last one clicked.
+    fn handle_cell_cursor_keys(&mut self) {
+        if self.cell_cursor.is_none()
+            || self.num_rows == 0
+            || self.widths.is_empty()
+            || !self.has_keyboard_focus()
+        {
+            return;
+        }
+        let rows_per_page = self.rows_per_page();
+        let (last_row, last_col) = (self.num_rows - 1, self.widths.len() - 1);
+        let Some(cell_cursor) = self.cell_cursor.as_deref_mut() else {
+            return;
+        };
+
+        let ui = &self.layout.ui;
+        let (mut row, mut col) = cell_cursor.unwrap_or_default();
+        let moved = ui.input(|i| {
+            let mut moved = false;
+            for event in &i.events {
+                let egui::Event::Key {
+                    key,
+                    pressed: true,
+                    modifiers,
+                    ..
+                } = event
+                else {
+                    continue;
+                };
+                match key {
+                    egui::Key::ArrowUp => row = row.saturating_sub(1),
+                    egui::Key::ArrowDown => row += 1,
+                    egui::Key::ArrowLeft => col = col.saturating_sub(1),
+                    egui::Key::ArrowRight => col += 1,
+                    egui::Key::PageUp => row = row.saturating_sub(rows_per_page),
+                    egui::Key::PageDown => row += rows_per_page,
+                    egui::Key::Home if modifiers.command => row = 0,
+                    egui::Key::Home => col = 0,
+                    egui::Key::End if modifiers.command => row = last_row,
+                    egui::Key::End => col = last_col,
+                    _ => continue,
+                }
+                moved = true;
+            }
+            moved
+        });
+        if !moved {
+            return;
+        }
+
+        let row = row.at_most(last_row);
+        *cell_cursor = Some((row, col.at_most(last_col)));
+        ui.data_mut(|d| d.insert_temp(self.scroll_to_cursor_id, row));
+        ui.ctx().request_repaint();
+    }
+
+    // Move the selection with the arrow keys, if this table was the last one clicked.
+    fn handle_selection_keys(&mut self) {
+        if self.num_rows == 0 || !self.has_keyboard_focus() {
+            return;
+        }
+        let Some((mode, selection)) = &mut self.selection else {
+            return;
+        };
+
+        let ui = &self.layout.ui;
+        let Some(mut cursor) = ui.data(|d| d.get_temp::<SelectionCursor>(self.selection_id)) else {
+            return;
+        };
+
+        let (up, down, shift) = ui.input(|i| {
+            (
+                i.key_pressed(egui::Key::ArrowUp),
+                i.key_pressed(egui::Key::ArrowDown),
+                i.modifiers.shift,
+            )
+        });
+        if up == down {
+            return;
+        }
+
+        cursor.row = if up {
+            cursor.row.saturating_sub(1)
+        } else {
+            cursor.row + 1
+        }
+        .at_most(self.num_rows - 1);
+
+        selection.clear();
+        if shift && *mode == SelectionMode::Multi {
+            selection.extend(cursor.range());
+        } else {
+            cursor.anchor = cursor.row;
+            selection.insert(cursor.row);
+        }
+
+        ui.data_mut(|d| d.insert_temp(self.selection_id, cursor));
+        ui.ctx().request_repaint();
     }
 
     // Capture the hover information for the just created row. This is used in the next render
@@ -1110,6 +2864,11 @@ impl<'a> TableBody<'a> {
 
 impl<'a> Drop for TableBody<'a> {
     fn drop(&mut self) {
+        self.handle_selection_keys();
+        self.handle_cell_cursor_keys();
+        self.handle_copy_key();
+        self.handle_row_drag();
+        self.edit_state.store(self.layout.ui.ctx());
         self.layout.allocate_rect();
     }
 }
@@ -1121,6 +2880,9 @@ pub struct TableRow<'a, 'b> {
     columns: &'b [Column],
     widths: &'b [f32],
 
+    /// In which order the columns are shown, see [`Table::column_order`].
+    column_order: &'b [usize],
+
     /// grows during building with the maximum widths
     max_used_widths: &'b mut [f32],
 
@@ -1131,19 +2893,213 @@ pub struct TableRow<'a, 'b> {
     striped: bool,
     hovered: bool,
     selected: bool,
+    style: RowStyle,
 
     response: &'b mut Option<Response>,
+
+    /// Only set for header rows.
+    sort_order: Option<&'b mut Option<(usize, SortOrder)>>,
+
+    /// Only set for header rows of tables with [`TableBuilder::reorderable_columns`].
+    header_cell_responses: Option<&'b mut Vec<Response>>,
+
+    /// Only set for [`TableBody::tree_row`]s.
+    tree_node: Option<TreeNode>,
+
+    /// Only set for body rows.
+    edit_state: Option<&'b mut CellEditState>,
+
+    /// Only set for body rows of tables with [`TableBuilder::cell_cursor`].
+    cell_cursor: Option<&'b mut Option<(usize, usize)>>,
+
+    /// Only set for body rows of tables with [`TableBuilder::reorderable_rows`].
+    row_drag_id: Option<egui::Id>,
 }
 
 impl<'a, 'b> TableRow<'a, 'b> {
     /// Add the contents of a column.
     ///
+    /// If the cell is scrolled out of view horizontally, no [`Ui`] is created for it,
+    /// and `add_cell_contents` is not called.
+    ///
     /// Returns the used space (`min_rect`) plus the [`Response`] of the whole cell.
     #[cfg_attr(debug_assertions, track_caller)]
     pub fn col(&mut self, add_cell_contents: impl FnOnce(&mut Ui)) -> (Rect, Response) {
+        self.cell(None, add_cell_contents)
+    }
+
+    /// Add the contents of a cell spanning `span` columns.
+    ///
+    /// The cell is as wide as the columns it spans, and its contents is clipped to it.
+    /// Mostly useful in [`TableBuilder::group_header`].
+    ///
+    /// Returns the used space (`min_rect`) plus the [`Response`] of the whole cell.
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn col_span(
+        &mut self,
+        span: usize,
+        add_cell_contents: impl FnOnce(&mut Ui),
+    ) -> (Rect, Response) {
+        let col_index = self.col_index;
+        let end = (col_index + span).min(self.widths.len());
+        let widths = &self.widths[col_index.min(end)..end];
+        if widths.len() < span {
+            crate::log_or_panic!(
+                "Added more `Table` columns than were pre-allocated ({} pre-allocated)",
+                self.widths.len()
+            );
+        }
+        self.col_index = col_index + span;
+
+        let spacing_x = self.layout.ui.spacing().item_spacing.x;
+        let width = widths.iter().sum::<f32>() + spacing_x * widths.len().saturating_sub(1) as f32;
+        let width = CellSize::Absolute(width);
+        let height = CellSize::Absolute(self.height);
+        let id_source = egui::Id::new((self.row_index, col_index));
+
+        let (used_rect, response) = if self.layout.is_horizontally_visible(&width, &height) {
+            let flags = StripLayoutFlags {
+                clip: true,
+                striped: self.striped,
+                hovered: self.hovered && self.style.hover_highlight,
+                selected: self.selected,
+                bg_fill: self.style.bg_fill,
+                text_color: self.style.text_color,
+                sense: None,
+                layout: None,
+            };
+            let (used_rect, egui::InnerResponse { response, .. }) =
+                self.layout
+                    .add(flags, width, height, id_source, add_cell_contents);
+            (used_rect, response)
+        } else {
+            let response = self.layout.add_hidden(width, height, id_source);
+            (Rect::from_min_size(response.rect.min, Vec2::ZERO), response)
+        };
+
+        *self.response = Some(
+            self.response
+                .as_ref()
+                .map_or(response.clone(), |r| r.union(response.clone())),
+        );
+
+        (used_rect, response)
+    }
+
+    /// Add a cell which the user can edit by double-clicking it.
+    ///
+    /// Normally `show` is used to display the `value`.
+    /// When the user double-clicks the cell, `edit` is used instead, and given a copy of the value to edit.
+    /// The editor should return the [`Response`] of the widget which should get keyboard focus.
+    ///
+    /// Pressing enter (or clicking elsewhere) writes the edited copy back to `value`,
+    /// while pressing escape discards it.
+    /// Pressing tab also writes the edited copy back, and starts editing the next editable cell.
+    ///
+    /// See also [`Self::col_editable_text`].
+    ///
+    /// Returns the used space (`min_rect`) plus the [`Response`] of the whole cell.
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn col_editable<T: Clone + Send + Sync + 'static>(
+        &mut self,
+        value: &mut T,
+        show: impl FnOnce(&mut Ui, &T),
+        edit: impl FnOnce(&mut Ui, &mut T) -> Response,
+    ) -> (Rect, Response) {
+        let cell = (self.row_index, self.col_index);
+
+        let Some(edit_state) = self.edit_state.as_deref_mut() else {
+            // Header cells can't be edited:
+            return self.col(|ui| show(ui, value));
+        };
+
+        if edit_state.focus_next {
+            // The previous editable cell was left with tab:
+            edit_state.start(cell);
+        }
+
+        if edit_state.editing != Some(cell) {
+            let sense = self.layout.sense | egui::Sense::click();
+            let (used_rect, response) = self.cell(Some(sense), |ui| show(ui, value));
+            if response.double_clicked() {
+                if let Some(edit_state) = self.edit_state.as_deref_mut() {
+                    edit_state.start(cell);
+                }
+                self.layout.ui.ctx().request_repaint();
+            }
+            return (used_rect, response);
+        }
+
+        let request_focus = std::mem::take(&mut edit_state.request_focus);
+        let buffer_id = edit_state.id.with("buffer");
+        let mut buffer = self
+            .layout
+            .ui
+            .data(|d| d.get_temp::<T>(buffer_id))
+            .unwrap_or_else(|| value.clone());
+
+        let mut editor_response = None;
+        let (used_rect, response) = self.col(|ui| {
+            let response = edit(ui, &mut buffer);
+            if request_focus {
+                response.request_focus();
+            }
+            editor_response = Some(response);
+        });
+
+        let Some(editor_response) = editor_response else {
+            // The cell is scrolled out of view; keep editing once it comes back.
+            return (used_rect, response);
+        };
+
+        let ui = &self.layout.ui;
+        if editor_response.lost_focus() {
+            let (escape, tab) = ui.input(|i| {
+                (
+                    i.key_pressed(egui::Key::Escape),
+                    i.key_pressed(egui::Key::Tab) && !i.modifiers.shift,
+                )
+            });
+            if !escape {
+                *value = buffer;
+            }
+            ui.data_mut(|d| d.remove::<T>(buffer_id));
+            if let Some(edit_state) = self.edit_state.as_deref_mut() {
+                edit_state.editing = None;
+                edit_state.focus_next = tab && !escape;
+            }
+        } else {
+            ui.data_mut(|d| d.insert_temp(buffer_id, buffer));
+        }
+
+        (used_rect, response)
+    }
+
+    /// Add a text cell which the user can edit by double-clicking it.
+    ///
+    /// See [`Self::col_editable`] for details.
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn col_editable_text(&mut self, text: &mut String) -> (Rect, Response) {
+        self.col_editable(
+            text,
+            |ui, text| {
+                ui.label(text.as_str());
+            },
+            |ui, text| ui.add(egui::TextEdit::singleline(text).desired_width(f32::INFINITY)),
+        )
+    }
+
+    #[cfg_attr(debug_assertions, track_caller)]
+    fn cell(
+        &mut self,
+        sense: Option<egui::Sense>,
+        add_cell_contents: impl FnOnce(&mut Ui),
+    ) -> (Rect, Response) {
         let col_index = self.col_index;
 
         let clip = self.columns.get(col_index).map_or(false, |c| c.clip);
+        let sortable =
+            self.sort_order.is_some() && self.columns.get(col_index).map_or(false, |c| c.sortable);
 
         let width = if let Some(width) = self.widths.get(col_index) {
             self.col_index += 1;
@@ -1156,28 +3112,100 @@ impl<'a, 'b> TableRow<'a, 'b> {
             8.0 // anything will look wrong, so pick something that is obviously wrong
         };
 
+        let width_points = width;
         let width = CellSize::Absolute(width);
         let height = CellSize::Absolute(self.height);
+        let id_source = egui::Id::new((self.row_index, col_index));
+
+        if !self.layout.is_horizontally_visible(&width, &height) {
+            let response = self.layout.add_hidden(width, height, id_source);
+
+            if let Some(max_w) = self.max_used_widths.get_mut(col_index) {
+                // We didn't measure the contents, so don't let the column shrink:
+                *max_w = max_w.max(width_points);
+            }
+            if let Some(header_cell_responses) = &mut self.header_cell_responses {
+                header_cell_responses.push(response.clone());
+            }
+            *self.response = Some(
+                self.response
+                    .as_ref()
+                    .map_or(response.clone(), |r| r.union(response.clone())),
+            );
+
+            return (Rect::from_min_size(response.rect.min, Vec2::ZERO), response);
+        }
 
         let flags = StripLayoutFlags {
             clip,
             striped: self.striped,
-            hovered: self.hovered,
+            hovered: self.hovered && self.style.hover_highlight,
             selected: self.selected,
+            bg_fill: self.style.bg_fill,
+            text_color: self.style.text_color,
+            sense: match (sortable, self.header_cell_responses.is_some()) {
+                (true, true) => Some(egui::Sense::click_and_drag()),
+                (true, false) => Some(egui::Sense::click()),
+                (false, true) => Some(egui::Sense::drag()),
+                (false, false) => sense,
+            },
+            layout: None,
         };
 
-        let (used_rect, response) = self.layout.add(
-            flags,
-            width,
-            height,
-            egui::Id::new((self.row_index, col_index)),
-            add_cell_contents,
-        );
+        // The first cell of a tree row is indented, and has the expand/collapse button:
+        let tree_node = if col_index == 0 { self.tree_node } else { None };
+        let add_cell_contents = move |ui: &mut Ui| {
+            if let Some(tree_node) = tree_node {
+                tree_node.ui(ui, add_cell_contents);
+            } else {
+                add_cell_contents(ui);
+            }
+        };
+
+        let (used_rect, egui::InnerResponse { response, .. }) =
+            self.layout
+                .add(flags, width, height, id_source, add_cell_contents);
 
         if let Some(max_w) = self.max_used_widths.get_mut(col_index) {
             *max_w = max_w.max(used_rect.width());
         }
 
+        if let Some(cell_cursor) = &mut self.cell_cursor {
+            let cell = (self.row_index, col_index);
+            if response.clicked() {
+                **cell_cursor = Some(cell);
+            }
+            if **cell_cursor == Some(cell) {
+                let stroke = self.layout.ui.visuals().selection.stroke;
+                self.layout
+                    .ui
+                    .painter()
+                    .rect_stroke(response.rect, 0.0, stroke);
+            }
+        }
+
+        if let Some(header_cell_responses) = &mut self.header_cell_responses {
+            header_cell_responses.push(response.clone());
+        }
+
+        if sortable {
+            if let Some(sort_order) = &mut self.sort_order {
+                if response.clicked() {
+                    **sort_order = Some(sort_order_after_click(**sort_order, col_index));
+                }
+                if response.hovered() {
+                    self.layout
+                        .ui
+                        .ctx()
+                        .set_cursor_icon(egui::CursorIcon::PointingHand);
+                }
+                if let Some((_, order)) = (**sort_order).filter(|(column, _)| *column == col_index)
+                {
+                    paint_sort_indicator(self.layout.ui, response.rect, order);
+                }
+            }
+        }
+
         *self.response = Some(
             self.response
                 .as_ref()
@@ -1202,6 +3230,31 @@ impl<'a, 'b> TableRow<'a, 'b> {
             .expect("Should only be called after `col`")
     }
 
+    /// Add a cell with a grab handle, by which the user can drag the row to another position.
+    ///
+    /// See [`TableBuilder::reorderable_rows`].
+    /// Without it, or in the header, this adds an empty cell.
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub fn drag_handle(&mut self) -> (Rect, Response) {
+        let (row_drag_id, row_index) = (self.row_drag_id, self.row_index);
+        self.col(|ui| {
+            let Some(row_drag_id) = row_drag_id else {
+                return;
+            };
+            let response = ui.add(
+                egui::Label::new("☰")
+                    .selectable(false)
+                    .sense(egui::Sense::drag()),
+            );
+            if response.hovered() {
+                ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
+            }
+            if response.drag_started() {
+                ui.data_mut(|d| d.insert_temp(row_drag_id, row_index));
+            }
+        })
+    }
+
     /// Returns the index of the row.
     #[inline]
     pub fn index(&self) -> usize {
@@ -1213,6 +3266,200 @@ impl<'a, 'b> TableRow<'a, 'b> {
     pub fn col_index(&self) -> usize {
         self.col_index
     }
+
+    /// In which order the columns are shown, see [`Table::column_order`].
+    ///
+    /// Use this to add the header cells in the right order
+    /// when using [`TableBuilder::reorderable_columns`] or [`TableBuilder::hideable_columns`].
+    #[inline]
+    pub fn column_order(&self) -> &'b [usize] {
+        self.column_order
+    }
+}
+
+/// Which cell of a table is being edited with [`TableRow::col_editable`].
+///
+/// The value being edited is stored separately, since it can be of any type.
+#[derive(Clone, Copy)]
+struct CellEditState {
+    id: egui::Id,
+
+    /// `(row, column)`
+    editing: Option<(usize, usize)>,
+
+    /// The editor was just opened, and should get keyboard focus.
+    request_focus: bool,
+
+    /// Tab was pressed in the previous editable cell, so the next one should start editing.
+    focus_next: bool,
+}
+
+impl CellEditState {
+    fn load(ctx: &egui::Context, id: egui::Id) -> Self {
+        ctx.data(|d| d.get_temp(id)).unwrap_or(Self {
+            id,
+            editing: None,
+            request_focus: false,
+            focus_next: false,
+        })
+    }
+
+    fn store(mut self, ctx: &egui::Context) {
+        // Tab in the last editable cell stops editing:
+        self.focus_next = false;
+        ctx.data_mut(|d| d.insert_temp(self.id, self));
+    }
+
+    fn start(&mut self, cell: (usize, usize)) {
+        self.editing = Some(cell);
+        self.request_focus = true;
+        self.focus_next = false;
+    }
+}
+
+/// A row added with [`TableBody::tree_row`].
+#[derive(Clone, Copy)]
+struct TreeNode {
+    /// Where the expanded state is stored.
+    id: egui::Id,
+    depth: usize,
+    has_children: bool,
+}
+
+impl TreeNode {
+    /// Indent the contents of the first cell, and show the expand/collapse button.
+    fn ui(self, ui: &mut Ui, add_cell_contents: impl FnOnce(&mut Ui)) {
+        ui.horizontal(|ui| {
+            ui.add_space(self.depth as f32 * ui.spacing().indent);
+            if self.has_children {
+                let mut state = egui::collapsing_header::CollapsingState::load_with_default_open(
+                    ui.ctx(),
+                    self.id,
+                    false,
+                );
+                state.show_toggle_button(ui, egui::collapsing_header::paint_default_icon);
+                state.store(ui.ctx());
+            } else {
+                // Line up with the rows that have a button:
+                ui.add_space(ui.spacing().indent);
+            }
+            add_cell_contents(ui);
+        });
+    }
+}
+
+/// Show where a dragged header cell would be dropped.
+///
+/// Returns `Some((from, to))` when the header cell at `from` was dropped, so it should be moved to `to`.
+fn column_reorder_ui(
+    ui: &Ui,
+    state_id: egui::Id,
+    header_cell_responses: &[Response],
+) -> Option<(usize, usize)> {
+    let (from, dragged) = header_cell_responses
+        .iter()
+        .enumerate()
+        .find(|(_, response)| response.dragged() || response.drag_stopped())?;
+    let pointer_x = ui.ctx().pointer_latest_pos()?.x;
+
+    let others: Vec<&Response> = header_cell_responses
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != from)
+        .map(|(_, response)| response)
+        .collect();
+    let to = others
+        .iter()
+        .filter(|response| response.rect.center().x < pointer_x)
+        .count();
+
+    if dragged.drag_stopped() {
+        return (from != to).then_some((from, to));
+    }
+
+    ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+
+    let half_spacing = 0.5 * ui.spacing().item_spacing.x;
+    let indicator_x = match (others.get(to), others.last()) {
+        (Some(next), _) => next.rect.left() - half_spacing,
+        (None, Some(last)) => last.rect.right() + half_spacing,
+        (None, None) => dragged.rect.left() - half_spacing,
+    };
+    let indicator_x = ui.ctx().animate_value_with_time(
+        state_id.with("__column_drop_indicator"),
+        indicator_x,
+        ui.style().animation_time,
+    );
+
+    // Paint on top of everything, so the ghost is shown above the other header cells:
+    let painter = ui.ctx().layer_painter(egui::LayerId::new(
+        egui::Order::Tooltip,
+        state_id.with("__column_drag"),
+    ));
+    painter.vline(
+        indicator_x,
+        dragged.rect.y_range(),
+        ui.visuals().selection.stroke,
+    );
+
+    let ghost_rect = dragged
+        .rect
+        .translate(egui::vec2(pointer_x - dragged.rect.center().x, 0.0));
+    painter.rect(
+        ghost_rect,
+        ui.visuals().widgets.active.rounding,
+        ui.visuals().selection.bg_fill.gamma_multiply(0.5),
+        ui.visuals().selection.stroke,
+    );
+
+    None
+}
+
+/// The header context menu of [`TableBuilder::hideable_columns`]: a checkbox for each column.
+///
+/// Returns the index of the column that was hidden or shown, if any.
+fn column_visibility_menu_ui(
+    ui: &mut Ui,
+    column_names: &[String],
+    num_columns: usize,
+    column_order: &[usize],
+) -> Option<usize> {
+    let mut toggled_column = None;
+    for column_index in 0..num_columns {
+        let mut shown = column_order.contains(&column_index);
+        let name = column_names
+            .get(column_index)
+            .cloned()
+            .unwrap_or_else(|| format!("Column {}", column_index + 1));
+        // Don't let the user hide the last shown column:
+        let enabled = !shown || 1 < column_order.len();
+        if ui
+            .add_enabled(enabled, egui::Checkbox::new(&mut shown, name))
+            .changed()
+        {
+            toggled_column = Some(column_index);
+        }
+    }
+    toggled_column
+}
+
+/// Paint a small triangle at the right side of a sorted header cell.
+fn paint_sort_indicator(ui: &Ui, cell_rect: Rect, order: SortOrder) {
+    let size = 0.5 * ui.spacing().icon_width;
+    let rect = Rect::from_center_size(
+        egui::pos2(cell_rect.right() - 0.5 * size, cell_rect.center().y),
+        egui::vec2(size, 0.5 * size),
+    );
+    let points = match order {
+        SortOrder::Ascending => vec![rect.center_top(), rect.right_bottom(), rect.left_bottom()],
+        SortOrder::Descending => vec![rect.left_top(), rect.right_top(), rect.center_bottom()],
+    };
+    let color = ui.visuals().strong_text_color();
+    ui.painter().add(egui::Shape::convex_polygon(
+        points,
+        color,
+        egui::Stroke::NONE,
+    ));
 }
 
 impl<'a, 'b> Drop for TableRow<'a, 'b> {
@@ -1221,3 +3468,136 @@ impl<'a, 'b> Drop for TableRow<'a, 'b> {
         self.layout.end_line();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(num_columns: usize, sort_order: Option<(usize, SortOrder)>) -> TableState {
+        TableState {
+            column_widths: vec![100.0; num_columns],
+            sort_order,
+            scroll_offset_x: 0.0,
+            column_order: (0..num_columns).collect(),
+            hidden_columns: vec![],
+        }
+    }
+
+    #[test]
+    fn test_sort_order() {
+        let mut names = vec!["b", "c", "a"];
+        names.sort_by(|a, b| SortOrder::Ascending.apply(a.cmp(b)));
+        assert_eq!(names, vec!["a", "b", "c"]);
+        names.sort_by(|a, b| SortOrder::Descending.apply(a.cmp(b)));
+        assert_eq!(names, vec!["c", "b", "a"]);
+
+        assert_eq!(SortOrder::Ascending.reversed(), SortOrder::Descending);
+        assert_eq!(SortOrder::Descending.reversed(), SortOrder::Ascending);
+    }
+
+    #[test]
+    fn test_sort_order_after_click() {
+        use SortOrder::{Ascending, Descending};
+
+        assert_eq!(sort_order_after_click(None, 1), (1, Ascending));
+        assert_eq!(
+            sort_order_after_click(Some((1, Ascending)), 1),
+            (1, Descending)
+        );
+        assert_eq!(
+            sort_order_after_click(Some((1, Descending)), 1),
+            (1, Ascending)
+        );
+        assert_eq!(
+            sort_order_after_click(Some((1, Descending)), 2),
+            (2, Ascending)
+        );
+    }
+
+    #[test]
+    fn test_sort_column_follows_moved_columns() {
+        let mut state = state(4, Some((1, SortOrder::Descending)));
+
+        // Moving the sorted column:
+        state.move_column(1, 3);
+        assert_eq!(state.column_order, vec![0, 2, 3, 1]);
+        assert_eq!(state.sort_order, Some((3, SortOrder::Descending)));
+        assert_eq!(state.sort_column(), Some(1));
+
+        // Moving another column past it:
+        state.move_column(0, 3);
+        assert_eq!(state.column_order, vec![2, 3, 1, 0]);
+        assert_eq!(state.sort_order, Some((2, SortOrder::Descending)));
+        assert_eq!(state.sort_column(), Some(1));
+
+        // Out of range moves are ignored:
+        state.move_column(0, 4);
+        assert_eq!(state.column_order, vec![2, 3, 1, 0]);
+    }
+
+    #[test]
+    fn test_sort_column_follows_hidden_columns() {
+        let mut state = state(3, Some((2, SortOrder::Ascending)));
+
+        state.toggle_column(0);
+        assert_eq!(state.column_order, vec![1, 2]);
+        assert_eq!(state.sort_order, Some((1, SortOrder::Ascending)));
+
+        state.toggle_column(0);
+        assert_eq!(state.column_order, vec![0, 1, 2]);
+        assert_eq!(state.sort_order, Some((2, SortOrder::Ascending)));
+
+        // Hiding the sorted column stops sorting:
+        state.toggle_column(2);
+        assert_eq!(state.column_order, vec![0, 1]);
+        assert_eq!(state.sort_order, None);
+    }
+
+    #[test]
+    fn test_tab_separated() {
+        let rows = BTreeSet::from([3, 1]);
+        let cell_text = |row: usize, column: usize| match column {
+            0 => row.to_string(),
+            1 => format!("name\t{row}"),
+            _ => "multi\nline".to_owned(),
+        };
+
+        // Rows in order, columns in the order they are shown:
+        assert_eq!(
+            tab_separated(&rows, &[2, 0, 1], &cell_text),
+            "multi line\t1\tname 1\nmulti line\t3\tname 3"
+        );
+        assert_eq!(tab_separated(&rows, &[0], &cell_text), "1\n3");
+        assert_eq!(tab_separated(&BTreeSet::new(), &[0], &cell_text), "");
+    }
+
+    #[test]
+    fn test_pagination() {
+        assert_eq!(num_pages(0, 10), 1);
+        assert_eq!(num_pages(1, 10), 1);
+        assert_eq!(num_pages(10, 10), 1);
+        assert_eq!(num_pages(11, 10), 2);
+        assert_eq!(num_pages(1000, 50), 20);
+        assert_eq!(num_pages(5, 0), 5, "An empty page size counts as one");
+
+        egui::__run_test_ui(|ui| {
+            let mut page = 7;
+            ui.push_id("pages", |ui| {
+                TableBuilder::new(ui)
+                    .column(Column::remainder())
+                    .paginate(10, 25, &mut page)
+                    .body(|_| {});
+            });
+            assert_eq!(page, 2, "The page is clamped to the last page");
+
+            let mut page = 1;
+            ui.push_id("no_rows", |ui| {
+                TableBuilder::new(ui)
+                    .column(Column::remainder())
+                    .paginate(10, 0, &mut page)
+                    .body(|_| {});
+            });
+            assert_eq!(page, 0);
+        });
+    }
+}
@@ -0,0 +1,458 @@
+//! A tree of expandable nodes, with selection, drag-and-drop and keyboard navigation.
+//!
+//! Children are only added when their parent is expanded, so even big trees are cheap.
+
+use std::hash::Hash;
+
+use egui::{ahash::HashSet, vec2, Event, Id, Key, Rect, Response, Sense, Ui, WidgetText};
+
+/// Marks the tree that was clicked last, and so gets the arrow keys.
+const KEYBOARD_TREE_ID: &str = "__tree_view_keyboard";
+
+#[derive(Clone, Default, serde::Deserialize, serde::Serialize)]
+struct TreeViewState {
+    /// The nodes that are expanded.
+    open: HashSet<Id>,
+
+    selected: Option<Id>,
+
+    /// The node that is being dragged, if any.
+    #[serde(skip)]
+    dragged: Option<Id>,
+
+    /// Scroll to the selected node, e.g. after it was selected with the keyboard.
+    #[serde(skip)]
+    scroll_to_selected: bool,
+}
+
+/// A tree view with expand/collapse arrows, selection, drag-and-drop and keyboard navigation.
+///
+/// Which nodes are expanded and selected is remembered by the tree view.
+/// The children of a node are only added when it is expanded,
+/// so they can be loaded lazily.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let response = egui_extras::TreeView::new("my_tree").show(ui, |tree| {
+///     tree.dir("src", "src", |tree| {
+///         tree.leaf("src/lib.rs", "lib.rs");
+///         tree.leaf("src/main.rs", "main.rs");
+///     });
+///     tree.leaf("Cargo.toml", "Cargo.toml");
+/// });
+/// if let Some((node, new_parent)) = response.dropped {
+///     println!("Move {node:?} to {new_parent:?}");
+/// }
+/// # });
+/// ```
+pub struct TreeView {
+    id_source: Id,
+    row_height: Option<f32>,
+    indent: Option<f32>,
+    drag_and_drop: bool,
+}
+
+impl TreeView {
+    /// The id source must be unique within the parent [`Ui`].
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            row_height: None,
+            indent: None,
+            drag_and_drop: true,
+        }
+    }
+
+    /// The height of each node.
+    ///
+    /// Default: [`egui::style::Spacing::interact_size`]`.y`.
+    #[inline]
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = Some(row_height);
+        self
+    }
+
+    /// How much to indent the children of a node.
+    ///
+    /// Default: [`egui::style::Spacing::indent`].
+    #[inline]
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = Some(indent);
+        self
+    }
+
+    /// Can nodes be dragged onto other nodes to move them there?
+    ///
+    /// See [`TreeViewResponse::dropped`].
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn drag_and_drop(mut self, drag_and_drop: bool) -> Self {
+        self.drag_and_drop = drag_and_drop;
+        self
+    }
+
+    /// Add the nodes of the tree with [`TreeViewBuilder::leaf`] and [`TreeViewBuilder::dir`].
+    ///
+    /// `N` identifies a node, and must be unique within the tree.
+    pub fn show<N: Clone + Hash>(
+        self,
+        ui: &mut Ui,
+        add_nodes: impl FnOnce(&mut TreeViewBuilder<'_, N>),
+    ) -> TreeViewResponse<N> {
+        let tree_id = ui.make_persistent_id(self.id_source);
+        let state = ui
+            .data_mut(|d| d.get_persisted::<TreeViewState>(tree_id))
+            .unwrap_or_default();
+        let row_height = self
+            .row_height
+            .unwrap_or_else(|| ui.spacing().interact_size.y);
+        let indent = self.indent.unwrap_or_else(|| ui.spacing().indent);
+
+        let inner_response = ui.vertical(|ui| {
+            ui.spacing_mut().item_spacing.y = 0.0;
+            let mut builder = TreeViewBuilder {
+                ui,
+                tree_id,
+                state,
+                row_height,
+                indent,
+                drag_and_drop: self.drag_and_drop,
+                parent: None,
+                depth: 0,
+                rows: Vec::new(),
+                selection_changed: false,
+                activated: None,
+            };
+            add_nodes(&mut builder);
+            builder.finish()
+        });
+
+        let (selected, selection_changed, activated, dropped) = inner_response.inner;
+        TreeViewResponse {
+            response: inner_response.response,
+            selected,
+            selection_changed,
+            activated,
+            dropped,
+        }
+    }
+}
+
+/// What happened in a [`TreeView`] this frame.
+pub struct TreeViewResponse<N> {
+    /// The response of the whole tree.
+    pub response: Response,
+
+    /// The selected node, unless it is hidden in a collapsed parent.
+    pub selected: Option<N>,
+
+    /// Was a node selected this frame, either by a click or by the keyboard?
+    pub selection_changed: bool,
+
+    /// A node that was double-clicked, or that enter was pressed on.
+    pub activated: Option<N>,
+
+    /// A node was dragged onto a new parent: `(node, new_parent)`.
+    ///
+    /// A `new_parent` of `None` means the top level of the tree.
+    /// It is up to you to move the node in your data.
+    pub dropped: Option<(N, Option<N>)>,
+}
+
+struct Row<N> {
+    id: Id,
+    node: N,
+
+    /// Index of the parent in [`TreeViewBuilder::rows`].
+    parent: Option<usize>,
+
+    is_dir: bool,
+    open: bool,
+    rect: Rect,
+}
+
+/// Adds nodes to a [`TreeView`].
+pub struct TreeViewBuilder<'ui, N> {
+    ui: &'ui mut Ui,
+    tree_id: Id,
+    state: TreeViewState,
+    row_height: f32,
+    indent: f32,
+    drag_and_drop: bool,
+
+    /// Index in [`Self::rows`] of the node we are adding children to.
+    parent: Option<usize>,
+    depth: usize,
+
+    /// All nodes shown this frame, in order.
+    rows: Vec<Row<N>>,
+
+    selection_changed: bool,
+    activated: Option<N>,
+}
+
+impl<'ui, N: Clone + Hash> TreeViewBuilder<'ui, N> {
+    /// Add a node without children.
+    ///
+    /// Returns `None` if the node is scrolled out of view.
+    pub fn leaf(&mut self, node: N, label: impl Into<WidgetText>) -> Option<Response> {
+        self.node(node, label.into(), false).1
+    }
+
+    /// Add a node that can be expanded to show its children.
+    ///
+    /// `add_children` is only called when the node is expanded.
+    ///
+    /// Returns `None` if the node is scrolled out of view.
+    pub fn dir(
+        &mut self,
+        node: N,
+        label: impl Into<WidgetText>,
+        add_children: impl FnOnce(&mut Self),
+    ) -> Option<Response> {
+        let (index, response) = self.node(node, label.into(), true);
+        if self.rows[index].open {
+            let (parent, depth) = (self.parent, self.depth);
+            self.parent = Some(index);
+            self.depth += 1;
+            add_children(self);
+            self.parent = parent;
+            self.depth = depth;
+        }
+        response
+    }
+
+    fn node(&mut self, node: N, label: WidgetText, is_dir: bool) -> (usize, Option<Response>) {
+        let id = self.tree_id.with(&node);
+        let (_, rect) = self
+            .ui
+            .allocate_space(vec2(self.ui.available_width(), self.row_height));
+        let index = self.rows.len();
+        self.rows.push(Row {
+            id,
+            node,
+            parent: self.parent,
+            is_dir,
+            open: is_dir && self.state.open.contains(&id),
+            rect,
+        });
+
+        let selected = self.state.selected == Some(id);
+        if selected && std::mem::take(&mut self.state.scroll_to_selected) {
+            self.ui.scroll_to_rect(rect, None);
+        }
+
+        // Don't spend time on nodes that are scrolled out of view:
+        if !self.ui.is_rect_visible(rect) {
+            return (index, None);
+        }
+
+        let sense = if self.drag_and_drop {
+            Sense::click_and_drag()
+        } else {
+            Sense::click()
+        };
+        let response = self.ui.interact(rect, id, sense);
+
+        let icon_rect = Rect::from_min_size(
+            rect.left_top() + vec2(self.depth as f32 * self.indent, 0.0),
+            vec2(self.indent, self.row_height),
+        );
+        let icon_response = is_dir.then(|| {
+            // Registered after the row, so it gets the clicks:
+            self.ui.interact(icon_rect, id.with("icon"), Sense::click())
+        });
+
+        if icon_response.as_ref().is_some_and(Response::clicked) {
+            self.toggle(index);
+        } else if response.double_clicked() {
+            if is_dir {
+                self.toggle(index);
+            }
+            self.activated = Some(self.rows[index].node.clone());
+        } else if response.clicked() {
+            self.select(index);
+            self.ui
+                .data_mut(|d| d.insert_temp(Id::new(KEYBOARD_TREE_ID), self.tree_id));
+        }
+        if response.drag_started() {
+            self.state.dragged = Some(id);
+        }
+
+        let selected = self.state.selected == Some(id);
+        let visuals = self.ui.style().interact_selectable(&response, selected);
+        if selected || response.hovered() {
+            self.ui
+                .painter()
+                .rect_filled(rect, visuals.rounding, visuals.weak_bg_fill);
+        }
+
+        if let Some(icon_response) = &icon_response {
+            let openness = self.ui.ctx().animate_bool(id, self.rows[index].open);
+            egui::collapsing_header::paint_default_icon(self.ui, openness, icon_response);
+        }
+
+        let text_x = icon_rect.right() + self.ui.spacing().item_spacing.x;
+        let galley = label.into_galley(
+            self.ui,
+            Some(false),
+            rect.right() - text_x,
+            egui::TextStyle::Button,
+        );
+        let text_pos = egui::pos2(text_x, rect.center().y - 0.5 * galley.size().y);
+        self.ui
+            .painter_at(rect)
+            .galley(text_pos, galley, visuals.text_color());
+
+        (index, Some(response))
+    }
+
+    fn toggle(&mut self, index: usize) {
+        let row = &mut self.rows[index];
+        row.open = !row.open;
+        if row.open {
+            self.state.open.insert(row.id);
+        } else {
+            self.state.open.remove(&row.id);
+        }
+    }
+
+    fn select(&mut self, index: usize) {
+        self.state.selected = Some(self.rows[index].id);
+        self.selection_changed = true;
+    }
+
+    /// Is `ancestor` the node at `index`, or one of its parents?
+    fn is_ancestor(&self, ancestor: usize, mut index: Option<usize>) -> bool {
+        while let Some(i) = index {
+            if i == ancestor {
+                return true;
+            }
+            index = self.rows[i].parent;
+        }
+        false
+    }
+
+    fn has_keyboard_focus(&self) -> bool {
+        let is_active = self
+            .ui
+            .data(|d| d.get_temp::<Id>(Id::new(KEYBOARD_TREE_ID)))
+            == Some(self.tree_id);
+        is_active && self.ui.memory(|mem| mem.focused().is_none())
+    }
+
+    /// Move the selection with the arrow keys, expand and collapse nodes with left and right.
+    fn handle_keys(&mut self) {
+        if self.rows.is_empty() || !self.has_keyboard_focus() {
+            return;
+        }
+
+        let keys: Vec<Key> = self.ui.input(|i| {
+            i.events
+                .iter()
+                .filter_map(|event| match event {
+                    Event::Key {
+                        key, pressed: true, ..
+                    } => Some(*key),
+                    _ => None,
+                })
+                .collect()
+        });
+
+        let last = self.rows.len() - 1;
+        for key in keys {
+            let current = self
+                .state
+                .selected
+                .and_then(|id| self.rows.iter().position(|row| row.id == id));
+            let previous_selection = self.state.selected;
+            match (key, current) {
+                (Key::ArrowUp, Some(i)) => self.select(i.saturating_sub(1)),
+                (Key::ArrowDown, Some(i)) => self.select((i + 1).min(last)),
+                (Key::ArrowUp | Key::ArrowDown | Key::Home, _) => self.select(0),
+                (Key::End, _) => self.select(last),
+                (Key::ArrowLeft, Some(i)) => {
+                    if self.rows[i].open {
+                        self.toggle(i);
+                    } else if let Some(parent) = self.rows[i].parent {
+                        self.select(parent);
+                    }
+                }
+                (Key::ArrowRight, Some(i)) => {
+                    if self.rows[i].is_dir && !self.rows[i].open {
+                        self.toggle(i);
+                    } else if self
+                        .rows
+                        .get(i + 1)
+                        .is_some_and(|row| row.parent == Some(i))
+                    {
+                        self.select(i + 1);
+                    }
+                }
+                (Key::Space, Some(i)) if self.rows[i].is_dir => self.toggle(i),
+                (Key::Enter, Some(i)) => self.activated = Some(self.rows[i].node.clone()),
+                _ => continue,
+            }
+            if self.state.selected != previous_selection {
+                self.state.scroll_to_selected = true;
+            }
+            self.ui.ctx().request_repaint();
+        }
+    }
+
+    /// Show where a dragged node would be dropped, and drop it when released.
+    fn handle_drag_and_drop(&mut self) -> Option<(N, Option<N>)> {
+        let dragged = self.state.dragged?;
+        let Some(dragged) = self.rows.iter().position(|row| row.id == dragged) else {
+            // Hidden in a collapsed parent.
+            self.state.dragged = None;
+            return None;
+        };
+        self.ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+
+        // Dropping a node on a leaf moves it next to the leaf:
+        let pointer = self.ui.ctx().pointer_interact_pos();
+        let hovered =
+            pointer.and_then(|pos| self.rows.iter().position(|row| row.rect.contains(pos)));
+        let target = hovered.map(|i| {
+            if self.rows[i].is_dir {
+                Some(i)
+            } else {
+                self.rows[i].parent
+            }
+        });
+        let target = target.filter(|&target| {
+            target != self.rows[dragged].parent && !self.is_ancestor(dragged, target)
+        });
+
+        if let Some(target) = target {
+            let target_rect = target.map_or(self.ui.min_rect(), |i| self.rows[i].rect);
+            let stroke = self.ui.visuals().selection.stroke;
+            self.ui.painter().rect_stroke(target_rect, 0.0, stroke);
+        }
+
+        if !self.ui.input(|i| i.pointer.any_released()) {
+            return None;
+        }
+        self.state.dragged = None;
+        let target = target?;
+        Some((
+            self.rows[dragged].node.clone(),
+            target.map(|i| self.rows[i].node.clone()),
+        ))
+    }
+
+    fn finish(mut self) -> (Option<N>, bool, Option<N>, Option<(N, Option<N>)>) {
+        self.handle_keys();
+        let dropped = self.handle_drag_and_drop();
+        let selected = self
+            .state
+            .selected
+            .and_then(|id| self.rows.iter().find(|row| row.id == id))
+            .map(|row| row.node.clone());
+        self.ui
+            .data_mut(|d| d.insert_persisted(self.tree_id, self.state));
+        (selected, self.selection_changed, self.activated, dropped)
+    }
+}
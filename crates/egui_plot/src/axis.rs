@@ -164,7 +164,7 @@ impl<'a> AxisHints<'a> {
         }
         let tick_rounded = round_to_decimals(tick, max_digits);
         if tick.abs() < 10.0_f64.powf(-(max_digits as f64)) && tick != 0.0 {
-            return format!("{tick_rounded:+e}");
+            return format!("{tick:+e}");
         }
         tick_rounded.to_string()
     }
@@ -0,0 +1,412 @@
+//! Date and time axes, where the values are unix timestamps.
+
+use std::ops::RangeInclusive;
+
+use crate::{
+    fill_marks_between, next_power, sort_and_dedup_marks, GridInput, GridMark, GridSpacer,
+};
+
+const SECONDS_PER_MINUTE: f64 = 60.0;
+const SECONDS_PER_HOUR: f64 = 60.0 * SECONDS_PER_MINUTE;
+const SECONDS_PER_DAY: f64 = 24.0 * SECONDS_PER_HOUR;
+const SECONDS_PER_MONTH: f64 = 30.44 * SECONDS_PER_DAY; // on average
+const SECONDS_PER_YEAR: f64 = 365.25 * SECONDS_PER_DAY; // on average
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The distance between two grid marks on a date/time axis.
+#[derive(Clone, Copy, Debug)]
+enum TimeStep {
+    /// A fixed number of seconds (which may be less than one).
+    Seconds(f64),
+
+    /// A number of calendar months, which have different lengths.
+    Months(i64),
+}
+
+/// The steps we snap to, from small to large.
+///
+/// Smaller steps than a second are powers of ten, larger steps than these are multiples of ten years.
+const TIME_STEPS: [TimeStep; 27] = [
+    TimeStep::Seconds(1.0),
+    TimeStep::Seconds(2.0),
+    TimeStep::Seconds(5.0),
+    TimeStep::Seconds(10.0),
+    TimeStep::Seconds(15.0),
+    TimeStep::Seconds(30.0),
+    TimeStep::Seconds(SECONDS_PER_MINUTE),
+    TimeStep::Seconds(2.0 * SECONDS_PER_MINUTE),
+    TimeStep::Seconds(5.0 * SECONDS_PER_MINUTE),
+    TimeStep::Seconds(10.0 * SECONDS_PER_MINUTE),
+    TimeStep::Seconds(15.0 * SECONDS_PER_MINUTE),
+    TimeStep::Seconds(30.0 * SECONDS_PER_MINUTE),
+    TimeStep::Seconds(SECONDS_PER_HOUR),
+    TimeStep::Seconds(2.0 * SECONDS_PER_HOUR),
+    TimeStep::Seconds(3.0 * SECONDS_PER_HOUR),
+    TimeStep::Seconds(6.0 * SECONDS_PER_HOUR),
+    TimeStep::Seconds(12.0 * SECONDS_PER_HOUR),
+    TimeStep::Seconds(SECONDS_PER_DAY),
+    TimeStep::Seconds(2.0 * SECONDS_PER_DAY),
+    TimeStep::Months(1),
+    TimeStep::Months(2),
+    TimeStep::Months(3),
+    TimeStep::Months(6),
+    TimeStep::Months(12),
+    TimeStep::Months(2 * 12),
+    TimeStep::Months(5 * 12),
+    TimeStep::Months(10 * 12),
+];
+
+impl TimeStep {
+    /// The smallest step which is at least this many seconds.
+    fn at_least(seconds: f64) -> Self {
+        if seconds < 1.0 {
+            return Self::Seconds(next_power(seconds, 10.0));
+        }
+        TIME_STEPS
+            .iter()
+            .copied()
+            .find(|step| seconds <= step.approx_seconds())
+            .unwrap_or_else(|| {
+                let years = next_power(seconds / SECONDS_PER_YEAR, 10.0);
+                Self::Months(12 * years as i64)
+            })
+    }
+
+    fn approx_seconds(self) -> f64 {
+        match self {
+            Self::Seconds(seconds) => seconds,
+            Self::Months(months) => months as f64 * SECONDS_PER_MONTH,
+        }
+    }
+
+    /// Fill in all the marks of this step between `min` and `max`.
+    fn fill_marks(self, out: &mut Vec<GridMark>, (min, max): (f64, f64)) {
+        match self {
+            Self::Seconds(seconds) => fill_marks_between(out, seconds, (min, max)),
+            Self::Months(months) => {
+                let step_size = self.approx_seconds();
+                let (year, month, _) = civil_from_days((min / SECONDS_PER_DAY).floor() as i64);
+
+                // Months since year 0, rounded down to a multiple of the step:
+                let mut month_index = (12 * year + month as i64 - 1).div_euclid(months) * months;
+                loop {
+                    let year = month_index.div_euclid(12);
+                    let month = month_index.rem_euclid(12) as u32 + 1;
+                    let value = days_from_civil(year, month, 1) as f64 * SECONDS_PER_DAY;
+                    if max <= value {
+                        break;
+                    }
+                    if min <= value {
+                        out.push(GridMark { value, step_size });
+                    }
+                    month_index += months;
+                }
+            }
+        }
+    }
+}
+
+/// Places grid marks at whole seconds, minutes, hours, days, months and years,
+/// interpreting the values as unix timestamps (seconds since 1970-01-01 00:00:00 UTC).
+///
+/// Use together with [`date_time_formatter`], or just call [`crate::Plot::x_axis_date_time`].
+pub fn date_time_grid_spacer() -> GridSpacer<'static> {
+    let get_marks = |input: GridInput| -> Vec<GridMark> {
+        // handle degenerate cases
+        if input.base_step_size.abs() < f64::EPSILON || !input.base_step_size.is_finite() {
+            return Vec::new();
+        }
+
+        let mut marks = Vec::new();
+        for level in 0..3 {
+            let step = TimeStep::at_least(input.base_step_size * 10.0_f64.powi(level));
+            step.fill_marks(&mut marks, input.bounds);
+        }
+        sort_and_dedup_marks(&mut marks);
+        marks
+    };
+
+    Box::new(get_marks)
+}
+
+/// Formats the ticks of an axis where the values are unix timestamps
+/// (seconds since 1970-01-01 00:00:00 UTC) as times and dates, in UTC.
+///
+/// How much is shown depends on the spacing of the ticks, e.g. `14:30`, `Mar 5`, `Mar` or `2024`.
+///
+/// Use together with [`date_time_grid_spacer`], or just call [`crate::Plot::x_axis_date_time`].
+pub fn date_time_formatter(
+    mark: GridMark,
+    _max_digits: usize,
+    _range: &RangeInclusive<f64>,
+) -> String {
+    let UtcDateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        millisecond,
+    } = UtcDateTime::from_timestamp(mark.value);
+    let month_name = MONTH_NAMES[month as usize - 1];
+    let is_midnight = hour == 0 && minute == 0 && second == 0 && millisecond == 0;
+
+    if mark.step_size < 1.0 {
+        format!("{hour:02}:{minute:02}:{second:02}.{millisecond:03}")
+    } else if mark.step_size < SECONDS_PER_MINUTE {
+        format!("{hour:02}:{minute:02}:{second:02}")
+    } else if mark.step_size < SECONDS_PER_DAY && !is_midnight {
+        format!("{hour:02}:{minute:02}")
+    } else if mark.step_size < SECONDS_PER_MONTH && !(day == 1 && month == 1) {
+        format!("{month_name} {day}")
+    } else if mark.step_size < SECONDS_PER_YEAR && month != 1 {
+        month_name.to_owned()
+    } else {
+        year.to_string()
+    }
+}
+
+/// A unix timestamp, broken down into its parts.
+struct UtcDateTime {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    millisecond: u32,
+}
+
+impl UtcDateTime {
+    fn from_timestamp(timestamp: f64) -> Self {
+        let milliseconds = (timestamp * 1000.0).round() as i64;
+        let days = milliseconds.div_euclid(1000 * SECONDS_PER_DAY as i64);
+        let millisecond_of_day = milliseconds.rem_euclid(1000 * SECONDS_PER_DAY as i64);
+        let (year, month, day) = civil_from_days(days);
+        let second_of_day = (millisecond_of_day / 1000) as u32;
+        Self {
+            year,
+            month,
+            day,
+            hour: second_of_day / 3600,
+            minute: second_of_day / 60 % 60,
+            second: second_of_day % 60,
+            millisecond: (millisecond_of_day % 1000) as u32,
+        }
+    }
+}
+
+/// The `(year, month, day)` of the given number of days since 1970-01-01.
+///
+/// Uses the proleptic Gregorian calendar, see <https://howardhinnant.github.io/date_algorithms.html>.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u32;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// The number of days since 1970-01-01 of the given date.
+///
+/// The inverse of [`civil_from_days`].
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_from_march = (month as i64 + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timestamp(year: i64, month: u32, day: u32) -> f64 {
+        days_from_civil(year, month, day) as f64 * SECONDS_PER_DAY
+    }
+
+    fn marks(step: TimeStep, min: f64, max: f64) -> Vec<f64> {
+        let mut marks = vec![];
+        step.fill_marks(&mut marks, (min, max));
+        marks.into_iter().map(|mark| mark.value).collect()
+    }
+
+    fn format(value: f64, step_size: f64) -> String {
+        date_time_formatter(GridMark { value, step_size }, 0, &(0.0..=1.0))
+    }
+
+    #[test]
+    fn test_epoch() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+
+        let epoch = UtcDateTime::from_timestamp(0.0);
+        assert_eq!((epoch.year, epoch.month, epoch.day), (1970, 1, 1));
+        assert_eq!((epoch.hour, epoch.minute, epoch.second), (0, 0, 0));
+        assert_eq!(epoch.millisecond, 0);
+
+        let time = UtcDateTime::from_timestamp(1_700_000_000.25);
+        assert_eq!((time.year, time.month, time.day), (2023, 11, 14));
+        assert_eq!((time.hour, time.minute, time.second), (22, 13, 20));
+        assert_eq!(time.millisecond, 250);
+    }
+
+    #[test]
+    fn test_leap_days() {
+        assert_eq!(days_from_civil(2024, 2, 29), 19_782);
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        assert_eq!(civil_from_days(19_783), (2024, 3, 1));
+
+        // Every fourth year, except for every hundredth, except for every four hundredth:
+        assert_eq!(
+            civil_from_days(days_from_civil(2000, 2, 28) + 1),
+            (2000, 2, 29)
+        );
+        assert_eq!(
+            civil_from_days(days_from_civil(1900, 2, 28) + 1),
+            (1900, 3, 1)
+        );
+        assert_eq!(
+            civil_from_days(days_from_civil(2023, 2, 28) + 1),
+            (2023, 3, 1)
+        );
+
+        for days in (-1_000_000..1_000_000).step_by(97) {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn test_negative_timestamps() {
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(days_from_civil(1900, 1, 1), -25_567);
+
+        let time = UtcDateTime::from_timestamp(-0.5);
+        assert_eq!((time.year, time.month, time.day), (1969, 12, 31));
+        assert_eq!((time.hour, time.minute, time.second), (23, 59, 59));
+        assert_eq!(time.millisecond, 500);
+
+        assert_eq!(format(-SECONDS_PER_DAY + 3600.0, 3600.0), "01:00");
+    }
+
+    #[test]
+    fn test_month_marks() {
+        assert_eq!(
+            marks(
+                TimeStep::Months(1),
+                timestamp(2024, 1, 15),
+                timestamp(2024, 5, 10)
+            ),
+            vec![
+                timestamp(2024, 2, 1),
+                timestamp(2024, 3, 1),
+                timestamp(2024, 4, 1),
+                timestamp(2024, 5, 1),
+            ]
+        );
+
+        // Quarters start in January, April, July and October:
+        assert_eq!(
+            marks(
+                TimeStep::Months(3),
+                timestamp(2023, 2, 1),
+                timestamp(2023, 12, 31)
+            ),
+            vec![
+                timestamp(2023, 4, 1),
+                timestamp(2023, 7, 1),
+                timestamp(2023, 10, 1),
+            ]
+        );
+
+        // Across the epoch:
+        assert_eq!(
+            marks(
+                TimeStep::Months(1),
+                timestamp(1969, 11, 15),
+                timestamp(1970, 2, 10)
+            ),
+            vec![
+                timestamp(1969, 12, 1),
+                timestamp(1970, 1, 1),
+                timestamp(1970, 2, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_year_marks() {
+        assert_eq!(
+            marks(
+                TimeStep::Months(12),
+                timestamp(2019, 6, 1),
+                timestamp(2023, 2, 1)
+            ),
+            vec![
+                timestamp(2020, 1, 1),
+                timestamp(2021, 1, 1),
+                timestamp(2022, 1, 1),
+                timestamp(2023, 1, 1),
+            ]
+        );
+
+        // Decades start at years divisible by ten, also before the epoch:
+        assert_eq!(
+            marks(
+                TimeStep::Months(10 * 12),
+                timestamp(1945, 1, 1),
+                timestamp(1985, 1, 1)
+            ),
+            vec![
+                timestamp(1950, 1, 1),
+                timestamp(1960, 1, 1),
+                timestamp(1970, 1, 1),
+                timestamp(1980, 1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_time_step() {
+        assert!(matches!(TimeStep::at_least(0.03), TimeStep::Seconds(s) if s == 0.1));
+        assert!(matches!(TimeStep::at_least(40.0), TimeStep::Seconds(s) if s == 60.0));
+        assert!(matches!(
+            TimeStep::at_least(3.0 * SECONDS_PER_DAY),
+            TimeStep::Months(1)
+        ));
+        assert!(matches!(
+            TimeStep::at_least(40.0 * SECONDS_PER_YEAR),
+            TimeStep::Months(1200)
+        ));
+    }
+
+    #[test]
+    fn test_formatter() {
+        let time = timestamp(2024, 3, 5) + 14.5 * SECONDS_PER_HOUR;
+        assert_eq!(format(time + 0.25, 0.1), "14:30:00.250");
+        assert_eq!(format(time, 1.0), "14:30:00");
+        assert_eq!(format(time, SECONDS_PER_HOUR), "14:30");
+        assert_eq!(format(timestamp(2024, 3, 5), SECONDS_PER_HOUR), "Mar 5");
+        assert_eq!(format(timestamp(2024, 3, 1), SECONDS_PER_MONTH), "Mar");
+        assert_eq!(format(timestamp(2024, 1, 1), SECONDS_PER_MONTH), "2024");
+        assert_eq!(format(timestamp(2024, 1, 1), SECONDS_PER_DAY), "2024");
+    }
+}
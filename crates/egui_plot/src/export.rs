@@ -0,0 +1,795 @@
+//! Exporting a plot to a PNG image or an SVG document.
+
+use std::{fmt::Write as _, ops::Range, path::Path};
+
+use egui::{
+    epaint::{
+        tessellator::Tessellator, ClippedShape, ColorMode, PathStroke, Primitive, RectShape,
+        Stroke, TextShape,
+    },
+    Color32, ColorImage, Context, FontFamily, LayerId, Pos2, Rect, Shape, TextureId,
+};
+
+use crate::PlotResponse;
+
+/// The file format to export a plot to, see [`PlotResponse::export`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// A PNG image, rasterized at the export resolution.
+    ///
+    /// The image data is stored uncompressed, so the file takes a little over
+    /// `width × height × 4` bytes.
+    Png,
+
+    /// An SVG document.
+    Svg,
+}
+
+impl ExportFormat {
+    /// The format belonging to a file extension (`png` or `svg`, ignoring case).
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        if extension.eq_ignore_ascii_case("png") {
+            Some(Self::Png)
+        } else if extension.eq_ignore_ascii_case("svg") {
+            Some(Self::Svg)
+        } else {
+            None
+        }
+    }
+}
+
+/// The shapes a plot painted in the current frame: the background, grid, axes, items and legend.
+#[derive(Clone, Debug)]
+pub(crate) struct PlotPaint {
+    pub(crate) layer_id: LayerId,
+    pub(crate) shapes: Range<usize>,
+    pub(crate) rect: Rect,
+}
+
+impl PlotPaint {
+    /// The number of shapes painted on the layer so far, which is where the shapes of a plot start.
+    pub(crate) fn shape_count(ctx: &Context, layer_id: LayerId) -> usize {
+        ctx.graphics(|graphics| {
+            graphics
+                .get(layer_id)
+                .map_or(0, |list| list.all_entries().len())
+        })
+    }
+
+    fn clipped_shapes(&self, ctx: &Context) -> Vec<ClippedShape> {
+        ctx.graphics(|graphics| {
+            graphics.get(self.layer_id).map_or_else(Vec::new, |list| {
+                list.all_entries()
+                    .skip(self.shapes.start)
+                    .take(self.shapes.len())
+                    .cloned()
+                    .collect()
+            })
+        })
+    }
+}
+
+impl<R> PlotResponse<R> {
+    /// Export the plot, as it was painted in this frame, to a PNG image or an SVG document.
+    ///
+    /// The plot is re-rendered at `pixels_per_point` pixels per ui point,
+    /// so e.g. `2.0` exports at twice the size of a plot on a screen with a scale factor of one,
+    /// regardless of the actual scale factor.
+    ///
+    /// This must be called in the same frame as [`crate::Plot::show`], before the frame ends.
+    ///
+    /// Textures other than the font texture, like those of [`crate::PlotImage`]
+    /// and [`crate::Heatmap`], are not available here, so they are exported as plain areas.
+    pub fn export(&self, format: ExportFormat, pixels_per_point: f32) -> Vec<u8> {
+        match format {
+            ExportFormat::Png => encode_png(&self.export_image(pixels_per_point)),
+            ExportFormat::Svg => self.export_svg(pixels_per_point).into_bytes(),
+        }
+    }
+
+    /// Export the plot to a file, in the format given by its extension (`.png` or `.svg`).
+    ///
+    /// See [`Self::export`].
+    ///
+    /// # Errors
+    /// Returns an error of kind [`std::io::ErrorKind::InvalidInput`] if the extension of `path`
+    /// is neither `png` nor `svg`, and any error from writing the file.
+    pub fn export_to_file(
+        &self,
+        path: impl AsRef<Path>,
+        pixels_per_point: f32,
+    ) -> std::io::Result<()> {
+        let path = path.as_ref();
+        let format = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(ExportFormat::from_extension)
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("Can't export a plot to {path:?}: expected a .png or .svg file"),
+                )
+            })?;
+        std::fs::write(path, self.export(format, pixels_per_point))
+    }
+
+    /// Render the plot to an image, with `pixels_per_point` pixels per ui point.
+    ///
+    /// See [`Self::export`].
+    pub fn export_image(&self, pixels_per_point: f32) -> ColorImage {
+        let ctx = &self.response.ctx;
+        let shapes = self.paint.clipped_shapes(ctx);
+        let background = ctx.style().visuals.panel_fill;
+        rasterize(ctx, shapes, self.paint.rect, pixels_per_point, background)
+    }
+
+    /// Write the plot as an SVG document, sized `pixels_per_point` pixels per ui point.
+    ///
+    /// See [`Self::export`].
+    pub fn export_svg(&self, pixels_per_point: f32) -> String {
+        let ctx = &self.response.ctx;
+        let shapes = self.paint.clipped_shapes(ctx);
+        let background = ctx.style().visuals.panel_fill;
+        svg_document(&shapes, self.paint.rect, pixels_per_point, background)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Rasterization
+
+/// A color with premultiplied alpha, in gamma space, with components in `0..=1`.
+type Premultiplied = [f32; 4];
+
+fn premultiplied(color: Color32) -> Premultiplied {
+    color.to_array().map(|c| c as f32 / 255.0)
+}
+
+fn rasterize(
+    ctx: &Context,
+    shapes: Vec<ClippedShape>,
+    rect: Rect,
+    pixels_per_point: f32,
+    background: Color32,
+) -> ColorImage {
+    let width = ((rect.width() * pixels_per_point).round() as usize).max(1);
+    let height = ((rect.height() * pixels_per_point).round() as usize).max(1);
+
+    let (font_image, font_tex_size, prepared_discs) = ctx.fonts(|fonts| {
+        let atlas = fonts.texture_atlas();
+        let atlas = atlas.lock();
+        (atlas.image().clone(), atlas.size(), atlas.prepared_discs())
+    });
+    let font_pixels: Vec<Color32> = font_image.srgba_pixels(None).collect();
+    let options = ctx.tessellation_options(|options| *options);
+    let primitives = Tessellator::new(pixels_per_point, options, font_tex_size, prepared_discs)
+        .tessellate_shapes(shapes);
+
+    let mut pixels = vec![premultiplied(background); width * height];
+    let to_pixels = |pos: Pos2| ((pos - rect.min) * pixels_per_point).to_pos2();
+
+    for primitive in primitives {
+        let Primitive::Mesh(mesh) = primitive.primitive else {
+            continue;
+        };
+        let clip_rect = Rect::from_min_max(
+            to_pixels(primitive.clip_rect.min),
+            to_pixels(primitive.clip_rect.max),
+        );
+        let x_range = (clip_rect.min.x.max(0.0) as usize)
+            ..(clip_rect.max.x.ceil().max(0.0) as usize).min(width);
+        let y_range = (clip_rect.min.y.max(0.0) as usize)
+            ..(clip_rect.max.y.ceil().max(0.0) as usize).min(height);
+        let texture = (mesh.texture_id == TextureId::default())
+            .then_some((font_pixels.as_slice(), font_image.size));
+
+        for triangle in mesh.indices.chunks_exact(3) {
+            let vertices = [0, 1, 2].map(|i| &mesh.vertices[triangle[i] as usize]);
+            let positions = vertices.map(|vertex| to_pixels(vertex.pos));
+            let area = edge(positions[0], positions[1], positions[2]);
+            if area == 0.0 {
+                continue;
+            }
+
+            let min = positions[0].min(positions[1]).min(positions[2]);
+            let max = positions[0].max(positions[1]).max(positions[2]);
+            let xs = (min.x.max(x_range.start as f32) as usize)
+                ..(max.x.ceil().max(0.0) as usize).min(x_range.end);
+            let ys = (min.y.max(y_range.start as f32) as usize)
+                ..(max.y.ceil().max(0.0) as usize).min(y_range.end);
+
+            for y in ys {
+                for x in xs.clone() {
+                    let pixel = Pos2::new(x as f32 + 0.5, y as f32 + 0.5);
+                    let weights = [
+                        edge(positions[1], positions[2], pixel) / area,
+                        edge(positions[2], positions[0], pixel) / area,
+                        edge(positions[0], positions[1], pixel) / area,
+                    ];
+                    if weights.iter().any(|&weight| weight < 0.0) {
+                        continue;
+                    }
+
+                    let mut color = [0.0; 4];
+                    let mut uv = Pos2::ZERO;
+                    for (vertex, weight) in vertices.iter().zip(weights) {
+                        for (c, v) in color.iter_mut().zip(premultiplied(vertex.color)) {
+                            *c += weight * v;
+                        }
+                        uv += weight * vertex.uv.to_vec2();
+                    }
+                    if let Some((texels, [tex_width, tex_height])) = texture {
+                        let tx = ((uv.x * tex_width as f32) as usize).min(tex_width - 1);
+                        let ty = ((uv.y * tex_height as f32) as usize).min(tex_height - 1);
+                        let texel = premultiplied(texels[ty * tex_width + tx]);
+                        for (c, t) in color.iter_mut().zip(texel) {
+                            *c *= t;
+                        }
+                    }
+
+                    let destination = &mut pixels[y * width + x];
+                    let transparency = 1.0 - color[3];
+                    for (d, c) in destination.iter_mut().zip(color) {
+                        *d = c + *d * transparency;
+                    }
+                }
+            }
+        }
+    }
+
+    ColorImage {
+        size: [width, height],
+        pixels: pixels
+            .into_iter()
+            .map(|[r, g, b, a]| {
+                let [r, g, b, a] = [r, g, b, a].map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+                Color32::from_rgba_premultiplied(r, g, b, a)
+            })
+            .collect(),
+    }
+}
+
+/// Twice the signed area of the triangle `a`, `b`, `c`.
+fn edge(a: Pos2, b: Pos2, c: Pos2) -> f32 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+// ----------------------------------------------------------------------------
+// PNG encoding
+
+/// Encode an image as a PNG file, using uncompressed deflate blocks.
+///
+/// The result is a little over `width × height × 4` bytes.
+fn encode_png(image: &ColorImage) -> Vec<u8> {
+    let [width, height] = image.size;
+
+    let mut scanlines = Vec::with_capacity(height * (1 + 4 * width));
+    for row in image.pixels.chunks_exact(width) {
+        scanlines.push(0); // No filter
+        for pixel in row {
+            scanlines.extend_from_slice(&pixel.to_srgba_unmultiplied());
+        }
+    }
+
+    // A zlib stream of stored deflate blocks.
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = scanlines.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let len = block.len() as u16;
+        zlib.push(u8::from(blocks.peek().is_none()));
+        zlib.extend_from_slice(&len.to_le_bytes());
+        zlib.extend_from_slice(&(!len).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend_from_slice(&adler32(&scanlines).to_be_bytes());
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    header.extend_from_slice(&[8, 6, 0, 0, 0]); // 8 bit RGBA, no interlacing
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    png_chunk(&mut png, b"IHDR", &header);
+    png_chunk(&mut png, b"IDAT", &zlib);
+    png_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0_u32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for &byte in bytes {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+// ----------------------------------------------------------------------------
+// SVG
+
+fn svg_document(
+    shapes: &[ClippedShape],
+    rect: Rect,
+    pixels_per_point: f32,
+    background: Color32,
+) -> String {
+    let mut svg = String::new();
+    let size = rect.size() * pixels_per_point;
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}">"#,
+        size.x,
+        size.y,
+        rect.min.x,
+        rect.min.y,
+        rect.width(),
+        rect.height()
+    )
+    .ok();
+    write_rect(&mut svg, &RectShape::filled(rect, 0.0, background));
+
+    // Consecutive shapes with the same clip rectangle share a clip path.
+    let mut clip_rect = None;
+    for (clip_index, shape) in shapes.iter().enumerate() {
+        if clip_rect != Some(shape.clip_rect) {
+            if clip_rect.is_some() {
+                svg.push_str("</g>\n");
+            }
+            let Rect { min, max } = shape.clip_rect;
+            writeln!(
+                svg,
+                r#"<clipPath id="clip{clip_index}"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath>"#,
+                min.x,
+                min.y,
+                max.x - min.x,
+                max.y - min.y
+            )
+            .ok();
+            writeln!(svg, r#"<g clip-path="url(#clip{clip_index})">"#).ok();
+            clip_rect = Some(shape.clip_rect);
+        }
+        write_shape(&mut svg, &shape.shape);
+    }
+    if clip_rect.is_some() {
+        svg.push_str("</g>\n");
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn write_shape(svg: &mut String, shape: &Shape) {
+    match shape {
+        Shape::Noop | Shape::Callback(_) => {}
+        Shape::Vec(shapes) => {
+            for shape in shapes {
+                write_shape(svg, shape);
+            }
+        }
+        Shape::Circle(circle) => {
+            writeln!(
+                svg,
+                r#"<circle cx="{}" cy="{}" r="{}"{}{}/>"#,
+                circle.center.x,
+                circle.center.y,
+                circle.radius,
+                fill(circle.fill),
+                stroke(circle.stroke)
+            )
+            .ok();
+        }
+        Shape::Ellipse(ellipse) => {
+            writeln!(
+                svg,
+                r#"<ellipse cx="{}" cy="{}" rx="{}" ry="{}"{}{}/>"#,
+                ellipse.center.x,
+                ellipse.center.y,
+                ellipse.radius.x,
+                ellipse.radius.y,
+                fill(ellipse.fill),
+                stroke(ellipse.stroke)
+            )
+            .ok();
+        }
+        Shape::LineSegment { points, stroke: s } => {
+            writeln!(
+                svg,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}"{}/>"#,
+                points[0].x,
+                points[0].y,
+                points[1].x,
+                points[1].y,
+                path_stroke(s)
+            )
+            .ok();
+        }
+        Shape::Path(path) => {
+            let element = if path.closed { "polygon" } else { "polyline" };
+            let fill = if path.closed {
+                fill(path.fill)
+            } else {
+                fill(Color32::TRANSPARENT)
+            };
+            writeln!(
+                svg,
+                r#"<{element} points="{}"{fill}{}/>"#,
+                points(&path.points),
+                path_stroke(&path.stroke)
+            )
+            .ok();
+        }
+        Shape::Rect(rect) => write_rect(svg, rect),
+        Shape::Text(text) => write_text(svg, text),
+        Shape::Mesh(mesh) => {
+            for triangle in mesh.indices.chunks_exact(3) {
+                let vertices = [0, 1, 2].map(|i| &mesh.vertices[triangle[i] as usize]);
+                let color = vertices[0].color;
+                writeln!(
+                    svg,
+                    r#"<polygon points="{}"{}/>"#,
+                    points(&vertices.map(|vertex| vertex.pos)),
+                    fill(color)
+                )
+                .ok();
+            }
+        }
+        Shape::QuadraticBezier(bezier) => {
+            let [from, control, to] = bezier.points;
+            let close = if bezier.closed { " Z" } else { "" };
+            let fill = if bezier.closed {
+                fill(bezier.fill)
+            } else {
+                fill(Color32::TRANSPARENT)
+            };
+            writeln!(
+                svg,
+                r#"<path d="M {} {} Q {} {} {} {}{close}"{fill}{}/>"#,
+                from.x,
+                from.y,
+                control.x,
+                control.y,
+                to.x,
+                to.y,
+                path_stroke(&bezier.stroke)
+            )
+            .ok();
+        }
+        Shape::CubicBezier(bezier) => {
+            let [from, control1, control2, to] = bezier.points;
+            let close = if bezier.closed { " Z" } else { "" };
+            let fill = if bezier.closed {
+                fill(bezier.fill)
+            } else {
+                fill(Color32::TRANSPARENT)
+            };
+            writeln!(
+                svg,
+                r#"<path d="M {} {} C {} {} {} {} {} {}{close}"{fill}{}/>"#,
+                from.x,
+                from.y,
+                control1.x,
+                control1.y,
+                control2.x,
+                control2.y,
+                to.x,
+                to.y,
+                path_stroke(&bezier.stroke)
+            )
+            .ok();
+        }
+    }
+}
+
+fn write_rect(svg: &mut String, rect: &RectShape) {
+    let rounding = rect.rounding;
+    let radius = (rounding.nw + rounding.ne + rounding.sw + rounding.se) / 4.0;
+    writeln!(
+        svg,
+        r#"<rect x="{}" y="{}" width="{}" height="{}" rx="{radius}"{}{}/>"#,
+        rect.rect.min.x,
+        rect.rect.min.y,
+        rect.rect.width(),
+        rect.rect.height(),
+        fill(rect.fill),
+        stroke(rect.stroke)
+    )
+    .ok();
+}
+
+fn write_text(svg: &mut String, text: &TextShape) {
+    let galley = &text.galley;
+    let rotation = if text.angle == 0.0 {
+        String::new()
+    } else {
+        format!(
+            r#" transform="rotate({} {} {})""#,
+            text.angle.to_degrees(),
+            text.pos.x,
+            text.pos.y
+        )
+    };
+
+    for row in &galley.rows {
+        let Some(first_glyph) = row.glyphs.first() else {
+            continue;
+        };
+        let format = &galley.job.sections[first_glyph.section_index as usize].format;
+        let mut color = text.override_text_color.unwrap_or(format.color);
+        if color == Color32::PLACEHOLDER {
+            color = text.fallback_color;
+        }
+        if text.opacity_factor < 1.0 {
+            color = color.gamma_multiply(text.opacity_factor);
+        }
+        let family = match format.font_id.family {
+            FontFamily::Monospace => "monospace",
+            _ => "sans-serif",
+        };
+        let position = text.pos + first_glyph.pos.to_vec2();
+        writeln!(
+            svg,
+            r#"<text x="{}" y="{}" font-family="{family}" font-size="{}" xml:space="preserve"{}{rotation}>{}</text>"#,
+            position.x,
+            position.y,
+            format.font_id.size,
+            fill(color),
+            escape(&row.text())
+        )
+        .ok();
+    }
+}
+
+fn points(points: &[Pos2]) -> String {
+    let mut list = String::new();
+    for point in points {
+        write!(list, "{},{} ", point.x, point.y).ok();
+    }
+    list.trim_end().to_owned()
+}
+
+fn fill(color: Color32) -> String {
+    paint("fill", color)
+}
+
+fn stroke(stroke: Stroke) -> String {
+    if stroke.is_empty() {
+        String::new()
+    } else {
+        format!(
+            r#"{} stroke-width="{}""#,
+            paint("stroke", stroke.color),
+            stroke.width
+        )
+    }
+}
+
+fn path_stroke(stroke: &PathStroke) -> String {
+    match &stroke.color {
+        ColorMode::Solid(color) => self::stroke(Stroke::new(stroke.width, *color)),
+        ColorMode::UV(_) => String::new(),
+    }
+}
+
+fn paint(attribute: &str, color: Color32) -> String {
+    if color.a() == 0 {
+        return format!(r#" {attribute}="none""#);
+    }
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    if a == u8::MAX {
+        format!(r##" {attribute}="#{r:02x}{g:02x}{b:02x}""##)
+    } else {
+        format!(
+            r##" {attribute}="#{r:02x}{g:02x}{b:02x}" {attribute}-opacity="{}""##,
+            f32::from(a) / 255.0
+        )
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The chunks of a PNG file, after checking the signature and the CRC of each chunk.
+    fn png_chunks(png: &[u8]) -> Vec<([u8; 4], &[u8])> {
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        let mut chunks = vec![];
+        let mut rest = &png[8..];
+        while !rest.is_empty() {
+            let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+            let kind_and_data = &rest[4..8 + len];
+            let crc = u32::from_be_bytes(rest[8 + len..12 + len].try_into().unwrap());
+            assert_eq!(crc, crc32(kind_and_data), "Bad CRC");
+            chunks.push((kind_and_data[..4].try_into().unwrap(), &kind_and_data[4..]));
+            rest = &rest[12 + len..];
+        }
+        chunks
+    }
+
+    /// Decode a zlib stream of stored deflate blocks, checking the Adler-32 checksum.
+    fn inflate_stored(zlib: &[u8]) -> Vec<u8> {
+        assert_eq!(&zlib[..2], &[0x78, 0x01]);
+        let mut data = vec![];
+        let mut rest = &zlib[2..];
+        loop {
+            let is_final = rest[0] & 1 == 1;
+            assert_eq!(rest[0] >> 1, 0, "Expected a stored block");
+            let len = u16::from_le_bytes([rest[1], rest[2]]);
+            let not_len = u16::from_le_bytes([rest[3], rest[4]]);
+            assert_eq!(len, !not_len);
+            data.extend_from_slice(&rest[5..5 + len as usize]);
+            rest = &rest[5 + len as usize..];
+            if is_final {
+                break;
+            }
+        }
+        assert_eq!(rest, adler32(&data).to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+        assert_eq!(adler32(b""), 1);
+    }
+
+    #[test]
+    fn test_png_round_trip() {
+        // Big enough to need more than one deflate block:
+        let (width, height) = (300, 100);
+        let pixels = (0..width * height)
+            .map(|i| Color32::from_rgb(i as u8, (i / width) as u8, 7))
+            .collect();
+        let image = ColorImage {
+            size: [width, height],
+            pixels,
+        };
+        let png = encode_png(&image);
+
+        let chunks = png_chunks(&png);
+        let kinds: Vec<&[u8; 4]> = chunks.iter().map(|(kind, _)| kind).collect();
+        assert_eq!(kinds, vec![b"IHDR", b"IDAT", b"IEND"]);
+
+        let header = chunks[0].1;
+        assert_eq!(u32::from_be_bytes(header[..4].try_into().unwrap()), 300);
+        assert_eq!(u32::from_be_bytes(header[4..8].try_into().unwrap()), 100);
+        assert_eq!(&header[8..], &[8, 6, 0, 0, 0]);
+
+        let scanlines = inflate_stored(chunks[1].1);
+        assert_eq!(scanlines.len(), height * (1 + 4 * width));
+        for (row, scanline) in scanlines.chunks_exact(1 + 4 * width).enumerate() {
+            assert_eq!(scanline[0], 0, "Expected no filter");
+            for (column, rgba) in scanline[1..].chunks_exact(4).enumerate() {
+                let pixel = image.pixels[row * width + column];
+                assert_eq!(rgba, pixel.to_srgba_unmultiplied());
+            }
+        }
+
+        // Uncompressed:
+        assert!(png.len() > width * height * 4);
+    }
+
+    #[test]
+    fn test_rasterize_filled_rect() {
+        let ctx = Context::default();
+        ctx.tessellation_options_mut(|options| options.feathering = false);
+        let _ = ctx.run(Default::default(), |ctx| {
+            let plot_rect = Rect::from_min_size(Pos2::new(10.0, 20.0), egui::vec2(40.0, 30.0));
+            let filled = Rect::from_min_size(Pos2::new(20.0, 30.0), egui::vec2(10.0, 5.0));
+            let shapes = vec![ClippedShape {
+                clip_rect: Rect::EVERYTHING,
+                shape: Shape::rect_filled(filled, 0.0, Color32::RED),
+            }];
+
+            let image = rasterize(ctx, shapes, plot_rect, 2.0, Color32::BLUE);
+            assert_eq!(image.size, [80, 60]);
+
+            let count = |color| image.pixels.iter().filter(|&&pixel| pixel == color).count();
+            assert_eq!(count(Color32::RED), 20 * 10);
+            assert_eq!(count(Color32::BLUE), 80 * 60 - 20 * 10);
+            // The top left pixel of the filled rect:
+            assert_eq!(image.pixels[20 * 80 + 20], Color32::RED);
+            assert_eq!(image.pixels[19 * 80 + 19], Color32::BLUE);
+        });
+    }
+
+    #[test]
+    fn test_svg_document() {
+        let rect = Rect::from_min_size(Pos2::new(10.0, 20.0), egui::vec2(40.0, 30.0));
+        let clip_rect = Rect::from_min_size(Pos2::new(10.0, 20.0), egui::vec2(40.0, 25.0));
+        let shapes = [
+            Shape::circle_filled(Pos2::new(30.0, 35.0), 5.0, Color32::RED),
+            Shape::line_segment(
+                [Pos2::new(10.0, 20.0), Pos2::new(50.0, 50.0)],
+                Stroke::new(2.0, Color32::from_black_alpha(128)),
+            ),
+        ]
+        .map(|shape| ClippedShape { clip_rect, shape });
+
+        let svg = svg_document(&shapes, rect, 2.0, Color32::WHITE);
+        assert_eq!(
+            svg.lines().collect::<Vec<_>>(),
+            vec![
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="80" height="60" viewBox="10 20 40 30">"#,
+                r##"<rect x="10" y="20" width="40" height="30" rx="0" fill="#ffffff"/>"##,
+                r#"<clipPath id="clip0"><rect x="10" y="20" width="40" height="25"/></clipPath>"#,
+                r#"<g clip-path="url(#clip0)">"#,
+                r##"<circle cx="30" cy="35" r="5" fill="#ff0000"/>"##,
+                r##"<line x1="10" y1="20" x2="50" y2="50" stroke="#000000" stroke-opacity="0.5019608" stroke-width="2"/>"##,
+                "</g>",
+                "</svg>",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_export_plot() {
+        let ctx = Context::default();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let response = crate::Plot::new("export_test")
+                    .width(100.0)
+                    .height(50.0)
+                    .show(ui, |plot_ui| {
+                        plot_ui.line(crate::Line::new(crate::PlotPoints::new(vec![
+                            [0.0, 0.0],
+                            [1.0, 1.0],
+                        ])));
+                    });
+
+                let image = response.export_image(2.0);
+                assert!(image.size[0] >= 200 && image.size[1] >= 100);
+
+                let png = response.export(ExportFormat::Png, 2.0);
+                let header = png_chunks(&png)[0].1;
+                assert_eq!(header[..4], (image.size[0] as u32).to_be_bytes());
+                assert_eq!(header[4..8], (image.size[1] as u32).to_be_bytes());
+
+                let svg = String::from_utf8(response.export(ExportFormat::Svg, 1.0)).unwrap();
+                assert!(svg.starts_with("<svg "));
+                assert!(svg.contains("<polyline "), "The line is missing:\n{svg}");
+                assert!(svg.ends_with("</svg>\n"));
+            });
+        });
+    }
+
+    #[test]
+    fn test_format_from_extension() {
+        assert_eq!(ExportFormat::from_extension("PNG"), Some(ExportFormat::Png));
+        assert_eq!(ExportFormat::from_extension("svg"), Some(ExportFormat::Svg));
+        assert_eq!(ExportFormat::from_extension("jpg"), None);
+    }
+}
@@ -0,0 +1,173 @@
+use egui::emath::NumExt as _;
+use egui::epaint::{Color32, RectShape, Rounding, Shape, Stroke};
+
+use crate::{CandleStick, Cursor, PlotPoint, PlotTransform};
+
+use super::{add_rulers_and_text, highlighted_color, Orientation, PlotConfig, RectElement};
+
+/// One candle in a [`CandleStick`] chart: the open, high, low and close values of a period.
+///
+/// The body of the candle spans from the open to the close value,
+/// and its wick from the low to the high value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Candle {
+    /// Name of plot element in the diagram (annotated by default formatter).
+    pub name: String,
+
+    /// Position on the X axis, e.g. the start of the period.
+    pub x: f64,
+
+    /// The first value of the period.
+    pub open: f64,
+
+    /// The highest value of the period.
+    pub high: f64,
+
+    /// The lowest value of the period.
+    pub low: f64,
+
+    /// The last value of the period.
+    pub close: f64,
+
+    /// Width of the body of the candle.
+    pub width: f64,
+}
+
+impl Candle {
+    /// Create a candle. Its colors are set by its [`CandleStick`] parent.
+    ///
+    /// Check [`Candle`] fields for detailed description.
+    pub fn new(x: f64, open: f64, high: f64, low: f64, close: f64) -> Self {
+        Self {
+            name: String::default(),
+            x,
+            open,
+            high,
+            low,
+            close,
+            width: 0.5,
+        }
+    }
+
+    /// Name of this candle.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the width of the body.
+    #[inline]
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Did the value go up (or stay the same) during the period?
+    #[inline]
+    pub fn is_up(&self) -> bool {
+        self.close >= self.open
+    }
+
+    pub(super) fn add_shapes(
+        &self,
+        parent: &CandleStick,
+        transform: &PlotTransform,
+        highlighted: bool,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let color = if self.is_up() {
+            parent.up_color
+        } else {
+            parent.down_color
+        };
+        let mut wick_stroke = parent.wick_stroke;
+        if wick_stroke.color == Color32::TRANSPARENT {
+            wick_stroke.color = color;
+        }
+        let (stroke, fill) = if highlighted {
+            wick_stroke.width *= 2.0;
+            highlighted_color(Stroke::new(1.0, color), color)
+        } else {
+            (Stroke::new(1.0, color), color)
+        };
+
+        let wick = Shape::line_segment(
+            [
+                transform.position_from_point(&PlotPoint::new(self.x, self.high)),
+                transform.position_from_point(&PlotPoint::new(self.x, self.low)),
+            ],
+            wick_stroke,
+        );
+        shapes.push(wick);
+
+        let body = transform.rect_from_values(
+            &PlotPoint::new(self.x - self.width / 2.0, self.open),
+            &PlotPoint::new(self.x + self.width / 2.0, self.close),
+        );
+        shapes.push(Shape::Rect(RectShape::new(
+            body,
+            Rounding::ZERO,
+            fill,
+            stroke,
+        )));
+    }
+
+    pub(super) fn add_rulers_and_text(
+        &self,
+        parent: &CandleStick,
+        plot: &PlotConfig<'_>,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+    ) {
+        let text: Option<String> = parent
+            .element_formatter
+            .as_ref()
+            .map(|fmt| fmt(self, parent));
+
+        add_rulers_and_text(self, plot, text, shapes, cursors);
+    }
+}
+
+impl RectElement for Candle {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn bounds_min(&self) -> PlotPoint {
+        PlotPoint::new(self.x - self.width / 2.0, self.low)
+    }
+
+    fn bounds_max(&self) -> PlotPoint {
+        PlotPoint::new(self.x + self.width / 2.0, self.high)
+    }
+
+    fn values_with_ruler(&self) -> Vec<PlotPoint> {
+        [self.open, self.high, self.low, self.close]
+            .into_iter()
+            .map(|value| PlotPoint::new(self.x, value))
+            .collect()
+    }
+
+    fn orientation(&self) -> Orientation {
+        Orientation::Vertical
+    }
+
+    fn default_values_format(&self, transform: &PlotTransform) -> String {
+        let scale = transform.dvalue_dpos()[1];
+        let decimals = ((-scale.abs().log10()).ceil().at_least(0.0) as usize)
+            .at_most(6)
+            .at_least(1);
+        format!(
+            "Open = {open:.decimals$}\
+             \nHigh = {high:.decimals$}\
+             \nLow = {low:.decimals$}\
+             \nClose = {close:.decimals$}",
+            open = self.open,
+            high = self.high,
+            low = self.low,
+            close = self.close,
+        )
+    }
+}
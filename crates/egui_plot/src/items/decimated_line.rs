@@ -0,0 +1,388 @@
+use std::{ops::RangeInclusive, sync::Arc};
+
+use crate::*;
+
+use super::ClosestElem;
+
+/// A line through a large number of points, such as a long or streaming time series.
+///
+/// Instead of drawing every point, only the first, lowest, highest and last point
+/// within each pixel column are kept, which looks the same as drawing all points.
+/// The simplified line is cached, and only recomputed when the visible x range,
+/// the width of the plot, or the data changes.
+///
+/// The points must be sorted by their x value.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use std::sync::Arc;
+/// use egui_plot::{DecimatedLine, Plot, PlotPoint};
+///
+/// let samples: Arc<[PlotPoint]> = (0..100_000)
+///     .map(|i| {
+///         let t = i as f64 * 1e-2;
+///         PlotPoint::new(t, (t * 3.0).sin() + 0.1 * (t * 1000.0).sin())
+///     })
+///     .collect();
+///
+/// Plot::new("signal").show(ui, |plot_ui| {
+///     plot_ui.decimated_line(DecimatedLine::new(samples.clone()).name("signal"));
+/// });
+/// # });
+/// ```
+pub struct DecimatedLine {
+    pub(crate) points: Arc<[PlotPoint]>,
+    revision: u64,
+    pub(crate) stroke: Stroke,
+    name: String,
+    highlight: bool,
+    allow_hover: bool,
+    on_secondary_y_axis: bool,
+    id: Option<Id>,
+}
+
+impl DecimatedLine {
+    /// Create a line through the given points, which must be sorted by their x value.
+    ///
+    /// The points are shared, so keep them in an [`Arc`] to avoid copying them every frame.
+    pub fn new(points: impl Into<Arc<[PlotPoint]>>) -> Self {
+        Self {
+            points: points.into(),
+            revision: 0,
+            stroke: Stroke::new(1.5, Color32::TRANSPARENT),
+            name: Default::default(),
+            highlight: false,
+            allow_hover: true,
+            on_secondary_y_axis: false,
+            id: None,
+        }
+    }
+
+    /// A number that must change whenever the points change, other than by appending points.
+    ///
+    /// The simplified line is only recomputed when this, the number of points,
+    /// or the visible part of the plot changes. Default: `0`.
+    #[inline]
+    pub fn revision(mut self, revision: u64) -> Self {
+        self.revision = revision;
+        self
+    }
+
+    /// Highlight this line in the plot by scaling up the line.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Plot this item against the secondary Y axis of the plot. Default: `false`.
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn on_secondary_y_axis(mut self, on_secondary_y_axis: bool) -> Self {
+        self.on_secondary_y_axis = on_secondary_y_axis;
+        self
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Name of this line.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the line's id which is used to identify it in the plot's response.
+    ///
+    /// The id is also used to cache the simplified line. Without one, the name is used.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// The simplified points for the current view, from the cache if possible.
+    fn decimated(&self, ctx: &Context, transform: &PlotTransform) -> Arc<[PlotPoint]> {
+        let id = self
+            .id
+            .unwrap_or_else(|| Id::new("egui_plot_decimated_line").with(&self.name));
+        let bounds = transform.bounds();
+        let key = DecimationKey {
+            revision: self.revision,
+            len: self.points.len(),
+            x_range: [bounds.min()[0], bounds.max()[0]],
+            columns: (transform.frame().width() * ctx.pixels_per_point()).ceil() as usize,
+            x_scale: transform.scales()[0],
+        };
+
+        if let Some((cached_key, decimated)) =
+            ctx.data(|d| d.get_temp::<(DecimationKey, Arc<[PlotPoint]>)>(id))
+        {
+            if cached_key == key {
+                return decimated;
+            }
+        }
+
+        let decimated: Arc<[PlotPoint]> =
+            decimate(&self.points, transform, ctx.pixels_per_point()).into();
+        ctx.data_mut(|d| d.insert_temp(id, (key, decimated.clone())));
+        decimated
+    }
+}
+
+/// Everything the simplified line of a [`DecimatedLine`] depends on.
+#[derive(Clone, Copy, PartialEq)]
+struct DecimationKey {
+    revision: u64,
+    len: usize,
+    x_range: [f64; 2],
+    columns: usize,
+    x_scale: Scale,
+}
+
+/// Keep the first, lowest, highest and last of the visible points in each pixel column,
+/// plus the closest point outside the view on either side, so that the line leaves the view.
+fn decimate(
+    points: &[PlotPoint],
+    transform: &PlotTransform,
+    pixels_per_point: f32,
+) -> Vec<PlotPoint> {
+    let bounds = transform.bounds();
+    let start = points
+        .partition_point(|point| point.x < bounds.min()[0])
+        .saturating_sub(1);
+    let end = (points.partition_point(|point| point.x <= bounds.max()[0]) + 1).min(points.len());
+    let visible = &points[start..end];
+
+    let left = transform.frame().left();
+    let column_of = |point: &PlotPoint| {
+        ((transform.position_from_point_x(point.x) - left) * pixels_per_point).floor()
+    };
+
+    let mut decimated = Vec::new();
+    let mut i = 0;
+    while i < visible.len() {
+        let column = column_of(&visible[i]);
+        let first = i;
+        let (mut lowest, mut highest) = (i, i);
+        i += 1;
+        while i < visible.len() && column_of(&visible[i]) == column {
+            if visible[i].y < visible[lowest].y {
+                lowest = i;
+            }
+            if visible[i].y > visible[highest].y {
+                highest = i;
+            }
+            i += 1;
+        }
+        let last = i - 1;
+
+        let mut kept = [first, lowest, highest, last];
+        kept.sort_unstable();
+        let mut previous = None;
+        for index in kept {
+            if previous != Some(index) {
+                decimated.push(visible[index]);
+                previous = Some(index);
+            }
+        }
+    }
+    decimated
+}
+
+impl PlotItem for DecimatedLine {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let values_tf: Vec<_> = self
+            .decimated(ui.ctx(), transform)
+            .iter()
+            .map(|v| transform.position_from_point(v))
+            .collect();
+        LineStyle::Solid.style_line(values_tf, self.stroke, self.highlight, shapes);
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn on_secondary_y_axis(&self) -> bool {
+        self.on_secondary_y_axis
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(&self.points)
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for point in self.points.iter() {
+            bounds.extend_with(point);
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        // Only the points close to the pointer are candidates, which are quick to find because
+        // the points are sorted.
+        let radius = 16.0;
+        let min_x = transform.value_from_position(point - vec2(radius, 0.0)).x;
+        let max_x = transform.value_from_position(point + vec2(radius, 0.0)).x;
+        let start = self.points.partition_point(|value| value.x < min_x);
+        let end = self.points.partition_point(|value| value.x <= max_x);
+
+        (start..end)
+            .map(|index| {
+                let pos = transform.position_from_point(&self.points[index]);
+                let dist_sq = point.distance_sq(pos);
+                ClosestElem { index, dist_sq }
+            })
+            .min_by_key(|e| e.dist_sq.ord())
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 10 points wide frame showing x from 0 to 10.
+    fn transform() -> PlotTransform {
+        let frame = Rect::from_min_size(Pos2::ZERO, vec2(10.0, 10.0));
+        let bounds = PlotBounds::from_min_max([0.0, 0.0], [10.0, 1.0]);
+        PlotTransform::new(frame, bounds, false, false)
+    }
+
+    /// Points from `min_x`, 0.01 apart, with pseudo-random y values.
+    fn points(min_x: f64, count: usize) -> Vec<PlotPoint> {
+        (0..count)
+            .map(|i| PlotPoint::new(min_x + i as f64 * 0.01, ((i * 7919) % 1000) as f64))
+            .collect()
+    }
+
+    fn is_subsequence(decimated: &[PlotPoint], points: &[PlotPoint]) -> bool {
+        let mut points = points.iter();
+        decimated
+            .iter()
+            .all(|kept| points.any(|point| point.x == kept.x && point.y == kept.y))
+    }
+
+    #[test]
+    fn test_decimate_keeps_extremes_of_each_column() {
+        let transform = transform();
+        let points = points(0.0, 1000);
+
+        for pixels_per_point in [1.0, 2.0] {
+            let decimated = decimate(&points, &transform, pixels_per_point);
+            let columns = (10.0 * pixels_per_point) as usize;
+            assert!(decimated.len() <= 4 * columns, "{}", decimated.len());
+            assert!(is_subsequence(&decimated, &points));
+
+            let column_of = |point: &PlotPoint| {
+                (transform.position_from_point_x(point.x) * pixels_per_point).floor() as usize
+            };
+            for column in 0..columns {
+                let in_column = |point: &&PlotPoint| column_of(point) == column;
+                let all: Vec<&PlotPoint> = points.iter().filter(in_column).collect();
+                let kept: Vec<&PlotPoint> = decimated.iter().filter(in_column).collect();
+
+                let (first, last) = (all[0], all[all.len() - 1]);
+                assert_eq!(kept[0].x, first.x);
+                assert_eq!(kept[kept.len() - 1].x, last.x);
+
+                let min_y = |points: &[&PlotPoint]| {
+                    points.iter().map(|p| p.y).fold(f64::INFINITY, f64::min)
+                };
+                let max_y = |points: &[&PlotPoint]| {
+                    points.iter().map(|p| p.y).fold(-f64::INFINITY, f64::max)
+                };
+                assert_eq!(min_y(&kept), min_y(&all));
+                assert_eq!(max_y(&kept), max_y(&all));
+            }
+        }
+    }
+
+    #[test]
+    fn test_decimate_outside_of_view() {
+        // From -5 to 15, while the view is from 0 to 10:
+        let points = points(-5.0, 2001);
+        let decimated = decimate(&points, &transform(), 1.0);
+        assert!(is_subsequence(&decimated, &points));
+
+        // Only the closest point on either side of the view is kept:
+        let outside: Vec<f64> = decimated
+            .iter()
+            .map(|point| point.x)
+            .filter(|x| !(0.0..=10.0).contains(x))
+            .collect();
+        assert_eq!(outside.len(), 2);
+        assert!(outside[0] < 0.0 && -0.02 < outside[0]);
+        assert!(10.0 < outside[1] && outside[1] < 10.02);
+    }
+
+    #[test]
+    fn test_decimate_few_points() {
+        let transform = transform();
+        assert!(decimate(&[], &transform, 1.0).is_empty());
+
+        // Fewer points than pixel columns are all kept:
+        let points: Vec<PlotPoint> = (0..5)
+            .map(|i| PlotPoint::new(2.0 * i as f64, i as f64))
+            .collect();
+        let decimated = decimate(&points, &transform, 1.0);
+        assert_eq!(decimated.len(), points.len());
+        assert!(is_subsequence(&decimated, &points));
+    }
+}
@@ -0,0 +1,146 @@
+use egui::{pos2, Shape, Stroke};
+
+use crate::{PlotBounds, PlotPoint, PlotTransform};
+
+/// Error bars for the points of a [`Points`](crate::Points) or [`Line`](crate::Line) series,
+/// showing the uncertainty of each point.
+///
+/// The errors are given per point, in the same order as the points.
+/// Points without an error (because the list of errors is shorter) get no error bar.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{ErrorBars, Plot, Points};
+///
+/// let measurements = Points::new(vec![[1.0, 2.0], [2.0, 2.5], [3.0, 2.2]])
+///     .radius(3.0)
+///     .error_bars(ErrorBars::new().y([0.3, 0.2, 0.4]));
+/// Plot::new("measurements").show(ui, |plot_ui| plot_ui.points(measurements));
+/// # });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorBars {
+    /// For each point: how far its error bar reaches to the left and to the right.
+    x: Vec<[f64; 2]>,
+
+    /// For each point: how far its error bar reaches down and up.
+    y: Vec<[f64; 2]>,
+
+    cap_width: f32,
+}
+
+impl Default for ErrorBars {
+    fn default() -> Self {
+        Self {
+            x: Vec::new(),
+            y: Vec::new(),
+            cap_width: 6.0,
+        }
+    }
+}
+
+impl ErrorBars {
+    /// No error bars, until some are added with [`Self::x`] or [`Self::y`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Horizontal error bars, reaching the same distance to the left and to the right of each point.
+    #[inline]
+    pub fn x(mut self, errors: impl IntoIterator<Item = f64>) -> Self {
+        self.x = errors.into_iter().map(|error| [error, error]).collect();
+        self
+    }
+
+    /// Horizontal error bars, reaching `[left, right]` of each point.
+    #[inline]
+    pub fn x_asymmetric(mut self, errors: impl IntoIterator<Item = [f64; 2]>) -> Self {
+        self.x = errors.into_iter().collect();
+        self
+    }
+
+    /// Vertical error bars, reaching the same distance below and above each point.
+    #[inline]
+    pub fn y(mut self, errors: impl IntoIterator<Item = f64>) -> Self {
+        self.y = errors.into_iter().map(|error| [error, error]).collect();
+        self
+    }
+
+    /// Vertical error bars, reaching `[below, above]` each point.
+    #[inline]
+    pub fn y_asymmetric(mut self, errors: impl IntoIterator<Item = [f64; 2]>) -> Self {
+        self.y = errors.into_iter().collect();
+        self
+    }
+
+    /// The width of the caps at the ends of the error bars, in ui points. Default: `6.0`.
+    ///
+    /// Use `0.0` for error bars without caps.
+    #[inline]
+    pub fn cap_width(mut self, cap_width: f32) -> Self {
+        self.cap_width = cap_width;
+        self
+    }
+
+    pub(super) fn add_shapes(
+        &self,
+        points: &[PlotPoint],
+        transform: &PlotTransform,
+        stroke: Stroke,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let half_cap = 0.5 * self.cap_width;
+
+        for (point, [left, right]) in points.iter().zip(&self.x) {
+            let y = transform.position_from_point_y(point.y);
+            let ends = [
+                transform.position_from_point_x(point.x - left),
+                transform.position_from_point_x(point.x + right),
+            ];
+            shapes.push(Shape::line_segment(
+                [pos2(ends[0], y), pos2(ends[1], y)],
+                stroke,
+            ));
+            if half_cap > 0.0 {
+                for x in ends {
+                    shapes.push(Shape::line_segment(
+                        [pos2(x, y - half_cap), pos2(x, y + half_cap)],
+                        stroke,
+                    ));
+                }
+            }
+        }
+
+        for (point, [below, above]) in points.iter().zip(&self.y) {
+            let x = transform.position_from_point_x(point.x);
+            let ends = [
+                transform.position_from_point_y(point.y - below),
+                transform.position_from_point_y(point.y + above),
+            ];
+            shapes.push(Shape::line_segment(
+                [pos2(x, ends[0]), pos2(x, ends[1])],
+                stroke,
+            ));
+            if half_cap > 0.0 {
+                for y in ends {
+                    shapes.push(Shape::line_segment(
+                        [pos2(x - half_cap, y), pos2(x + half_cap, y)],
+                        stroke,
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Extend the bounds of the points so that they include the error bars.
+    pub(super) fn extend_bounds(&self, points: &[PlotPoint], bounds: &mut PlotBounds) {
+        for (point, [left, right]) in points.iter().zip(&self.x) {
+            bounds.extend_with_x(point.x - left);
+            bounds.extend_with_x(point.x + right);
+        }
+        for (point, [below, above]) in points.iter().zip(&self.y) {
+            bounds.extend_with_y(point.y - below);
+            bounds.extend_with_y(point.y + above);
+        }
+    }
+}
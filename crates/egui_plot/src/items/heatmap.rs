@@ -0,0 +1,560 @@
+use std::ops::RangeInclusive;
+
+use crate::*;
+
+use super::{add_rulers_and_text, ClosestElem, Orientation, PlotConfig, RectElement};
+
+/// Maps a value between 0 and 1 to a color, see [`Heatmap::color_map`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorMap {
+    /// From dark blue over green to yellow. Perceptually uniform, and readable by the color blind.
+    #[default]
+    Viridis,
+
+    /// From black over purple to light yellow. Perceptually uniform.
+    Magma,
+
+    /// From black over red to light yellow. Perceptually uniform.
+    Inferno,
+
+    /// From blue over pink to yellow. Perceptually uniform.
+    Plasma,
+
+    /// From black to white.
+    Grayscale,
+
+    /// Evenly spaced colors to interpolate between, from the lowest to the highest value.
+    Custom(Vec<Color32>),
+}
+
+impl ColorMap {
+    /// The color for `t` (clamped to the range 0-1).
+    pub fn color_at(&self, t: f32) -> Color32 {
+        let stops: &[Color32] = match self {
+            Self::Viridis => &VIRIDIS,
+            Self::Magma => &MAGMA,
+            Self::Inferno => &INFERNO,
+            Self::Plasma => &PLASMA,
+            Self::Grayscale => &[Color32::BLACK, Color32::WHITE],
+            Self::Custom(stops) => stops,
+        };
+        match stops {
+            [] => Color32::TRANSPARENT,
+            [color] => *color,
+            _ => {
+                let t = t.clamp(0.0, 1.0) * (stops.len() - 1) as f32;
+                let index = (t.floor() as usize).min(stops.len() - 2);
+                let (a, b) = (stops[index], stops[index + 1]);
+                let t = t - index as f32;
+                let mix = |a: u8, b: u8| lerp(a as f32..=b as f32, t).round() as u8;
+                Color32::from_rgba_premultiplied(
+                    mix(a.r(), b.r()),
+                    mix(a.g(), b.g()),
+                    mix(a.b(), b.b()),
+                    mix(a.a(), b.a()),
+                )
+            }
+        }
+    }
+}
+
+const VIRIDIS: [Color32; 9] = [
+    Color32::from_rgb(68, 1, 84),
+    Color32::from_rgb(72, 40, 120),
+    Color32::from_rgb(62, 74, 137),
+    Color32::from_rgb(49, 104, 142),
+    Color32::from_rgb(38, 130, 142),
+    Color32::from_rgb(31, 158, 137),
+    Color32::from_rgb(53, 183, 121),
+    Color32::from_rgb(110, 206, 88),
+    Color32::from_rgb(253, 231, 37),
+];
+
+const MAGMA: [Color32; 9] = [
+    Color32::from_rgb(0, 0, 4),
+    Color32::from_rgb(28, 16, 68),
+    Color32::from_rgb(79, 18, 123),
+    Color32::from_rgb(129, 37, 129),
+    Color32::from_rgb(181, 54, 122),
+    Color32::from_rgb(229, 80, 100),
+    Color32::from_rgb(251, 135, 97),
+    Color32::from_rgb(254, 194, 135),
+    Color32::from_rgb(252, 253, 191),
+];
+
+const INFERNO: [Color32; 9] = [
+    Color32::from_rgb(0, 0, 4),
+    Color32::from_rgb(31, 12, 72),
+    Color32::from_rgb(85, 15, 109),
+    Color32::from_rgb(136, 34, 106),
+    Color32::from_rgb(186, 54, 85),
+    Color32::from_rgb(227, 89, 51),
+    Color32::from_rgb(249, 140, 10),
+    Color32::from_rgb(249, 201, 50),
+    Color32::from_rgb(252, 255, 164),
+];
+
+const PLASMA: [Color32; 9] = [
+    Color32::from_rgb(13, 8, 135),
+    Color32::from_rgb(76, 2, 161),
+    Color32::from_rgb(126, 3, 168),
+    Color32::from_rgb(169, 35, 149),
+    Color32::from_rgb(204, 71, 120),
+    Color32::from_rgb(229, 107, 93),
+    Color32::from_rgb(248, 149, 64),
+    Color32::from_rgb(253, 197, 39),
+    Color32::from_rgb(240, 249, 33),
+];
+
+/// A grid of cells, colored by their values, e.g. for spectrograms and correlation matrices.
+///
+/// The values are given row by row. The first row is at the bottom of the heatmap, and the
+/// first value of each row at its left. Cells with a NaN value are not painted.
+///
+/// The heatmap is painted as a single texture, which is only updated when the values change.
+/// The texture is remembered by the [`Self::id`] of the heatmap, or by its name if it has none,
+/// so give each heatmap in a plot a unique id or name.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{ColorMap, Heatmap, Plot};
+///
+/// let values: Vec<f64> = (0..100).map(|i| ((i % 10) * (i / 10)) as f64).collect();
+/// let heatmap = Heatmap::new(values, 10).color_map(ColorMap::Magma).name("Products");
+/// Plot::new("multiplication_table").show(ui, |plot_ui| plot_ui.heatmap(heatmap));
+/// # });
+/// ```
+pub struct Heatmap {
+    values: Vec<f64>,
+    columns: usize,
+    origin: PlotPoint,
+    cell_size: [f64; 2],
+    color_map: ColorMap,
+    range: Option<RangeInclusive<f64>>,
+    name: String,
+    highlight: bool,
+    allow_hover: bool,
+    on_secondary_y_axis: bool,
+    id: Option<Id>,
+}
+
+impl Heatmap {
+    /// Create a heatmap of `values`, given row by row, with `columns` values per row.
+    ///
+    /// A last row with fewer values is ignored.
+    pub fn new(values: Vec<f64>, columns: usize) -> Self {
+        Self {
+            values,
+            columns: columns.at_least(1),
+            origin: PlotPoint::new(0.0, 0.0),
+            cell_size: [1.0, 1.0],
+            color_map: ColorMap::default(),
+            range: None,
+            name: String::new(),
+            highlight: false,
+            allow_hover: true,
+            on_secondary_y_axis: false,
+            id: None,
+        }
+    }
+
+    /// The lower left corner of the first cell. Default: `(0, 0)`.
+    #[inline]
+    pub fn origin(mut self, origin: impl Into<PlotPoint>) -> Self {
+        self.origin = origin.into();
+        self
+    }
+
+    /// The width and height of each cell, in plot units. Default: `[1.0, 1.0]`.
+    #[inline]
+    pub fn cell_size(mut self, cell_size: [f64; 2]) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    /// How values are mapped to colors. Default: [`ColorMap::Viridis`].
+    #[inline]
+    pub fn color_map(mut self, color_map: ColorMap) -> Self {
+        self.color_map = color_map;
+        self
+    }
+
+    /// The values that map to the lowest and highest colors of the color map.
+    /// Values outside of the range get the colors at its ends.
+    ///
+    /// By default, this is the range of the values.
+    #[inline]
+    pub fn range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Name of this heatmap.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on, and when hovering a cell.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Highlight this heatmap in the plot.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Plot this item against the secondary Y axis of the plot. Default: `false`.
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn on_secondary_y_axis(mut self, on_secondary_y_axis: bool) -> Self {
+        self.on_secondary_y_axis = on_secondary_y_axis;
+        self
+    }
+
+    /// Set the heatmap's id which is used to identify it in the plot's response,
+    /// and to remember its texture.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    fn rows(&self) -> usize {
+        self.values.len() / self.columns
+    }
+
+    fn value_range(&self) -> RangeInclusive<f64> {
+        self.range.clone().unwrap_or_else(|| {
+            // The values of an incomplete last row are ignored:
+            let (min, max) = self.values[..self.rows() * self.columns]
+                .iter()
+                .filter(|value| !value.is_nan())
+                .fold((f64::INFINITY, -f64::INFINITY), |(min, max), value| {
+                    (min.min(*value), max.max(*value))
+                });
+            min..=max
+        })
+    }
+
+    fn image(&self) -> ColorImage {
+        let columns = self.columns;
+        let rows = self.rows();
+        let range = self.value_range();
+        let pixels = (0..rows)
+            .rev() // The first row is at the bottom.
+            .flat_map(|row| &self.values[row * columns..(row + 1) * columns])
+            .map(|value| {
+                if value.is_nan() {
+                    Color32::TRANSPARENT
+                } else {
+                    let t = remap_clamp(*value, range.clone(), 0.0..=1.0);
+                    self.color_map.color_at(t as f32)
+                }
+            })
+            .collect();
+        ColorImage {
+            size: [columns, rows],
+            pixels,
+        }
+    }
+
+    /// Upload the image to a texture, or update the one uploaded before if the values changed.
+    fn texture_id(&self, ctx: &Context) -> TextureId {
+        let id = self
+            .id
+            .unwrap_or_else(|| Id::new("egui_plot_heatmap").with(&self.name));
+        let hash = egui::util::hash((
+            self.columns,
+            self.values
+                .iter()
+                .map(|value| value.to_bits())
+                .collect::<Vec<_>>(),
+            &self.color_map,
+            self.range
+                .as_ref()
+                .map(|range| [range.start().to_bits(), range.end().to_bits()]),
+        ));
+
+        let texture = ctx.data(|d| d.get_temp::<(u64, TextureHandle)>(id));
+        let texture = match texture {
+            Some((old_hash, texture)) if old_hash == hash => return texture.id(),
+            Some((_, mut texture)) => {
+                texture.set(self.image(), TextureOptions::NEAREST);
+                texture
+            }
+            None => ctx.load_texture("egui_plot_heatmap", self.image(), TextureOptions::NEAREST),
+        };
+        let texture_id = texture.id();
+        ctx.data_mut(|d| d.insert_temp(id, (hash, texture)));
+        texture_id
+    }
+
+    fn cell(&self, index: usize) -> HeatmapCell<'_> {
+        let (column, row) = (index % self.columns, index / self.columns);
+        let [width, height] = self.cell_size;
+        let min = PlotPoint::new(
+            self.origin.x + column as f64 * width,
+            self.origin.y + row as f64 * height,
+        );
+        HeatmapCell {
+            name: &self.name,
+            min,
+            max: PlotPoint::new(min.x + width, min.y + height),
+            value: self.values[index],
+        }
+    }
+}
+
+impl PlotItem for Heatmap {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        if self.rows() == 0 {
+            return;
+        }
+
+        let bounds = self.bounds();
+        let rect = transform.rect_from_values(
+            &PlotPoint::new(bounds.min[0], bounds.min[1]),
+            &PlotPoint::new(bounds.max[0], bounds.max[1]),
+        );
+        let mut mesh = Mesh::with_texture(self.texture_id(ui.ctx()));
+        let uv = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+        mesh.add_rect_with_uv(rect, uv, Color32::WHITE);
+        shapes.push(Shape::mesh(mesh));
+
+        if self.highlight {
+            shapes.push(Shape::rect_stroke(
+                rect,
+                Rounding::ZERO,
+                Stroke::new(1.0, ui.visuals().strong_text_color()),
+            ));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.color_map.color_at(0.5)
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn on_secondary_y_axis(&self) -> bool {
+        self.on_secondary_y_axis
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        let rows = self.rows();
+        if rows > 0 {
+            let [width, height] = self.cell_size;
+            bounds.extend_with(&self.origin);
+            bounds.extend_with(&PlotPoint::new(
+                self.origin.x + self.columns as f64 * width,
+                self.origin.y + rows as f64 * height,
+            ));
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let rows = self.rows();
+        if rows == 0 {
+            return None;
+        }
+
+        // The cell under the point, or the one at the edge closest to it:
+        let value = transform.value_from_position(point);
+        let [width, height] = self.cell_size;
+        let column = ((value.x - self.origin.x) / width).floor();
+        let row = ((value.y - self.origin.y) / height).floor();
+        let column = column.clamp(0.0, (self.columns - 1) as f64) as usize;
+        let row = row.clamp(0.0, (rows - 1) as f64) as usize;
+        let index = row * self.columns + column;
+
+        let cell = self.cell(index);
+        let rect = transform.rect_from_values(&cell.min, &cell.max);
+        Some(ClosestElem {
+            index,
+            dist_sq: rect.distance_sq_to_pos(point),
+        })
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter<'_>,
+    ) {
+        let cell = self.cell(elem.index);
+
+        let rect = plot.transform.rect_from_values(&cell.min, &cell.max);
+        shapes.push(Shape::rect_stroke(
+            rect,
+            Rounding::ZERO,
+            Stroke::new(2.0, plot.ui.visuals().strong_text_color()),
+        ));
+        add_rulers_and_text(&cell, plot, None, shapes, cursors);
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+}
+
+/// One cell of a [`Heatmap`], for showing its value on hover.
+struct HeatmapCell<'a> {
+    name: &'a str,
+    min: PlotPoint,
+    max: PlotPoint,
+    value: f64,
+}
+
+impl RectElement for HeatmapCell<'_> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn bounds_min(&self) -> PlotPoint {
+        self.min
+    }
+
+    fn bounds_max(&self) -> PlotPoint {
+        self.max
+    }
+
+    fn values_with_ruler(&self) -> Vec<PlotPoint> {
+        vec![self.bounds().center()]
+    }
+
+    fn orientation(&self) -> Orientation {
+        Orientation::Vertical
+    }
+
+    fn default_values_format(&self, _transform: &PlotTransform) -> String {
+        emath::format_with_decimals_in_range(self.value, 0..=6)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_map() {
+        let gray = ColorMap::Grayscale;
+        assert_eq!(gray.color_at(0.0), Color32::BLACK);
+        assert_eq!(gray.color_at(0.5), Color32::from_gray(128));
+        assert_eq!(gray.color_at(1.0), Color32::WHITE);
+
+        // Out of range values are clamped:
+        assert_eq!(gray.color_at(-1.0), Color32::BLACK);
+        assert_eq!(gray.color_at(2.0), Color32::WHITE);
+
+        assert_eq!(ColorMap::Viridis.color_at(0.0), VIRIDIS[0]);
+        assert_eq!(ColorMap::Viridis.color_at(1.0), VIRIDIS[8]);
+        assert_eq!(ColorMap::Viridis.color_at(0.5), VIRIDIS[4]);
+
+        let custom = ColorMap::Custom(vec![Color32::RED, Color32::BLUE, Color32::GREEN]);
+        assert_eq!(custom.color_at(0.5), Color32::BLUE);
+        assert_eq!(
+            custom.color_at(0.75),
+            Color32::from_rgb(0, 128, 128) // Halfway between blue and green
+        );
+        assert_eq!(
+            ColorMap::Custom(vec![Color32::RED]).color_at(0.3),
+            Color32::RED
+        );
+        assert_eq!(ColorMap::Custom(vec![]).color_at(0.3), Color32::TRANSPARENT);
+    }
+
+    #[test]
+    fn test_image() {
+        // The first row is at the bottom, and the incomplete last row is ignored:
+        let heatmap =
+            Heatmap::new(vec![0.0, 1.0, 2.0, f64::NAN, 5.0], 2).color_map(ColorMap::Grayscale);
+        let image = heatmap.image();
+        assert_eq!(image.size, [2, 2]);
+        assert_eq!(
+            image.pixels,
+            vec![
+                Color32::WHITE,
+                Color32::TRANSPARENT,
+                Color32::BLACK,
+                Color32::from_gray(128),
+            ]
+        );
+
+        // Values outside of the range get the colors at its ends:
+        let image = heatmap.range(0.5..=1.0).image();
+        assert_eq!(
+            image.pixels,
+            vec![
+                Color32::WHITE,
+                Color32::TRANSPARENT,
+                Color32::BLACK,
+                Color32::WHITE,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_closest() {
+        // Two by two cells of 10x5 plot units, shown in a 100x100 frame:
+        let heatmap = Heatmap::new(vec![0.0, 1.0, 2.0, 3.0], 2)
+            .origin([-10.0, 0.0])
+            .cell_size([10.0, 5.0]);
+        let bounds = heatmap.bounds();
+        assert_eq!((bounds.min(), bounds.max()), ([-10.0, 0.0], [10.0, 10.0]));
+        let frame = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+        let transform = PlotTransform::new(frame, bounds, false, false);
+
+        // Inside the upper left cell, which is in the second row:
+        let closest = heatmap.find_closest(pos2(25.0, 25.0), &transform).unwrap();
+        assert_eq!(closest.index, 2);
+        assert_eq!(closest.dist_sq, 0.0);
+
+        // Inside the lower right cell:
+        let closest = heatmap.find_closest(pos2(99.0, 99.0), &transform).unwrap();
+        assert_eq!(closest.index, 1);
+        assert_eq!(closest.dist_sq, 0.0);
+
+        // Left of the heatmap, the closest cell is the one at the edge:
+        let closest = heatmap.find_closest(pos2(-10.0, 75.0), &transform).unwrap();
+        assert_eq!(closest.index, 0);
+        assert_eq!(closest.dist_sq, 100.0);
+
+        let empty = Heatmap::new(vec![], 2);
+        assert!(empty.find_closest(pos2(25.0, 25.0), &transform).is_none());
+    }
+}
@@ -12,12 +12,20 @@ use rect_elem::*;
 
 pub use bar::Bar;
 pub use box_elem::{BoxElem, BoxSpread};
+pub use candle::Candle;
+pub use decimated_line::DecimatedLine;
+pub use error_bars::ErrorBars;
+pub use heatmap::{ColorMap, Heatmap};
 pub use values::{
     ClosestElem, LineStyle, MarkerShape, Orientation, PlotGeometry, PlotPoint, PlotPoints,
 };
 
 mod bar;
 mod box_elem;
+mod candle;
+mod decimated_line;
+mod error_bars;
+mod heatmap;
 mod rect_elem;
 mod values;
 
@@ -49,6 +57,13 @@ pub trait PlotItem {
     /// Can the user hover this item?
     fn allow_hover(&self) -> bool;
 
+    /// Is this item plotted against the secondary Y axis?
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    fn on_secondary_y_axis(&self) -> bool {
+        false
+    }
+
     fn geometry(&self) -> PlotGeometry<'_>;
 
     fn bounds(&self) -> PlotBounds;
@@ -126,6 +141,7 @@ pub struct HLine {
     pub(super) name: String,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
+    pub(super) on_secondary_y_axis: bool,
     pub(super) style: LineStyle,
     id: Option<Id>,
 }
@@ -138,6 +154,7 @@ impl HLine {
             name: String::default(),
             highlight: false,
             allow_hover: true,
+            on_secondary_y_axis: false,
             style: LineStyle::Solid,
             id: None,
         }
@@ -157,6 +174,15 @@ impl HLine {
         self
     }
 
+    /// Plot this item against the secondary Y axis of the plot. Default: `false`.
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn on_secondary_y_axis(mut self, on_secondary_y_axis: bool) -> Self {
+        self.on_secondary_y_axis = on_secondary_y_axis;
+        self
+    }
+
     /// Add a stroke.
     #[inline]
     pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
@@ -250,6 +276,10 @@ impl PlotItem for HLine {
         self.allow_hover
     }
 
+    fn on_secondary_y_axis(&self) -> bool {
+        self.on_secondary_y_axis
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::None
     }
@@ -274,6 +304,7 @@ pub struct VLine {
     pub(super) name: String,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
+    pub(super) on_secondary_y_axis: bool,
     pub(super) style: LineStyle,
     id: Option<Id>,
 }
@@ -286,6 +317,7 @@ impl VLine {
             name: String::default(),
             highlight: false,
             allow_hover: true,
+            on_secondary_y_axis: false,
             style: LineStyle::Solid,
             id: None,
         }
@@ -305,6 +337,15 @@ impl VLine {
         self
     }
 
+    /// Plot this item against the secondary Y axis of the plot. Default: `false`.
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn on_secondary_y_axis(mut self, on_secondary_y_axis: bool) -> Self {
+        self.on_secondary_y_axis = on_secondary_y_axis;
+        self
+    }
+
     /// Add a stroke.
     #[inline]
     pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
@@ -398,6 +439,10 @@ impl PlotItem for VLine {
         self.allow_hover
     }
 
+    fn on_secondary_y_axis(&self) -> bool {
+        self.on_secondary_y_axis
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::None
     }
@@ -421,8 +466,10 @@ pub struct Line {
     pub(super) name: String,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
+    pub(super) on_secondary_y_axis: bool,
     pub(super) fill: Option<f32>,
     pub(super) style: LineStyle,
+    error_bars: ErrorBars,
     id: Option<Id>,
 }
 
@@ -434,8 +481,10 @@ impl Line {
             name: Default::default(),
             highlight: false,
             allow_hover: true,
+            on_secondary_y_axis: false,
             fill: None,
             style: LineStyle::Solid,
+            error_bars: ErrorBars::default(),
             id: None,
         }
     }
@@ -454,6 +503,15 @@ impl Line {
         self
     }
 
+    /// Plot this item against the secondary Y axis of the plot. Default: `false`.
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn on_secondary_y_axis(mut self, on_secondary_y_axis: bool) -> Self {
+        self.on_secondary_y_axis = on_secondary_y_axis;
+        self
+    }
+
     /// Add a stroke.
     #[inline]
     pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
@@ -482,6 +540,15 @@ impl Line {
         self
     }
 
+    /// Show error bars at the points of the line, in the color of the line.
+    ///
+    /// To fill the area of uncertainty around a line instead, see [`Band`].
+    #[inline]
+    pub fn error_bars(mut self, error_bars: ErrorBars) -> Self {
+        self.error_bars = error_bars;
+        self
+    }
+
     /// Set the line's style. Default is `LineStyle::Solid`.
     #[inline]
     pub fn style(mut self, style: LineStyle) -> Self {
@@ -525,6 +592,7 @@ impl PlotItem for Line {
             highlight,
             mut fill,
             style,
+            error_bars,
             ..
         } = self;
 
@@ -575,6 +643,10 @@ impl PlotItem for Line {
             shapes.push(Shape::Mesh(mesh));
         }
         style.style_line(values_tf, *stroke, *highlight, shapes);
+
+        let error_bar_width = if *highlight { 2.0 } else { 1.0 };
+        let error_bar_stroke = Stroke::new(error_bar_width, stroke.color);
+        error_bars.add_shapes(series.points(), transform, error_bar_stroke, shapes);
     }
 
     fn initialize(&mut self, x_range: RangeInclusive<f64>) {
@@ -601,12 +673,19 @@ impl PlotItem for Line {
         self.allow_hover
     }
 
+    fn on_secondary_y_axis(&self) -> bool {
+        self.on_secondary_y_axis
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::Points(self.series.points())
     }
 
     fn bounds(&self) -> PlotBounds {
-        self.series.bounds()
+        let mut bounds = self.series.bounds();
+        self.error_bars
+            .extend_bounds(self.series.points(), &mut bounds);
+        bounds
     }
 
     fn id(&self) -> Option<Id> {
@@ -621,6 +700,7 @@ pub struct Polygon {
     pub(super) name: String,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
+    pub(super) on_secondary_y_axis: bool,
     pub(super) fill_color: Option<Color32>,
     pub(super) style: LineStyle,
     id: Option<Id>,
@@ -634,6 +714,7 @@ impl Polygon {
             name: Default::default(),
             highlight: false,
             allow_hover: true,
+            on_secondary_y_axis: false,
             fill_color: None,
             style: LineStyle::Solid,
             id: None,
@@ -655,6 +736,15 @@ impl Polygon {
         self
     }
 
+    /// Plot this item against the secondary Y axis of the plot. Default: `false`.
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn on_secondary_y_axis(mut self, on_secondary_y_axis: bool) -> Self {
+        self.on_secondary_y_axis = on_secondary_y_axis;
+        self
+    }
+
     /// Add a custom stroke.
     #[inline]
     pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
@@ -753,6 +843,10 @@ impl PlotItem for Polygon {
         self.allow_hover
     }
 
+    fn on_secondary_y_axis(&self) -> bool {
+        self.on_secondary_y_axis
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::Points(self.series.points())
     }
@@ -766,6 +860,257 @@ impl PlotItem for Polygon {
     }
 }
 
+/// A filled area between a lower and an upper curve, e.g. to show the uncertainty of measurements.
+///
+/// The `i`th point of the lower curve is connected to the `i`th point of the upper curve,
+/// so both should have the same number of points, usually at the same X values.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Band, Line, Plot, PlotPoints};
+///
+/// let mean = |x: f64| x.sin();
+/// let spread = |x: f64| 0.2 + 0.1 * x.cos();
+/// let band = Band::new(
+///     PlotPoints::from_explicit_callback(move |x| mean(x) - spread(x), .., 100),
+///     PlotPoints::from_explicit_callback(move |x| mean(x) + spread(x), .., 100),
+/// )
+/// .name("Mean");
+/// let line = Line::new(PlotPoints::from_explicit_callback(mean, .., 100)).name("Mean");
+/// Plot::new("confidence").show(ui, |plot_ui| {
+///     plot_ui.band(band);
+///     plot_ui.line(line);
+/// });
+/// # });
+/// ```
+pub struct Band {
+    pub(super) lower: PlotPoints,
+    pub(super) upper: PlotPoints,
+    pub(super) color: Color32,
+    fill_alpha: f32,
+    width: f32,
+    name: String,
+    highlight: bool,
+    allow_hover: bool,
+    on_secondary_y_axis: bool,
+    id: Option<Id>,
+}
+
+impl Band {
+    pub fn new(lower: impl Into<PlotPoints>, upper: impl Into<PlotPoints>) -> Self {
+        Self {
+            lower: lower.into(),
+            upper: upper.into(),
+            color: Color32::TRANSPARENT,
+            fill_alpha: 0.2,
+            width: 0.0,
+            name: Default::default(),
+            highlight: false,
+            allow_hover: true,
+            on_secondary_y_axis: false,
+            id: None,
+        }
+    }
+
+    /// Highlight this band in the plot by making it more opaque.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Plot this item against the secondary Y axis of the plot. Default: `false`.
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn on_secondary_y_axis(mut self, on_secondary_y_axis: bool) -> Self {
+        self.on_secondary_y_axis = on_secondary_y_axis;
+        self
+    }
+
+    /// Color of the band. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// How opaque the area between the curves is filled. Default: `0.2`.
+    #[inline]
+    pub fn fill_alpha(mut self, alpha: impl Into<f32>) -> Self {
+        self.fill_alpha = alpha.into();
+        self
+    }
+
+    /// Width of the lines along the lower and upper curves. Default: `0.0`, i.e. no lines.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Name of this band.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the band's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// The point of the lower curve, followed by the points of the upper curve.
+    fn point(&self, index: usize) -> PlotPoint {
+        let lower = self.lower.points();
+        lower
+            .get(index)
+            .copied()
+            .unwrap_or_else(|| self.upper.points()[index - lower.len()])
+    }
+}
+
+impl PlotItem for Band {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let lower = self.lower.points();
+        let upper = self.upper.points();
+        let n_values = lower.len().min(upper.len());
+
+        if n_values >= 2 {
+            let mut fill_alpha = self.fill_alpha;
+            if self.highlight {
+                fill_alpha = (2.0 * fill_alpha).at_most(1.0);
+            }
+            let fill_color = Rgba::from(self.color)
+                .to_opaque()
+                .multiply(fill_alpha)
+                .into();
+
+            // A strip of triangles between the two curves:
+            let mut mesh = Mesh::default();
+            mesh.reserve_triangles((n_values - 1) * 2);
+            mesh.reserve_vertices(n_values * 2);
+            for (lower, upper) in lower.iter().zip(upper) {
+                mesh.colored_vertex(transform.position_from_point(lower), fill_color);
+                mesh.colored_vertex(transform.position_from_point(upper), fill_color);
+            }
+            for i in 0..(n_values as u32 - 1) {
+                let i = 2 * i;
+                mesh.add_triangle(i, i + 1, i + 2);
+                mesh.add_triangle(i + 1, i + 2, i + 3);
+            }
+            shapes.push(Shape::Mesh(mesh));
+        }
+
+        if self.width > 0.0 {
+            let stroke = Stroke::new(self.width, self.color);
+            for curve in [lower, upper] {
+                let line = curve
+                    .iter()
+                    .map(|value| transform.position_from_point(value))
+                    .collect();
+                LineStyle::Solid.style_line(line, stroke, self.highlight, shapes);
+            }
+        }
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.lower.generate_points(x_range.clone());
+        self.upper.generate_points(x_range);
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn on_secondary_y_axis(&self) -> bool {
+        self.on_secondary_y_axis
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = self.lower.bounds();
+        bounds.merge(&self.upper.bounds());
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        self.lower
+            .points()
+            .iter()
+            .chain(self.upper.points())
+            .enumerate()
+            .map(|(index, value)| {
+                let pos = transform.position_from_point(value);
+                let dist_sq = point.distance_sq(pos);
+                ClosestElem { index, dist_sq }
+            })
+            .min_by_key(|e| e.dist_sq.ord())
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter<'_>,
+    ) {
+        let value = self.point(elem.index);
+        let pointer = plot.transform.position_from_point(&value);
+        shapes.push(Shape::circle_filled(pointer, 3.0, rulers_color(plot.ui)));
+
+        rulers_at_value(
+            pointer,
+            value,
+            self.name(),
+            plot,
+            shapes,
+            cursors,
+            label_formatter,
+        );
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+}
+
 /// Text inside the plot.
 #[derive(Clone)]
 pub struct Text {
@@ -774,6 +1119,7 @@ pub struct Text {
     pub(super) name: String,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
+    pub(super) on_secondary_y_axis: bool,
     pub(super) color: Color32,
     pub(super) anchor: Align2,
     id: Option<Id>,
@@ -787,6 +1133,7 @@ impl Text {
             name: Default::default(),
             highlight: false,
             allow_hover: true,
+            on_secondary_y_axis: false,
             color: Color32::TRANSPARENT,
             anchor: Align2::CENTER_CENTER,
             id: None,
@@ -807,6 +1154,15 @@ impl Text {
         self
     }
 
+    /// Plot this item against the secondary Y axis of the plot. Default: `false`.
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn on_secondary_y_axis(mut self, on_secondary_y_axis: bool) -> Self {
+        self.on_secondary_y_axis = on_secondary_y_axis;
+        self
+    }
+
     /// Text color.
     #[inline]
     pub fn color(mut self, color: impl Into<Color32>) -> Self {
@@ -891,6 +1247,10 @@ impl PlotItem for Text {
         self.allow_hover
     }
 
+    fn on_secondary_y_axis(&self) -> bool {
+        self.on_secondary_y_axis
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::None
     }
@@ -926,8 +1286,10 @@ pub struct Points {
     pub(super) highlight: bool,
 
     pub(super) allow_hover: bool,
+    pub(super) on_secondary_y_axis: bool,
 
     pub(super) stems: Option<f32>,
+    error_bars: ErrorBars,
     id: Option<Id>,
 }
 
@@ -942,7 +1304,9 @@ impl Points {
             name: Default::default(),
             highlight: false,
             allow_hover: true,
+            on_secondary_y_axis: false,
             stems: None,
+            error_bars: ErrorBars::default(),
             id: None,
         }
     }
@@ -968,6 +1332,15 @@ impl Points {
         self
     }
 
+    /// Plot this item against the secondary Y axis of the plot. Default: `false`.
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn on_secondary_y_axis(mut self, on_secondary_y_axis: bool) -> Self {
+        self.on_secondary_y_axis = on_secondary_y_axis;
+        self
+    }
+
     /// Set the marker's color.
     #[inline]
     pub fn color(mut self, color: impl Into<Color32>) -> Self {
@@ -989,6 +1362,13 @@ impl Points {
         self
     }
 
+    /// Show error bars at the points, in the color of the markers.
+    #[inline]
+    pub fn error_bars(mut self, error_bars: ErrorBars) -> Self {
+        self.error_bars = error_bars;
+        self
+    }
+
     /// Set the maximum extent of the marker around its position, in ui points.
     #[inline]
     pub fn radius(mut self, radius: impl Into<f32>) -> Self {
@@ -1031,6 +1411,7 @@ impl PlotItem for Points {
             mut radius,
             highlight,
             stems,
+            error_bars,
             ..
         } = self;
 
@@ -1051,6 +1432,10 @@ impl PlotItem for Points {
 
         let y_reference = stems.map(|y| transform.position_from_point(&PlotPoint::new(0.0, y)).y);
 
+        let error_bar_width = if *highlight { 2.0 } else { 1.0 };
+        let error_bar_stroke = Stroke::new(error_bar_width, *color);
+        error_bars.add_shapes(series.points(), transform, error_bar_stroke, shapes);
+
         series
             .points()
             .iter()
@@ -1170,12 +1555,19 @@ impl PlotItem for Points {
         self.allow_hover
     }
 
+    fn on_secondary_y_axis(&self) -> bool {
+        self.on_secondary_y_axis
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::Points(self.series.points())
     }
 
     fn bounds(&self) -> PlotBounds {
-        self.series.bounds()
+        let mut bounds = self.series.bounds();
+        self.error_bars
+            .extend_bounds(self.series.points(), &mut bounds);
+        bounds
     }
 
     fn id(&self) -> Option<Id> {
@@ -1192,6 +1584,7 @@ pub struct Arrows {
     pub(super) name: String,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
+    pub(super) on_secondary_y_axis: bool,
     id: Option<Id>,
 }
 
@@ -1205,6 +1598,7 @@ impl Arrows {
             name: Default::default(),
             highlight: false,
             allow_hover: true,
+            on_secondary_y_axis: false,
             id: None,
         }
     }
@@ -1223,6 +1617,15 @@ impl Arrows {
         self
     }
 
+    /// Plot this item against the secondary Y axis of the plot. Default: `false`.
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn on_secondary_y_axis(mut self, on_secondary_y_axis: bool) -> Self {
+        self.on_secondary_y_axis = on_secondary_y_axis;
+        self
+    }
+
     /// Set the length of the arrow tips
     #[inline]
     pub fn tip_length(mut self, tip_length: f32) -> Self {
@@ -1328,6 +1731,10 @@ impl PlotItem for Arrows {
         self.allow_hover
     }
 
+    fn on_secondary_y_axis(&self) -> bool {
+        self.on_secondary_y_axis
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::Points(self.origins.points())
     }
@@ -1353,6 +1760,7 @@ pub struct PlotImage {
     pub(super) tint: Color32,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
+    pub(super) on_secondary_y_axis: bool,
     pub(super) name: String,
     id: Option<Id>,
 }
@@ -1369,6 +1777,7 @@ impl PlotImage {
             name: Default::default(),
             highlight: false,
             allow_hover: true,
+            on_secondary_y_axis: false,
             texture_id: texture_id.into(),
             uv: Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
             size: size.into(),
@@ -1393,6 +1802,15 @@ impl PlotImage {
         self
     }
 
+    /// Plot this item against the secondary Y axis of the plot. Default: `false`.
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn on_secondary_y_axis(mut self, on_secondary_y_axis: bool) -> Self {
+        self.on_secondary_y_axis = on_secondary_y_axis;
+        self
+    }
+
     /// Select UV range. Default is (0,0) in top-left, (1,1) bottom right.
     #[inline]
     pub fn uv(mut self, uv: impl Into<Rect>) -> Self {
@@ -1472,6 +1890,7 @@ impl PlotItem for PlotImage {
                 tint: *tint,
                 rotation: Some((Rot2::from_angle(screen_rotation), Vec2::splat(0.5))),
                 rounding: Rounding::ZERO,
+                nine_slice: None,
             },
             &(*texture_id, image_screen_rect.size()).into(),
         );
@@ -1516,6 +1935,10 @@ impl PlotItem for PlotImage {
         self.allow_hover
     }
 
+    fn on_secondary_y_axis(&self) -> bool {
+        self.on_secondary_y_axis
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::None
     }
@@ -1553,6 +1976,7 @@ pub struct BarChart {
 
     highlight: bool,
     allow_hover: bool,
+    on_secondary_y_axis: bool,
     id: Option<Id>,
 }
 
@@ -1566,6 +1990,7 @@ impl BarChart {
             element_formatter: None,
             highlight: false,
             allow_hover: true,
+            on_secondary_y_axis: false,
             id: None,
         }
     }
@@ -1641,6 +2066,15 @@ impl BarChart {
         self
     }
 
+    /// Plot this item against the secondary Y axis of the plot. Default: `false`.
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn on_secondary_y_axis(mut self, on_secondary_y_axis: bool) -> Self {
+        self.on_secondary_y_axis = on_secondary_y_axis;
+        self
+    }
+
     /// Add a custom way to format an element.
     /// Can be used to display a set number of decimals or custom labels.
     #[inline]
@@ -1674,6 +2108,91 @@ impl BarChart {
         self
     }
 
+    /// Stacks the bars of the charts on top of each other, in the given order.
+    ///
+    /// Unlike [`Self::stack_on`], bars are stacked with the bars of the other charts that have
+    /// the same argument, so the charts don't need to have the same number of bars.
+    /// Positive values are stacked on top of other positive values.
+    /// Negative values are stacked below other negative values.
+    ///
+    /// Hovering a bar shows the value of that bar, not of the whole stack.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Bar, BarChart, Plot};
+    ///
+    /// let apples = BarChart::new(vec![Bar::new(0.0, 3.0), Bar::new(1.0, 2.0)]).name("Apples");
+    /// let pears = BarChart::new(vec![Bar::new(1.0, 4.0)]).name("Pears");
+    /// Plot::new("fruit").show(ui, |plot_ui| {
+    ///     for chart in BarChart::stacked([apples, pears]) {
+    ///         plot_ui.bar_chart(chart);
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn stacked(charts: impl IntoIterator<Item = Self>) -> Vec<Self> {
+        let mut charts: Vec<Self> = charts.into_iter().collect();
+
+        // For each argument: the top of the positive bars and the bottom of the negative bars so far.
+        let mut stacks: Vec<(f64, f64, f64)> = Vec::new();
+        for chart in &mut charts {
+            for bar in &mut chart.bars {
+                let index = stacks
+                    .iter()
+                    .position(|(argument, _, _)| *argument == bar.argument)
+                    .unwrap_or_else(|| {
+                        let base = bar.base_offset.unwrap_or(0.0);
+                        stacks.push((bar.argument, base, base));
+                        stacks.len() - 1
+                    });
+                let (_, top, bottom) = &mut stacks[index];
+                let end = if bar.value.is_sign_positive() {
+                    top
+                } else {
+                    bottom
+                };
+                bar.base_offset = Some(*end);
+                *end += bar.value;
+            }
+        }
+
+        charts
+    }
+
+    /// Places the bars of the charts side by side, in the given order,
+    /// in groups around the arguments of the bars.
+    ///
+    /// The bars of a group share the width that each of them had before.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Bar, BarChart, Plot};
+    ///
+    /// let last_year = BarChart::new(vec![Bar::new(0.0, 3.0), Bar::new(1.0, 2.0)]).name("2023");
+    /// let this_year = BarChart::new(vec![Bar::new(0.0, 4.0), Bar::new(1.0, 1.0)]).name("2024");
+    /// Plot::new("sales").show(ui, |plot_ui| {
+    ///     for chart in BarChart::grouped([last_year, this_year]) {
+    ///         plot_ui.bar_chart(chart);
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn grouped(charts: impl IntoIterator<Item = Self>) -> Vec<Self> {
+        let mut charts: Vec<Self> = charts.into_iter().collect();
+
+        let count = charts.len() as f64;
+        for (index, chart) in charts.iter_mut().enumerate() {
+            // Relative to the center of the group, in bar widths:
+            let offset = index as f64 - 0.5 * (count - 1.0);
+            for bar in &mut chart.bars {
+                bar.bar_width /= count;
+                bar.argument += offset * bar.bar_width;
+            }
+        }
+
+        charts
+    }
+
     /// Set the bar chart's id which is used to identify it in the plot's response.
     #[inline]
     pub fn id(mut self, id: Id) -> Self {
@@ -1713,6 +2232,10 @@ impl PlotItem for BarChart {
         self.allow_hover
     }
 
+    fn on_secondary_y_axis(&self) -> bool {
+        self.on_secondary_y_axis
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::Rects
     }
@@ -1759,6 +2282,7 @@ pub struct BoxPlot {
 
     highlight: bool,
     allow_hover: bool,
+    on_secondary_y_axis: bool,
     id: Option<Id>,
 }
 
@@ -1772,6 +2296,7 @@ impl BoxPlot {
             element_formatter: None,
             highlight: false,
             allow_hover: true,
+            on_secondary_y_axis: false,
             id: None,
         }
     }
@@ -1840,6 +2365,15 @@ impl BoxPlot {
         self
     }
 
+    /// Plot this item against the secondary Y axis of the plot. Default: `false`.
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn on_secondary_y_axis(mut self, on_secondary_y_axis: bool) -> Self {
+        self.on_secondary_y_axis = on_secondary_y_axis;
+        self
+    }
+
     /// Add a custom way to format an element.
     /// Can be used to display a set number of decimals or custom labels.
     #[inline]
@@ -1887,6 +2421,10 @@ impl PlotItem for BoxPlot {
         self.allow_hover
     }
 
+    fn on_secondary_y_axis(&self) -> bool {
+        self.on_secondary_y_axis
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::Rects
     }
@@ -1922,6 +2460,212 @@ impl PlotItem for BoxPlot {
     }
 }
 
+/// A candlestick chart (also known as an OHLC chart) containing a series of [`Candle`]s,
+/// typically showing how a price developed over time.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Candle, CandleStick, Plot};
+///
+/// let candles = vec![
+///     Candle::new(0.0, 10.0, 12.5, 9.5, 12.0),
+///     Candle::new(1.0, 12.0, 13.0, 10.0, 10.5),
+/// ];
+/// Plot::new("prices").show(ui, |plot_ui| {
+///     plot_ui.candle_stick(CandleStick::new(candles).name("ACME"));
+/// });
+/// # });
+/// ```
+pub struct CandleStick {
+    pub(super) candles: Vec<Candle>,
+    pub(super) up_color: Color32,
+    pub(super) down_color: Color32,
+    pub(super) wick_stroke: Stroke,
+    pub(super) name: String,
+
+    /// A custom element formatter
+    pub(super) element_formatter: Option<Box<dyn Fn(&Candle, &CandleStick) -> String>>,
+
+    highlight: bool,
+    allow_hover: bool,
+    on_secondary_y_axis: bool,
+    id: Option<Id>,
+}
+
+impl CandleStick {
+    /// Create a chart containing multiple `candles`.
+    pub fn new(candles: Vec<Candle>) -> Self {
+        Self {
+            candles,
+            up_color: Color32::from_rgb(38, 166, 91),
+            down_color: Color32::from_rgb(219, 64, 64),
+            wick_stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            name: String::new(),
+            element_formatter: None,
+            highlight: false,
+            allow_hover: true,
+            on_secondary_y_axis: false,
+            id: None,
+        }
+    }
+
+    /// The color of the candles that close at or above their open value. Default is green.
+    ///
+    /// This is the color that shows up in the legend.
+    #[inline]
+    pub fn up_color(mut self, color: impl Into<Color32>) -> Self {
+        self.up_color = color.into();
+        self
+    }
+
+    /// The color of the candles that close below their open value. Default is red.
+    #[inline]
+    pub fn down_color(mut self, color: impl Into<Color32>) -> Self {
+        self.down_color = color.into();
+        self
+    }
+
+    /// The stroke of the wicks, which span from the low to the high value.
+    ///
+    /// Default is a thin line, which takes the color of the candle
+    /// as long as the color of the stroke is [`Color32::TRANSPARENT`].
+    #[inline]
+    pub fn wick_stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.wick_stroke = stroke.into();
+        self
+    }
+
+    /// Set the width of the bodies of all candles.
+    #[inline]
+    pub fn width(mut self, width: f64) -> Self {
+        for candle in &mut self.candles {
+            candle.width = width;
+        }
+        self
+    }
+
+    /// Name of this chart.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on. Multiple series may
+    /// share the same name, in which case they will also share an entry in the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Highlight all plot elements.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Plot this item against the secondary Y axis of the plot. Default: `false`.
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    #[inline]
+    pub fn on_secondary_y_axis(mut self, on_secondary_y_axis: bool) -> Self {
+        self.on_secondary_y_axis = on_secondary_y_axis;
+        self
+    }
+
+    /// Add a custom way to format an element.
+    /// Can be used to display a set number of decimals, dates or custom labels.
+    ///
+    /// By default, the name of the candle and its open, high, low and close values are shown.
+    #[inline]
+    pub fn element_formatter(mut self, formatter: Box<dyn Fn(&Candle, &Self) -> String>) -> Self {
+        self.element_formatter = Some(formatter);
+        self
+    }
+
+    /// Set the chart's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+impl PlotItem for CandleStick {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        for candle in &self.candles {
+            candle.add_shapes(self, transform, self.highlight, shapes);
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
+        // nothing to do
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.up_color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn on_secondary_y_axis(&self) -> bool {
+        self.on_secondary_y_axis
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for candle in &self.candles {
+            bounds.merge(&candle.bounds());
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        find_closest_rect(&self.candles, point, transform)
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter<'_>,
+    ) {
+        let candle = &self.candles[elem.index];
+
+        candle.add_shapes(self, plot.transform, true, shapes);
+        candle.add_rulers_and_text(self, plot, shapes, cursors);
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Helper functions
 
@@ -2100,3 +2844,97 @@ where
         })
         .min_by_key(|e| e.dist_sq.ord())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The `(argument, lower, upper)` of each bar of each chart.
+    fn extents(charts: &[BarChart]) -> Vec<Vec<(f64, f64, f64)>> {
+        charts
+            .iter()
+            .map(|chart| {
+                chart
+                    .bars
+                    .iter()
+                    .map(|bar| (bar.argument, bar.lower(), bar.upper()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_stacked_bars() {
+        let charts = BarChart::stacked([
+            BarChart::new(vec![Bar::new(0.0, 3.0), Bar::new(1.0, 2.0)]),
+            // Matched by argument, not by index:
+            BarChart::new(vec![Bar::new(1.0, 4.0), Bar::new(2.0, 1.0)]),
+            BarChart::new(vec![Bar::new(0.0, 1.0), Bar::new(1.0, 0.5)]),
+        ]);
+        assert_eq!(
+            extents(&charts),
+            vec![
+                vec![(0.0, 0.0, 3.0), (1.0, 0.0, 2.0)],
+                vec![(1.0, 2.0, 6.0), (2.0, 0.0, 1.0)],
+                vec![(0.0, 3.0, 4.0), (1.0, 6.0, 6.5)],
+            ]
+        );
+
+        // Hovering still shows the value of a single bar:
+        assert_eq!(charts[1].bars[0].value, 4.0);
+    }
+
+    #[test]
+    fn test_stacked_negative_bars() {
+        // Negative values stack downwards, separately from the positive ones,
+        // starting at the base offset of the first bar of each argument:
+        let charts = BarChart::stacked([
+            BarChart::new(vec![Bar::new(0.0, 2.0).base_offset(1.0)]),
+            BarChart::new(vec![Bar::new(0.0, -3.0)]),
+            BarChart::new(vec![Bar::new(0.0, 1.0)]),
+            BarChart::new(vec![Bar::new(0.0, -1.0)]),
+        ]);
+        assert_eq!(
+            extents(&charts),
+            vec![
+                vec![(0.0, 1.0, 3.0)],
+                vec![(0.0, -2.0, 1.0)],
+                vec![(0.0, 3.0, 4.0)],
+                vec![(0.0, -3.0, -2.0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_grouped_bars() {
+        let charts = BarChart::grouped([
+            BarChart::new(vec![Bar::new(0.0, 3.0), Bar::new(1.0, 2.0)]),
+            BarChart::new(vec![Bar::new(0.0, 4.0), Bar::new(1.0, 1.0).width(0.6)]),
+            BarChart::new(vec![Bar::new(0.0, 5.0)]),
+        ]);
+
+        let arguments_and_widths: Vec<Vec<(f64, f64)>> = charts
+            .iter()
+            .map(|chart| {
+                chart
+                    .bars
+                    .iter()
+                    .map(|bar| (bar.argument, bar.bar_width))
+                    .collect()
+            })
+            .collect();
+        // The default width is 0.5, shared by the three bars of a group:
+        let width = 0.5 / 3.0;
+        assert_eq!(
+            arguments_and_widths,
+            vec![
+                vec![(-width, width), (1.0 - width, width)],
+                vec![(0.0, width), (1.0, 0.6 / 3.0)],
+                vec![(width, width)],
+            ]
+        );
+
+        // The values stay the same:
+        assert_eq!(extents(&charts)[2], vec![(width, 0.0, 5.0)]);
+    }
+}
@@ -88,14 +88,18 @@ struct LegendEntry {
     color: Color32,
     checked: bool,
     hovered: bool,
+
+    /// Is the item plotted against the secondary Y axis?
+    on_secondary_y_axis: bool,
 }
 
 impl LegendEntry {
-    fn new(color: Color32, checked: bool) -> Self {
+    fn new(color: Color32, checked: bool, on_secondary_y_axis: bool) -> Self {
         Self {
             color,
             checked,
             hovered: false,
+            on_secondary_y_axis,
         }
     }
 
@@ -104,8 +108,16 @@ impl LegendEntry {
             color,
             checked,
             hovered,
+            on_secondary_y_axis,
         } = self;
 
+        // Point towards the secondary Y axis, on the right side of the plot:
+        let text = if *on_secondary_y_axis {
+            format!("{text} ⏵")
+        } else {
+            text
+        };
+
         let font_id = text_style.resolve(ui.style());
 
         let galley = ui.fonts(|f| f.layout_delayed_color(text, font_id, f32::INFINITY));
@@ -162,7 +174,6 @@ impl LegendEntry {
         let text_position = pos2(text_position_x, rect.center().y - 0.5 * galley.size().y);
         painter.galley(text_position, galley, visuals.text_color());
 
-        *checked ^= response.clicked_by(PointerButton::Primary);
         *hovered = response.hovered();
 
         response
@@ -184,6 +195,7 @@ impl LegendWidget {
         config: Legend,
         items: &[Box<dyn PlotItem>],
         hidden_items: &ahash::HashSet<String>, // Existing hiddent items in the plot memory.
+        has_secondary_y_axis: bool,
     ) -> Option<Self> {
         // If `config.hidden_items` is not `None`, it is used.
         let hidden_items = config.hidden_items.as_ref().unwrap_or(hidden_items);
@@ -206,7 +218,9 @@ impl LegendWidget {
                     .or_insert_with(|| {
                         let color = item.color();
                         let checked = !hidden_items.contains(item.name());
-                        LegendEntry::new(color, checked)
+                        let on_secondary_y_axis =
+                            has_secondary_y_axis && item.on_secondary_y_axis();
+                        LegendEntry::new(color, checked, on_secondary_y_axis)
                     });
             });
         (!entries.is_empty()).then_some(Self {
@@ -265,16 +279,41 @@ impl Widget for &mut LegendWidget {
                     ..Default::default()
                 }
                 .multiply_with_opacity(config.background_alpha);
-                background_frame
+                let mut clicked_entry = None;
+                let response = background_frame
                     .show(ui, |ui| {
                         entries
                             .iter_mut()
-                            .map(|(name, entry)| entry.ui(ui, name.clone(), &config.text_style))
+                            .map(|(name, entry)| {
+                                let response = entry.ui(ui, name.clone(), &config.text_style);
+                                if response.clicked_by(PointerButton::Primary) {
+                                    clicked_entry = Some(name.clone());
+                                }
+                                response
+                            })
                             .reduce(|r1, r2| r1.union(r2))
                             .unwrap()
                     })
-                    .inner
+                    .inner;
+                if let Some(clicked_entry) = clicked_entry {
+                    if ui.input(|i| i.modifiers.command) {
+                        solo_entry(entries, &clicked_entry);
+                    } else if let Some(entry) = entries.get_mut(&clicked_entry) {
+                        entry.checked ^= true;
+                    }
+                }
+                response
             })
             .inner
     }
 }
+
+/// Show only the given entry, or all entries if it already is the only one shown.
+fn solo_entry(entries: &mut BTreeMap<String, LegendEntry>, solo_name: &str) {
+    let is_solo = entries
+        .iter()
+        .all(|(name, entry)| entry.checked == (name == solo_name));
+    for (name, entry) in entries.iter_mut() {
+        entry.checked = is_solo || name == solo_name;
+    }
+}
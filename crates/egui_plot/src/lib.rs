@@ -7,6 +7,8 @@
 //!
 
 mod axis;
+mod date_time;
+mod export;
 mod items;
 mod legend;
 mod memory;
@@ -22,18 +24,22 @@ use epaint::Hsva;
 
 pub use crate::{
     axis::{Axis, AxisHints, HPlacement, Placement, VPlacement},
+    date_time::{date_time_formatter, date_time_grid_spacer},
+    export::ExportFormat,
     items::{
-        Arrows, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, ClosestElem, HLine, Line, LineStyle,
-        MarkerShape, Orientation, PlotConfig, PlotGeometry, PlotImage, PlotItem, PlotPoint,
-        PlotPoints, Points, Polygon, Text, VLine,
+        Arrows, Band, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, Candle, CandleStick, ClosestElem,
+        ColorMap, DecimatedLine, ErrorBars, HLine, Heatmap, Line, LineStyle, MarkerShape,
+        Orientation, PlotConfig, PlotGeometry, PlotImage, PlotItem, PlotPoint, PlotPoints, Points,
+        Polygon, Text, VLine,
     },
     legend::{Corner, Legend},
     memory::PlotMemory,
     plot_ui::PlotUi,
-    transform::{PlotBounds, PlotTransform},
+    transform::{PlotBounds, PlotTransform, Scale},
 };
 
 use axis::AxisWidget;
+use export::PlotPaint;
 use items::{horizontal_line, rulers_color, vertical_line};
 use legend::LegendWidget;
 
@@ -119,10 +125,23 @@ pub struct PlotResponse<R> {
     /// The transform between screen coordinates and plot coordinates.
     pub transform: PlotTransform,
 
+    /// The transform for items plotted against the secondary Y axis, if the plot has one.
+    ///
+    /// See [`Plot::secondary_y_axis`].
+    pub secondary_transform: Option<PlotTransform>,
+
     /// The id of a currently hovered item if any.
     ///
     /// This is `None` if either no item was hovered, or the hovered item didn't provide an id.
     pub hovered_plot_item: Option<Id>,
+
+    /// The names of the items that are hidden, because the user deselected them in the legend.
+    ///
+    /// Store this and pass it to [`Legend::hidden_items`] to persist the selection in your app.
+    pub hidden_items: ahash::HashSet<String>,
+
+    /// What the plot painted in this frame, for [`Self::export`].
+    pub(crate) paint: PlotPaint,
 }
 
 // ----------------------------------------------------------------------------
@@ -157,6 +176,7 @@ pub struct Plot<'a> {
     min_auto_bounds: PlotBounds,
     margin_fraction: Vec2,
     boxed_zoom_pointer_button: PointerButton,
+    boxed_zoom_modifiers: Option<Modifiers>,
     linked_axes: Option<(Id, Vec2b)>,
     linked_cursors: Option<(Id, Vec2b)>,
 
@@ -174,6 +194,7 @@ pub struct Plot<'a> {
     coordinates_formatter: Option<(Corner, CoordinatesFormatter<'a>)>,
     x_axes: Vec<AxisHints<'a>>, // default x axes
     y_axes: Vec<AxisHints<'a>>, // default y axes
+    secondary_y_axis: Option<AxisHints<'a>>,
     legend_config: Option<Legend>,
     show_background: bool,
     show_axes: Vec2b,
@@ -183,6 +204,7 @@ pub struct Plot<'a> {
     grid_spacers: [GridSpacer<'a>; 2],
     sharp_grid_lines: bool,
     clamp_grid: bool,
+    scales: [Scale; 2],
 
     sense: Sense,
 }
@@ -204,6 +226,7 @@ impl<'a> Plot<'a> {
             min_auto_bounds: PlotBounds::NOTHING,
             margin_fraction: Vec2::splat(0.05),
             boxed_zoom_pointer_button: PointerButton::Secondary,
+            boxed_zoom_modifiers: None,
             linked_axes: None,
             linked_cursors: None,
 
@@ -221,6 +244,7 @@ impl<'a> Plot<'a> {
             coordinates_formatter: None,
             x_axes: vec![AxisHints::new(Axis::X)],
             y_axes: vec![AxisHints::new(Axis::Y)],
+            secondary_y_axis: None,
             legend_config: None,
             show_background: true,
             show_axes: true.into(),
@@ -230,6 +254,7 @@ impl<'a> Plot<'a> {
             grid_spacers: [log_grid_spacer(10), log_grid_spacer(10)],
             sharp_grid_lines: true,
             clamp_grid: false,
+            scales: [Scale::Linear; 2],
 
             sense: egui::Sense::click_and_drag(),
         }
@@ -358,6 +383,9 @@ impl<'a> Plot<'a> {
 
     /// Whether to allow zooming in the plot by dragging out a box with the secondary mouse button.
     ///
+    /// The user can press Escape to cancel the boxed zoom while dragging,
+    /// and double-click the plot to reset the bounds (see [`Self::allow_double_click_reset`]).
+    ///
     /// Default: `true`.
     #[inline]
     pub fn allow_boxed_zoom(mut self, on: bool) -> Self {
@@ -372,6 +400,16 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Also allow boxed zooming by dragging with the primary mouse button
+    /// while holding down these modifiers, e.g. [`Modifiers::SHIFT`].
+    ///
+    /// This is in addition to [`Self::boxed_zoom_pointer_button`]. Default: `None`.
+    #[inline]
+    pub fn boxed_zoom_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.boxed_zoom_modifiers = Some(modifiers);
+        self
+    }
+
     /// Whether to allow dragging in the plot to move the bounds. Default: `true`.
     #[inline]
     pub fn allow_drag<T>(mut self, on: T) -> Self
@@ -457,6 +495,32 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Interpret the X values as unix timestamps, in seconds since 1970-01-01 00:00:00 UTC.
+    ///
+    /// The grid marks snap to whole seconds, minutes, hours, days, months and years,
+    /// and the ticks of the main X axis are labeled with times and dates (in UTC).
+    ///
+    /// This is a shorthand for using [`date_time_grid_spacer`] with [`Self::x_grid_spacer`]
+    /// and [`date_time_formatter`] with [`Self::x_axis_formatter`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Line, Plot, PlotPoints};
+    ///
+    /// let start = 1_700_000_000.0; // a unix timestamp
+    /// let series: PlotPoints = (0..48)
+    ///     .map(|hour| [start + 3600.0 * hour as f64, (hour as f64 / 4.0).sin()])
+    ///     .collect();
+    /// Plot::new("time_series")
+    ///     .x_axis_date_time()
+    ///     .show(ui, |plot_ui| plot_ui.line(Line::new(series)));
+    /// # });
+    /// ```
+    pub fn x_axis_date_time(self) -> Self {
+        self.x_grid_spacer(date_time_grid_spacer())
+            .x_axis_formatter(date_time_formatter)
+    }
+
     /// Default is a log-10 grid, i.e. every plot unit is divided into 10 other units.
     ///
     /// See [`Self::x_grid_spacer`] for explanation.
@@ -466,6 +530,46 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// How the X values are placed along the axis. Default: [`Scale::Linear`].
+    ///
+    /// With [`Scale::Log10`], every power of ten takes up the same width.
+    /// Points, grid lines, axis ticks and the coordinates shown on hover all follow the scale.
+    /// Values that are not positive cannot be shown on a logarithmic axis,
+    /// and [`Self::data_aspect`] is ignored when any axis is not linear.
+    ///
+    /// This also sets a grid spacer suited for the scale (see [`log10_grid_spacer`]),
+    /// so call [`Self::x_grid_spacer`] after this if you want another one.
+    /// For non-linear scales, the grid spacer works on the scaled values (e.g. decades).
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Line, Plot, PlotPoints, Scale};
+    ///
+    /// let series: PlotPoints = (0..100).map(|i| [i as f64, 1.1_f64.powi(i)]).collect();
+    /// Plot::new("growth")
+    ///     .y_axis_scale(Scale::Log10)
+    ///     .show(ui, |plot_ui| plot_ui.line(Line::new(series)));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn x_axis_scale(mut self, scale: Scale) -> Self {
+        self.scales[0] = scale;
+        self.grid_spacers[0] = default_grid_spacer(scale);
+        self
+    }
+
+    /// How the Y values are placed along the axis. Default: [`Scale::Linear`].
+    ///
+    /// This does not affect the secondary Y axis.
+    ///
+    /// See [`Self::x_axis_scale`] for more.
+    #[inline]
+    pub fn y_axis_scale(mut self, scale: Scale) -> Self {
+        self.scales[1] = scale;
+        self.grid_spacers[1] = default_grid_spacer(scale);
+        self
+    }
+
     /// Set when the grid starts showing.
     ///
     /// When grid lines are closer than the given minimum, they will be hidden.
@@ -537,6 +641,11 @@ impl<'a> Plot<'a> {
     }
 
     /// Show a legend including all named items.
+    ///
+    /// Clicking an entry hides or shows its items, and command-clicking (ctrl-clicking)
+    /// shows only its items (or all items again, if they already were the only ones shown).
+    /// Hovering an entry highlights its items and dims all others.
+    /// The hidden items are reported in [`PlotResponse::hidden_items`].
     #[inline]
     pub fn legend(mut self, legend: Legend) -> Self {
         self.legend_config = Some(legend);
@@ -587,6 +696,9 @@ impl<'a> Plot<'a> {
 
     /// Add this plot to a cursor link group so that this plot will share the cursor position with other plots
     /// in the same group. A plot cannot belong to more than one cursor group.
+    ///
+    /// With `link_x`, hovering one plot also marks the values of the items in the other plots
+    /// at the same X, which is useful for synchronized time-series views.
     #[inline]
     pub fn link_cursor(mut self, group_id: impl Into<Id>, link_x: bool, link_y: bool) -> Self {
         self.linked_cursors = Some((
@@ -717,6 +829,41 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Add a secondary Y axis on the right side of the plot, with its own scale.
+    ///
+    /// Items are plotted against it if they are marked with e.g. [`Line::on_secondary_y_axis`].
+    /// Its bounds are computed automatically from those items,
+    /// so that e.g. temperature and pressure can share the same X axis.
+    ///
+    /// Panning and zooming the plot moves both Y axes together.
+    /// Scroll or zoom while hovering the secondary axis to change only its scale.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{AxisHints, Legend, Line, Plot, PlotPoints};
+    ///
+    /// let temperature = Line::new(PlotPoints::from_ys_f64(&[18.0, 21.5, 24.0, 22.0]))
+    ///     .name("Temperature");
+    /// let pressure = Line::new(PlotPoints::from_ys_f64(&[1013.0, 1009.0, 1004.0, 1011.0]))
+    ///     .name("Pressure")
+    ///     .on_secondary_y_axis(true);
+    ///
+    /// Plot::new("weather")
+    ///     .y_axis_label("°C")
+    ///     .secondary_y_axis(AxisHints::new_y().label("hPa"))
+    ///     .legend(Legend::default())
+    ///     .show(ui, |plot_ui| {
+    ///         plot_ui.line(temperature);
+    ///         plot_ui.line(pressure);
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn secondary_y_axis(mut self, hints: AxisHints<'a>) -> Self {
+        self.secondary_y_axis = Some(hints.placement(HPlacement::Right));
+        self
+    }
+
     /// Interact with and add items to the plot and finally draw it.
     pub fn show<R>(
         self,
@@ -741,6 +888,7 @@ impl<'a> Plot<'a> {
             allow_double_click_reset,
             allow_boxed_zoom,
             boxed_zoom_pointer_button,
+            boxed_zoom_modifiers,
             default_auto_bounds,
             min_auto_bounds,
             margin_fraction,
@@ -755,6 +903,7 @@ impl<'a> Plot<'a> {
             coordinates_formatter,
             x_axes,
             y_axes,
+            secondary_y_axis,
             legend_config,
             reset,
             show_background,
@@ -767,6 +916,7 @@ impl<'a> Plot<'a> {
             clamp_grid,
             grid_spacers,
             sharp_grid_lines,
+            scales,
             sense,
         } = self;
 
@@ -775,6 +925,9 @@ impl<'a> Plot<'a> {
         let allow_drag = allow_drag.and(ui.is_enabled());
         let allow_scroll = allow_scroll.and(ui.is_enabled());
 
+        // Remember where our shapes start, so that the plot can be exported.
+        let first_shape = PlotPaint::shape_count(ui.ctx(), ui.layer_id());
+
         // Determine position of widget.
         let pos = ui.available_rect_before_wrap().min;
         // Minimum values for screen protection
@@ -813,11 +966,12 @@ impl<'a> Plot<'a> {
 
         let plot_id = id.unwrap_or_else(|| ui.make_persistent_id(id_source));
 
-        let ([x_axis_widgets, y_axis_widgets], plot_rect) = axis_widgets(
+        let ([x_axis_widgets, y_axis_widgets], secondary_y_axis_widget, plot_rect) = axis_widgets(
             PlotMemory::load(ui.ctx(), plot_id).as_ref(), // TODO(emilk): avoid loading plot memory twice
             show_axes,
             complete_rect,
             [&x_axes, &y_axes],
+            secondary_y_axis.as_ref(),
         );
 
         // Allocate the plot window.
@@ -839,12 +993,16 @@ impl<'a> Plot<'a> {
         }
         .unwrap_or_else(|| PlotMemory {
             auto_bounds: default_auto_bounds,
+            secondary_auto_bounds: true,
             hovered_legend_item: None,
             hidden_items: Default::default(),
-            transform: PlotTransform::new(plot_rect, min_auto_bounds, center_axis.x, center_axis.y),
+            transform: PlotTransform::new(plot_rect, min_auto_bounds, center_axis.x, center_axis.y)
+                .with_scales(scales),
+            secondary_y_bounds: None,
             last_click_pos_for_zoom: None,
             x_axis_thickness: Default::default(),
             y_axis_thickness: Default::default(),
+            secondary_y_axis_thickness: Default::default(),
         });
 
         let last_plot_transform = mem.transform;
@@ -881,8 +1039,15 @@ impl<'a> Plot<'a> {
         }
 
         // --- Legend ---
-        let legend = legend_config
-            .and_then(|config| LegendWidget::try_new(plot_rect, config, &items, &mem.hidden_items));
+        let legend = legend_config.and_then(|config| {
+            LegendWidget::try_new(
+                plot_rect,
+                config,
+                &items,
+                &mem.hidden_items,
+                secondary_y_axis.is_some(),
+            )
+        });
         // Don't show hover cursor when hovering over legend.
         if mem.hovered_legend_item.is_some() {
             show_x = false;
@@ -951,6 +1116,7 @@ impl<'a> Plot<'a> {
         // Allow double-clicking to reset to the initial bounds.
         if allow_double_click_reset && response.double_clicked() {
             mem.auto_bounds = true.into();
+            mem.secondary_auto_bounds = true;
         }
 
         // Apply bounds modifications.
@@ -992,23 +1158,25 @@ impl<'a> Plot<'a> {
                 if auto_x {
                     bounds.merge_x(&item_bounds);
                 }
-                if auto_y {
+                if auto_y && !(secondary_y_axis.is_some() && item.on_secondary_y_axis()) {
                     bounds.merge_y(&item_bounds);
                 }
             }
 
             if auto_x {
-                bounds.add_relative_margin_x(margin_fraction);
+                add_relative_margin(&mut bounds, Axis::X, scales[0], margin_fraction);
             }
 
             if auto_y {
-                bounds.add_relative_margin_y(margin_fraction);
+                add_relative_margin(&mut bounds, Axis::Y, scales[1], margin_fraction);
             }
         }
 
-        mem.transform = PlotTransform::new(plot_rect, bounds, center_axis.x, center_axis.y);
+        mem.transform =
+            PlotTransform::new(plot_rect, bounds, center_axis.x, center_axis.y).with_scales(scales);
 
         // Enforce aspect ratio
+        let data_aspect = data_aspect.filter(|_| scales == [Scale::Linear; 2]);
         if let Some(data_aspect) = data_aspect {
             if let Some((_, linked_axes)) = &linked_axes {
                 let change_x = linked_axes.y && !linked_axes.x;
@@ -1024,8 +1192,51 @@ impl<'a> Plot<'a> {
             }
         }
 
+        // The secondary Y axis has its own bounds, which follow the pan and zoom of the main Y axis.
+        let y_bounds_before_interaction = {
+            let bounds = mem.transform.bounds();
+            [bounds.min[1], bounds.max[1]]
+        };
+        let mut secondary_y_bounds = secondary_y_axis.as_ref().map(|_| {
+            if let (false, Some(secondary_y_bounds)) =
+                (mem.secondary_auto_bounds, mem.secondary_y_bounds)
+            {
+                secondary_y_bounds
+            } else {
+                let mut bounds = PlotBounds::NOTHING;
+                for item in items.iter().filter(|item| item.on_secondary_y_axis()) {
+                    bounds.merge_y(&item.bounds());
+                }
+                if bounds.is_finite_y() {
+                    bounds.add_relative_margin_y(margin_fraction);
+                    [bounds.min[1], bounds.max[1]]
+                } else {
+                    y_bounds_before_interaction
+                }
+            }
+        });
+
+        // Start a boxed zoom, either with the boxed zoom button,
+        // or with the primary button while holding down the boxed zoom modifiers.
+        if allow_boxed_zoom && response.drag_started() {
+            let modifiers_down = boxed_zoom_modifiers.map_or(false, |modifiers| {
+                ui.input(|i| i.modifiers.matches_logically(modifiers))
+            });
+            if response.dragged_by(boxed_zoom_pointer_button)
+                || (modifiers_down && response.dragged_by(PointerButton::Primary))
+            {
+                // it would be best for egui that input has a memory of the last click pos because it's a common pattern
+                mem.last_click_pos_for_zoom = response.hover_pos();
+            }
+        }
+        if ui.input(|i| i.key_pressed(Key::Escape)) {
+            // Cancel the boxed zoom
+            mem.last_click_pos_for_zoom = None;
+        }
+        let is_boxed_zooming = mem.last_click_pos_for_zoom.is_some();
+
         // Dragging
-        if allow_drag.any() && response.dragged_by(PointerButton::Primary) {
+        if allow_drag.any() && response.dragged_by(PointerButton::Primary) && !is_boxed_zooming {
             response = response.on_hover_cursor(CursorIcon::Grabbing);
             let mut delta = -response.drag_delta();
             if !allow_drag.x {
@@ -1041,30 +1252,13 @@ impl<'a> Plot<'a> {
         // Zooming
         let mut boxed_zoom_rect = None;
         if allow_boxed_zoom {
-            // Save last click to allow boxed zooming
-            if response.drag_started() && response.dragged_by(boxed_zoom_pointer_button) {
-                // it would be best for egui that input has a memory of the last click pos because it's a common pattern
-                mem.last_click_pos_for_zoom = response.hover_pos();
-            }
             let box_start_pos = mem.last_click_pos_for_zoom;
             let box_end_pos = response.hover_pos();
             if let (Some(box_start_pos), Some(box_end_pos)) = (box_start_pos, box_end_pos) {
                 // while dragging prepare a Shape and draw it later on top of the plot
-                if response.dragged_by(boxed_zoom_pointer_button) {
+                if response.dragged() {
                     response = response.on_hover_cursor(CursorIcon::ZoomIn);
-                    let rect = epaint::Rect::from_two_pos(box_start_pos, box_end_pos);
-                    boxed_zoom_rect = Some((
-                        epaint::RectShape::stroke(
-                            rect,
-                            0.0,
-                            epaint::Stroke::new(4., Color32::DARK_BLUE),
-                        ), // Outer stroke
-                        epaint::RectShape::stroke(
-                            rect,
-                            0.0,
-                            epaint::Stroke::new(2., Color32::WHITE),
-                        ), // Inner stroke
-                    ));
+                    boxed_zoom_rect = Some(epaint::Rect::from_two_pos(box_start_pos, box_end_pos));
                 }
                 // when the click is release perform the zoom
                 if response.drag_stopped() {
@@ -1084,11 +1278,13 @@ impl<'a> Plot<'a> {
                         mem.transform.set_bounds(new_bounds);
                         mem.auto_bounds = false.into();
                     }
-                    // reset the boxed zoom state
-                    mem.last_click_pos_for_zoom = None;
                 }
             }
         }
+        if !response.dragged() {
+            // reset the boxed zoom state
+            mem.last_click_pos_for_zoom = None;
+        }
 
         // Note: we catch zoom/pan if the response contains the pointer, even if it isn't hovered.
         // For instance: The user is painting another interactive widget on top of the plot
@@ -1129,26 +1325,59 @@ impl<'a> Plot<'a> {
             }
         }
 
+        if let Some(secondary_y_bounds) = &mut secondary_y_bounds {
+            // Follow the changes the user made to the main Y axis:
+            let [min, max] = y_bounds_before_interaction;
+            let bounds = mem.transform.bounds();
+            if [bounds.min[1], bounds.max[1]] != [min, max] && min < max {
+                let [secondary_min, secondary_max] = *secondary_y_bounds;
+                let scale = (secondary_max - secondary_min) / (max - min);
+                *secondary_y_bounds = [
+                    secondary_min + (bounds.min[1] - min) * scale,
+                    secondary_min + (bounds.max[1] - min) * scale,
+                ];
+                mem.secondary_auto_bounds = false;
+            }
+
+            // Zoom and scroll only the secondary Y axis when hovering it:
+            let hover_pos = ui.input(|i| i.pointer.hover_pos());
+            if let (Some(widget), Some(hover_pos)) = (&secondary_y_axis_widget, hover_pos) {
+                if ui.rect_contains_pointer(widget.rect) {
+                    let mut transform = secondary_y_transform(&mem.transform, *secondary_y_bounds);
+                    let zoom_factor = ui.input(|i| i.zoom_delta_2d().y);
+                    let scroll_delta = ui.input(|i| i.smooth_scroll_delta.y);
+                    if allow_zoom.y && zoom_factor != 1.0 {
+                        transform.zoom(vec2(1.0, zoom_factor), hover_pos);
+                        mem.secondary_auto_bounds = false;
+                    }
+                    if allow_scroll.y && scroll_delta != 0.0 {
+                        transform.translate_bounds(vec2(0.0, -scroll_delta));
+                        mem.secondary_auto_bounds = false;
+                    }
+                    let bounds = transform.bounds();
+                    *secondary_y_bounds = [bounds.min[1], bounds.max[1]];
+                }
+            }
+        }
+
         // --- transform initialized
 
         // Add legend widgets to plot
         let bounds = mem.transform.bounds();
         let x_axis_range = bounds.range_x();
-        let x_steps = Arc::new({
-            let input = GridInput {
-                bounds: (bounds.min[0], bounds.max[0]),
-                base_step_size: mem.transform.dvalue_dpos()[0].abs() * grid_spacing.min as f64,
-            };
-            (grid_spacers[0])(input)
-        });
+        let x_steps = Arc::new(grid_marks(
+            &grid_spacers[0],
+            &mem.transform,
+            Axis::X,
+            grid_spacing.min,
+        ));
         let y_axis_range = bounds.range_y();
-        let y_steps = Arc::new({
-            let input = GridInput {
-                bounds: (bounds.min[1], bounds.max[1]),
-                base_step_size: mem.transform.dvalue_dpos()[1].abs() * grid_spacing.min as f64,
-            };
-            (grid_spacers[1])(input)
-        });
+        let y_steps = Arc::new(grid_marks(
+            &grid_spacers[1],
+            &mem.transform,
+            Axis::Y,
+            grid_spacing.min,
+        ));
         for (i, mut widget) in x_axis_widgets.into_iter().enumerate() {
             widget.range = x_axis_range.clone();
             widget.transform = Some(mem.transform);
@@ -1164,6 +1393,27 @@ impl<'a> Plot<'a> {
             mem.y_axis_thickness.insert(i, thickness);
         }
 
+        let secondary_transform = secondary_y_bounds
+            .map(|secondary_y_bounds| secondary_y_transform(&mem.transform, secondary_y_bounds));
+        mem.secondary_y_bounds = secondary_transform.map(|transform| {
+            let bounds = transform.bounds();
+            [bounds.min[1], bounds.max[1]]
+        });
+        if let (Some(mut widget), Some(transform)) = (secondary_y_axis_widget, secondary_transform)
+        {
+            let bounds = transform.bounds();
+            widget.range = bounds.range_y();
+            widget.transform = Some(transform);
+            widget.steps = Arc::new(grid_marks(
+                &grid_spacers[1],
+                &transform,
+                Axis::Y,
+                grid_spacing.min,
+            ));
+            let (_response, thickness) = widget.ui(ui, Axis::Y);
+            mem.secondary_y_axis_thickness = thickness;
+        }
+
         // Initialize values from functions.
         for item in &mut items {
             item.initialize(mem.transform.bounds().range_x());
@@ -1178,6 +1428,8 @@ impl<'a> Plot<'a> {
             show_grid,
             grid_spacing,
             transform: mem.transform,
+            secondary_transform,
+            hovered_legend_item: mem.hovered_legend_item.clone(),
             draw_cursor_x: linked_cursors.as_ref().map_or(false, |group| group.1.x),
             draw_cursor_y: linked_cursors.as_ref().map_or(false, |group| group.1.y),
             draw_cursors,
@@ -1189,12 +1441,7 @@ impl<'a> Plot<'a> {
         let (plot_cursors, hovered_plot_item) = prepared.ui(ui, &response);
 
         if let Some(boxed_zoom_rect) = boxed_zoom_rect {
-            ui.painter()
-                .with_clip_rect(plot_rect)
-                .add(boxed_zoom_rect.0);
-            ui.painter()
-                .with_clip_rect(plot_rect)
-                .add(boxed_zoom_rect.1);
+            paint_boxed_zoom_rect(ui, plot_rect, boxed_zoom_rect);
         }
 
         if let Some(mut legend) = legend {
@@ -1230,6 +1477,7 @@ impl<'a> Plot<'a> {
         }
 
         let transform = mem.transform;
+        let hidden_items = mem.hidden_items.clone();
         mem.store(ui.ctx(), plot_id);
 
         let response = if show_x || show_y {
@@ -1240,22 +1488,78 @@ impl<'a> Plot<'a> {
 
         ui.advance_cursor_after_rect(complete_rect);
 
+        let paint = PlotPaint {
+            layer_id: ui.layer_id(),
+            shapes: first_shape..PlotPaint::shape_count(ui.ctx(), ui.layer_id()),
+            rect: complete_rect,
+        };
+
         PlotResponse {
             inner,
             response,
             transform,
+            secondary_transform,
             hovered_plot_item,
+            hidden_items,
+            paint,
         }
     }
 }
 
+/// Paint the selection of a boxed zoom, dimming the rest of the plot.
+fn paint_boxed_zoom_rect(ui: &Ui, plot_rect: Rect, rect: Rect) {
+    let painter = ui.painter().with_clip_rect(plot_rect);
+
+    let dim_color = Color32::from_black_alpha(96);
+    let outside = [
+        Rect::from_min_max(plot_rect.min, pos2(plot_rect.max.x, rect.min.y)),
+        Rect::from_min_max(pos2(plot_rect.min.x, rect.max.y), plot_rect.max),
+        Rect::from_min_max(
+            pos2(plot_rect.min.x, rect.min.y),
+            pos2(rect.min.x, rect.max.y),
+        ),
+        Rect::from_min_max(
+            pos2(rect.max.x, rect.min.y),
+            pos2(plot_rect.max.x, rect.max.y),
+        ),
+    ];
+    for outside in outside {
+        if outside.is_positive() {
+            painter.rect_filled(outside, 0.0, dim_color);
+        }
+    }
+
+    painter.rect_stroke(rect, 0.0, epaint::Stroke::new(4., Color32::DARK_BLUE)); // Outer stroke
+    painter.rect_stroke(rect, 0.0, epaint::Stroke::new(2., Color32::WHITE)); // Inner stroke
+}
+
+/// The transform for the secondary Y axis: the same as the main transform, but with other Y bounds.
+fn secondary_y_transform(transform: &PlotTransform, [min, max]: [f64; 2]) -> PlotTransform {
+    let mut bounds = *transform.bounds();
+    bounds.min[1] = min;
+    bounds.max[1] = max;
+    PlotTransform::new(*transform.frame(), bounds, false, false)
+        .with_scales([transform.scales()[0], Scale::Linear])
+}
+
+/// Adds a margin to the bounds of the given axis, as a fraction of their size on the screen.
+fn add_relative_margin(bounds: &mut PlotBounds, axis: Axis, scale: Scale, margin_fraction: Vec2) {
+    let iaxis = usize::from(axis);
+    let [min, max] = scale.sanitize_range([bounds.min[iaxis], bounds.max[iaxis]]);
+    let (min, max) = (scale.apply(min), scale.apply(max));
+    let margin = margin_fraction[iaxis] as f64 * (max - min).max(0.0);
+    bounds.min[iaxis] = scale.invert(min - margin);
+    bounds.max[iaxis] = scale.invert(max + margin);
+}
+
 /// Returns the rect left after adding axes.
 fn axis_widgets<'a>(
     mem: Option<&PlotMemory>,
     show_axes: Vec2b,
     complete_rect: Rect,
     [x_axes, y_axes]: [&'a [AxisHints<'a>]; 2],
-) -> ([Vec<AxisWidget<'a>>; 2], Rect) {
+    secondary_y_axis: Option<&'a AxisHints<'a>>,
+) -> ([Vec<AxisWidget<'a>>; 2], Option<AxisWidget<'a>>, Rect) {
     // Next we want to create this layout.
     // Indices are only examples.
     //
@@ -1281,6 +1585,7 @@ fn axis_widgets<'a>(
 
     let mut x_axis_widgets = Vec::<AxisWidget<'_>>::new();
     let mut y_axis_widgets = Vec::<AxisWidget<'_>>::new();
+    let mut secondary_y_axis_widget = None;
 
     // Will shrink as we add more axes.
     let mut rect_left = complete_rect;
@@ -1340,6 +1645,19 @@ fn axis_widgets<'a>(
             };
             y_axis_widgets.push(AxisWidget::new(cfg.clone(), rect));
         }
+
+        if let Some(cfg) = secondary_y_axis {
+            let mut width = cfg.thickness(Axis::Y);
+            if let Some(mem) = mem {
+                width = width.max(mem.secondary_y_axis_thickness);
+            }
+
+            let right = rect_left.right();
+            *rect_left.right_mut() -= width;
+            let left = rect_left.right();
+            let rect = Rect::from_x_y_ranges(left..=right, plot_y_range);
+            secondary_y_axis_widget = Some(AxisWidget::new(cfg.clone(), rect));
+        }
     }
 
     let mut plot_rect = rect_left;
@@ -1348,6 +1666,7 @@ fn axis_widgets<'a>(
     if plot_rect.width() <= 0.0 || plot_rect.height() <= 0.0 {
         y_axis_widgets.clear();
         x_axis_widgets.clear();
+        secondary_y_axis_widget = None;
         plot_rect = complete_rect;
     }
 
@@ -1357,7 +1676,11 @@ fn axis_widgets<'a>(
         widget.rect = Rect::from_x_y_ranges(plot_rect.x_range(), widget.rect.y_range());
     }
 
-    ([x_axis_widgets, y_axis_widgets], plot_rect)
+    (
+        [x_axis_widgets, y_axis_widgets],
+        secondary_y_axis_widget,
+        plot_rect,
+    )
 }
 
 /// User-requested modifications to the plot bounds. We collect them in the plot build function to later apply
@@ -1378,6 +1701,9 @@ enum BoundsModification {
 pub struct GridInput {
     /// Min/max of the visible data range (the values at the two edges of the plot,
     /// for the current axis).
+    ///
+    /// For an axis with a non-linear [`Scale`], these are the scaled values (e.g. decades),
+    /// and so are the values of the returned [`GridMark`]s.
     pub bounds: (f64, f64),
 
     /// Recommended (but not required) lower-bound on the step size returned by custom grid spacers.
@@ -1401,6 +1727,8 @@ pub struct GridMark {
     /// matches the difference between two `value`s precisely, but rather that grid marks of
     /// same thickness have same `step_size`. For example, months can have a different number
     /// of days, but consistently using a `step_size` of 30 days is a valid approximation.
+    ///
+    /// For an axis with a non-linear [`Scale`], this is in scaled units (e.g. decades).
     pub step_size: f64,
 }
 
@@ -1432,6 +1760,83 @@ pub fn log_grid_spacer(log_base: i64) -> GridSpacer<'static> {
     Box::new(step_sizes)
 }
 
+/// Grid marks for an axis with [`Scale::Log10`]: at the powers of ten,
+/// and at their multiples 2, 3, …, 9 when there is room for those.
+///
+/// When zoomed out far, only every 10th, 100th, … power of ten is marked.
+///
+/// The bounds of the [`GridInput`] and the values of the returned marks are in decades,
+/// i.e. the log10 of the plot values.
+pub fn log10_grid_spacer() -> GridSpacer<'static> {
+    let get_marks = |input: GridInput| -> Vec<GridMark> {
+        // handle degenerate cases
+        if input.base_step_size.abs() < f64::EPSILON {
+            return Vec::new();
+        }
+
+        let (min, max) = input.bounds;
+        let decade_step = next_power(input.base_step_size, 10.0).max(1.0);
+        let mut marks = generate_marks(
+            [decade_step, decade_step * 10.0, decade_step * 100.0],
+            input.bounds,
+        );
+
+        if decade_step == 1.0 {
+            // The gap between 9 and 10 is the smallest, so fade these in by that.
+            let step_size = (10.0_f64 / 9.0).log10();
+            for decade in (min.floor() as i64)..=(max.ceil() as i64) {
+                for multiple in 2..=9 {
+                    let value = decade as f64 + (multiple as f64).log10();
+                    if min <= value && value <= max {
+                        marks.push(GridMark { value, step_size });
+                    }
+                }
+            }
+            sort_and_dedup_marks(&mut marks);
+        }
+
+        marks
+    };
+
+    Box::new(get_marks)
+}
+
+/// The grid spacer an axis gets when its scale is set.
+fn default_grid_spacer(scale: Scale) -> GridSpacer<'static> {
+    match scale {
+        Scale::Linear => log_grid_spacer(10),
+        Scale::Log10 => log10_grid_spacer(),
+    }
+}
+
+/// Asks the grid spacer for the marks of the given axis.
+///
+/// The spacer works on the scaled values (see [`PlotTransform::scaled_bounds`]),
+/// and the values of the returned marks are mapped back to plot values.
+fn grid_marks(
+    grid_spacer: &GridSpacer<'_>,
+    transform: &PlotTransform,
+    axis: Axis,
+    min_spacing: f32,
+) -> Vec<GridMark> {
+    let iaxis = usize::from(axis);
+    let scaled_bounds = transform.scaled_bounds();
+    let input = GridInput {
+        bounds: (scaled_bounds.min[iaxis], scaled_bounds.max[iaxis]),
+        base_step_size: transform.dvalue_dpos()[iaxis].abs() * min_spacing as f64,
+    };
+    let scale = transform.scales()[iaxis];
+    let mut marks = grid_spacer(input);
+    if scale != Scale::Linear {
+        for mark in &mut marks {
+            // Avoid values like 299.99999999999994, which the formatters would show as is.
+            let value = scale.invert(mark.value);
+            mark.value = format!("{value:.12e}").parse().unwrap_or(value);
+        }
+    }
+    marks
+}
+
 /// Splits the grid into uniform-sized spacings (e.g. 100, 25, 1).
 ///
 /// This function should return 3 positive step sizes, designating where the lines in the grid are drawn.
@@ -1459,6 +1864,11 @@ struct PreparedPlot<'a> {
     coordinates_formatter: Option<(Corner, CoordinatesFormatter<'a>)>,
     // axis_formatters: [AxisFormatter; 2],
     transform: PlotTransform,
+    secondary_transform: Option<PlotTransform>,
+
+    /// Items with another name are dimmed while a legend entry is hovered.
+    hovered_legend_item: Option<String>,
+
     show_grid: Vec2b,
     grid_spacing: Rangef,
     grid_spacers: [GridSpacer<'a>; 2],
@@ -1471,6 +1881,14 @@ struct PreparedPlot<'a> {
 }
 
 impl<'a> PreparedPlot<'a> {
+    /// The transform to use for the given item, which depends on which Y axis it is plotted against.
+    fn item_transform(&self, item: &dyn PlotItem) -> &PlotTransform {
+        match &self.secondary_transform {
+            Some(secondary_transform) if item.on_secondary_y_axis() => secondary_transform,
+            _ => &self.transform,
+        }
+    }
+
     fn ui(self, ui: &mut Ui, response: &Response) -> (Vec<Cursor>, Option<Id>) {
         let mut axes_shapes = Vec::new();
 
@@ -1484,14 +1902,26 @@ impl<'a> PreparedPlot<'a> {
         // Sort the axes by strength so that those with higher strength are drawn in front.
         axes_shapes.sort_by(|(_, strength1), (_, strength2)| strength1.total_cmp(strength2));
 
-        let mut shapes = axes_shapes.into_iter().map(|(shape, _)| shape).collect();
+        let mut shapes: Vec<Shape> = axes_shapes.into_iter().map(|(shape, _)| shape).collect();
 
         let transform = &self.transform;
 
         let mut plot_ui = ui.child_ui(*transform.frame(), Layout::default());
         plot_ui.set_clip_rect(transform.frame().intersect(ui.clip_rect()));
         for item in &self.items {
-            item.shapes(&plot_ui, transform, &mut shapes);
+            let first_shape = shapes.len();
+            item.shapes(&plot_ui, self.item_transform(&**item), &mut shapes);
+            if self
+                .hovered_legend_item
+                .as_ref()
+                .map_or(false, |name| name != item.name())
+            {
+                for shape in &mut shapes[first_shape..] {
+                    epaint::shape_transform::adjust_colors(shape, |color| {
+                        *color = color.gamma_multiply(0.25);
+                    });
+                }
+            }
         }
 
         let hover_pos = response.hover_pos();
@@ -1532,6 +1962,15 @@ impl<'a> PreparedPlot<'a> {
         draw_cursor(&self.draw_cursors, false);
         draw_cursor(&cursors, true);
 
+        // Show the values at the cursors of linked plots, unless this plot is hovered itself.
+        if self.draw_cursor_x && hover_pos.is_none() {
+            for cursor in &self.draw_cursors {
+                if let Cursor::Vertical { x } = *cursor {
+                    self.values_at_x(ui, x, &mut shapes);
+                }
+            }
+        }
+
         let painter = ui.painter().with_clip_rect(*transform.frame());
         painter.extend(shapes);
 
@@ -1571,11 +2010,7 @@ impl<'a> PreparedPlot<'a> {
         let bounds = transform.bounds();
         let value_cross = 0.0_f64.clamp(bounds.min[1 - iaxis], bounds.max[1 - iaxis]);
 
-        let input = GridInput {
-            bounds: (bounds.min[iaxis], bounds.max[iaxis]),
-            base_step_size: transform.dvalue_dpos()[iaxis].abs() * fade_range.min as f64,
-        };
-        let steps = (grid_spacers[iaxis])(input);
+        let steps = grid_marks(&grid_spacers[iaxis], transform, axis, fade_range.min);
 
         let clamp_range = clamp_grid.then(|| {
             let mut tight_bounds = PlotBounds::NOTHING;
@@ -1652,6 +2087,41 @@ impl<'a> PreparedPlot<'a> {
         }
     }
 
+    /// Mark the point of each item that is closest to the given x value, and label its value.
+    fn values_at_x(&self, ui: &Ui, x: f64, shapes: &mut Vec<Shape>) {
+        if !self.show_y || !self.transform.bounds().range_x().contains(&x) {
+            return;
+        }
+
+        for item in self.items.iter().filter(|item| item.allow_hover()) {
+            let PlotGeometry::Points(points) = item.geometry() else {
+                continue;
+            };
+            let Some(value) = points.iter().min_by_key(|point| (point.x - x).abs().ord()) else {
+                continue;
+            };
+
+            let transform = self.item_transform(&**item);
+            let plot = items::PlotConfig {
+                ui,
+                transform,
+                show_x: false,
+                show_y: true,
+            };
+            let pointer = transform.position_from_point(value);
+            shapes.push(Shape::circle_filled(pointer, 3.0, item.color()));
+            items::rulers_at_value(
+                pointer,
+                *value,
+                item.name(),
+                &plot,
+                shapes,
+                &mut Vec::new(),
+                &self.label_formatter,
+            );
+        }
+    }
+
     fn hover(&self, ui: &Ui, pointer: Pos2, shapes: &mut Vec<Shape>) -> (Vec<Cursor>, Option<Id>) {
         let Self {
             transform,
@@ -1673,7 +2143,7 @@ impl<'a> PreparedPlot<'a> {
             .filter(|entry| entry.allow_hover())
             .filter_map(|item| {
                 let item = &**item;
-                let closest = item.find_closest(pointer, transform);
+                let closest = item.find_closest(pointer, self.item_transform(item));
 
                 Some(item).zip(closest)
             });
@@ -1682,19 +2152,24 @@ impl<'a> PreparedPlot<'a> {
             .min_by_key(|(_, elem)| elem.dist_sq.ord())
             .filter(|(_, elem)| elem.dist_sq <= interact_radius_sq);
 
-        let plot = items::PlotConfig {
-            ui,
-            transform,
-            show_x: *show_x,
-            show_y: *show_y,
-        };
-
         let mut cursors = Vec::new();
 
         let hovered_plot_item_id = if let Some((item, elem)) = closest {
+            let plot = items::PlotConfig {
+                ui,
+                transform: self.item_transform(item),
+                show_x: *show_x,
+                show_y: *show_y,
+            };
             item.on_hover(elem, shapes, &mut cursors, &plot, label_formatter);
             item.id()
         } else {
+            let plot = items::PlotConfig {
+                ui,
+                transform,
+                show_x: *show_x,
+                show_y: *show_y,
+            };
             let value = transform.value_from_position(pointer);
             items::rulers_at_value(
                 pointer,
@@ -1731,7 +2206,12 @@ fn generate_marks(step_sizes: [f64; 3], bounds: (f64, f64)) -> Vec<GridMark> {
     fill_marks_between(&mut steps, step_sizes[0], bounds);
     fill_marks_between(&mut steps, step_sizes[1], bounds);
     fill_marks_between(&mut steps, step_sizes[2], bounds);
+    sort_and_dedup_marks(&mut steps);
+    steps
+}
 
+/// Sort the marks by value, and remove duplicates.
+fn sort_and_dedup_marks(steps: &mut Vec<GridMark>) {
     // Remove duplicates:
     // This can happen because we have overlapping steps, e.g.:
     // step_size[0] =   10  =>  [-10, 0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 100, 110, 120]
@@ -1745,8 +2225,6 @@ fn generate_marks(step_sizes: [f64; 3], bounds: (f64, f64)) -> Vec<GridMark> {
         ord => ord,
     });
     steps.dedup_by(|a, b| a.value == b.value);
-
-    steps
 }
 
 fn cmp_f64(a: f64, b: f64) -> Ordering {
@@ -14,6 +14,11 @@ pub struct PlotMemory {
     /// the bounds, for example by moving or zooming.
     pub auto_bounds: Vec2b,
 
+    /// Indicates if the secondary Y axis uses automatic bounds.
+    ///
+    /// See [`crate::Plot::secondary_y_axis`].
+    pub secondary_auto_bounds: bool,
+
     /// Display string of the hovered legend item if any.
     pub hovered_legend_item: Option<String>,
 
@@ -23,6 +28,9 @@ pub struct PlotMemory {
     /// The transform from last frame.
     pub(crate) transform: PlotTransform,
 
+    /// The `[min, max]` bounds of the secondary Y axis from last frame, if the plot has one.
+    pub(crate) secondary_y_bounds: Option<[f64; 2]>,
+
     /// Allows to remember the first click position when performing a boxed zoom
     pub(crate) last_click_pos_for_zoom: Option<Pos2>,
 
@@ -32,6 +40,7 @@ pub struct PlotMemory {
     /// in order to fit the labels, if necessary.
     pub(crate) x_axis_thickness: BTreeMap<usize, f32>,
     pub(crate) y_axis_thickness: BTreeMap<usize, f32>,
+    pub(crate) secondary_y_axis_thickness: f32,
 }
 
 impl PlotMemory {
@@ -134,6 +134,32 @@ impl PlotUi {
         self.items.push(Box::new(line));
     }
 
+    /// Add a line through many points, which is simplified to what is visible.
+    pub fn decimated_line(&mut self, mut line: DecimatedLine) {
+        if line.points.is_empty() {
+            return;
+        };
+
+        // Give the stroke an automatic color if no color has been assigned.
+        if line.stroke.color == Color32::TRANSPARENT {
+            line.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(line));
+    }
+
+    /// Add a band, filling the area between two curves.
+    pub fn band(&mut self, mut band: Band) {
+        if band.lower.is_empty() && band.upper.is_empty() {
+            return;
+        };
+
+        // Give the band an automatic color if no color has been assigned.
+        if band.color == Color32::TRANSPARENT {
+            band.color = self.auto_color();
+        }
+        self.items.push(Box::new(band));
+    }
+
     /// Add a polygon. The polygon has to be convex.
     pub fn polygon(&mut self, mut polygon: Polygon) {
         if polygon.series.is_empty() {
@@ -220,6 +246,19 @@ impl PlotUi {
         self.items.push(Box::new(box_plot));
     }
 
+    /// Add a heatmap.
+    pub fn heatmap(&mut self, heatmap: Heatmap) {
+        self.items.push(Box::new(heatmap));
+    }
+
+    /// Add a candlestick chart.
+    pub fn candle_stick(&mut self, candle_stick: CandleStick) {
+        if candle_stick.candles.is_empty() {
+            return;
+        }
+        self.items.push(Box::new(candle_stick));
+    }
+
     /// Add a bar chart.
     pub fn bar_chart(&mut self, mut chart: BarChart) {
         if chart.bars.is_empty() {
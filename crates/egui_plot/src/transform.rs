@@ -222,6 +222,59 @@ impl PlotBounds {
     }
 }
 
+/// How the values on an axis are placed along it.
+///
+/// See [`crate::Plot::x_axis_scale`] and [`crate::Plot::y_axis_scale`].
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Scale {
+    /// Equal differences in value take up equal space.
+    #[default]
+    Linear,
+
+    /// Equal ratios of values take up equal space, e.g. each power of ten.
+    ///
+    /// Only positive values can be shown.
+    Log10,
+}
+
+impl Scale {
+    /// From a plot value to its position along a linear axis, e.g. `1000.0` to `3.0` for [`Self::Log10`].
+    #[inline]
+    pub fn apply(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Log10 => value.log10(),
+        }
+    }
+
+    /// The inverse of [`Self::apply`].
+    #[inline]
+    pub fn invert(self, scaled: f64) -> f64 {
+        match self {
+            Self::Linear => scaled,
+            Self::Log10 => 10.0_f64.powf(scaled),
+        }
+    }
+
+    /// Change the range of values so that it can be shown on this scale.
+    pub(crate) fn sanitize_range(self, [min, max]: [f64; 2]) -> [f64; 2] {
+        match self {
+            Self::Linear => [min, max],
+            Self::Log10 => {
+                if 0.0 < min && min < max && max.is_finite() {
+                    [min, max]
+                } else if 0.0 < max && max.is_finite() {
+                    // Show the three decades below the largest value.
+                    [max * 1e-3, max]
+                } else {
+                    [1.0, 10.0]
+                }
+            }
+        }
+    }
+}
+
 /// Contains the screen rectangle and the plot bounds and provides methods to transform between them.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Clone, Copy, Debug)]
@@ -237,6 +290,10 @@ pub struct PlotTransform {
 
     /// Whether to always center the y-range of the bounds.
     y_centered: bool,
+
+    /// The scales of the X and Y axes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    scales: [Scale; 2],
 }
 
 impl PlotTransform {
@@ -262,9 +319,30 @@ impl PlotTransform {
             bounds,
             x_centered,
             y_centered,
+            scales: [Scale::Linear; 2],
         }
     }
 
+    /// Place the values along the X and Y axes using these scales.
+    ///
+    /// Bounds that cannot be shown on a scale (e.g. negative values on [`Scale::Log10`]) are replaced.
+    #[inline]
+    pub fn with_scales(mut self, scales: [Scale; 2]) -> Self {
+        for (axis, scale) in scales.into_iter().enumerate() {
+            let [min, max] = scale.sanitize_range([self.bounds.min[axis], self.bounds.max[axis]]);
+            self.bounds.min[axis] = min;
+            self.bounds.max[axis] = max;
+        }
+        self.scales = scales;
+        self
+    }
+
+    /// The scales of the X and Y axes.
+    #[inline]
+    pub fn scales(&self) -> [Scale; 2] {
+        self.scales
+    }
+
     /// ui-space rectangle.
     #[inline]
     pub fn frame(&self) -> &Rect {
@@ -282,6 +360,40 @@ impl PlotTransform {
         self.bounds = bounds;
     }
 
+    /// The bounds after applying the [`Scale`] of each axis.
+    ///
+    /// The screen positions are a linear function of these, e.g. the bounds are in decades for [`Scale::Log10`].
+    pub fn scaled_bounds(&self) -> PlotBounds {
+        let [x_scale, y_scale] = self.scales;
+        PlotBounds::from_min_max(
+            [
+                x_scale.apply(self.bounds.min[0]),
+                y_scale.apply(self.bounds.min[1]),
+            ],
+            [
+                x_scale.apply(self.bounds.max[0]),
+                y_scale.apply(self.bounds.max[1]),
+            ],
+        )
+    }
+
+    fn set_scaled_bounds(&mut self, scaled_bounds: PlotBounds) {
+        let [x_scale, y_scale] = self.scales;
+        let bounds = PlotBounds::from_min_max(
+            [
+                x_scale.invert(scaled_bounds.min[0]),
+                y_scale.invert(scaled_bounds.min[1]),
+            ],
+            [
+                x_scale.invert(scaled_bounds.max[0]),
+                y_scale.invert(scaled_bounds.max[1]),
+            ],
+        );
+        if bounds.is_valid() {
+            self.bounds = bounds;
+        }
+    }
+
     pub fn translate_bounds(&mut self, mut delta_pos: Vec2) {
         if self.x_centered {
             delta_pos.x = 0.;
@@ -291,33 +403,39 @@ impl PlotTransform {
         }
         delta_pos.x *= self.dvalue_dpos()[0] as f32;
         delta_pos.y *= self.dvalue_dpos()[1] as f32;
-        self.bounds.translate(delta_pos);
+        let mut scaled_bounds = self.scaled_bounds();
+        scaled_bounds.translate(delta_pos);
+        self.set_scaled_bounds(scaled_bounds);
     }
 
     /// Zoom by a relative factor with the given screen position as center.
     pub fn zoom(&mut self, zoom_factor: Vec2, center: Pos2) {
         let center = self.value_from_position(center);
 
-        let mut new_bounds = self.bounds;
+        let [x_scale, y_scale] = self.scales;
+        let center = PlotPoint::new(x_scale.apply(center.x), y_scale.apply(center.y));
+        let mut new_bounds = self.scaled_bounds();
         new_bounds.zoom(zoom_factor, center);
 
         if new_bounds.is_valid() {
-            self.bounds = new_bounds;
+            self.set_scaled_bounds(new_bounds);
         }
     }
 
     pub fn position_from_point_x(&self, value: f64) -> f32 {
+        let scale = self.scales[0];
         remap(
-            value,
-            self.bounds.min[0]..=self.bounds.max[0],
+            scale.apply(value),
+            scale.apply(self.bounds.min[0])..=scale.apply(self.bounds.max[0]),
             (self.frame.left() as f64)..=(self.frame.right() as f64),
         ) as f32
     }
 
     pub fn position_from_point_y(&self, value: f64) -> f32 {
+        let scale = self.scales[1];
         remap(
-            value,
-            self.bounds.min[1]..=self.bounds.max[1],
+            scale.apply(value),
+            scale.apply(self.bounds.min[1])..=scale.apply(self.bounds.max[1]),
             (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
         ) as f32
     }
@@ -332,17 +450,18 @@ impl PlotTransform {
 
     /// Plot point from screen/ui position.
     pub fn value_from_position(&self, pos: Pos2) -> PlotPoint {
+        let scaled_bounds = self.scaled_bounds();
         let x = remap(
             pos.x as f64,
             (self.frame.left() as f64)..=(self.frame.right() as f64),
-            self.bounds.min[0]..=self.bounds.max[0],
+            scaled_bounds.min[0]..=scaled_bounds.max[0],
         );
         let y = remap(
             pos.y as f64,
             (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
-            self.bounds.min[1]..=self.bounds.max[1],
+            scaled_bounds.min[1]..=scaled_bounds.max[1],
         );
-        PlotPoint::new(x, y)
+        PlotPoint::new(self.scales[0].invert(x), self.scales[1].invert(y))
     }
 
     /// Transform a rectangle of plot values to a screen-coordinate rectangle.
@@ -360,13 +479,22 @@ impl PlotTransform {
     }
 
     /// delta position / delta value = how many ui points per step in the X axis in "plot space"
+    ///
+    /// For a non-linear axis, this is per step of the scaled value (see [`Self::scaled_bounds`]).
     pub fn dpos_dvalue_x(&self) -> f64 {
-        self.frame.width() as f64 / self.bounds.width()
+        let scale = self.scales[0];
+        self.frame.width() as f64
+            / (scale.apply(self.bounds.max[0]) - scale.apply(self.bounds.min[0]))
     }
 
     /// delta position / delta value = how many ui points per step in the Y axis in "plot space"
+    ///
+    /// For a non-linear axis, this is per step of the scaled value (see [`Self::scaled_bounds`]).
     pub fn dpos_dvalue_y(&self) -> f64 {
-        -self.frame.height() as f64 / self.bounds.height() // negated y axis!
+        let scale = self.scales[1];
+        -self.frame.height() as f64
+            / (scale.apply(self.bounds.max[1]) - scale.apply(self.bounds.min[1]))
+        // negated y axis!
     }
 
     /// delta position / delta value = how many ui points per step in "plot space"
@@ -431,3 +559,89 @@ impl PlotTransform {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() <= 1e-9 * b.abs().max(1.0), "{a} != {b}");
+    }
+
+    /// A 300x300 frame showing x from 1 to 1000 on a log scale, and y from 0 to 3.
+    fn log_x_transform() -> PlotTransform {
+        let frame = Rect::from_min_size(Pos2::ZERO, Vec2::splat(300.0));
+        let bounds = PlotBounds::from_min_max([1.0, 0.0], [1000.0, 3.0]);
+        PlotTransform::new(frame, bounds, false, false).with_scales([Scale::Log10, Scale::Linear])
+    }
+
+    #[test]
+    fn test_scale() {
+        assert_eq!(Scale::Linear.apply(-5.0), -5.0);
+        assert_close(Scale::Log10.apply(1000.0), 3.0);
+        assert_close(Scale::Log10.apply(0.01), -2.0);
+        for value in [1e-6, 0.5, 1.0, 42.0, 1e12] {
+            assert_close(Scale::Log10.invert(Scale::Log10.apply(value)), value);
+        }
+    }
+
+    #[test]
+    fn test_sanitize_range() {
+        assert_eq!(Scale::Linear.sanitize_range([-1.0, 1.0]), [-1.0, 1.0]);
+        assert_eq!(Scale::Log10.sanitize_range([0.1, 10.0]), [0.1, 10.0]);
+        // Three decades below the largest value:
+        assert_eq!(Scale::Log10.sanitize_range([-5.0, 100.0]), [0.1, 100.0]);
+        assert_eq!(Scale::Log10.sanitize_range([0.0, 100.0]), [0.1, 100.0]);
+        // Nothing positive to show:
+        assert_eq!(Scale::Log10.sanitize_range([-5.0, -1.0]), [1.0, 10.0]);
+        assert_eq!(
+            Scale::Log10.sanitize_range([1.0, f64::INFINITY]),
+            [1.0, 10.0]
+        );
+
+        let frame = Rect::from_min_size(Pos2::ZERO, Vec2::splat(300.0));
+        let bounds = PlotBounds::from_min_max([-5.0, -5.0], [100.0, 100.0]);
+        let transform =
+            PlotTransform::new(frame, bounds, false, false).with_scales([Scale::Log10; 2]);
+        assert_eq!(transform.bounds().min(), [0.1, 0.1]);
+        assert_eq!(transform.bounds().max(), [100.0, 100.0]);
+    }
+
+    #[test]
+    fn test_log_positions() {
+        let transform = log_x_transform();
+
+        // Each decade takes up a third of the frame:
+        assert_eq!(transform.position_from_point_x(1.0), 0.0);
+        assert_eq!(transform.position_from_point_x(10.0), 100.0);
+        assert_eq!(transform.position_from_point_x(100.0), 200.0);
+        assert_eq!(transform.position_from_point_x(1000.0), 300.0);
+        assert_eq!(transform.position_from_point_y(1.0), 200.0);
+
+        let value = transform.value_from_position(Pos2::new(150.0, 100.0));
+        assert_close(value.x, 10.0_f64.powf(1.5));
+        assert_close(value.y, 2.0);
+
+        assert_close(transform.dpos_dvalue_x(), 100.0);
+        let scaled_bounds = transform.scaled_bounds();
+        assert_close(scaled_bounds.min()[0], 0.0);
+        assert_close(scaled_bounds.max()[0], 3.0);
+    }
+
+    #[test]
+    fn test_log_pan_and_zoom() {
+        // Panning by a third of the frame moves by a decade:
+        let mut transform = log_x_transform();
+        transform.translate_bounds(Vec2::new(100.0, 0.0));
+        assert_close(transform.bounds().min()[0], 10.0);
+        assert_close(transform.bounds().max()[0], 10_000.0);
+
+        // Zooming in around 10 keeps 10 in place, and the decades the same size:
+        let mut transform = log_x_transform();
+        transform.zoom(Vec2::new(3.0, 1.0), Pos2::new(100.0, 150.0));
+        assert_close(transform.bounds().min()[0], 10.0_f64.powf(2.0 / 3.0));
+        assert_close(transform.bounds().max()[0], 10.0_f64.powf(5.0 / 3.0));
+        assert_close(transform.position_from_point_x(10.0) as f64, 100.0);
+    }
+}
@@ -264,6 +264,8 @@ impl std::fmt::Debug for ColorImage {
 /// Each value represents "coverage", i.e. how much a texel is covered by a character.
 ///
 /// This is roughly interpreted as the opacity of a white image.
+///
+/// Color glyphs, like color emoji, are stored in [`Self::colors`].
 #[derive(Clone, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct FontImage {
@@ -274,6 +276,12 @@ pub struct FontImage {
     ///
     /// Often you want to use [`Self::srgba_pixels`] instead.
     pub pixels: Vec<f32>,
+
+    /// Premultiplied colors of the pixels covered by color glyphs,
+    /// and [`Color32::TRANSPARENT`] everywhere else.
+    ///
+    /// Empty if there are no color glyphs, otherwise the same size as [`Self::pixels`].
+    pub colors: Vec<Color32>,
 }
 
 impl FontImage {
@@ -281,6 +289,7 @@ impl FontImage {
         Self {
             size,
             pixels: vec![0.0; size[0] * size[1]],
+            colors: vec![],
         }
     }
 
@@ -302,7 +311,12 @@ impl FontImage {
     #[inline]
     pub fn srgba_pixels(&self, gamma: Option<f32>) -> impl ExactSizeIterator<Item = Color32> + '_ {
         let gamma = gamma.unwrap_or(0.55); // TODO(emilk): this default coverage gamma is a magic constant, chosen by eye. I don't even know why we need it.
-        self.pixels.iter().map(move |coverage| {
+        self.pixels.iter().enumerate().map(move |(i, coverage)| {
+            if let Some(&color) = self.colors.get(i) {
+                if color != Color32::TRANSPARENT {
+                    return color;
+                }
+            }
             let alpha = coverage.powf(gamma);
             // We want to multiply with `vec4(alpha)` in the fragment shader:
             let a = fast_round(alpha * 255.0);
@@ -310,20 +324,36 @@ impl FontImage {
         })
     }
 
+    /// Set the color of a pixel covered by a color glyph, e.g. an emoji.
+    pub fn set_color(&mut self, (x, y): (usize, usize), color: Color32) {
+        let [w, h] = self.size;
+        assert!(x < w && y < h);
+        if self.colors.is_empty() {
+            self.colors = vec![Color32::TRANSPARENT; self.pixels.len()];
+        }
+        self.colors[y * w + x] = color;
+        self.pixels[y * w + x] = color.a() as f32 / 255.0;
+    }
+
     /// Clone a sub-region as a new image.
     pub fn region(&self, [x, y]: [usize; 2], [w, h]: [usize; 2]) -> Self {
         assert!(x + w <= self.width());
         assert!(y + h <= self.height());
 
         let mut pixels = Vec::with_capacity(w * h);
+        let mut colors = Vec::with_capacity(if self.colors.is_empty() { 0 } else { w * h });
         for y in y..y + h {
             let offset = y * self.width() + x;
             pixels.extend(&self.pixels[offset..(offset + w)]);
+            if !self.colors.is_empty() {
+                colors.extend(&self.colors[offset..(offset + w)]);
+            }
         }
         assert_eq!(pixels.len(), w * h);
         Self {
             size: [w, h],
             pixels,
+            colors,
         }
     }
 }
@@ -200,6 +200,58 @@ impl Mesh {
         });
     }
 
+    /// Rectangle with a texture and color, scaled with nine-slice scaling.
+    ///
+    /// `inner_rect` is the part of `rect` that the center of the texture is stretched to cover,
+    /// and `inner_uv` is the center part of `uv`.
+    /// The four corners are mapped unstretched between `rect` and `inner_rect`,
+    /// while the four edges are only stretched along their length.
+    pub fn add_rect_nine_slice(
+        &mut self,
+        rect: Rect,
+        inner_rect: Rect,
+        uv: Rect,
+        inner_uv: Rect,
+        color: Color32,
+    ) {
+        let xs = [
+            rect.left(),
+            inner_rect.left(),
+            inner_rect.right(),
+            rect.right(),
+        ];
+        let ys = [
+            rect.top(),
+            inner_rect.top(),
+            inner_rect.bottom(),
+            rect.bottom(),
+        ];
+        let us = [uv.left(), inner_uv.left(), inner_uv.right(), uv.right()];
+        let vs = [uv.top(), inner_uv.top(), inner_uv.bottom(), uv.bottom()];
+
+        let idx = self.vertices.len() as u32;
+        self.reserve_triangles(2 * 9);
+        self.reserve_vertices(4 * 4);
+
+        for (&y, &v) in ys.iter().zip(&vs) {
+            for (&x, &u) in xs.iter().zip(&us) {
+                self.vertices.push(Vertex {
+                    pos: pos2(x, y),
+                    uv: pos2(u, v),
+                    color,
+                });
+            }
+        }
+
+        for row in 0..3 {
+            for col in 0..3 {
+                let top_left = idx + 4 * row + col;
+                self.add_triangle(top_left, top_left + 1, top_left + 4);
+                self.add_triangle(top_left + 4, top_left + 1, top_left + 5);
+            }
+        }
+    }
+
     /// Uniformly colored rectangle.
     #[inline(always)]
     pub fn add_colored_rect(&mut self, rect: Rect, color: Color32) {
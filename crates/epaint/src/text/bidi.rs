@@ -0,0 +1,241 @@
+//! Bidirectional text: putting right-to-left scripts (Arabic, Hebrew, …) in visual order.
+//!
+//! This is a simplified version of the [Unicode Bidirectional Algorithm](https://unicode.org/reports/tr9/).
+//! It resolves the embedding level of each character from its direction and the direction of its
+//! neighbors, and then reverses the runs of right-to-left text on each row.
+//! Explicit embeddings, overrides and isolates are not supported.
+//!
+//! The glyphs of a [`Row`] stay in logical order (the order of the text),
+//! only their x positions change. Glyphs that are part of right-to-left text get
+//! [`super::Glyph::rtl`] set.
+
+use super::{FontsImpl, LayoutJob, Row};
+
+/// The direction of a character, simplified from the Unicode bidi classes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Class {
+    /// Strong left-to-right, e.g. latin letters.
+    Ltr,
+
+    /// Strong right-to-left, e.g. hebrew and arabic letters.
+    Rtl,
+
+    /// Digits, which are left-to-right, but don't change the direction of the text around them.
+    Number,
+
+    /// Combining marks, which take the direction of the preceding character.
+    Mark,
+
+    /// Whitespace, punctuation and symbols, which take the direction of the text around them.
+    Neutral,
+}
+
+fn class(c: char) -> Class {
+    match c {
+        '0'..='9' | '\u{0660}'..='\u{0669}' | '\u{06F0}'..='\u{06F9}' => Class::Number,
+        '\u{0300}'..='\u{036F}' | '\u{1AB0}'..='\u{1AFF}' | '\u{20D0}'..='\u{20FF}' => Class::Mark,
+        c if is_rtl(c) => Class::Rtl,
+        c if c.is_alphanumeric() => Class::Ltr,
+        _ => Class::Neutral,
+    }
+}
+
+/// Is this a character from a right-to-left script?
+fn is_rtl(c: char) -> bool {
+    matches!(c,
+        '\u{0590}'..='\u{08FF}' // Hebrew, Arabic, Syriac, Thaana, NKo, Samaritan, Mandaic, …
+        | '\u{FB1D}'..='\u{FDFF}' // Hebrew and Arabic presentation forms A
+        | '\u{FE70}'..='\u{FEFF}' // Arabic presentation forms B
+        | '\u{10800}'..='\u{10FFF}' // Historic right-to-left scripts
+        | '\u{1E800}'..='\u{1EFFF}' // Adlam, Arabic mathematical symbols, …
+    )
+}
+
+/// The character to show instead of `c` in right-to-left text, e.g. `)` for `(`.
+pub(super) fn mirrored(c: char) -> Option<char> {
+    Some(match c {
+        '(' => ')',
+        ')' => '(',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '<' => '>',
+        '>' => '<',
+        '«' => '»',
+        '»' => '«',
+        '‹' => '›',
+        '›' => '‹',
+        _ => return None,
+    })
+}
+
+/// Put the glyphs of right-to-left text in visual order.
+///
+/// Rows are grouped into paragraphs, and the direction of each paragraph is decided by its
+/// first strong character. Does nothing for text without any right-to-left characters.
+pub(super) fn reorder_rows(fonts: &mut FontsImpl, job: &LayoutJob, rows: &mut [Row]) {
+    if !rows
+        .iter()
+        .any(|row| row.glyphs.iter().any(|glyph| is_rtl(glyph.chr)))
+    {
+        return; // Fast path for the common case
+    }
+
+    let mut paragraph_start = 0;
+    for i in 0..rows.len() {
+        if rows[i].ends_with_newline || i + 1 == rows.len() {
+            reorder_paragraph(fonts, job, &mut rows[paragraph_start..=i]);
+            paragraph_start = i + 1;
+        }
+    }
+}
+
+fn reorder_paragraph(fonts: &mut FontsImpl, job: &LayoutJob, rows: &mut [Row]) {
+    let classes: Vec<Class> = rows
+        .iter()
+        .flat_map(|row| row.glyphs.iter().map(|glyph| class(glyph.chr)))
+        .collect();
+
+    if !classes.contains(&Class::Rtl) {
+        return;
+    }
+
+    let base_rtl = classes
+        .iter()
+        .find_map(|class| match class {
+            Class::Ltr => Some(false),
+            Class::Rtl => Some(true),
+            _ => None,
+        })
+        .unwrap_or(false);
+
+    let mut levels = resolve_levels(&classes, base_rtl);
+
+    let base_level = u8::from(base_rtl);
+    let mut start = 0;
+    for row in rows {
+        let end = start + row.glyphs.len();
+        let row_levels = &mut levels[start..end];
+
+        // Trailing whitespace goes back to the paragraph direction (rule L1):
+        for (glyph, level) in row.glyphs.iter().zip(row_levels.iter_mut()).rev() {
+            if !glyph.chr.is_whitespace() {
+                break;
+            }
+            *level = base_level;
+        }
+
+        reorder_row(fonts, job, row, row_levels);
+        start = end;
+    }
+}
+
+/// The embedding level of each character: even for left-to-right, odd for right-to-left.
+fn resolve_levels(classes: &[Class], base_rtl: bool) -> Vec<u8> {
+    let base = if base_rtl { Class::Rtl } else { Class::Ltr };
+
+    // Marks take the direction of the preceding character,
+    // and numbers after left-to-right text become left-to-right (rules W1 and W7):
+    let mut resolved = Vec::with_capacity(classes.len());
+    let mut last_strong = base;
+    for &class in classes {
+        let class = match class {
+            Class::Mark => resolved.last().copied().unwrap_or(base),
+            Class::Number if last_strong == Class::Ltr => Class::Ltr,
+            class => class,
+        };
+        if matches!(class, Class::Ltr | Class::Rtl) {
+            last_strong = class;
+        }
+        resolved.push(class);
+    }
+
+    // Neutrals between text of the same direction take that direction,
+    // otherwise the direction of the paragraph (rules N1 and N2).
+    // Numbers count as right-to-left here.
+    let direction = |class: Class| match class {
+        Class::Ltr => Class::Ltr,
+        _ => Class::Rtl,
+    };
+    let mut i = 0;
+    while i < resolved.len() {
+        if resolved[i] != Class::Neutral {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < resolved.len() && resolved[i] == Class::Neutral {
+            i += 1;
+        }
+        let before = if start == 0 {
+            base
+        } else {
+            direction(resolved[start - 1])
+        };
+        let after = resolved.get(i).copied().map_or(base, direction);
+        let class = if before == after { before } else { base };
+        resolved[start..i].fill(class);
+    }
+
+    // Rules I1 and I2:
+    resolved
+        .into_iter()
+        .map(|class| match (base_rtl, class) {
+            (false, Class::Ltr) => 0,
+            (false, Class::Number) => 2,
+            (false, _) | (true, Class::Rtl) => 1,
+            (true, _) => 2,
+        })
+        .collect()
+}
+
+/// Reverse the right-to-left runs of one row (rule L2), and move the glyphs accordingly.
+fn reorder_row(fonts: &mut FontsImpl, job: &LayoutJob, row: &mut Row, levels: &[u8]) {
+    if row.glyphs.is_empty() || levels.iter().all(|&level| level == 0) {
+        return;
+    }
+
+    let mut visual_order: Vec<usize> = (0..row.glyphs.len()).collect();
+    let max_level = levels.iter().copied().max().unwrap_or(0);
+
+    // From the highest level down to level 1,
+    // reverse every run of glyphs at that level or higher:
+    for level in (1..=max_level).rev() {
+        let mut i = 0;
+        while i < visual_order.len() {
+            if levels[visual_order[i]] < level {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < visual_order.len() && level <= levels[visual_order[i]] {
+                i += 1;
+            }
+            visual_order[start..i].reverse();
+        }
+    }
+
+    let pixels_per_point = fonts.pixels_per_point();
+    let mut x = row.glyphs.first().map_or(0.0, |glyph| glyph.pos.x);
+    for index in visual_order {
+        let glyph = &mut row.glyphs[index];
+        glyph.rtl = levels[index] % 2 == 1;
+
+        if glyph.rtl {
+            if let Some(mirrored) = mirrored(glyph.chr) {
+                let section = &job.sections[glyph.section_index as usize];
+                let (_, glyph_info) = fonts
+                    .font(&section.format.font_id)
+                    .font_impl_and_glyph_info(mirrored);
+                glyph.uv_rect = glyph_info.uv_rect;
+                glyph.size.x = glyph_info.advance_width;
+            }
+        }
+
+        glyph.pos.x = x;
+        x += glyph.size.x;
+        x = (x * pixels_per_point).round() / pixels_per_point;
+    }
+    row.rect.max.x = x;
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "color_emoji")]
+use crate::Color32;
 use crate::{
     mutex::{Mutex, RwLock},
     text::FontTweak,
@@ -5,6 +7,7 @@ use crate::{
 };
 use emath::{vec2, Vec2};
 use std::collections::BTreeSet;
+use std::ops::RangeInclusive;
 use std::sync::Arc;
 
 // ----------------------------------------------------------------------------
@@ -24,6 +27,12 @@ pub struct UvRect {
 
     /// Bottom right corner (exclusive).
     pub max: [u16; 2],
+
+    /// Does the glyph have its own colors, like a color emoji?
+    ///
+    /// If so, it is stored in [`crate::FontImage::colors`],
+    /// and is painted without the color of the text.
+    pub colored: bool,
 }
 
 impl UvRect {
@@ -78,6 +87,14 @@ pub struct FontImpl {
     pixels_per_point: f32,
     glyph_info_cache: RwLock<ahash::HashMap<char, GlyphInfo>>, // TODO(emilk): standard Mutex
     atlas: Arc<Mutex<TextureAtlas>>,
+
+    /// The font file and the axis values of variable fonts, for shaping text.
+    #[cfg(feature = "shaping")]
+    shaping_data: Option<(Arc<crate::text::FontData>, crate::text::FontVariations)>,
+
+    /// Glyphs produced by shaping, which are looked up by id rather than by character.
+    #[cfg(feature = "shaping")]
+    glyph_id_cache: RwLock<ahash::HashMap<ab_glyph::GlyphId, GlyphInfo>>,
 }
 
 impl FontImpl {
@@ -132,9 +149,24 @@ impl FontImpl {
             pixels_per_point,
             glyph_info_cache: Default::default(),
             atlas,
+            #[cfg(feature = "shaping")]
+            shaping_data: None,
+            #[cfg(feature = "shaping")]
+            glyph_id_cache: Default::default(),
         }
     }
 
+    /// Use this font file to shape text, see [`Self::shape`].
+    #[cfg(feature = "shaping")]
+    pub(crate) fn with_shaping_data(
+        mut self,
+        data: Arc<crate::text::FontData>,
+        variations: crate::text::FontVariations,
+    ) -> Self {
+        self.shaping_data = Some((data, variations));
+        self
+    }
+
     /// Code points that will always be replaced by the replacement character.
     ///
     /// See also [`invisible_char`].
@@ -231,6 +263,126 @@ impl FontImpl {
         }
     }
 
+    /// Shape a run of text with this font, returning the glyph of each character.
+    ///
+    /// Each glyph of a cluster is given to one of its characters, in order.
+    /// Characters without a glyph of their own, like the second character of a ligature,
+    /// get an invisible zero-width glyph. Returns `None` for every character if the font
+    /// can't be used for shaping.
+    #[cfg(feature = "shaping")]
+    fn shape(&self, text: &str) -> Vec<Option<GlyphInfo>> {
+        use ab_glyph::Font as _;
+
+        let num_chars = text.chars().count();
+        let Some((data, variations)) = &self.shaping_data else {
+            return vec![None; num_chars];
+        };
+        let Some(mut face) = rustybuzz::Face::from_slice(&data.font, data.index) else {
+            return vec![None; num_chars];
+        };
+        let variations: Vec<rustybuzz::Variation> = variations
+            .axes()
+            .map(|(axis, value)| rustybuzz::Variation {
+                tag: rustybuzz::ttf_parser::Tag::from_bytes(axis),
+                value,
+            })
+            .collect();
+        face.set_variations(&variations);
+
+        let mut buffer = rustybuzz::UnicodeBuffer::new();
+        buffer.push_str(text);
+        buffer.guess_segment_properties();
+        let rtl = buffer.direction() == rustybuzz::Direction::RightToLeft;
+        let output = rustybuzz::shape(&face, &[], buffer);
+
+        // From font units to points:
+        let scale = self.scale_in_pixels as f32
+            / self.ab_glyph_font.height_unscaled()
+            / self.pixels_per_point;
+
+        // Right-to-left text comes out in visual order, but we want logical order:
+        let mut glyphs: Vec<_> = output
+            .glyph_infos()
+            .iter()
+            .zip(output.glyph_positions())
+            .collect();
+        if rtl {
+            glyphs.reverse();
+        }
+
+        let mut glyph_infos = Vec::with_capacity(num_chars);
+        let mut glyphs = glyphs.into_iter().peekable();
+        while let Some((info, _)) = glyphs.peek().copied() {
+            let cluster_start = info.cluster as usize;
+            let mut cluster = vec![];
+            while let Some(&(info, position)) = glyphs.peek() {
+                if info.cluster as usize != cluster_start {
+                    break;
+                }
+                cluster.push(self.shaped_glyph_info(info.glyph_id, position, scale));
+                glyphs.next();
+            }
+            let cluster_end = glyphs
+                .peek()
+                .map_or(text.len(), |(info, _)| info.cluster as usize);
+            let num_cluster_chars = text
+                .get(cluster_start..cluster_end)
+                .map_or(0, |cluster_text| cluster_text.chars().count());
+
+            // Any extra glyphs are dropped, but keep their advance:
+            if num_cluster_chars < cluster.len() && 0 < num_cluster_chars {
+                let extra_advance: f32 = cluster[num_cluster_chars..]
+                    .iter()
+                    .map(|glyph_info| glyph_info.advance_width)
+                    .sum();
+                cluster.truncate(num_cluster_chars);
+                if let Some(last) = cluster.last_mut() {
+                    last.advance_width += extra_advance;
+                }
+            }
+            cluster.resize(num_cluster_chars, GlyphInfo::default());
+            glyph_infos.extend(cluster.into_iter().map(Some));
+        }
+
+        if glyph_infos.len() == num_chars {
+            glyph_infos
+        } else {
+            // Unexpected clusters, so don't trust the shaping:
+            vec![None; num_chars]
+        }
+    }
+
+    #[cfg(feature = "shaping")]
+    fn shaped_glyph_info(
+        &self,
+        glyph_id: u32,
+        position: &rustybuzz::GlyphPosition,
+        scale: f32,
+    ) -> GlyphInfo {
+        let glyph_id = ab_glyph::GlyphId(glyph_id as u16);
+
+        let glyph_info = if glyph_id.0 == 0 {
+            GlyphInfo::default()
+        } else {
+            let cached = self.glyph_id_cache.read().get(&glyph_id).copied();
+            cached.unwrap_or_else(|| {
+                let glyph_info = self.allocate_glyph(glyph_id);
+                self.glyph_id_cache.write().insert(glyph_id, glyph_info);
+                glyph_info
+            })
+        };
+
+        let offset = vec2(position.x_offset as f32, -position.y_offset as f32) * scale;
+        GlyphInfo {
+            id: glyph_id,
+            advance_width: position.x_advance as f32 * scale,
+            uv_rect: UvRect {
+                offset: glyph_info.uv_rect.offset + offset,
+                ..glyph_info.uv_rect
+            },
+        }
+    }
+
     #[inline]
     pub fn pair_kerning(
         &self,
@@ -272,7 +424,19 @@ impl FontImpl {
             ab_glyph::Point { x: 0.0, y: 0.0 },
         );
 
-        let uv_rect = self.ab_glyph_font.outline_glyph(glyph).map(|glyph| {
+        #[cfg(feature = "color_emoji")]
+        let color_uv_rect = self.allocate_color_glyph(glyph_id);
+        #[cfg(not(feature = "color_emoji"))]
+        let color_uv_rect = None;
+
+        // Color glyphs don't need the outline:
+        let outline_glyph = if color_uv_rect.is_some() {
+            None
+        } else {
+            self.ab_glyph_font.outline_glyph(glyph)
+        };
+
+        let uv_rect = outline_glyph.map(|glyph| {
             let bb = glyph.px_bounds();
             let glyph_width = bb.width() as usize;
             let glyph_height = bb.height() as usize;
@@ -303,10 +467,11 @@ impl FontImpl {
                         (glyph_pos.0 + glyph_width) as u16,
                         (glyph_pos.1 + glyph_height) as u16,
                     ],
+                    colored: false,
                 }
             }
         });
-        let uv_rect = uv_rect.unwrap_or_default();
+        let uv_rect = color_uv_rect.or(uv_rect).unwrap_or_default();
 
         let advance_width_in_points = self
             .ab_glyph_font
@@ -320,6 +485,128 @@ impl FontImpl {
             uv_rect,
         }
     }
+
+    /// Rasterize a glyph from the color bitmaps of the font (`CBDT` or `sbix` tables),
+    /// as used by color emoji fonts.
+    ///
+    /// Returns `None` if the font has no color bitmap for the glyph.
+    #[cfg(feature = "color_emoji")]
+    fn allocate_color_glyph(&self, glyph_id: ab_glyph::GlyphId) -> Option<UvRect> {
+        use ab_glyph::{Font as _, GlyphImageFormat};
+
+        let font = &self.ab_glyph_font;
+        let em_in_pixels =
+            self.scale_in_pixels as f32 * font.units_per_em()? / font.height_unscaled();
+        let image = font.glyph_raster_image2(glyph_id, em_in_pixels.round() as u16)?;
+
+        let (size, pixels) = match image.format {
+            GlyphImageFormat::Png => decode_png(image.data)?,
+            GlyphImageFormat::BitmapPremulBgra32 => {
+                let pixels = image
+                    .data
+                    .chunks_exact(4)
+                    .map(|bgra| {
+                        Color32::from_rgba_premultiplied(bgra[2], bgra[1], bgra[0], bgra[3])
+                    })
+                    .collect();
+                ([image.width as usize, image.height as usize], pixels)
+            }
+            _ => return None, // Monochrome bitmaps: use the outlines instead
+        };
+        if size[0] == 0 || size[1] == 0 || pixels.len() != size[0] * size[1] {
+            return None;
+        }
+
+        // The bitmaps come in a few fixed sizes, so scale to the size we need:
+        let scale = em_in_pixels / image.pixels_per_em.max(1) as f32;
+        let glyph_width = ((size[0] as f32 * scale).round() as usize).max(1);
+        let glyph_height = ((size[1] as f32 * scale).round() as usize).max(1);
+        let pixels = resize_premultiplied(&pixels, size, [glyph_width, glyph_height]);
+
+        let glyph_pos = {
+            let atlas = &mut self.atlas.lock();
+            let (glyph_pos, atlas_image) = atlas.allocate((glyph_width, glyph_height));
+            for y in 0..glyph_height {
+                for x in 0..glyph_width {
+                    let color = pixels[y * glyph_width + x];
+                    atlas_image.set_color((glyph_pos.0 + x, glyph_pos.1 + y), color);
+                }
+            }
+            glyph_pos
+        };
+
+        // `origin` is the bottom left corner of the bitmap, relative to the baseline, with y up:
+        let offset_in_pixels = vec2(image.origin.x, -(image.origin.y + size[1] as f32)) * scale;
+        let offset = offset_in_pixels / self.pixels_per_point + self.y_offset_in_points * Vec2::Y;
+        Some(UvRect {
+            offset,
+            size: vec2(glyph_width as f32, glyph_height as f32) / self.pixels_per_point,
+            min: [glyph_pos.0 as u16, glyph_pos.1 as u16],
+            max: [
+                (glyph_pos.0 + glyph_width) as u16,
+                (glyph_pos.1 + glyph_height) as u16,
+            ],
+            colored: true,
+        })
+    }
+}
+
+/// Decode the PNG of a color glyph into premultiplied pixels.
+#[cfg(feature = "color_emoji")]
+fn decode_png(data: &[u8]) -> Option<([usize; 2], Vec<Color32>)> {
+    let mut decoder = png::Decoder::new(data);
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+    let mut reader = decoder.read_info().ok()?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer).ok()?;
+    let bytes = &buffer[..info.buffer_size()];
+
+    let pixels = match info.color_type {
+        png::ColorType::Rgba => bytes
+            .chunks_exact(4)
+            .map(|p| Color32::from_rgba_unmultiplied(p[0], p[1], p[2], p[3]))
+            .collect(),
+        png::ColorType::Rgb => bytes
+            .chunks_exact(3)
+            .map(|p| Color32::from_rgb(p[0], p[1], p[2]))
+            .collect(),
+        png::ColorType::GrayscaleAlpha => bytes
+            .chunks_exact(2)
+            .map(|p| Color32::from_rgba_unmultiplied(p[0], p[0], p[0], p[1]))
+            .collect(),
+        png::ColorType::Grayscale => bytes.iter().map(|&g| Color32::from_gray(g)).collect(),
+        png::ColorType::Indexed => return None, // expanded to rgb(a) by the decoder
+    };
+    Some(([info.width as usize, info.height as usize], pixels))
+}
+
+/// Resize an image by averaging the pixels that end up in the same place.
+#[cfg(feature = "color_emoji")]
+fn resize_premultiplied(
+    pixels: &[Color32],
+    [w, h]: [usize; 2],
+    [new_w, new_h]: [usize; 2],
+) -> Vec<Color32> {
+    let mut resized = Vec::with_capacity(new_w * new_h);
+    for y in 0..new_h {
+        let y_range = (y * h / new_h)..((y + 1) * h / new_h).max(y * h / new_h + 1);
+        for x in 0..new_w {
+            let x_range = (x * w / new_w)..((x + 1) * w / new_w).max(x * w / new_w + 1);
+            let mut sum = [0_u32; 4];
+            for sy in y_range.clone() {
+                for sx in x_range.clone() {
+                    let pixel = pixels[sy.min(h - 1) * w + sx.min(w - 1)];
+                    for (sum, value) in sum.iter_mut().zip(pixel.to_array()) {
+                        *sum += value as u32;
+                    }
+                }
+            }
+            let count = (y_range.len() * x_range.len()) as u32;
+            let [r, g, b, a] = sum.map(|sum| (sum / count) as u8);
+            resized.push(Color32::from_rgba_premultiplied(r, g, b, a));
+        }
+    }
+    resized
 }
 
 type FontIndex = usize;
@@ -329,6 +616,13 @@ type FontIndex = usize;
 pub struct Font {
     fonts: Vec<Arc<FontImpl>>,
 
+    /// How many of [`Self::fonts`] are tried for every character.
+    /// The rest are only used for the characters of their fallbacks.
+    num_family_fonts: usize,
+
+    /// Fonts to try first for some characters: Unicode ranges and indices into [`Self::fonts`].
+    fallbacks: Vec<(Vec<RangeInclusive<char>>, Vec<FontIndex>)>,
+
     /// Lazily calculated.
     characters: Option<BTreeSet<char>>,
 
@@ -340,9 +634,40 @@ pub struct Font {
 
 impl Font {
     pub fn new(fonts: Vec<Arc<FontImpl>>) -> Self {
+        Self::with_fallbacks(fonts, Vec::new())
+    }
+
+    /// Like [`Self::new`], but the characters in the given Unicode ranges are first looked up
+    /// in the fonts of their fallback, see [`crate::text::FontDefinitions::fallbacks`].
+    pub fn with_fallbacks(
+        mut fonts: Vec<Arc<FontImpl>>,
+        fallbacks: Vec<(Vec<RangeInclusive<char>>, Vec<Arc<FontImpl>>)>,
+    ) -> Self {
+        let num_family_fonts = fonts.len();
+        let fallbacks = fallbacks
+            .into_iter()
+            .map(|(ranges, fallback_fonts)| {
+                let indices = fallback_fonts
+                    .into_iter()
+                    .map(|font| {
+                        fonts
+                            .iter()
+                            .position(|existing| Arc::ptr_eq(existing, &font))
+                            .unwrap_or_else(|| {
+                                fonts.push(font);
+                                fonts.len() - 1
+                            })
+                    })
+                    .collect();
+                (ranges, indices)
+            })
+            .collect();
+
         if fonts.is_empty() {
             return Self {
                 fonts,
+                num_family_fonts,
+                fallbacks,
                 characters: None,
                 replacement_glyph: Default::default(),
                 pixels_per_point: 1.0,
@@ -356,6 +681,8 @@ impl Font {
 
         let mut slf = Self {
             fonts,
+            num_family_fonts,
+            fallbacks,
             characters: None,
             replacement_glyph: Default::default(),
             pixels_per_point,
@@ -462,14 +789,72 @@ impl Font {
         (Some(font_impl), glyph_info)
     }
 
-    fn glyph_info_no_cache_or_fallback(&mut self, c: char) -> Option<(FontIndex, GlyphInfo)> {
-        for (font_index, font_impl) in self.fonts.iter().enumerate() {
-            if let Some(glyph_info) = font_impl.glyph_info(c) {
-                self.glyph_info_cache.insert(c, (font_index, glyph_info));
-                return Some((font_index, glyph_info));
+    /// Shape the text, returning the glyph of each character, or `None` for characters
+    /// that should be looked up one at a time.
+    ///
+    /// The text is split into runs of characters that use the same font, and each run is
+    /// shaped on its own. This gives ligatures, joining forms, reordering of Indic vowel signs
+    /// and kerning, for fonts that support them.
+    #[cfg(feature = "shaping")]
+    pub(crate) fn shape(&mut self, text: &str) -> Vec<Option<GlyphInfo>> {
+        let mut glyph_infos = Vec::with_capacity(text.len());
+        let mut run_start = 0;
+        let mut run_font = None;
+
+        for (byte_index, c) in text.char_indices() {
+            // Brackets are mirrored in right-to-left text by the bidi pass instead,
+            // and the other special characters are handled by `FontImpl::glyph_info`:
+            let shapeable = !matches!(c, '\n' | '\t' | '\u{2009}')
+                && !invisible_char(c)
+                && super::bidi::mirrored(c).is_none();
+            let font_index = if shapeable && !self.fonts.is_empty() {
+                let font_index_glyph_info = self.glyph_info(c);
+                (font_index_glyph_info != self.replacement_glyph).then_some(font_index_glyph_info.0)
+            } else {
+                None
+            };
+
+            if font_index != run_font || font_index.is_none() {
+                self.shape_run(&text[run_start..byte_index], run_font, &mut glyph_infos);
+                run_start = byte_index;
+                run_font = font_index;
             }
         }
-        None
+        self.shape_run(&text[run_start..], run_font, &mut glyph_infos);
+
+        glyph_infos
+    }
+
+    #[cfg(feature = "shaping")]
+    fn shape_run(
+        &self,
+        run: &str,
+        font_index: Option<FontIndex>,
+        out: &mut Vec<Option<GlyphInfo>>,
+    ) {
+        if let Some(font_index) = font_index {
+            out.extend(self.fonts[font_index].shape(run));
+        } else {
+            out.extend(run.chars().map(|_| None));
+        }
+    }
+
+    fn glyph_info_no_cache_or_fallback(&mut self, c: char) -> Option<(FontIndex, GlyphInfo)> {
+        let fallback_fonts = self
+            .fallbacks
+            .iter()
+            .filter(|(ranges, _)| ranges.iter().any(|range| range.contains(&c)))
+            .flat_map(|(_, font_indices)| font_indices.iter().copied());
+
+        let font_index_glyph_info =
+            fallback_fonts
+                .chain(0..self.num_family_fonts)
+                .find_map(|font_index| {
+                    let glyph_info = self.fonts[font_index].glyph_info(c)?;
+                    Some((font_index, glyph_info))
+                })?;
+        self.glyph_info_cache.insert(c, font_index_glyph_info);
+        Some(font_index_glyph_info)
     }
 }
 
@@ -491,7 +876,8 @@ fn invisible_char(c: char) -> bool {
 
     matches!(
         c,
-        '\u{200B}' // ZERO WIDTH SPACE
+        '\u{00AD}' // SOFT HYPHEN (only shown where a row is broken)
+            | '\u{200B}' // ZERO WIDTH SPACE
             | '\u{200C}' // ZERO WIDTH NON-JOINER
             | '\u{200D}' // ZERO WIDTH JOINER
             | '\u{200E}' // LEFT-TO-RIGHT MARK
@@ -1,4 +1,4 @@
-use std::{collections::BTreeMap, sync::Arc};
+use std::{collections::BTreeMap, ops::RangeInclusive, sync::Arc};
 
 use crate::{
     mutex::{Mutex, MutexGuard},
@@ -21,7 +21,12 @@ pub struct FontId {
 
     /// What font family to use.
     pub family: FontFamily,
-    // TODO(emilk): weight (bold), italics, …
+
+    /// Axis values for variable fonts, e.g. the weight.
+    ///
+    /// These override the [`FontTweak::variations`] of the fonts in the family.
+    pub variations: FontVariations,
+    // TODO(emilk): italics, …
 }
 
 impl Default for FontId {
@@ -30,6 +35,7 @@ impl Default for FontId {
         Self {
             size: 14.0,
             family: FontFamily::Proportional,
+            variations: FontVariations::NONE,
         }
     }
 }
@@ -37,7 +43,23 @@ impl Default for FontId {
 impl FontId {
     #[inline]
     pub const fn new(size: f32, family: FontFamily) -> Self {
-        Self { size, family }
+        Self {
+            size,
+            family,
+            variations: FontVariations::NONE,
+        }
+    }
+
+    /// Use these axis values for variable fonts.
+    ///
+    /// ```
+    /// # use epaint::text::{FontId, FontVariations};
+    /// let semi_bold = FontId::proportional(14.0).variations(FontVariations::NONE.weight(600.0));
+    /// ```
+    #[inline]
+    pub fn variations(mut self, variations: FontVariations) -> Self {
+        self.variations = variations;
+        self
     }
 
     #[inline]
@@ -55,9 +77,14 @@ impl FontId {
 impl std::hash::Hash for FontId {
     #[inline(always)]
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        let Self { size, family } = self;
+        let Self {
+            size,
+            family,
+            variations,
+        } = self;
         emath::OrderedFloat(*size).hash(state);
         family.hash(state);
+        variations.hash(state);
     }
 }
 
@@ -174,6 +201,13 @@ pub struct FontTweak {
     /// A positive value shifts the text downwards.
     /// A negative value shifts it upwards.
     pub baseline_offset_factor: f32,
+
+    /// Axis values to use if this is a variable font.
+    ///
+    /// This way a single variable font file can be added several times,
+    /// e.g. as a regular and a bold font.
+    /// [`FontId::variations`] overrides these.
+    pub variations: FontVariations,
 }
 
 impl Default for FontTweak {
@@ -183,21 +217,126 @@ impl Default for FontTweak {
             y_offset_factor: 0.0,
             y_offset: 0.0,
             baseline_offset_factor: -0.0333, // makes the default fonts look more centered in buttons and such
+            variations: FontVariations::NONE,
         }
     }
 }
 
 // ----------------------------------------------------------------------------
 
-fn ab_glyph_font_from_font_data(name: &str, data: &FontData) -> ab_glyph::FontArc {
+/// Values for the axes of an `OpenType` variable font.
+///
+/// Axes without a value use the default of the font.
+/// Values for axes that a font doesn't have are ignored,
+/// so these can also be used for families that mix variable and static fonts.
+///
+/// The glyphs are rasterized and cached separately for each combination of values,
+/// so avoid animating these.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FontVariations {
+    /// The `wght` axis, e.g. `400.0` for regular and `700.0` for bold.
+    pub weight: Option<f32>,
+
+    /// The `wdth` axis, in percent of the normal width, e.g. `75.0` for condensed text.
+    pub width: Option<f32>,
+
+    /// The `slnt` axis, in degrees counter-clockwise, e.g. `-10.0` to lean to the right.
+    pub slant: Option<f32>,
+}
+
+impl FontVariations {
+    /// Use the defaults of the font.
+    pub const NONE: Self = Self {
+        weight: None,
+        width: None,
+        slant: None,
+    };
+
+    /// Set the `wght` axis.
+    #[inline]
+    pub fn weight(mut self, weight: f32) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    /// Set the `wdth` axis.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the `slnt` axis.
+    #[inline]
+    pub fn slant(mut self, slant: f32) -> Self {
+        self.slant = Some(slant);
+        self
+    }
+
+    /// Use the values of `fallback` for the axes that have no value here.
+    #[inline]
+    pub fn or(self, fallback: Self) -> Self {
+        Self {
+            weight: self.weight.or(fallback.weight),
+            width: self.width.or(fallback.width),
+            slant: self.slant.or(fallback.slant),
+        }
+    }
+
+    /// The `OpenType` axis tags and values that are set.
+    pub(crate) fn axes(&self) -> impl Iterator<Item = (&'static [u8; 4], f32)> {
+        [
+            (b"wght", self.weight),
+            (b"wdth", self.width),
+            (b"slnt", self.slant),
+        ]
+        .into_iter()
+        .filter_map(|(tag, value)| Some((tag, value?)))
+    }
+}
+
+impl Eq for FontVariations {}
+
+impl std::hash::Hash for FontVariations {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self {
+            weight,
+            width,
+            slant,
+        } = self;
+        weight.map(OrderedFloat).hash(state);
+        width.map(OrderedFloat).hash(state);
+        slant.map(OrderedFloat).hash(state);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+fn ab_glyph_font_from_font_data(
+    name: &str,
+    data: &FontData,
+    variations: FontVariations,
+) -> ab_glyph::FontArc {
+    fn set_variations(font: &mut impl ab_glyph::VariableFont, variations: FontVariations) {
+        for (axis, value) in variations.axes() {
+            font.set_variation(axis, value);
+        }
+    }
+
     match &data.font {
         std::borrow::Cow::Borrowed(bytes) => {
-            ab_glyph::FontRef::try_from_slice_and_index(bytes, data.index)
-                .map(ab_glyph::FontArc::from)
+            ab_glyph::FontRef::try_from_slice_and_index(bytes, data.index).map(|mut font| {
+                set_variations(&mut font, variations);
+                ab_glyph::FontArc::from(font)
+            })
         }
         std::borrow::Cow::Owned(bytes) => {
-            ab_glyph::FontVec::try_from_vec_and_index(bytes.clone(), data.index)
-                .map(ab_glyph::FontArc::from)
+            ab_glyph::FontVec::try_from_vec_and_index(bytes.clone(), data.index).map(|mut font| {
+                set_variations(&mut font, variations);
+                ab_glyph::FontArc::from(font)
+            })
         }
     }
     .unwrap_or_else(|err| panic!("Error parsing {name:?} TTF/OTF font file: {err}"))
@@ -245,6 +384,29 @@ pub struct FontDefinitions {
     /// the first font and then move to the second, and so on.
     /// So the first font is the primary, and then comes a list of fallbacks in order of priority.
     pub families: BTreeMap<FontFamily, Vec<String>>,
+
+    /// Fonts to try first for the characters of certain scripts, per [`FontFamily`].
+    ///
+    /// For a character in the ranges of one or more [`FontFallback`]s of its family,
+    /// `epaint` first tries their fonts, in order, before the fonts in [`Self::families`].
+    /// This lets you pick e.g. one font for Latin text and another for CJK text in the same label:
+    ///
+    /// ```
+    /// # use epaint::text::{FontDefinitions, FontFallback, FontFamily};
+    /// let mut fonts = FontDefinitions::default();
+    /// // fonts.font_data.insert("my_brand_font".to_owned(), …);
+    /// // fonts.font_data.insert("NotoSansCJK".to_owned(), …);
+    /// # fonts.font_data.insert("my_brand_font".to_owned(), fonts.font_data["Ubuntu-Light"].clone());
+    /// # fonts.font_data.insert("NotoSansCJK".to_owned(), fonts.font_data["Ubuntu-Light"].clone());
+    /// fonts.fallbacks.insert(
+    ///     FontFamily::Proportional,
+    ///     vec![
+    ///         FontFallback::new(FontFallback::LATIN.iter().cloned(), ["my_brand_font"]),
+    ///         FontFallback::new(FontFallback::CJK.iter().cloned(), ["NotoSansCJK"]),
+    ///     ],
+    /// );
+    /// ```
+    pub fallbacks: BTreeMap<FontFamily, Vec<FontFallback>>,
 }
 
 impl Default for FontDefinitions {
@@ -319,6 +481,7 @@ impl Default for FontDefinitions {
         Self {
             font_data,
             families,
+            fallbacks: Default::default(),
         }
     }
 }
@@ -333,6 +496,42 @@ impl FontDefinitions {
         Self {
             font_data: Default::default(),
             families,
+            fallbacks: Default::default(),
+        }
+    }
+
+    /// Add a font, e.g. to support more characters.
+    ///
+    /// A font with the same name is replaced.
+    pub fn add_font(&mut self, font: FontInsert) {
+        let FontInsert {
+            name,
+            data,
+            families,
+        } = font;
+        self.font_data.insert(name.clone(), data);
+        for InsertFontFamily { family, priority } in families {
+            match priority {
+                FontPriority::Highest => {
+                    self.families
+                        .entry(family)
+                        .or_default()
+                        .insert(0, name.clone());
+                }
+                FontPriority::Lowest => {
+                    self.families.entry(family).or_default().push(name.clone());
+                }
+                FontPriority::Ranges(ranges) => {
+                    let fallback = FontFallback {
+                        ranges,
+                        fonts: vec![name.clone()],
+                    };
+                    self.fallbacks
+                        .entry(family)
+                        .or_default()
+                        .insert(0, fallback);
+                }
+            }
         }
     }
 
@@ -356,6 +555,149 @@ impl FontDefinitions {
 
 // ----------------------------------------------------------------------------
 
+/// Fonts to try first for the characters in some Unicode ranges, e.g. those of one script.
+///
+/// See [`FontDefinitions::fallbacks`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FontFallback {
+    /// The characters to use these fonts for.
+    pub ranges: Vec<RangeInclusive<char>>,
+
+    /// Keys into [`FontDefinitions::font_data`], in order of priority.
+    pub fonts: Vec<String>,
+}
+
+impl FontFallback {
+    /// Basic Latin, Latin-1 and the Latin Extended blocks.
+    pub const LATIN: &'static [RangeInclusive<char>] =
+        &['\u{0}'..='\u{24F}', '\u{1E00}'..='\u{1EFF}'];
+
+    /// Greek and Coptic.
+    pub const GREEK: &'static [RangeInclusive<char>] =
+        &['\u{370}'..='\u{3FF}', '\u{1F00}'..='\u{1FFF}'];
+
+    /// Cyrillic, including its supplement.
+    pub const CYRILLIC: &'static [RangeInclusive<char>] = &['\u{400}'..='\u{52F}'];
+
+    /// Hebrew.
+    pub const HEBREW: &'static [RangeInclusive<char>] = &['\u{590}'..='\u{5FF}'];
+
+    /// Arabic, including its supplement.
+    pub const ARABIC: &'static [RangeInclusive<char>] =
+        &['\u{600}'..='\u{6FF}', '\u{750}'..='\u{77F}'];
+
+    /// Devanagari.
+    pub const DEVANAGARI: &'static [RangeInclusive<char>] = &['\u{900}'..='\u{97F}'];
+
+    /// Thai.
+    pub const THAI: &'static [RangeInclusive<char>] = &['\u{E00}'..='\u{E7F}'];
+
+    /// Chinese, Japanese and Korean: the CJK ideographs, kana, Hangul,
+    /// CJK punctuation and full-width forms.
+    pub const CJK: &'static [RangeInclusive<char>] = &[
+        '\u{1100}'..='\u{11FF}',   // Hangul Jamo
+        '\u{2E80}'..='\u{2FDF}',   // CJK radicals
+        '\u{3000}'..='\u{30FF}',   // CJK symbols and punctuation, Hiragana, Katakana
+        '\u{3130}'..='\u{318F}',   // Hangul compatibility Jamo
+        '\u{3400}'..='\u{4DBF}',   // CJK unified ideographs extension A
+        '\u{4E00}'..='\u{9FFF}',   // CJK unified ideographs
+        '\u{AC00}'..='\u{D7AF}',   // Hangul syllables
+        '\u{F900}'..='\u{FAFF}',   // CJK compatibility ideographs
+        '\u{FF00}'..='\u{FFEF}',   // Half-width and full-width forms
+        '\u{20000}'..='\u{3134F}', // CJK unified ideographs extensions B-G
+    ];
+
+    /// Emoji and pictographs.
+    pub const EMOJI: &'static [RangeInclusive<char>] = &[
+        '\u{2600}'..='\u{27BF}',   // Miscellaneous symbols and dingbats
+        '\u{1F000}'..='\u{1F2FF}', // Mahjong, domino and playing cards, enclosed characters
+        '\u{1F300}'..='\u{1FAFF}', // Pictographs, emoticons, transport, etc
+    ];
+
+    /// Use `fonts` (keys into [`FontDefinitions::font_data`]) for the characters in `ranges`.
+    pub fn new(
+        ranges: impl IntoIterator<Item = RangeInclusive<char>>,
+        fonts: impl IntoIterator<Item = impl ToString>,
+    ) -> Self {
+        Self {
+            ranges: ranges.into_iter().collect(),
+            fonts: fonts.into_iter().map(|font| font.to_string()).collect(),
+        }
+    }
+
+    /// Does this apply to the given character?
+    pub fn contains(&self, c: char) -> bool {
+        self.ranges.iter().any(|range| range.contains(&c))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A font to add to the existing ones, see [`Fonts::add_font`] and [`FontDefinitions::add_font`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FontInsert {
+    /// The key of the font in [`FontDefinitions::font_data`].
+    pub name: String,
+
+    /// The font itself.
+    pub data: FontData,
+
+    /// Which families to add the font to, and where.
+    pub families: Vec<InsertFontFamily>,
+}
+
+impl FontInsert {
+    /// Add the font `data`, named `name`, to the given `families`.
+    pub fn new(name: &str, data: FontData, families: Vec<InsertFontFamily>) -> Self {
+        Self {
+            name: name.to_owned(),
+            data,
+            families,
+        }
+    }
+}
+
+/// A [`FontFamily`] to add a font to, see [`FontInsert`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InsertFontFamily {
+    /// The family to add the font to.
+    pub family: FontFamily,
+
+    /// Where in the family to add the font.
+    pub priority: FontPriority,
+}
+
+/// Where to add a font in a [`FontFamily`], see [`InsertFontFamily`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FontPriority {
+    /// Before the other fonts of the family, so it is used for all characters it has.
+    Highest,
+
+    /// After the other fonts of the family, so it is only used for the characters they lack.
+    Lowest,
+
+    /// Before the other fonts of the family, but only for the characters in these ranges.
+    ///
+    /// See [`FontDefinitions::fallbacks`].
+    Ranges(Vec<RangeInclusive<char>>),
+}
+
+impl FontPriority {
+    /// Will a font added with this priority be used for `c`, if it has a glyph for it?
+    ///
+    /// `had_glyph` is whether the family could already display `c`.
+    fn takes_over(&self, c: char, had_glyph: bool) -> bool {
+        match self {
+            Self::Highest => true,
+            Self::Lowest => !had_glyph,
+            Self::Ranges(ranges) => ranges.iter().any(|range| range.contains(&c)),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// The collection of fonts used by `epaint`.
 ///
 /// Required in order to paint text. Create one and reuse. Cheap to clone.
@@ -414,6 +756,15 @@ impl Fonts {
         fonts_and_cache.galley_cache.flush_cache();
     }
 
+    /// Add a font to the existing ones, e.g. to support more characters.
+    ///
+    /// Unlike creating new [`Fonts`] with changed [`FontDefinitions`], this keeps the glyphs
+    /// already in the font atlas, and only the cached [`Galley`]s whose text the new font changes
+    /// are laid out again.
+    pub fn add_font(&self, font: FontInsert) {
+        self.lock().add_font(font);
+    }
+
     /// Call at the end of each frame (before painting) to get the change to the font texture since last call.
     pub fn font_image_delta(&self) -> Option<crate::ImageDelta> {
         self.lock().fonts.atlas.lock().take_delta()
@@ -563,6 +914,58 @@ impl FontsAndCache {
     fn layout_job(&mut self, job: LayoutJob) -> Arc<Galley> {
         self.galley_cache.layout(&mut self.fonts, job)
     }
+
+    fn add_font(&mut self, font: FontInsert) {
+        use ab_glyph::Font as _;
+
+        let Self {
+            fonts,
+            galley_cache,
+        } = self;
+
+        if fonts.definitions.font_data.contains_key(&font.name) {
+            // Replacing a font can change any text, so start over.
+            let mut definitions = fonts.definitions.clone();
+            definitions.add_font(font);
+            *fonts = FontsImpl::new(fonts.pixels_per_point, fonts.max_texture_side, definitions);
+            *galley_cache = Default::default();
+            return;
+        }
+
+        // Forget the galleys with a character that will now be displayed with the new font.
+        let new_font =
+            ab_glyph_font_from_font_data(&font.name, &font.data, font.data.tweak.variations);
+        galley_cache.cache.retain(|_, cached| {
+            let job = &cached.galley.job;
+            !job.sections.iter().any(|section| {
+                let font_id = &section.format.font_id;
+                font.families
+                    .iter()
+                    .filter(|insert| insert.family == font_id.family)
+                    .any(|insert| {
+                        job.text[section.byte_range.clone()].chars().any(|c| {
+                            new_font.glyph_id(c).0 != 0
+                                && insert.priority.takes_over(c, fonts.has_glyph(font_id, c))
+                        })
+                    })
+            })
+        });
+
+        // The fonts of the changed families are recreated when next used,
+        // reusing the glyphs of their existing font implementations.
+        fonts
+            .font_impl_cache
+            .ab_glyph_fonts
+            .insert(font.name.clone(), (font.data.tweak, new_font));
+        fonts
+            .font_impl_cache
+            .font_data
+            .insert(font.name.clone(), Arc::new(font.data.clone()));
+        fonts.sized_family.retain(|(_, family, _), _| {
+            font.families.iter().all(|insert| &insert.family != family)
+        });
+        fonts.definitions.add_font(font);
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -576,7 +979,7 @@ pub struct FontsImpl {
     definitions: FontDefinitions,
     atlas: Arc<Mutex<TextureAtlas>>,
     font_impl_cache: FontImplCache,
-    sized_family: ahash::HashMap<(OrderedFloat<f32>, FontFamily), Font>,
+    sized_family: ahash::HashMap<(OrderedFloat<f32>, FontFamily, FontVariations), Font>,
 }
 
 impl FontsImpl {
@@ -623,10 +1026,14 @@ impl FontsImpl {
 
     /// Get the right font implementation from size and [`FontFamily`].
     pub fn font(&mut self, font_id: &FontId) -> &mut Font {
-        let FontId { size, family } = font_id;
+        let FontId {
+            size,
+            family,
+            variations,
+        } = font_id;
 
         self.sized_family
-            .entry((OrderedFloat(*size), family.clone()))
+            .entry((OrderedFloat(*size), family.clone(), *variations))
             .or_insert_with(|| {
                 let fonts = &self.definitions.families.get(family);
                 let fonts = fonts
@@ -634,10 +1041,32 @@ impl FontsImpl {
 
                 let fonts: Vec<Arc<FontImpl>> = fonts
                     .iter()
-                    .map(|font_name| self.font_impl_cache.font_impl(*size, font_name))
+                    .map(|font_name| {
+                        self.font_impl_cache
+                            .font_impl(*size, font_name, *variations)
+                    })
                     .collect();
 
-                Font::new(fonts)
+                let fallbacks = self
+                    .definitions
+                    .fallbacks
+                    .get(family)
+                    .into_iter()
+                    .flatten()
+                    .map(|fallback| {
+                        let fonts = fallback
+                            .fonts
+                            .iter()
+                            .map(|font_name| {
+                                self.font_impl_cache
+                                    .font_impl(*size, font_name, *variations)
+                            })
+                            .collect();
+                        (fallback.ranges.clone(), fonts)
+                    })
+                    .collect();
+
+                Font::with_fallbacks(fonts, fallbacks)
             })
     }
 
@@ -720,8 +1149,11 @@ struct FontImplCache {
     pixels_per_point: f32,
     ab_glyph_fonts: BTreeMap<String, (FontTweak, ab_glyph::FontArc)>,
 
-    /// Map font pixel sizes and names to the cached [`FontImpl`].
-    cache: ahash::HashMap<(u32, String), Arc<FontImpl>>,
+    /// The font files, for other variations of variable fonts, and for shaping text.
+    font_data: BTreeMap<String, Arc<FontData>>,
+
+    /// Map font pixel sizes, names and variations to the cached [`FontImpl`].
+    cache: ahash::HashMap<(u32, String, FontVariations), Arc<FontImpl>>,
 }
 
 impl FontImplCache {
@@ -734,7 +1166,7 @@ impl FontImplCache {
             .iter()
             .map(|(name, font_data)| {
                 let tweak = font_data.tweak;
-                let ab_glyph = ab_glyph_font_from_font_data(name, font_data);
+                let ab_glyph = ab_glyph_font_from_font_data(name, font_data, tweak.variations);
                 (name.clone(), (tweak, ab_glyph))
             })
             .collect();
@@ -743,11 +1175,20 @@ impl FontImplCache {
             atlas,
             pixels_per_point,
             ab_glyph_fonts,
+            font_data: font_data
+                .iter()
+                .map(|(name, font_data)| (name.clone(), Arc::new(font_data.clone())))
+                .collect(),
             cache: Default::default(),
         }
     }
 
-    pub fn font_impl(&mut self, scale_in_points: f32, font_name: &str) -> Arc<FontImpl> {
+    pub fn font_impl(
+        &mut self,
+        scale_in_points: f32,
+        font_name: &str,
+        variations: FontVariations,
+    ) -> Arc<FontImpl> {
         use ab_glyph::Font as _;
 
         let (tweak, ab_glyph_font) = self
@@ -765,20 +1206,37 @@ impl FontImplCache {
         let font_scaling = ab_glyph_font.height_unscaled() / units_per_em;
         let scale_in_pixels = scale_in_pixels * font_scaling;
 
+        let variations = variations.or(tweak.variations);
+
         self.cache
             .entry((
                 (scale_in_pixels * tweak.scale).round() as u32,
                 font_name.to_owned(),
+                variations,
             ))
             .or_insert_with(|| {
-                Arc::new(FontImpl::new(
+                let ab_glyph_font = if variations == tweak.variations {
+                    ab_glyph_font
+                } else {
+                    // Another instance of a variable font:
+                    let data = &self.font_data[font_name];
+                    ab_glyph_font_from_font_data(font_name, data, variations)
+                };
+
+                let font_impl = FontImpl::new(
                     self.atlas.clone(),
                     self.pixels_per_point,
                     font_name.to_owned(),
                     ab_glyph_font,
                     scale_in_pixels,
                     tweak,
-                ))
+                );
+                #[cfg(feature = "shaping")]
+                let font_impl = match self.font_data.get(font_name) {
+                    Some(data) => font_impl.with_shaping_data(data.clone(), variations),
+                    None => font_impl,
+                };
+                Arc::new(font_impl)
             })
             .clone()
     }
@@ -1,5 +1,6 @@
 //! Everything related to text, fonts, text layout, cursors etc.
 
+mod bidi;
 pub mod cursor;
 mod font;
 mod fonts;
@@ -10,10 +11,16 @@ mod text_layout_types;
 pub const TAB_SIZE: usize = 4;
 
 pub use {
-    fonts::{FontData, FontDefinitions, FontFamily, FontId, FontTweak, Fonts, FontsImpl},
+    fonts::{
+        FontData, FontDefinitions, FontFallback, FontFamily, FontId, FontInsert, FontPriority,
+        FontTweak, FontVariations, Fonts, FontsImpl, InsertFontFamily,
+    },
     text_layout::layout,
     text_layout_types::*,
 };
 
 /// Suggested character to use to replace those in password text fields.
 pub const PASSWORD_REPLACEMENT_CHAR: char = '•';
+
+/// Stands in for an inline image or widget in the text, see [`LayoutJob::append_inline`].
+pub const OBJECT_REPLACEMENT_CHARACTER: char = '\u{FFFC}';
@@ -5,7 +5,12 @@ use emath::*;
 
 use crate::{stroke::PathStroke, text::font::Font, Color32, Mesh, Stroke, Vertex};
 
-use super::{FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, Row, RowVisuals};
+use super::{FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, Row, RowVisuals, TextFormat};
+
+/// Invisible, except at the end of a row, where it is shown as a hyphen.
+///
+/// Put these in long words to allow breaking them in the middle.
+const SOFT_HYPHEN: char = '\u{AD}';
 
 // ----------------------------------------------------------------------------
 
@@ -101,6 +106,10 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
         }
     }
 
+    show_soft_hyphens(fonts, &job, &mut rows);
+
+    super::bidi::reorder_rows(fonts, &job, &mut rows);
+
     let justify = job.justify && job.wrap.max_width.is_finite();
 
     if justify || job.halign != Align::LEFT {
@@ -108,13 +117,15 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
         for (i, row) in rows.iter_mut().enumerate() {
             let is_last_row = i + 1 == num_rows;
             let justify_row = justify && !row.ends_with_newline && !is_last_row;
-            halign_and_justify_row(
-                point_scale,
-                row,
-                job.halign,
-                job.wrap.max_width,
-                justify_row,
-            );
+            in_visual_order(row, |row| {
+                halign_and_justify_row(
+                    point_scale,
+                    row,
+                    job.halign,
+                    job.wrap.max_width,
+                    justify_row,
+                );
+            });
         }
     }
 
@@ -136,11 +147,9 @@ fn layout_section(
         format,
     } = section;
     let font = fonts.font(&format.font_id);
-    let line_height = section
-        .format
-        .line_height
-        .unwrap_or_else(|| font.row_height());
+    let line_height = line_height(format, font);
     let extra_letter_spacing = section.format.extra_letter_spacing;
+    let extra_word_spacing = section.format.extra_word_spacing;
 
     let mut paragraph = out_paragraphs.last_mut().unwrap();
     if paragraph.glyphs.is_empty() {
@@ -151,19 +160,48 @@ fn layout_section(
 
     let mut last_glyph_id = None;
 
+    // With the `shaping` feature, most glyphs come from shaping the text,
+    // which also takes care of kerning. The rest are looked up one character at a time.
+    #[cfg(feature = "shaping")]
+    let mut shaped_glyphs = font.shape(&job.text[byte_range.clone()]).into_iter();
+    #[cfg(not(feature = "shaping"))]
+    let mut shaped_glyphs = std::iter::empty::<Option<super::font::GlyphInfo>>();
+
     for chr in job.text[byte_range.clone()].chars() {
+        let shaped_glyph = shaped_glyphs.next().flatten();
+
         if job.break_on_newline && chr == '\n' {
             out_paragraphs.push(Paragraph::from_section_index(section_index));
             paragraph = out_paragraphs.last_mut().unwrap();
             paragraph.empty_paragraph_height = line_height; // TODO(emilk): replace this hack with actually including `\n` in the glyphs?
+        } else if let Some(inline_size) = format.inline_size {
+            // An empty box for an inline image or widget, centered on the text:
+            let (font_impl, _) = font.font_impl_and_glyph_info(' ');
+            let text_ascent = font_impl.map_or(0.0, |font| font.ascent());
+            paragraph.glyphs.push(Glyph {
+                chr,
+                pos: pos2(paragraph.cursor_x, f32::NAN),
+                size: inline_size,
+                ascent: text_ascent + 0.5 * (inline_size.y - line_height),
+                uv_rect: Default::default(),
+                section_index,
+                rtl: false,
+            });
+
+            paragraph.cursor_x += inline_size.x + extra_letter_spacing;
+            paragraph.cursor_x = font.round_to_pixel(paragraph.cursor_x);
+            last_glyph_id = None;
         } else {
             let (font_impl, glyph_info) = font.font_impl_and_glyph_info(chr);
             if let Some(font_impl) = font_impl {
                 if let Some(last_glyph_id) = last_glyph_id {
-                    paragraph.cursor_x += font_impl.pair_kerning(last_glyph_id, glyph_info.id);
+                    if shaped_glyph.is_none() {
+                        paragraph.cursor_x += font_impl.pair_kerning(last_glyph_id, glyph_info.id);
+                    }
                     paragraph.cursor_x += extra_letter_spacing;
                 }
             }
+            let glyph_info = shaped_glyph.unwrap_or(glyph_info);
 
             paragraph.glyphs.push(Glyph {
                 chr,
@@ -172,15 +210,26 @@ fn layout_section(
                 ascent: font_impl.map_or(0.0, |font| font.ascent()), // Failure to find the font here would be weird
                 uv_rect: glyph_info.uv_rect,
                 section_index,
+                rtl: false,
             });
 
             paragraph.cursor_x += glyph_info.advance_width;
+            if chr.is_whitespace() {
+                paragraph.cursor_x += extra_word_spacing;
+            }
             paragraph.cursor_x = font.round_to_pixel(paragraph.cursor_x);
             last_glyph_id = Some(glyph_info.id);
         }
     }
 }
 
+/// The height of the rows of text with this format.
+fn line_height(format: &TextFormat, font: &Font) -> f32 {
+    format
+        .line_height
+        .unwrap_or_else(|| format.line_height_factor * font.row_height())
+}
+
 /// We ignore y at this stage
 fn rect_from_x_range(x_range: RangeInclusive<f32>) -> Rect {
     Rect::from_x_y_ranges(x_range, 0.0..=0.0)
@@ -215,6 +264,9 @@ fn rows_from_paragraphs(
                 ),
                 ends_with_newline: !is_last_paragraph,
             });
+        } else if job.vertical {
+            vertical_rows(paragraph, job, &mut rows, elided);
+            rows.last_mut().unwrap().ends_with_newline = !is_last_paragraph;
         } else {
             let paragraph_max_x = paragraph.glyphs.last().unwrap().max_x();
             if paragraph_max_x <= job.wrap.max_width {
@@ -237,6 +289,30 @@ fn rows_from_paragraphs(
     rows
 }
 
+/// One row per glyph, for [`LayoutJob::vertical`].
+fn vertical_rows(
+    paragraph: Paragraph,
+    job: &LayoutJob,
+    out_rows: &mut Vec<Row>,
+    elided: &mut bool,
+) {
+    for mut glyph in paragraph.glyphs {
+        if job.wrap.max_rows <= out_rows.len() {
+            *elided = true;
+            break;
+        }
+
+        glyph.pos.x = 0.0;
+        out_rows.push(Row {
+            section_index_at_start: glyph.section_index,
+            glyphs: vec![glyph],
+            visuals: Default::default(),
+            rect: rect_from_x_range(0.0..=glyph.max_x()),
+            ends_with_newline: false,
+        });
+    }
+}
+
 fn line_break(paragraph: &Paragraph, job: &LayoutJob, out_rows: &mut Vec<Row>, elided: &mut bool) {
     // Keeps track of good places to insert row break if we exceed `wrap_width`.
     let mut row_break_candidates = RowBreakCandidates::default();
@@ -352,10 +428,7 @@ fn replace_last_glyph_with_overflow_character(
     }
 
     fn row_height(section: &LayoutSection, font: &Font) -> f32 {
-        section
-            .format
-            .line_height
-            .unwrap_or_else(|| font.row_height())
+        line_height(&section.format, font)
     }
 
     let Some(overflow_character) = job.wrap.overflow_character else {
@@ -390,6 +463,7 @@ fn replace_last_glyph_with_overflow_character(
             ascent: font_impl.map_or(0.0, |font| font.ascent()), // Failure to find the font here would be weird
             uv_rect: replacement_glyph_info.uv_rect,
             section_index,
+            rtl: false,
         });
     } else {
         let section_index = row.section_index_at_start;
@@ -408,6 +482,7 @@ fn replace_last_glyph_with_overflow_character(
             ascent: font_impl.map_or(0.0, |font| font.ascent()), // Failure to find the font here would be weird
             uv_rect: replacement_glyph_info.uv_rect,
             section_index,
+            rtl: false,
         });
     }
 
@@ -475,6 +550,55 @@ fn replace_last_glyph_with_overflow_character(
     }
 }
 
+/// Show a hyphen at the end of each row that was broken at a [`SOFT_HYPHEN`].
+///
+/// Called before we have any Y coordinates.
+fn show_soft_hyphens(fonts: &mut FontsImpl, job: &LayoutJob, rows: &mut [Row]) {
+    let num_rows = rows.len();
+    for (i, row) in rows.iter_mut().enumerate() {
+        let is_last_row = i + 1 == num_rows;
+        if row.ends_with_newline || is_last_row {
+            continue;
+        }
+        let Some(last_glyph) = row.glyphs.last_mut() else {
+            continue;
+        };
+        if last_glyph.chr != SOFT_HYPHEN {
+            continue;
+        }
+
+        let section = &job.sections[last_glyph.section_index as usize];
+        let (_, glyph_info) = fonts
+            .font(&section.format.font_id)
+            .font_impl_and_glyph_info('-');
+        last_glyph.uv_rect = glyph_info.uv_rect;
+        last_glyph.size.x = glyph_info.advance_width;
+        row.rect.max.x = last_glyph.max_x();
+    }
+}
+
+/// Call `f` with the glyphs of the row sorted by their x position.
+///
+/// This is different from their logical order for right-to-left text.
+fn in_visual_order<R>(row: &mut Row, f: impl FnOnce(&mut Row) -> R) -> R {
+    if !row.glyphs.iter().any(|glyph| glyph.rtl) {
+        return f(row);
+    }
+
+    let mut order: Vec<usize> = (0..row.glyphs.len()).collect();
+    order.sort_by(|&a, &b| row.glyphs[a].pos.x.total_cmp(&row.glyphs[b].pos.x));
+    let mut logical = std::mem::take(&mut row.glyphs);
+    row.glyphs = order.iter().map(|&i| logical[i]).collect();
+
+    let result = f(row);
+
+    for (glyph, i) in row.glyphs.drain(..).zip(order) {
+        logical[i] = glyph;
+    }
+    row.glyphs = logical;
+    result
+}
+
 /// Horizontally aligned the text on a row.
 ///
 /// /// Ignores the Y coordinate.
@@ -532,25 +656,18 @@ fn halign_and_justify_row(
         .filter(|glyph| glyph.chr.is_whitespace())
         .count();
 
-    let mut extra_x_per_glyph = if num_glyphs_in_range == 1 {
-        0.0
-    } else {
-        (target_width - original_width) / (num_glyphs_in_range as f32 - 1.0)
-    };
-    extra_x_per_glyph = extra_x_per_glyph.at_least(0.0); // Don't contract
-
+    let mut extra_x_per_glyph = 0.0;
     let mut extra_x_per_space = 0.0;
     if 0 < num_spaces_in_range && num_spaces_in_range < num_glyphs_in_range {
-        // Add an integral number of pixels between each glyph,
-        // and add the balance to the spaces:
-
-        extra_x_per_glyph = point_scale.floor_to_pixel(extra_x_per_glyph);
-
-        extra_x_per_space = (target_width
-            - original_width
-            - extra_x_per_glyph * (num_glyphs_in_range as f32 - 1.0))
-            / (num_spaces_in_range as f32);
+        // Stretch the spaces between the words:
+        extra_x_per_space = (target_width - original_width) / (num_spaces_in_range as f32);
+    } else if 1 < num_glyphs_in_range {
+        // No words to spread out, so spread out the letters instead:
+        extra_x_per_glyph = point_scale
+            .floor_to_pixel((target_width - original_width) / (num_glyphs_in_range as f32 - 1.0));
     }
+    extra_x_per_glyph = extra_x_per_glyph.at_least(0.0); // Don't contract
+    extra_x_per_space = extra_x_per_space.at_least(0.0);
 
     let mut translate_x = target_min_x - original_min_x - extra_x_per_glyph * glyph_range.0 as f32;
 
@@ -605,7 +722,7 @@ fn galley_from_rows(
                 // raised text.
                 Align::Min => glyph.ascent,
             };
-            glyph.pos.y = cursor_y + align_offset;
+            glyph.pos.y = cursor_y + align_offset + format.baseline_offset;
         }
 
         row.rect.min.y = cursor_y;
@@ -614,6 +731,9 @@ fn galley_from_rows(
         min_x = min_x.min(row.rect.min.x);
         max_x = max_x.max(row.rect.max.x);
         cursor_y += line_height;
+        if row.ends_with_newline {
+            cursor_y += job.paragraph_spacing;
+        }
         cursor_y = point_scale.round_to_pixel(cursor_y);
     }
 
@@ -624,7 +744,9 @@ fn galley_from_rows(
     let mut num_indices = 0;
 
     for row in &mut rows {
-        row.visuals = tessellate_row(point_scale, &job, &format_summary, row);
+        row.visuals = in_visual_order(row, |row| {
+            tessellate_row(point_scale, &job, &format_summary, row)
+        });
         mesh_bounds = mesh_bounds.union(row.visuals.mesh_bounds);
         num_vertices += row.visuals.mesh.vertices.len();
         num_indices += row.visuals.mesh.indices.len();
@@ -649,6 +771,7 @@ struct FormatSummary {
     any_background: bool,
     any_underline: bool,
     any_strikethrough: bool,
+    any_stroke: bool,
 }
 
 fn format_summary(job: &LayoutJob) -> FormatSummary {
@@ -657,6 +780,7 @@ fn format_summary(job: &LayoutJob) -> FormatSummary {
         format_summary.any_background |= section.format.background != Color32::TRANSPARENT;
         format_summary.any_underline |= section.format.underline != Stroke::NONE;
         format_summary.any_strikethrough |= section.format.strikethrough != Stroke::NONE;
+        format_summary.any_stroke |= !section.format.stroke.is_empty();
     }
     format_summary
 }
@@ -680,10 +804,17 @@ fn tessellate_row(
         add_row_backgrounds(job, row, &mut mesh);
     }
 
+    if format_summary.any_stroke {
+        // The outlines go before the glyphs, so they are painted behind them:
+        tessellate_glyph_outlines(point_scale, job, row, &mut mesh);
+    }
+
     let glyph_vertex_start = mesh.vertices.len();
     tessellate_glyphs(point_scale, job, row, &mut mesh);
     let glyph_vertex_end = mesh.vertices.len();
 
+    tessellate_colored_glyphs(point_scale, job, row, &mut mesh);
+
     if format_summary.any_underline {
         add_row_hline(point_scale, row, &mut mesh, |glyph| {
             let format = &job.sections[glyph.section_index as usize].format;
@@ -758,56 +889,132 @@ fn add_row_backgrounds(job: &LayoutJob, row: &Row, mesh: &mut Mesh) {
 
 fn tessellate_glyphs(point_scale: PointScale, job: &LayoutJob, row: &Row, mesh: &mut Mesh) {
     for glyph in &row.glyphs {
-        let uv_rect = glyph.uv_rect;
-        if !uv_rect.is_nothing() {
-            let mut left_top = glyph.pos + uv_rect.offset;
-            left_top.x = point_scale.round_to_pixel(left_top.x);
-            left_top.y = point_scale.round_to_pixel(left_top.y);
-
-            let rect = Rect::from_min_max(left_top, left_top + uv_rect.size);
-            let uv = Rect::from_min_max(
-                pos2(uv_rect.min[0] as f32, uv_rect.min[1] as f32),
-                pos2(uv_rect.max[0] as f32, uv_rect.max[1] as f32),
+        if !glyph.uv_rect.colored {
+            let format = &job.sections[glyph.section_index as usize].format;
+            add_glyph(
+                point_scale,
+                glyph,
+                format.italics,
+                Vec2::ZERO,
+                format.color,
+                mesh,
             );
+        }
+    }
+}
 
+/// Glyphs with their own colors, like color emoji, are not tinted by the text color.
+///
+/// They are kept out of [`RowVisuals::glyph_vertex_range`] so that
+/// [`crate::TextShape::override_text_color`] doesn't affect them either.
+fn tessellate_colored_glyphs(point_scale: PointScale, job: &LayoutJob, row: &Row, mesh: &mut Mesh) {
+    for glyph in &row.glyphs {
+        if glyph.uv_rect.colored {
             let format = &job.sections[glyph.section_index as usize].format;
+            add_glyph(
+                point_scale,
+                glyph,
+                format.italics,
+                Vec2::ZERO,
+                Color32::WHITE,
+                mesh,
+            );
+        }
+    }
+}
 
-            let color = format.color;
-
-            if format.italics {
-                let idx = mesh.vertices.len() as u32;
-                mesh.add_triangle(idx, idx + 1, idx + 2);
-                mesh.add_triangle(idx + 2, idx + 1, idx + 3);
-
-                let top_offset = rect.height() * 0.25 * Vec2::X;
+/// Outline the glyphs with [`crate::text::TextFormat::stroke`].
+///
+/// Each glyph is drawn in the stroke color many times, offset in all directions
+/// by up to the stroke width.
+fn tessellate_glyph_outlines(point_scale: PointScale, job: &LayoutJob, row: &Row, mesh: &mut Mesh) {
+    for glyph in &row.glyphs {
+        let format = &job.sections[glyph.section_index as usize].format;
+        let stroke = format.stroke;
+        if stroke.is_empty() || glyph.uv_rect.is_nothing() || glyph.uv_rect.colored {
+            continue;
+        }
 
-                mesh.vertices.push(Vertex {
-                    pos: rect.left_top() + top_offset,
-                    uv: uv.left_top(),
-                    color,
-                });
-                mesh.vertices.push(Vertex {
-                    pos: rect.right_top() + top_offset,
-                    uv: uv.right_top(),
-                    color,
-                });
-                mesh.vertices.push(Vertex {
-                    pos: rect.left_bottom(),
-                    uv: uv.left_bottom(),
-                    color,
-                });
-                mesh.vertices.push(Vertex {
-                    pos: rect.right_bottom(),
-                    uv: uv.right_bottom(),
-                    color,
-                });
-            } else {
-                mesh.add_rect_with_uv(rect, uv, color);
+        // One ring of copies per pixel of width, with the copies about a pixel apart:
+        let width_in_pixels = stroke.width * point_scale.pixels_per_point;
+        let num_rings = width_in_pixels.ceil().max(1.0) as usize;
+        for ring in 1..=num_rings {
+            let radius = stroke.width * ring as f32 / num_rings as f32;
+            let circumference_in_pixels =
+                std::f32::consts::TAU * radius * point_scale.pixels_per_point;
+            let num_copies = (circumference_in_pixels.ceil() as usize).max(8);
+            for i in 0..num_copies {
+                let angle = std::f32::consts::TAU * i as f32 / num_copies as f32;
+                let offset = radius * Vec2::angled(angle);
+                add_glyph(
+                    point_scale,
+                    glyph,
+                    format.italics,
+                    offset,
+                    stroke.color,
+                    mesh,
+                );
             }
         }
     }
 }
 
+fn add_glyph(
+    point_scale: PointScale,
+    glyph: &Glyph,
+    italics: bool,
+    offset: Vec2,
+    color: Color32,
+    mesh: &mut Mesh,
+) {
+    let uv_rect = glyph.uv_rect;
+    if uv_rect.is_nothing() {
+        return;
+    }
+
+    let mut left_top = glyph.pos + uv_rect.offset;
+    left_top.x = point_scale.round_to_pixel(left_top.x);
+    left_top.y = point_scale.round_to_pixel(left_top.y);
+    left_top += offset;
+
+    let rect = Rect::from_min_max(left_top, left_top + uv_rect.size);
+    let uv = Rect::from_min_max(
+        pos2(uv_rect.min[0] as f32, uv_rect.min[1] as f32),
+        pos2(uv_rect.max[0] as f32, uv_rect.max[1] as f32),
+    );
+
+    if italics {
+        let idx = mesh.vertices.len() as u32;
+        mesh.add_triangle(idx, idx + 1, idx + 2);
+        mesh.add_triangle(idx + 2, idx + 1, idx + 3);
+
+        let top_offset = rect.height() * 0.25 * Vec2::X;
+
+        mesh.vertices.push(Vertex {
+            pos: rect.left_top() + top_offset,
+            uv: uv.left_top(),
+            color,
+        });
+        mesh.vertices.push(Vertex {
+            pos: rect.right_top() + top_offset,
+            uv: uv.right_top(),
+            color,
+        });
+        mesh.vertices.push(Vertex {
+            pos: rect.left_bottom(),
+            uv: uv.left_bottom(),
+            color,
+        });
+        mesh.vertices.push(Vertex {
+            pos: rect.right_bottom(),
+            uv: uv.right_bottom(),
+            color,
+        });
+    } else {
+        mesh.add_rect_with_uv(rect, uv, color);
+    }
+}
+
 /// Add a horizontal line over a row of glyphs with a stroke and y decided by a callback.
 fn add_row_hline(
     point_scale: PointScale,
@@ -874,7 +1081,7 @@ fn add_hline(point_scale: PointScale, [start, stop]: [Pos2; 2], stroke: Stroke,
 // ----------------------------------------------------------------------------
 
 /// Keeps track of good places to break a long row of text.
-/// Will focus primarily on spaces, secondarily on things like `-`
+/// Will focus primarily on spaces, secondarily on things like `-` and soft hyphens
 #[derive(Clone, Copy, Default)]
 struct RowBreakCandidates {
     /// Breaking at ` ` or other whitespace
@@ -887,7 +1094,7 @@ struct RowBreakCandidates {
     /// Breaking anywhere before a CJK character is acceptable too.
     pre_cjk: Option<usize>,
 
-    /// Breaking at a dash is a super-
+    /// Breaking at a dash or a [`SOFT_HYPHEN`] is a super-
     /// good idea.
     dash: Option<usize>,
 
@@ -908,7 +1115,7 @@ impl RowBreakCandidates {
             self.space = Some(index);
         } else if is_cjk(chr) && (glyphs.len() == 1 || is_cjk_break_allowed(glyphs[1].chr)) {
             self.cjk = Some(index);
-        } else if chr == '-' {
+        } else if chr == '-' || chr == SOFT_HYPHEN {
             self.dash = Some(index);
         } else if chr.is_ascii_punctuation() {
             self.punctuation = Some(index);
@@ -1014,6 +1221,175 @@ mod tests {
         assert_eq!(galley.rows.len(), 1);
     }
 
+    #[test]
+    fn test_line_height_and_paragraph_spacing() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+        let row_height = fonts.font(&FontId::default()).row_height();
+
+        let mut layout_job = LayoutJob::single_section(
+            "a\nb".into(),
+            TextFormat {
+                line_height_factor: 2.0,
+                ..Default::default()
+            },
+        );
+        layout_job.paragraph_spacing = 10.0;
+        let galley = layout(&mut fonts, layout_job.into());
+
+        assert_eq!(galley.rows.len(), 2);
+        assert_eq!(galley.rows[0].height(), (2.0 * row_height).round());
+        assert_eq!(galley.rows[1].min_y(), galley.rows[0].max_y() + 10.0);
+    }
+
+    #[test]
+    fn test_letter_and_word_spacing() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+        let mut glyph_x = |format: TextFormat| -> Vec<f32> {
+            let layout_job = LayoutJob::single_section("ab cd".into(), format);
+            let galley = layout(&mut fonts, layout_job.into());
+            galley.rows[0]
+                .glyphs
+                .iter()
+                .map(|glyph| glyph.pos.x)
+                .collect()
+        };
+
+        let normal = glyph_x(TextFormat::default());
+        let spaced = glyph_x(TextFormat {
+            extra_letter_spacing: 2.0,
+            extra_word_spacing: 10.0,
+            ..Default::default()
+        });
+
+        assert_eq!(spaced[1] - spaced[0], normal[1] - normal[0] + 2.0);
+        assert_eq!(spaced[3] - spaced[2], normal[3] - normal[2] + 10.0 + 2.0);
+        assert_eq!(spaced[4] - spaced[3], normal[4] - normal[3] + 2.0);
+    }
+
+    #[test]
+    fn test_inline_rects() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+        let mut layout_job = LayoutJob::default();
+        layout_job.append("Look: ", 0.0, TextFormat::default());
+        layout_job.append_inline(vec2(40.0, 30.0), 0.0, TextFormat::default());
+        layout_job.append(" and ", 0.0, TextFormat::default());
+        layout_job.append_inline(vec2(8.0, 8.0), 0.0, TextFormat::default());
+        let galley = layout(&mut fonts, layout_job.into());
+
+        assert_eq!(galley.rows.len(), 1);
+        assert_eq!(
+            galley.rows[0].height(),
+            30.0,
+            "Tall enough for the inline object"
+        );
+
+        let rects = galley.inline_rects();
+        assert_eq!(rects.len(), 2);
+        assert_eq!(rects[0].size(), vec2(40.0, 30.0));
+        assert_eq!(rects[0].top(), 0.0);
+        assert_eq!(rects[1].size(), vec2(8.0, 8.0));
+        assert!(rects[0].right() < rects[1].left());
+        assert_eq!(rects[1].right(), galley.rect.right());
+    }
+
+    #[test]
+    fn test_vertical() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+        let mut layout_job =
+            LayoutJob::single_section("日本語\n縦書き".into(), TextFormat::default());
+        layout_job.vertical = true;
+        layout_job.halign = Align::Center;
+        let galley = layout(&mut fonts, layout_job.into());
+
+        assert_eq!(galley.rows.len(), 6);
+        assert!(galley.rows.iter().all(|row| row.glyphs.len() == 1));
+        assert!(galley.rows[2].ends_with_newline);
+        assert_eq!(galley.rows[3].text(), "縦");
+        for pair in galley.rows.windows(2) {
+            assert_eq!(pair[0].max_y(), pair[1].min_y());
+            assert_eq!(pair[0].rect.center().x, pair[1].rect.center().x);
+        }
+
+        // Cursors move from row to row:
+        let cursor = galley.from_ccursor(cursor::CCursor::new(4));
+        assert_eq!(cursor.rcursor.row, 3);
+    }
+
+    #[test]
+    fn test_bidi_visual_order() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+        let text = "abc \u{5D0}\u{5D1}\u{5D2} 123";
+        let layout_job = LayoutJob::single_section(text.into(), TextFormat::default());
+        let galley = layout(&mut fonts, layout_job.into());
+        let row = &galley.rows[0];
+
+        // The glyphs stay in logical order:
+        assert_eq!(row.text(), text);
+
+        let visual_text: String = {
+            let mut glyphs = row.glyphs.clone();
+            glyphs.sort_by(|a, b| a.pos.x.total_cmp(&b.pos.x));
+            glyphs.iter().map(|glyph| glyph.chr).collect()
+        };
+        assert_eq!(visual_text, "abc 123 \u{5D2}\u{5D1}\u{5D0}");
+
+        let rtl: Vec<bool> = row.glyphs.iter().map(|glyph| glyph.rtl).collect();
+        assert_eq!(
+            rtl,
+            [false, false, false, false, true, true, true, true, false, false, false]
+        );
+
+        // The cursor before the first hebrew letter is at its right edge:
+        assert_eq!(row.x_offset(4), row.glyphs[4].max_x());
+
+        // Away from the boundaries between the directions, we get back the same column:
+        for column in [1, 2, 5, 6, 9, 10] {
+            assert_eq!(row.char_at(row.x_offset(column)), column);
+        }
+    }
+
+    #[test]
+    fn test_soft_hyphen() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+        let text = "extra\u{AD}ordinary";
+
+        let layout_job = LayoutJob::single_section(text.into(), TextFormat::default());
+        let galley = layout(&mut fonts, layout_job.clone().into());
+        assert_eq!(galley.rows.len(), 1);
+        assert_eq!(
+            galley.rows[0].glyphs[5].size.x, 0.0,
+            "Invisible within a row"
+        );
+        let full_width = galley.size().x;
+
+        let mut layout_job = layout_job;
+        layout_job.wrap.max_width = 0.75 * full_width;
+        let galley = layout(&mut fonts, layout_job.into());
+        assert_eq!(galley.rows.len(), 2);
+        assert_eq!(galley.rows[0].text(), "extra\u{AD}");
+        assert_eq!(galley.rows[1].text(), "ordinary");
+        assert!(0.0 < galley.rows[0].glyphs[5].size.x, "Shown as a hyphen");
+    }
+
+    #[test]
+    fn test_justify_stretches_spaces() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+        let mut layout_job =
+            LayoutJob::single_section("aa bb cc dd ee ff gg".into(), TextFormat::default());
+        layout_job.wrap.max_width = 50.0;
+        let ragged = layout(&mut fonts, layout_job.clone().into());
+        layout_job.justify = true;
+        let justified = layout(&mut fonts, layout_job.into());
+        assert!(2 <= justified.rows.len());
+
+        let row = &justified.rows[0];
+        assert_eq!(row.rect.width(), 50.0);
+
+        // The letters of a word stay together:
+        let letter_spacing = |row: &Row| row.glyphs[4].pos.x - row.glyphs[3].pos.x;
+        assert_eq!(letter_spacing(row), letter_spacing(&ragged.rows[0]));
+    }
+
     #[test]
     fn test_truncate_with_newline() {
         // No matter where we wrap, we should be appending the newline character.
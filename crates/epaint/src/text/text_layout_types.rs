@@ -4,7 +4,7 @@
 use std::ops::Range;
 use std::sync::Arc;
 
-use super::{cursor::*, font::UvRect};
+use super::{cursor::*, font::UvRect, OBJECT_REPLACEMENT_CHARACTER};
 use crate::{Color32, FontId, Mesh, Stroke};
 use emath::*;
 
@@ -73,7 +73,29 @@ pub struct LayoutJob {
     pub halign: Align,
 
     /// Justify text so that word-wrapped rows fill the whole [`TextWrapping::max_width`].
+    ///
+    /// The spaces between the words are stretched to fill the row.
+    /// Rows without spaces get extra space between the letters instead.
+    /// The last row of each paragraph is not justified.
     pub justify: bool,
+
+    /// Extra vertical space after each paragraph (i.e. after each `\n`), in points.
+    ///
+    /// Default: `0.0`.
+    pub paragraph_spacing: f32,
+
+    /// Lay out the text top-to-bottom, with one character per row,
+    /// e.g. for vertical CJK typesetting.
+    ///
+    /// The characters stay upright. Each `\n` results in an empty row.
+    /// [`TextWrapping::max_width`] is ignored, but [`TextWrapping::max_rows`] still applies.
+    /// Combine with [`Self::halign`] set to [`Align::Center`] to center the characters on each other.
+    ///
+    /// For text that should be rotated as a whole, e.g. a y-axis label,
+    /// use [`crate::TextShape::angle`] instead.
+    ///
+    /// Default: `false`.
+    pub vertical: bool,
 }
 
 impl Default for LayoutJob {
@@ -87,6 +109,8 @@ impl Default for LayoutJob {
             break_on_newline: true,
             halign: Align::LEFT,
             justify: false,
+            paragraph_spacing: 0.0,
+            vertical: false,
         }
     }
 }
@@ -159,6 +183,26 @@ impl LayoutJob {
         });
     }
 
+    /// Reserve room for an inline image or widget of the given size, e.g. an emoji image in a chat message.
+    ///
+    /// This adds a single [`OBJECT_REPLACEMENT_CHARACTER`] to the text,
+    /// which is laid out like any other character.
+    /// After layout, use [`Galley::inline_rects`] to find where to paint it.
+    ///
+    /// The `format` is used for the background, underline, and the height of the row
+    /// that the inline object is centered in.
+    pub fn append_inline(&mut self, size: Vec2, leading_space: f32, format: TextFormat) {
+        let format = TextFormat {
+            inline_size: Some(size),
+            ..format
+        };
+        self.append(
+            &OBJECT_REPLACEMENT_CHARACTER.to_string(),
+            leading_space,
+            format,
+        );
+    }
+
     /// The height of the tallest font used in the job.
     pub fn font_height(&self, fonts: &crate::Fonts) -> f32 {
         let mut max_height = 0.0_f32;
@@ -180,6 +224,8 @@ impl std::hash::Hash for LayoutJob {
             break_on_newline,
             halign,
             justify,
+            paragraph_spacing,
+            vertical,
         } = self;
 
         text.hash(state);
@@ -189,6 +235,8 @@ impl std::hash::Hash for LayoutJob {
         break_on_newline.hash(state);
         halign.hash(state);
         justify.hash(state);
+        emath::OrderedFloat(*paragraph_spacing).hash(state);
+        vertical.hash(state);
     }
 }
 
@@ -235,15 +283,33 @@ pub struct TextFormat {
     /// For even text it is recommended you round this to an even number of _pixels_.
     pub extra_letter_spacing: f32,
 
+    /// Extra spacing after each space (and other whitespace), in points.
+    ///
+    /// This is added on top of [`Self::extra_letter_spacing`].
+    ///
+    /// Default: 0.0.
+    ///
+    /// For even text it is recommended you round this to an even number of _pixels_.
+    pub extra_word_spacing: f32,
+
     /// Explicit line height of the text in points.
     ///
     /// This is the distance between the bottom row of two subsequent lines of text.
     ///
-    /// If `None` (the default), the line height is determined by the font.
+    /// If `None` (the default), the line height is determined by the font
+    /// and [`Self::line_height_factor`].
     ///
     /// For even text it is recommended you round this to an even number of _pixels_.
     pub line_height: Option<f32>,
 
+    /// Multiplies the line height given by the font,
+    /// e.g. `0.9` for dense tables or `1.5` for airy text.
+    ///
+    /// Ignored if [`Self::line_height`] is set.
+    ///
+    /// Default: `1.0`.
+    pub line_height_factor: f32,
+
     /// Text color
     pub color: Color32,
 
@@ -255,9 +321,34 @@ pub struct TextFormat {
 
     pub strikethrough: Stroke,
 
+    /// An outline around each glyph, drawn behind the text.
+    ///
+    /// This keeps text readable on top of backgrounds of any color, e.g. labels on a map
+    /// or over a video. The width is in points.
+    ///
+    /// Default: [`Stroke::NONE`].
+    pub stroke: Stroke,
+
     /// If you use a small font and [`Align::TOP`] you
     /// can get the effect of raised text.
     pub valign: Align,
+
+    /// Move the text vertically from the baseline, in points.
+    ///
+    /// Negative values move the text up, e.g. for superscripts,
+    /// and positive values move it down, e.g. for subscripts.
+    ///
+    /// Default: `0.0`.
+    pub baseline_offset: f32,
+
+    /// If set, each character of this section is laid out as an empty box of this size,
+    /// making room for an inline image or widget.
+    ///
+    /// The box is vertically centered on the text of the row.
+    /// Use [`LayoutJob::append_inline`] to add one, and [`Galley::inline_rects`] to find it after layout.
+    ///
+    /// Default: `None`.
+    pub inline_size: Option<Vec2>,
     // TODO(emilk): lowered
 }
 
@@ -267,13 +358,18 @@ impl Default for TextFormat {
         Self {
             font_id: FontId::default(),
             extra_letter_spacing: 0.0,
+            extra_word_spacing: 0.0,
             line_height: None,
+            line_height_factor: 1.0,
             color: Color32::GRAY,
             background: Color32::TRANSPARENT,
             italics: false,
             underline: Stroke::NONE,
             strikethrough: Stroke::NONE,
+            stroke: Stroke::NONE,
             valign: Align::BOTTOM,
+            baseline_offset: 0.0,
+            inline_size: None,
         }
     }
 }
@@ -284,25 +380,37 @@ impl std::hash::Hash for TextFormat {
         let Self {
             font_id,
             extra_letter_spacing,
+            extra_word_spacing,
             line_height,
+            line_height_factor,
             color,
             background,
             italics,
             underline,
             strikethrough,
+            stroke,
             valign,
+            baseline_offset,
+            inline_size,
         } = self;
         font_id.hash(state);
         emath::OrderedFloat(*extra_letter_spacing).hash(state);
+        emath::OrderedFloat(*extra_word_spacing).hash(state);
         if let Some(line_height) = *line_height {
             emath::OrderedFloat(line_height).hash(state);
         }
+        emath::OrderedFloat(*line_height_factor).hash(state);
         color.hash(state);
         background.hash(state);
         italics.hash(state);
         underline.hash(state);
         strikethrough.hash(state);
+        stroke.hash(state);
         valign.hash(state);
+        emath::OrderedFloat(*baseline_offset).hash(state);
+        inline_size
+            .map(|size| [OrderedFloat(size.x), OrderedFloat(size.y)])
+            .hash(state);
     }
 }
 
@@ -551,6 +659,12 @@ pub struct Glyph {
 
     /// Index into [`LayoutJob::sections`]. Decides color etc.
     pub section_index: u32,
+
+    /// Is this glyph part of right-to-left text, e.g. Arabic or Hebrew?
+    ///
+    /// The glyphs of a [`Row`] are always in logical order (the order of the text),
+    /// so the glyphs of right-to-left text have decreasing x positions.
+    pub rtl: bool,
 }
 
 impl Glyph {
@@ -603,21 +717,92 @@ impl Row {
     /// Closest char at the desired x coordinate.
     /// Returns something in the range `[0, char_count_excluding_newline()]`.
     pub fn char_at(&self, desired_x: f32) -> usize {
-        for (i, glyph) in self.glyphs.iter().enumerate() {
-            if desired_x < glyph.logical_rect().center().x {
-                return i;
+        if !self.has_rtl() {
+            for (i, glyph) in self.glyphs.iter().enumerate() {
+                if desired_x < glyph.logical_rect().center().x {
+                    return i;
+                }
             }
+            return self.char_count_excluding_newline();
         }
-        self.char_count_excluding_newline()
+
+        // The glyphs are not sorted by x, so find the one closest to `desired_x`:
+        let distance = |glyph: &Glyph| {
+            if desired_x < glyph.pos.x {
+                glyph.pos.x - desired_x
+            } else {
+                (desired_x - glyph.max_x()).at_least(0.0)
+            }
+        };
+        let Some((i, glyph)) = self
+            .glyphs
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| distance(a).total_cmp(&distance(b)))
+        else {
+            return 0;
+        };
+
+        // The right half of a left-to-right glyph, or the left half of a right-to-left glyph,
+        // is before the next character:
+        let right_half = glyph.logical_rect().center().x <= desired_x;
+        i + usize::from(right_half != glyph.rtl)
     }
 
+    /// The x coordinate of the cursor before the character at `column`.
     pub fn x_offset(&self, column: usize) -> f32 {
         if let Some(glyph) = self.glyphs.get(column) {
-            glyph.pos.x
+            if glyph.rtl {
+                glyph.max_x()
+            } else {
+                glyph.pos.x
+            }
+        } else if let Some(last) = self.glyphs.last().filter(|_| self.has_rtl()) {
+            // After the last character, which may be anywhere in the row:
+            if last.rtl {
+                last.pos.x
+            } else {
+                last.max_x()
+            }
         } else {
             self.rect.right()
         }
     }
+
+    /// The horizontal extents of the characters in the given column range, from left to right.
+    ///
+    /// This is a single range, unless the row mixes left-to-right and right-to-left text,
+    /// in which case the characters may not be next to each other.
+    pub fn x_ranges(&self, columns: Range<usize>) -> Vec<Rangef> {
+        if !self.has_rtl() {
+            return vec![Rangef::new(
+                self.x_offset(columns.start),
+                self.x_offset(columns.end),
+            )];
+        }
+
+        let end = columns.end.min(self.glyphs.len());
+        let start = columns.start.min(end);
+        let mut glyph_ranges: Vec<Rangef> = self.glyphs[start..end]
+            .iter()
+            .map(|glyph| Rangef::new(glyph.pos.x, glyph.max_x()))
+            .collect();
+        glyph_ranges.sort_by(|a, b| a.min.total_cmp(&b.min));
+
+        let mut ranges: Vec<Rangef> = vec![];
+        for range in glyph_ranges {
+            match ranges.last_mut() {
+                Some(last) if range.min <= last.max + 0.5 => last.max = last.max.max(range.max),
+                _ => ranges.push(range),
+            }
+        }
+        ranges
+    }
+
+    /// Does this row contain any right-to-left text?
+    fn has_rtl(&self) -> bool {
+        self.glyphs.iter().any(|glyph| glyph.rtl)
+    }
 }
 
 impl Galley {
@@ -636,6 +821,25 @@ impl Galley {
     pub fn size(&self) -> Vec2 {
         self.rect.size()
     }
+
+    /// Where the inline objects added with [`LayoutJob::append_inline`] ended up,
+    /// in the same order as in the text.
+    ///
+    /// The rectangles are relative to the galley position.
+    /// Inline objects that were elided are not included.
+    pub fn inline_rects(&self) -> Vec<Rect> {
+        self.rows
+            .iter()
+            .flat_map(|row| row.glyphs.iter())
+            .filter(|glyph| {
+                self.job.sections[glyph.section_index as usize]
+                    .format
+                    .inline_size
+                    .is_some()
+            })
+            .map(|glyph| glyph.logical_rect())
+            .collect()
+    }
 }
 
 impl AsRef<str> for Galley {